@@ -0,0 +1,114 @@
+use common_lib::domain::trade::PayoutModel;
+use serde::Serialize;
+
+// モデルごとの検証結果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    pub model_no: i32,
+    // 予想した回数（取引しなかった回数を含む）
+    pub forecasts: u64,
+    // 予想した方向（上昇・下落）が的中した回数・割合（取引しなかった予想を含む）
+    pub hits: u64,
+    pub hit_rate: f64,
+    // 予想値の平均絶対誤差
+    pub mae: f64,
+    pub trades: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+    // 引き分けを除いた勝率
+    pub win_rate: f64,
+    // 1回の取引あたりの期待値
+    pub expected_value: f64,
+    // 掛金1で取引した場合の損益の合計
+    pub total_profit: f64,
+    // 損益の累計の最大値からの最大下落幅
+    pub max_drawdown: f64,
+    #[serde(skip)]
+    peak_profit: f64,
+    #[serde(skip)]
+    abs_error_sum: f64,
+}
+
+impl Report {
+    pub fn new(model_no: i32) -> Self {
+        Report {
+            model_no,
+            ..Default::default()
+        }
+    }
+
+    pub fn record(&mut self, payout: &PayoutModel, current: f64, forecast: f64, truth: f64) {
+        self.forecasts += 1;
+        if (forecast - current) * (truth - current) > 0.0 {
+            self.hits += 1;
+        }
+        self.hit_rate = self.hits as f64 / self.forecasts as f64;
+        self.abs_error_sum += (forecast - truth).abs();
+        self.mae = self.abs_error_sum / self.forecasts as f64;
+
+        let direction = match payout.decide(current, forecast) {
+            Some(d) => d,
+            None => return,
+        };
+
+        let profit = payout.profit(direction, current, truth);
+        self.trades += 1;
+        if profit > 0.0 {
+            self.wins += 1;
+        } else if profit < 0.0 {
+            self.losses += 1;
+        } else {
+            self.draws += 1;
+        }
+
+        self.total_profit += profit;
+        self.peak_profit = self.peak_profit.max(self.total_profit);
+        self.max_drawdown = self.max_drawdown.max(self.peak_profit - self.total_profit);
+
+        let decided = self.wins + self.losses;
+        if decided > 0 {
+            self.win_rate = self.wins as f64 / decided as f64;
+        }
+        self.expected_value = self.total_profit / self.trades as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::domain::trade::Direction;
+
+    use super::*;
+
+    #[test]
+    fn test_for_report() {
+        let payout = PayoutModel {
+            payout_ratio: 0.8,
+            trade_threshold: 0.05,
+        };
+        assert_eq!(payout.decide(100.0, 100.01), None);
+        assert_eq!(payout.decide(100.0, 100.1), Some(Direction::High));
+        assert_eq!(payout.decide(100.0, 99.9), Some(Direction::Low));
+
+        let mut report = Report::new(1);
+        // 的中、的中、外れ、外れ、引き分け、取引なし
+        report.record(&payout, 100.0, 100.1, 100.2);
+        report.record(&payout, 100.0, 99.9, 99.8);
+        report.record(&payout, 100.0, 100.1, 99.9);
+        report.record(&payout, 100.0, 99.9, 100.1);
+        report.record(&payout, 100.0, 100.1, 100.0);
+        report.record(&payout, 100.0, 100.0, 100.5);
+
+        assert_eq!(report.forecasts, 6);
+        // 予想・実際のどちらかが変化しない場合は方向の的中に含めない
+        assert_eq!(report.hits, 2);
+        assert!((report.hit_rate - 2.0 / 6.0).abs() < 1e-9);
+        assert!((report.mae - 0.2).abs() < 1e-9);
+        assert_eq!(report.trades, 5);
+        assert_eq!((report.wins, report.losses, report.draws), (2, 2, 1));
+        assert!((report.total_profit - (-0.4)).abs() < 1e-9);
+        assert!((report.max_drawdown - 2.0).abs() < 1e-9);
+        assert!((report.win_rate - 0.5).abs() < 1e-9);
+        assert!((report.expected_value - (-0.08)).abs() < 1e-9);
+    }
+}
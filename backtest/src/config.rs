@@ -0,0 +1,135 @@
+use chrono::NaiveDateTime;
+use common_lib::{
+    config::Validator,
+    error::{MyError, MyResult},
+};
+use serde::Deserialize;
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // 共通設定
+    pub forecast_offset_minutes: usize,
+
+    // 検証する通貨ペア
+    pub backtest_pair: String,
+    // 検証するモデルの番号（カンマ区切り、空の場合は通貨ペアのモデルすべて）
+    #[serde(default)]
+    pub backtest_model_nos: Vec<i32>,
+    // 検証に使うレートの期間（yyyy-mm-dd hh:mm:ss）
+    pub backtest_from: String,
+    pub backtest_to: String,
+    // 直前のレートで埋める欠損の最大分数（これより長い欠損をまたぐ取引は行わない）
+    #[serde(default = "default_backtest_fill_gap_minutes")]
+    pub backtest_fill_gap_minutes: usize,
+
+    // 的中した場合の払戻率（掛金に対する利益の割合、例: 0.85）
+    pub backtest_payout_ratio: f64,
+    // 取引する予想変化幅の下限（予想レートと現在レートの差がこれ未満の場合は取引しない）
+    #[serde(default)]
+    pub backtest_trade_threshold: f64,
+
+    // 検証結果を backtest_results テーブルに保存する場合は true
+    #[serde(default = "default_backtest_save_results")]
+    pub backtest_save_results: bool,
+}
+
+fn default_backtest_fill_gap_minutes() -> usize {
+    5
+}
+
+fn default_backtest_save_results() -> bool {
+    true
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("forecast_offset_minutes", self.forecast_offset_minutes);
+        v.check_not_empty("backtest_pair", &self.backtest_pair);
+        match (self.get_from(), self.get_to()) {
+            (Ok(from), Ok(to)) => v.check(
+                from < to,
+                format!(
+                    "backtest_from must be before backtest_to, from: {}, to: {}",
+                    from, to
+                ),
+            ),
+            (from, to) => {
+                for err in [from.err(), to.err()].into_iter().flatten() {
+                    v.check(false, err.to_string());
+                }
+            }
+        }
+        v.check_positive("backtest_payout_ratio", self.backtest_payout_ratio);
+        v.check(
+            self.backtest_trade_threshold >= 0.0,
+            format!(
+                "backtest_trade_threshold must not be negative, value: {}",
+                self.backtest_trade_threshold
+            ),
+        );
+        v.finish()
+    }
+
+    pub fn get_from(&self) -> MyResult<NaiveDateTime> {
+        parse_datetime("backtest_from", &self.backtest_from)
+    }
+
+    pub fn get_to(&self) -> MyResult<NaiveDateTime> {
+        parse_datetime("backtest_to", &self.backtest_to)
+    }
+}
+
+fn parse_datetime(name: &str, value: &str) -> MyResult<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, DATETIME_FORMAT).map_err(|err| MyError::ParseError {
+        param_name: name.to_string(),
+        value: value.to_string(),
+        memo: format!("{}", err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            forecast_offset_minutes: 5,
+            backtest_pair: "USDJPY".to_string(),
+            backtest_model_nos: vec![1],
+            backtest_from: "2022-01-01 00:00:00".to_string(),
+            backtest_to: "2022-01-08 00:00:00".to_string(),
+            backtest_fill_gap_minutes: 5,
+            backtest_payout_ratio: 0.85,
+            backtest_trade_threshold: 0.0,
+            backtest_save_results: true,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(config().validate().is_ok());
+
+        let c = Config {
+            backtest_pair: "".to_string(),
+            backtest_from: "2022-01-08 00:00:00".to_string(),
+            backtest_to: "2022-01-01 00:00:00".to_string(),
+            backtest_payout_ratio: 0.0,
+            backtest_trade_threshold: -0.1,
+            ..config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+
+        let c = Config {
+            backtest_from: "2022/01/01".to_string(),
+            ..config()
+        };
+        assert!(c.validate().is_err());
+    }
+}
@@ -0,0 +1,192 @@
+extern crate common_lib;
+
+use common_lib::{
+    domain::{
+        backtest::BacktestResult, model::ForecastModel, series, service::convert_to_feature,
+        trade::PayoutModel,
+    },
+    error::MyResult,
+    logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+};
+use log::{error, info, warn};
+
+use crate::backtest::Report;
+
+mod backtest;
+mod config;
+
+const JOB_NAME: &str = "backtest";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    let config: config::Config;
+    match common_lib::config::load::<config::Config>() {
+        Ok(c) => {
+            config = c;
+        }
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli: mysql::client::DefaultClient;
+    match mysql::util::make_cli() {
+        Ok(cli) => {
+            mysql_cli = cli;
+        }
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    }
+
+    match run(&config, &mysql_cli) {
+        Ok(reports) => {
+            // 集計結果は他のツールで扱えるよう標準出力にJSONで出力する
+            match serde_json::to_string_pretty(&reports) {
+                Ok(s) => println!("{}", s),
+                Err(err) => error!("failed to serialize reports, error: {}", err),
+            }
+        }
+        Err(err) => {
+            error!("failed to run backtest, error: {}", err);
+        }
+    }
+}
+
+// 過去のレートを1分ずつ進めながら各モデルで予想し、予想に従って取引した場合の損益を集計する
+fn run(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<Vec<Report>> {
+    let pair = &config.backtest_pair;
+    let (from, to) = (config.get_from()?, config.get_to()?);
+    info!(
+        "start backtest, pair: {}, from: {}, to: {}, payout_ratio: {}, trade_threshold: {}",
+        pair, from, to, config.backtest_payout_ratio, config.backtest_trade_threshold
+    );
+
+    let (models, rates) = mysql_cli.with_transaction(|tx| {
+        Ok((
+            mysql_cli.select_forecast_models(tx, pair)?,
+            mysql_cli.select_rates_for_training(tx, pair, Some(from), Some(to))?,
+        ))
+    })?;
+    let models: Vec<ForecastModel> = models
+        .into_iter()
+        .filter(|m| match m.get_no() {
+            Ok(no) => {
+                config.backtest_model_nos.is_empty() || config.backtest_model_nos.contains(&no)
+            }
+            Err(_) => false,
+        })
+        .collect();
+    info!(
+        "model count: {}, rates count: {}",
+        models.len(),
+        rates.len()
+    );
+
+    // 長い欠損をまたいで取引しないよう、欠損の無い区間ごとに検証する
+    let mut points = series::resample_minutely(&rates);
+    series::forward_fill(&mut points, config.backtest_fill_gap_minutes);
+    let segments = series::continuous_segments(&points);
+
+    let payout = PayoutModel {
+        payout_ratio: config.backtest_payout_ratio,
+        trade_threshold: config.backtest_trade_threshold,
+    };
+    let mut reports = vec![];
+    let mut results = vec![];
+    for model in &models {
+        let report = backtest_model(config, &payout, model, &segments)?;
+        info!(
+            "backtest finished, model_no: {}, forecasts: {}, hit_rate: {:.4}, mae: {:.6}, trades: {}, win_rate: {:.4}, expected_value: {:.4}, total_profit: {:.4}, max_drawdown: {:.4}",
+            report.model_no,
+            report.forecasts,
+            report.hit_rate,
+            report.mae,
+            report.trades,
+            report.win_rate,
+            report.expected_value,
+            report.total_profit,
+            report.max_drawdown
+        );
+        results.push(BacktestResult {
+            pair: pair.to_string(),
+            model_no: report.model_no,
+            model_memo: model.get_memo(),
+            begin_at: from,
+            end_at: to,
+            forecast_offset_minutes: config.forecast_offset_minutes,
+            forecast_count: report.forecasts,
+            hit_rate: report.hit_rate,
+            mae: report.mae,
+            trade_count: report.trades,
+            win_rate: report.win_rate,
+            expected_value: report.expected_value,
+            total_profit: report.total_profit,
+            max_drawdown: report.max_drawdown,
+        });
+        reports.push(report);
+    }
+
+    // モデル同士を後から比較できるよう、検証結果を保存する
+    if config.backtest_save_results {
+        mysql_cli.with_transaction(|tx| {
+            for result in results.iter() {
+                mysql_cli.insert_backtest_result(tx, result)?;
+            }
+            Ok(())
+        })?;
+        info!("saved backtest results, count: {}", results.len());
+    }
+    Ok(reports)
+}
+
+fn backtest_model(
+    config: &config::Config,
+    payout: &PayoutModel,
+    model: &ForecastModel,
+    segments: &[Vec<f64>],
+) -> MyResult<Report> {
+    let model_no = model.get_no()?;
+    let input_size = model.get_input_data_size()?;
+    let params = model.get_feature_params()?;
+    let offset = config.forecast_offset_minutes;
+
+    let mut report = Report::new(model_no);
+    for rates in segments {
+        if rates.len() < input_size + offset {
+            continue;
+        }
+        for begin in 0..=(rates.len() - input_size - offset) {
+            let input = rates[begin..begin + input_size].to_vec();
+            let current = rates[begin + input_size - 1];
+            let truth = rates[begin + input_size - 1 + offset];
+
+            let forecast = match convert_to_feature(&input, &params).and_then(|f| model.predict(&f))
+            {
+                Ok(v) => v,
+                Err(err) => {
+                    warn!("forecast skipped, model_no: {}, error: {}", model_no, err);
+                    continue;
+                }
+            };
+            report.record(payout, current, forecast, truth);
+        }
+    }
+    Ok(report)
+}
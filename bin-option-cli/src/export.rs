@@ -0,0 +1,389 @@
+use std::{fs::File, io, path::Path, sync::Arc};
+
+use chrono::NaiveDateTime;
+use clap::ArgEnum;
+use common_lib::{
+    error::MyResult,
+    mysql::client::{Client, DefaultClient},
+};
+use parquet::{
+    basic::Compression,
+    data_type::{ByteArray, ByteArrayType, DataType, DoubleType, Int32Type, Int64Type},
+    errors::ParquetError,
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedFileWriter, SerializedRowGroupWriter},
+    },
+    schema::parser::parse_message_type,
+};
+use serde::{Serialize, Serializer};
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+// 1回のクエリで取得する件数
+const PAGE_SIZE: u32 = 10000;
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+pub enum Target {
+    // 学習用レート（rates_for_training）
+    Rates,
+    // 予想結果（forecast_results）
+    Results,
+    // 予想結果と実績値の組（forecast_evaluations）
+    Evaluations,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+pub enum Format {
+    Csv,
+    // 列の型を保ったまま pandas や duckdb で読み込める
+    Parquet,
+}
+
+#[derive(Serialize)]
+struct RateRow {
+    pair: String,
+    #[serde(serialize_with = "serialize_datetime")]
+    recorded_at: NaiveDateTime,
+    rate: f64,
+}
+
+#[derive(Serialize)]
+struct ResultRow {
+    id: String,
+    rate_id: String,
+    model_no: i32,
+    forecast_type: i32,
+    result: f64,
+    memo: String,
+    #[serde(serialize_with = "serialize_datetime")]
+    created_at: NaiveDateTime,
+}
+
+#[derive(Serialize)]
+struct EvaluationRow {
+    id: String,
+    forecast_result_id: String,
+    pair: String,
+    model_no: i32,
+    forecast_type: i32,
+    forecast: f64,
+    actual: f64,
+    #[serde(serialize_with = "serialize_datetime")]
+    target_at: NaiveDateTime,
+}
+
+// Parquetの列の定義と書き出し
+// 日時はタイムゾーン無しのタイムスタンプ（UTC、ミリ秒）とする
+trait ParquetRow: Sized {
+    const SCHEMA: &'static str;
+
+    fn write_columns(rows: &[Self], row_group: &mut RowGroup) -> MyResult<()>;
+}
+
+impl ParquetRow for RateRow {
+    const SCHEMA: &'static str = "
+        message rate {
+            REQUIRED BYTE_ARRAY pair (UTF8);
+            REQUIRED INT64 recorded_at (TIMESTAMP(MILLIS,false));
+            REQUIRED DOUBLE rate;
+        }
+    ";
+
+    fn write_columns(rows: &[Self], row_group: &mut RowGroup) -> MyResult<()> {
+        row_group.write_strings(rows.iter().map(|r| r.pair.as_str()))?;
+        row_group.write_datetimes(rows.iter().map(|r| &r.recorded_at))?;
+        row_group.write::<DoubleType>(rows.iter().map(|r| r.rate).collect())
+    }
+}
+
+impl ParquetRow for ResultRow {
+    const SCHEMA: &'static str = "
+        message result {
+            REQUIRED BYTE_ARRAY id (UTF8);
+            REQUIRED BYTE_ARRAY rate_id (UTF8);
+            REQUIRED INT32 model_no;
+            REQUIRED INT32 forecast_type;
+            REQUIRED DOUBLE result;
+            REQUIRED BYTE_ARRAY memo (UTF8);
+            REQUIRED INT64 created_at (TIMESTAMP(MILLIS,false));
+        }
+    ";
+
+    fn write_columns(rows: &[Self], row_group: &mut RowGroup) -> MyResult<()> {
+        row_group.write_strings(rows.iter().map(|r| r.id.as_str()))?;
+        row_group.write_strings(rows.iter().map(|r| r.rate_id.as_str()))?;
+        row_group.write::<Int32Type>(rows.iter().map(|r| r.model_no).collect())?;
+        row_group.write::<Int32Type>(rows.iter().map(|r| r.forecast_type).collect())?;
+        row_group.write::<DoubleType>(rows.iter().map(|r| r.result).collect())?;
+        row_group.write_strings(rows.iter().map(|r| r.memo.as_str()))?;
+        row_group.write_datetimes(rows.iter().map(|r| &r.created_at))
+    }
+}
+
+impl ParquetRow for EvaluationRow {
+    const SCHEMA: &'static str = "
+        message evaluation {
+            REQUIRED BYTE_ARRAY id (UTF8);
+            REQUIRED BYTE_ARRAY forecast_result_id (UTF8);
+            REQUIRED BYTE_ARRAY pair (UTF8);
+            REQUIRED INT32 model_no;
+            REQUIRED INT32 forecast_type;
+            REQUIRED DOUBLE forecast;
+            REQUIRED DOUBLE actual;
+            REQUIRED INT64 target_at (TIMESTAMP(MILLIS,false));
+        }
+    ";
+
+    fn write_columns(rows: &[Self], row_group: &mut RowGroup) -> MyResult<()> {
+        row_group.write_strings(rows.iter().map(|r| r.id.as_str()))?;
+        row_group.write_strings(rows.iter().map(|r| r.forecast_result_id.as_str()))?;
+        row_group.write_strings(rows.iter().map(|r| r.pair.as_str()))?;
+        row_group.write::<Int32Type>(rows.iter().map(|r| r.model_no).collect())?;
+        row_group.write::<Int32Type>(rows.iter().map(|r| r.forecast_type).collect())?;
+        row_group.write::<DoubleType>(rows.iter().map(|r| r.forecast).collect())?;
+        row_group.write::<DoubleType>(rows.iter().map(|r| r.actual).collect())?;
+        row_group.write_datetimes(rows.iter().map(|r| &r.target_at))
+    }
+}
+
+// 1ページ分の行を列ごとに書き出す（スキーマの列の順に呼び出す）
+struct RowGroup<'a> {
+    writer: SerializedRowGroupWriter<'a, File>,
+}
+
+impl RowGroup<'_> {
+    fn write<T: DataType>(&mut self, values: Vec<T::T>) -> MyResult<()> {
+        let mut column = self
+            .writer
+            .next_column()
+            .map_err(parquet_error)?
+            .ok_or_else(|| io::Error::other("number of columns does not match the schema"))?;
+        column
+            .typed::<T>()
+            .write_batch(&values, None, None)
+            .map_err(parquet_error)?;
+        column.close().map_err(parquet_error)?;
+        Ok(())
+    }
+
+    fn write_strings<'b>(&mut self, values: impl Iterator<Item = &'b str>) -> MyResult<()> {
+        self.write::<ByteArrayType>(values.map(ByteArray::from).collect())
+    }
+
+    fn write_datetimes<'b>(
+        &mut self,
+        values: impl Iterator<Item = &'b NaiveDateTime>,
+    ) -> MyResult<()> {
+        self.write::<Int64Type>(values.map(|t| t.and_utc().timestamp_millis()).collect())
+    }
+}
+
+fn parquet_error(err: ParquetError) -> io::Error {
+    io::Error::other(err)
+}
+
+// コマンドライン引数の日時（yyyy-mm-dd hh:mm:ss）
+pub fn parse_datetime(value: &str) -> Result<NaiveDateTime, String> {
+    NaiveDateTime::parse_from_str(value, DATETIME_FORMAT)
+        .map_err(|err| format!("invalid datetime, value: {}, error: {}", value, err))
+}
+
+fn serialize_datetime<S: Serializer>(t: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&t.format(DATETIME_FORMAT))
+}
+
+// 指定期間のデータをファイルに書き出し、書き出した件数を返す
+pub fn export(
+    mysql_cli: &DefaultClient,
+    target: Target,
+    pair: &str,
+    from: Option<NaiveDateTime>,
+    to: Option<NaiveDateTime>,
+    format: Format,
+    output: &Path,
+) -> MyResult<u64> {
+    match target {
+        Target::Rates => write_file(format, output, |offset| {
+            let rates = mysql_cli.with_transaction(|tx| {
+                mysql_cli.select_rates_for_training_paged(tx, pair, from, to, PAGE_SIZE, offset)
+            })?;
+            Ok(rates
+                .into_iter()
+                .map(|r| RateRow {
+                    pair: r.pair,
+                    recorded_at: r.recorded_at,
+                    rate: r.rate,
+                })
+                .collect())
+        }),
+        Target::Results => write_file(format, output, |offset| {
+            let results = mysql_cli.with_transaction(|tx| {
+                mysql_cli.select_forecast_results_paged(tx, pair, from, to, PAGE_SIZE, offset)
+            })?;
+            Ok(results
+                .into_iter()
+                .map(|r| ResultRow {
+                    id: r.id,
+                    rate_id: r.rate_id,
+                    model_no: r.model_no,
+                    forecast_type: r.forecast_type,
+                    result: r.result,
+                    memo: r.memo.unwrap_or_default(),
+                    created_at: r.created_at,
+                })
+                .collect())
+        }),
+        Target::Evaluations => write_file(format, output, |offset| {
+            let evaluations = mysql_cli.with_transaction(|tx| {
+                mysql_cli.select_forecast_evaluations_paged(tx, pair, from, to, PAGE_SIZE, offset)
+            })?;
+            Ok(evaluations
+                .into_iter()
+                .map(|e| EvaluationRow {
+                    id: e.id,
+                    forecast_result_id: e.forecast_result_id,
+                    pair: e.pair,
+                    model_no: e.model_no,
+                    forecast_type: e.forecast_type,
+                    forecast: e.forecast,
+                    actual: e.actual,
+                    target_at: e.target_at,
+                })
+                .collect())
+        }),
+    }
+}
+
+fn write_file<T, F>(format: Format, output: &Path, fetch: F) -> MyResult<u64>
+where
+    T: Serialize + ParquetRow,
+    F: FnMut(u32) -> MyResult<Vec<T>>,
+{
+    let file = File::create(output)?;
+    match format {
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(file);
+            let count = write_pages(fetch, |rows| {
+                for row in rows {
+                    writer.serialize(row).map_err(io::Error::from)?;
+                }
+                Ok(())
+            })?;
+            writer.flush()?;
+            Ok(count)
+        }
+        Format::Parquet => {
+            let schema = parse_message_type(T::SCHEMA).map_err(parquet_error)?;
+            let properties = WriterProperties::builder()
+                .set_compression(Compression::SNAPPY)
+                .build();
+            let mut writer =
+                SerializedFileWriter::new(file, Arc::new(schema), Arc::new(properties))
+                    .map_err(parquet_error)?;
+            // 1ページを1つの行グループにする
+            let count = write_pages(fetch, |rows| {
+                let mut row_group = RowGroup {
+                    writer: writer.next_row_group().map_err(parquet_error)?,
+                };
+                T::write_columns(&rows, &mut row_group)?;
+                row_group.writer.close().map_err(parquet_error)?;
+                Ok(())
+            })?;
+            writer.close().map_err(parquet_error)?;
+            Ok(count)
+        }
+    }
+}
+
+// 全件をメモリに載せないよう、ページ単位で取得しながら書き出す
+fn write_pages<T, F, W>(mut fetch: F, mut write: W) -> MyResult<u64>
+where
+    F: FnMut(u32) -> MyResult<Vec<T>>,
+    W: FnMut(Vec<T>) -> MyResult<()>,
+{
+    let mut count: u64 = 0;
+    let mut offset: u32 = 0;
+    loop {
+        let rows = fetch(offset)?;
+        let size = rows.len();
+        if size > 0 {
+            write(rows)?;
+        }
+
+        count += size as u64;
+        if size < PAGE_SIZE as usize {
+            return Ok(count);
+        }
+        offset += PAGE_SIZE;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::RowAccessor,
+    };
+
+    use super::*;
+
+    fn rates() -> Vec<RateRow> {
+        vec![
+            RateRow {
+                pair: "USDJPY".to_string(),
+                recorded_at: parse_datetime("2022-01-01 00:00:00").unwrap(),
+                rate: 100.1,
+            },
+            RateRow {
+                pair: "USDJPY".to_string(),
+                recorded_at: parse_datetime("2022-01-01 00:01:00").unwrap(),
+                rate: 100.2,
+            },
+        ]
+    }
+
+    fn output(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("export_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_for_write_file_csv() {
+        let output = output("csv");
+        let count = write_file(Format::Csv, &output, |_| Ok(rates())).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            "pair,recorded_at,rate\n\
+             USDJPY,2022-01-01 00:00:00,100.1\n\
+             USDJPY,2022-01-01 00:01:00,100.2\n"
+        );
+        fs::remove_file(&output).unwrap();
+    }
+
+    #[test]
+    fn test_for_write_file_parquet() {
+        let output = output("parquet");
+        let count = write_file(Format::Parquet, &output, |_| Ok(rates())).unwrap();
+        assert_eq!(count, 2);
+
+        let reader = SerializedFileReader::try_from(File::open(&output).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+        let rows: Vec<_> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect();
+        for (row, expected) in rows.iter().zip(rates().iter()) {
+            assert_eq!(row.get_string(0).unwrap(), &expected.pair);
+            assert_eq!(
+                row.get_timestamp_millis(1).unwrap(),
+                expected.recorded_at.and_utc().timestamp_millis()
+            );
+            assert_eq!(row.get_double(2).unwrap(), expected.rate);
+        }
+        fs::remove_file(&output).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+use std::{collections::BTreeMap, fs::File, io, path::Path};
+
+use chrono::{Duration, NaiveDateTime};
+use common_lib::{
+    domain::model::RateForTraining,
+    error::{MyError, MyResult},
+    mysql::client::{Client, DefaultClient},
+};
+use indicatif::{ProgressBar, ProgressStyle};
+use log::warn;
+
+// CSVの列と形式の対応
+#[derive(Debug, Clone)]
+pub struct ColumnMapping {
+    pub time_column: String,
+    pub rate_column: String,
+    // 指定しない場合は pair を使う
+    pub pair_column: Option<String>,
+    pub time_format: String,
+    // CSVの日時のUTCからのずれ（DBにはUTCで登録する）
+    pub utc_offset: Duration,
+    pub delimiter: u8,
+}
+
+// 取り込み結果の集計
+#[derive(Debug, Default)]
+pub struct Summary {
+    // 読み込んだ行数（ヘッダを除く）
+    pub rows: u64,
+    pub invalid: u64,
+    // ファイル内で同じ通貨ペア・日時のレートが重複していた件数（後の行を使う）
+    pub duplicated: u64,
+    pub inserted: u64,
+    // 登録済みのため無視した件数
+    pub skipped: u64,
+}
+
+// タイムゾーンを UTC からのずれに変換する（例: "UTC", "Z", "+09:00", "-0530"）
+pub fn parse_utc_offset(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid timezone, value: {}", value);
+    if value.eq_ignore_ascii_case("utc") || value == "Z" {
+        return Ok(Duration::zero());
+    }
+
+    let (sign, rest) = match value.chars().next() {
+        Some('+') => (1, &value[1..]),
+        Some('-') => (-1, &value[1..]),
+        _ => return Err(invalid()),
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let hours: i64 = digits[0..2].parse().map_err(|_| invalid())?;
+    let minutes: i64 = digits[2..4].parse().map_err(|_| invalid())?;
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok(Duration::minutes(sign * (hours * 60 + minutes)))
+}
+
+// 区切り文字を変換する（タブはコマンドラインで渡しにくいため tab, \t でも指定できる）
+// 省略した場合は、拡張子が .tsv ならタブ、それ以外はカンマとする
+pub fn parse_delimiter(value: Option<&str>, input: &Path) -> Result<u8, String> {
+    let value = match value {
+        Some(v) => v,
+        None => {
+            let is_tsv = matches!(
+                input.extension().and_then(|e| e.to_str()),
+                Some(e) if e.eq_ignore_ascii_case("tsv")
+            );
+            return Ok(if is_tsv { b'\t' } else { b',' });
+        }
+    };
+    match value {
+        "tab" | "\\t" => Ok(b'\t'),
+        v if v.len() == 1 && v.is_ascii() => Ok(v.as_bytes()[0]),
+        v => Err(format!(
+            "delimiter must be an ascii character or tab, value: {}",
+            v
+        )),
+    }
+}
+
+// CSVを読み込み、重複を除いたレートを通貨ペア・日時の順に返す
+pub fn read_rates<R: io::Read>(
+    reader: R,
+    pair: Option<&str>,
+    mapping: &ColumnMapping,
+    skip_invalid: bool,
+    summary: &mut Summary,
+) -> MyResult<Vec<RateForTraining>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(mapping.delimiter)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let headers = reader.headers().map_err(io::Error::from)?.clone();
+    let position = |name: &str| -> MyResult<usize> {
+        headers
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| MyError::ParseError {
+                param_name: "header".to_string(),
+                value: headers.iter().collect::<Vec<&str>>().join(","),
+                memo: format!("column is not found, name: {}", name),
+            })
+    };
+    let time_pos = position(&mapping.time_column)?;
+    let rate_pos = position(&mapping.rate_column)?;
+    let pair_pos = match &mapping.pair_column {
+        Some(name) => Some(position(name)?),
+        None => None,
+    };
+    if pair_pos.is_none() && pair.is_none() {
+        return Err(MyError::ParseError {
+            param_name: "pair".to_string(),
+            value: "".to_string(),
+            memo: "either pair or pair column is required".to_string(),
+        });
+    }
+
+    let mut rates: BTreeMap<(String, NaiveDateTime), f64> = BTreeMap::new();
+    for (i, record) in reader.records().enumerate() {
+        // ヘッダが1行目のため、データはファイルの2行目から
+        let line_no = i + 2;
+        summary.rows += 1;
+
+        let parsed = record
+            .map_err(|err| err.to_string())
+            .and_then(|r| parse_record(&r, time_pos, rate_pos, pair_pos, pair, mapping));
+        match parsed {
+            Ok((key, rate)) => {
+                if rates.insert(key, rate).is_some() {
+                    summary.duplicated += 1;
+                }
+            }
+            Err(memo) => {
+                let err = MyError::ParseError {
+                    param_name: format!("line {}", line_no),
+                    value: "".to_string(),
+                    memo,
+                };
+                if !skip_invalid {
+                    return Err(err);
+                }
+                warn!("invalid row skipped, error: {}", err);
+                summary.invalid += 1;
+            }
+        }
+    }
+
+    Ok(rates
+        .into_iter()
+        .map(|((pair, recorded_at), rate)| RateForTraining {
+            pair,
+            recorded_at,
+            rate,
+            created_at: recorded_at,
+            updated_at: recorded_at,
+        })
+        .collect())
+}
+
+fn parse_record(
+    record: &csv::StringRecord,
+    time_pos: usize,
+    rate_pos: usize,
+    pair_pos: Option<usize>,
+    pair: Option<&str>,
+    mapping: &ColumnMapping,
+) -> Result<((String, NaiveDateTime), f64), String> {
+    let column = |pos: usize| -> Result<&str, String> {
+        record
+            .get(pos)
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| format!("column {} is empty", pos + 1))
+    };
+
+    let pair = match (pair_pos, pair) {
+        (Some(pos), _) => column(pos)?.to_string(),
+        (None, Some(p)) => p.to_string(),
+        (None, None) => return Err("pair is not specified".to_string()),
+    };
+    let time = column(time_pos)?;
+    let recorded_at = NaiveDateTime::parse_from_str(time, &mapping.time_format)
+        .map_err(|err| format!("invalid time, value: {}, error: {}", time, err))?
+        - mapping.utc_offset;
+    let value = column(rate_pos)?;
+    let rate: f64 = value
+        .parse()
+        .map_err(|err| format!("invalid rate, value: {}, error: {}", value, err))?;
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err(format!("rate must be positive, value: {}", value));
+    }
+
+    Ok(((pair, recorded_at), rate))
+}
+
+// 一定件数ごとにトランザクションを分けて登録する
+// 登録済みのレートは無視されるため、途中で失敗した場合は同じファイルで再実行できる
+pub fn import(
+    mysql_cli: &DefaultClient,
+    input: &Path,
+    pair: Option<&str>,
+    mapping: &ColumnMapping,
+    skip_invalid: bool,
+    chunk_size: usize,
+    dry_run: bool,
+) -> MyResult<Summary> {
+    let mut summary = Summary::default();
+    let rates = read_rates(
+        File::open(input)?,
+        pair,
+        mapping,
+        skip_invalid,
+        &mut summary,
+    )?;
+
+    // 未登録の通貨ペアのレートは登録しない
+    let pairs = mysql_cli.with_transaction(|tx| mysql_cli.select_currency_pairs(tx))?;
+    if let Some(rate) = rates
+        .iter()
+        .find(|r| !pairs.iter().any(|p| p.pair == r.pair))
+    {
+        return Err(MyError::ParseError {
+            param_name: "pair".to_string(),
+            value: rate.pair.to_string(),
+            memo: "currency pair is not registered".to_string(),
+        });
+    }
+    if dry_run {
+        return Ok(summary);
+    }
+
+    let progress = ProgressBar::new(rates.len() as u64);
+    progress.set_style(
+        ProgressStyle::default_bar()
+            .template("{elapsed_precise} [{bar:40}] {pos}/{len} ({eta})")
+            .expect("invalid progress bar template"),
+    );
+    for chunk in rates.chunks(chunk_size) {
+        let inserted =
+            mysql_cli.with_transaction(|tx| mysql_cli.bulk_insert_rates_for_training(tx, chunk))?;
+        summary.inserted += inserted;
+        summary.skipped += chunk.len() as u64 - inserted;
+        progress.inc(chunk.len() as u64);
+    }
+    progress.finish();
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn mapping() -> ColumnMapping {
+        ColumnMapping {
+            time_column: "time".to_string(),
+            rate_column: "close".to_string(),
+            pair_column: None,
+            time_format: "%Y/%m/%d %H:%M".to_string(),
+            utc_offset: parse_utc_offset("+09:00").unwrap(),
+            delimiter: b',',
+        }
+    }
+
+    #[test]
+    fn test_for_parse_utc_offset() {
+        assert_eq!(parse_utc_offset("UTC").unwrap(), Duration::zero());
+        assert_eq!(parse_utc_offset("+09:00").unwrap(), Duration::hours(9));
+        assert_eq!(parse_utc_offset("-0530").unwrap(), Duration::minutes(-330));
+        assert!(parse_utc_offset("09:00").is_err());
+        assert!(parse_utc_offset("+25:00").is_err());
+    }
+
+    #[test]
+    fn test_for_parse_delimiter() {
+        let csv = Path::new("rates.csv");
+        let tsv = Path::new("rates.TSV");
+        assert_eq!(parse_delimiter(None, csv).unwrap(), b',');
+        assert_eq!(parse_delimiter(None, tsv).unwrap(), b'\t');
+        assert_eq!(parse_delimiter(Some(";"), tsv).unwrap(), b';');
+        assert_eq!(parse_delimiter(Some("tab"), csv).unwrap(), b'\t');
+        assert_eq!(parse_delimiter(Some("\\t"), csv).unwrap(), b'\t');
+        assert!(parse_delimiter(Some("，"), csv).is_err());
+        assert!(parse_delimiter(Some(",,"), csv).is_err());
+    }
+
+    #[test]
+    fn test_for_read_rates() {
+        let csv = "time,open,close\n\
+                   2022/01/01 09:01,1.0,100.1\n\
+                   2022/01/01 09:00,1.0,100.0\n\
+                   2022/01/01 09:01,1.0,100.2\n\
+                   2022/01/01 09:02,1.0,-1\n\
+                   invalid,1.0,100.3\n";
+
+        let mut summary = Summary::default();
+        let rates = read_rates(
+            csv.as_bytes(),
+            Some("USDJPY"),
+            &mapping(),
+            true,
+            &mut summary,
+        )
+        .unwrap();
+
+        // 日時の昇順に並べ、重複した日時は後の行を使い、日本時間をUTCに変換する
+        assert_eq!(rates.len(), 2);
+        assert_eq!(
+            rates[0].recorded_at,
+            NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(rates[1].rate, 100.2);
+        assert_eq!(
+            (summary.rows, summary.invalid, summary.duplicated),
+            (5, 2, 1)
+        );
+
+        let mut summary = Summary::default();
+        assert!(read_rates(
+            csv.as_bytes(),
+            Some("USDJPY"),
+            &mapping(),
+            false,
+            &mut summary
+        )
+        .is_err());
+    }
+}
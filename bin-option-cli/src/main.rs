@@ -0,0 +1,470 @@
+extern crate common_lib;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Duration, NaiveDateTime};
+use clap::{Parser, Subcommand};
+use common_lib::{
+    error::{MyError, MyResult},
+    logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+        model::{ForecastModelArchive, ForecastModelBlob},
+        transfer,
+    },
+};
+use log::{error, info, warn};
+
+mod export;
+mod import;
+mod requeue;
+
+const JOB_NAME: &str = "bin-option-cli";
+
+// 運用作業のための管理コマンド
+// ログは標準エラー出力、結果は標準出力に出力する
+#[derive(Parser, Debug)]
+#[clap(
+    name = "bin-option-cli",
+    about = "Admin tool for forecast models and data"
+)]
+struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    // 予想モデルの操作
+    #[clap(subcommand, about = "Manage forecast models")]
+    Models(ModelsCommand),
+    // 予想エラーの確認
+    #[clap(subcommand, about = "Inspect forecast errors")]
+    Errors(ErrorsCommand),
+    // 分析用のデータの書き出し
+    #[clap(about = "Export data of a pair to a CSV or Parquet file")]
+    Export {
+        #[clap(long, arg_enum)]
+        target: export::Target,
+        #[clap(long)]
+        pair: String,
+        // 期間（yyyy-mm-dd hh:mm:ss、省略した場合は制限なし）
+        #[clap(long, parse(try_from_str = export::parse_datetime))]
+        from: Option<NaiveDateTime>,
+        #[clap(long, parse(try_from_str = export::parse_datetime))]
+        to: Option<NaiveDateTime>,
+        #[clap(long, arg_enum, default_value = "csv")]
+        format: export::Format,
+        #[clap(long, parse(from_os_str))]
+        output: PathBuf,
+    }, // 過去のレートのCSVの取り込み
+    #[clap(about = "Import historical rates from a CSV/TSV file")]
+    ImportRates {
+        #[clap(long, parse(from_os_str))]
+        input: PathBuf,
+        // 通貨ペアの列が無い場合に使う
+        #[clap(long)]
+        pair: Option<String>,
+        #[clap(long)]
+        pair_column: Option<String>,
+        #[clap(long, default_value = "time")]
+        time_column: String,
+        #[clap(long, default_value = "rate")]
+        rate_column: String,
+        #[clap(long, default_value = "%Y-%m-%d %H:%M:%S")]
+        time_format: String,
+        // CSVの日時のタイムゾーン（UTC, +09:00 など）
+        #[clap(long, default_value = "UTC", parse(try_from_str = import::parse_utc_offset))]
+        timezone: Duration,
+        // 区切り文字（tab でタブ、省略した場合は拡張子が .tsv ならタブ、それ以外はカンマ）
+        #[clap(long)]
+        delimiter: Option<String>,
+        // 不正な行を無視して取り込みを続ける
+        #[clap(long)]
+        skip_invalid: bool,
+        #[clap(long, default_value = "1000")]
+        chunk_size: usize,
+        // 読み込みと確認のみ行い、登録しない
+        #[clap(long)]
+        dry_run: bool,
+    },
+    // rate-gateway の書き込みキューから捨てたレートの登録し直し
+    #[clap(about = "Requeue rates dropped by the rate-gateway write queue")]
+    RequeueDeadLetters {
+        // rate-gateway の WRITE_DEAD_LETTER_FILE
+        #[clap(long, parse(from_os_str))]
+        input: PathBuf,
+        #[clap(long, default_value = "1000")]
+        chunk_size: usize,
+        // 読み込みと確認のみ行い、登録しない
+        #[clap(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ModelsCommand {
+    #[clap(about = "List models with their performance")]
+    List {
+        // 省略した場合は登録済みの通貨ペアすべて
+        #[clap(long)]
+        pair: Option<String>,
+    },
+    #[clap(about = "Export a model to a file")]
+    Export {
+        #[clap(long)]
+        pair: String,
+        #[clap(long)]
+        no: i32,
+        #[clap(long, parse(from_os_str))]
+        output: PathBuf,
+    },
+    #[clap(about = "Import a model from a file (overwrites the same pair and no)")]
+    Import {
+        #[clap(long, parse(from_os_str))]
+        input: PathBuf,
+        // 指定した場合はファイル内の値の代わりに使う
+        #[clap(long)]
+        pair: Option<String>,
+        #[clap(long)]
+        no: Option<i32>,
+    },
+    #[clap(about = "Export all models of a pair to an archive file")]
+    ExportArchive {
+        #[clap(long)]
+        pair: String,
+        #[clap(long, parse(from_os_str))]
+        output: PathBuf,
+    },
+    #[clap(about = "Import all models from an archive file (overwrites the same pair and no)")]
+    ImportArchive {
+        #[clap(long, parse(from_os_str))]
+        input: PathBuf,
+        // 指定した場合はファイル内の通貨ペアの代わりに使う
+        #[clap(long)]
+        pair: Option<String>,
+    },
+    #[clap(about = "Delete a model")]
+    Delete {
+        #[clap(long)]
+        pair: String,
+        #[clap(long)]
+        no: i32,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ErrorsCommand {
+    #[clap(about = "List forecast errors")]
+    List {
+        #[clap(long)]
+        pair: String,
+        #[clap(long, default_value = "100")]
+        limit: u32,
+        #[clap(long, default_value = "0")]
+        offset: u32,
+    },
+}
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    let cli = Cli::parse();
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    let result = match cli.command {
+        Command::Models(c) => run_models(&mysql_cli, c),
+        Command::Errors(c) => run_errors(&mysql_cli, c),
+        Command::Export {
+            target,
+            pair,
+            from,
+            to,
+            format,
+            output,
+        } => run_export(&mysql_cli, target, &pair, from, to, format, &output),
+        Command::ImportRates {
+            input,
+            pair,
+            pair_column,
+            time_column,
+            rate_column,
+            time_format,
+            timezone,
+            delimiter,
+            skip_invalid,
+            chunk_size,
+            dry_run,
+        } => {
+            let delimiter = match import::parse_delimiter(delimiter.as_deref(), &input) {
+                Ok(d) => d,
+                Err(err) => {
+                    error!("{}", err);
+                    std::process::exit(1);
+                }
+            };
+            let mapping = import::ColumnMapping {
+                time_column,
+                rate_column,
+                pair_column,
+                time_format,
+                utc_offset: timezone,
+                delimiter,
+            };
+            run_import_rates(
+                &mysql_cli,
+                &input,
+                pair.as_deref(),
+                &mapping,
+                skip_invalid,
+                chunk_size,
+                dry_run,
+            )
+        }
+        Command::RequeueDeadLetters {
+            input,
+            chunk_size,
+            dry_run,
+        } => run_requeue_dead_letters(&mysql_cli, &input, chunk_size, dry_run),
+    };
+    if let Err(err) = result {
+        error!("failed to run command, error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run_models(mysql_cli: &DefaultClient, command: ModelsCommand) -> MyResult<()> {
+    match command {
+        ModelsCommand::List { pair } => {
+            let models = mysql_cli.with_transaction(|tx| {
+                let pairs = match &pair {
+                    Some(p) => vec![p.to_string()],
+                    None => mysql_cli
+                        .select_currency_pairs(tx)?
+                        .into_iter()
+                        .map(|p| p.pair)
+                        .collect(),
+                };
+                let mut models = vec![];
+                for p in pairs {
+                    models.extend(mysql_cli.select_forecast_models(tx, &p)?);
+                }
+                Ok(models)
+            })?;
+
+            println!("pair\tno\tinput_data_size\tmse\trmse\tmodel\tmemo");
+            for m in models {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    m.get_pair()?,
+                    m.get_no()?,
+                    m.get_input_data_size()?,
+                    m.get_performance_mse(),
+                    m.get_performance_rmse(),
+                    m,
+                    m.get_memo()
+                );
+            }
+        }
+        ModelsCommand::Export { pair, no, output } => {
+            let file = transfer::export_model(mysql_cli, &pair, no, &output)?;
+            info!(
+                "model exported, pair: {}, no: {}, version: {}, output: {}",
+                pair,
+                no,
+                file.version,
+                output.display()
+            );
+        }
+        ModelsCommand::Import { input, pair, no } => {
+            let file = transfer::import_model(mysql_cli, &input, pair.as_deref(), no)?;
+            info!(
+                "model imported, pair: {}, no: {}, exported_at: {}, input: {}",
+                file.model.pair,
+                file.model.model_no,
+                file.exported_at,
+                input.display()
+            );
+        }
+        ModelsCommand::ExportArchive { pair, output } => {
+            let records = mysql_cli
+                .with_transaction(|tx| mysql_cli.select_forecast_model_records(tx, &pair))?;
+            // 特徴量の設定が変わったモデルは復元しても使えないため書き出さない
+            let mut models = vec![];
+            for r in records {
+                if let Err(err) = r.validate_feature_params() {
+                    warn!("model skipped, {}", err);
+                    continue;
+                }
+                models.push(ForecastModelBlob::from_record(&r));
+            }
+            let archive = ForecastModelArchive::new(&pair, models);
+            fs::write(&output, archive.serialize()?)?;
+            info!(
+                "models exported, pair: {}, count: {}, version: {}, output: {}",
+                pair,
+                archive.models.len(),
+                archive.version,
+                output.display()
+            );
+        }
+        ModelsCommand::ImportArchive { input, pair } => {
+            let mut archive = ForecastModelArchive::deserialize(&fs::read(&input)?)?;
+            if let Some(p) = pair {
+                archive.pair = p.clone();
+                for m in archive.models.iter_mut() {
+                    m.pair = p.clone();
+                }
+            }
+            // 1件でも復元できない場合は何も登録しない
+            let models = archive.to_domain()?;
+            mysql_cli.with_transaction(|tx| {
+                for m in models.iter() {
+                    mysql_cli.upsert_forecast_model(tx, m)?;
+                }
+                Ok(())
+            })?;
+            info!(
+                "models imported, pair: {}, count: {}, exported_at: {}, input: {}",
+                archive.pair,
+                models.len(),
+                archive.exported_at,
+                input.display()
+            );
+        }
+        ModelsCommand::Delete { pair, no } => {
+            let deleted =
+                mysql_cli.with_transaction(|tx| mysql_cli.delete_forecast_model(tx, &pair, no))?;
+            if !deleted {
+                return Err(MyError::ForecastModelNotFound { pair, model_no: no });
+            }
+            info!("model deleted, pair: {}, no: {}", pair, no);
+        }
+    }
+    Ok(())
+}
+
+fn run_errors(mysql_cli: &DefaultClient, command: ErrorsCommand) -> MyResult<()> {
+    match command {
+        ErrorsCommand::List {
+            pair,
+            limit,
+            offset,
+        } => {
+            let errors = mysql_cli.with_transaction(|tx| {
+                mysql_cli.select_forecast_errors(tx, &pair, limit, offset)
+            })?;
+
+            println!("id\trate_id\tmodel_no\tsummary\tdetail");
+            for e in errors {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    e.id, e.rate_id, e.model_no, e.summary, e.detail
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_export(
+    mysql_cli: &DefaultClient,
+    target: export::Target,
+    pair: &str,
+    from: Option<NaiveDateTime>,
+    to: Option<NaiveDateTime>,
+    format: export::Format,
+    output: &Path,
+) -> MyResult<()> {
+    let count = export::export(mysql_cli, target, pair, from, to, format, output)?;
+    info!(
+        "data exported, target: {:?}, format: {:?}, pair: {}, count: {}, output: {}",
+        target,
+        format,
+        pair,
+        count,
+        output.display()
+    );
+    Ok(())
+}
+
+fn run_import_rates(
+    mysql_cli: &DefaultClient,
+    input: &Path,
+    pair: Option<&str>,
+    mapping: &import::ColumnMapping,
+    skip_invalid: bool,
+    chunk_size: usize,
+    dry_run: bool,
+) -> MyResult<()> {
+    if chunk_size == 0 {
+        return Err(MyError::InvalidConfig {
+            problems: vec!["chunk_size must be positive".to_string()],
+        });
+    }
+
+    let summary = import::import(
+        mysql_cli,
+        input,
+        pair,
+        mapping,
+        skip_invalid,
+        chunk_size,
+        dry_run,
+    )?;
+    info!(
+        "rates imported, input: {}, dry_run: {}, summary: {:?}",
+        input.display(),
+        dry_run,
+        summary
+    );
+    println!(
+        "rows: {}, invalid: {}, duplicated: {}, inserted: {}, skipped: {}",
+        summary.rows, summary.invalid, summary.duplicated, summary.inserted, summary.skipped
+    );
+    Ok(())
+}
+
+fn run_requeue_dead_letters(
+    mysql_cli: &DefaultClient,
+    input: &Path,
+    chunk_size: usize,
+    dry_run: bool,
+) -> MyResult<()> {
+    if chunk_size == 0 {
+        return Err(MyError::InvalidConfig {
+            problems: vec!["chunk_size must be positive".to_string()],
+        });
+    }
+
+    let summary = requeue::requeue(input, chunk_size, dry_run, |chunk| {
+        mysql_cli.with_transaction(|tx| mysql_cli.bulk_insert_rates_for_training(tx, chunk))
+    })?;
+    info!(
+        "dead letters requeued, input: {}, dry_run: {}, summary: {:?}",
+        input.display(),
+        dry_run,
+        summary
+    );
+    println!(
+        "rows: {}, inserted: {}, skipped: {}",
+        summary.rows, summary.inserted, summary.skipped
+    );
+    Ok(())
+}
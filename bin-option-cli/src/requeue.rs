@@ -0,0 +1,115 @@
+use std::{fs, path::Path};
+
+use common_lib::{dead_letter, domain::model::RateForTraining, error::MyResult};
+
+use crate::import::Summary;
+
+// rate-gateway が捨てたレートのファイルを読み込み、一定件数ごとに登録し直す
+// 登録済みのレートは無視されるため、途中で失敗した場合は同じコマンドで再実行できる
+pub fn requeue<F>(
+    input: &Path,
+    chunk_size: usize,
+    dry_run: bool,
+    mut insert: F,
+) -> MyResult<Summary>
+where
+    F: FnMut(&[RateForTraining]) -> MyResult<u64>,
+{
+    let mut summary = Summary::default();
+    if dry_run {
+        // 前回途中で失敗したファイルがあれば、次に登録し直すのはそちら
+        let taken = dead_letter::taken_path(input);
+        let path = if taken.exists() {
+            taken.as_path()
+        } else {
+            input
+        };
+        if path.exists() {
+            summary.rows = dead_letter::read(path)?.len() as u64;
+        }
+        return Ok(summary);
+    }
+
+    let taken = match dead_letter::take(input)? {
+        Some(p) => p,
+        None => return Ok(summary),
+    };
+    let rates = dead_letter::read(&taken)?;
+    summary.rows = rates.len() as u64;
+    for chunk in rates.chunks(chunk_size) {
+        let inserted = insert(chunk)?;
+        summary.inserted += inserted;
+        summary.skipped += chunk.len() as u64 - inserted;
+    }
+    fs::remove_file(&taken)?;
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn rates() -> Vec<RateForTraining> {
+        (0..5)
+            .map(|i| {
+                let time = format!("2022-01-01 00:{:02}:00", i);
+                RateForTraining::new("USDJPY", &time, 100.0 + i as f64).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_for_requeue() {
+        let input = std::env::temp_dir().join(format!("requeue_{}", std::process::id()));
+        let _ = fs::remove_file(&input);
+        let _ = fs::remove_file(dead_letter::taken_path(&input));
+
+        // ファイルが無い場合は何もしない
+        let summary = requeue(&input, 2, false, |_| panic!("must not insert")).unwrap();
+        assert_eq!(summary.rows, 0);
+
+        dead_letter::append(&input, &rates()).unwrap();
+        let summary = requeue(&input, 2, true, |_| panic!("must not insert")).unwrap();
+        assert_eq!((summary.rows, summary.inserted), (5, 0));
+        assert!(input.exists());
+
+        // 途中で失敗した場合はファイルを残し、追記中のファイルとは分けておく
+        let mut persisted = vec![];
+        let result = requeue(&input, 2, false, |chunk| {
+            if persisted.len() >= 2 {
+                return Err(MyError::ParseError {
+                    param_name: "test".to_string(),
+                    value: "".to_string(),
+                    memo: "insert failed".to_string(),
+                });
+            }
+            persisted.extend(chunk.iter().map(|r| r.rate));
+            Ok(chunk.len() as u64)
+        });
+        assert!(result.is_err());
+        assert!(!input.exists());
+        assert!(dead_letter::taken_path(&input).exists());
+        dead_letter::append(&input, &rates()[..1]).unwrap();
+
+        // 再実行すると残したファイルから登録し直し、登録済みのレートは無視される
+        let summary = requeue(&input, 2, false, |chunk| {
+            let inserted = chunk
+                .iter()
+                .filter(|r| !persisted.contains(&r.rate))
+                .count();
+            persisted.extend(chunk.iter().map(|r| r.rate));
+            Ok(inserted as u64)
+        })
+        .unwrap();
+        assert_eq!((summary.rows, summary.inserted, summary.skipped), (5, 3, 2));
+        assert!(!dead_letter::taken_path(&input).exists());
+
+        // 失敗中に追記されたレートは次の実行で登録し直す
+        let summary = requeue(&input, 2, false, |chunk| Ok(chunk.len() as u64)).unwrap();
+        assert_eq!((summary.rows, summary.inserted), (1, 1));
+        assert!(!input.exists());
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ClientError {
+    #[error("failed to init client, url:{}, memo:{}", url, memo)]
+    Init { url: String, memo: String },
+
+    // 通信エラーなど、レスポンスを受け取れなかった場合
+    #[error("failed to request, memo:{}", memo)]
+    Request { memo: String },
+
+    #[error("request timed out, timeout:{:?}", timeout)]
+    Timeout { timeout: Duration },
+
+    #[error("invalid request, message:{}", message)]
+    InvalidRequest { message: String },
+
+    #[error("not found, message:{}", message)]
+    NotFound { message: String },
+
+    #[error("conflict, message:{}", message)]
+    Conflict { message: String },
+
+    #[error("too many rates, message:{}, max:{:?}", message, max_rates)]
+    TooManyRates {
+        message: String,
+        max_rates: Option<i64>,
+    },
+
+    #[error("server error, message:{}", message)]
+    Server { message: String },
+
+    #[error(
+        "service unavailable, message:{}, retry_after:{:?}",
+        message,
+        retry_after
+    )]
+    Unavailable {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+
+    #[error(
+        "forecast is not completed, rate_id:{}, model_no:{}",
+        rate_id,
+        model_no
+    )]
+    ForecastNotCompleted { rate_id: String, model_no: i32 },
+
+    // forecast-batch で予想に失敗した場合（完了することはないため再試行しない）
+    #[error(
+        "forecast failed, rate_id:{}, model_no:{}, summary:{}, detail:{}",
+        rate_id,
+        model_no,
+        summary,
+        detail
+    )]
+    ForecastFailed {
+        rate_id: String,
+        model_no: i32,
+        summary: String,
+        detail: String,
+    },
+}
+
+impl ClientError {
+    // 再試行で解消する可能性があるか
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ClientError::Request { .. }
+                | ClientError::Timeout { .. }
+                | ClientError::Unavailable { .. }
+        )
+    }
+
+    pub(crate) fn unavailable(message: String, retry_after: Option<i32>) -> Self {
+        ClientError::Unavailable {
+            message,
+            retry_after: retry_after
+                .filter(|s| *s > 0)
+                .map(|s| Duration::from_secs(s as u64)),
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use std::{future::Future, time::Duration};
+
+use chrono::NaiveDateTime;
+use forecast_server_lib::{Api as _, ForecastHorizonRateIdModelNoGetResponse, RatesPostResponse};
+use log::warn;
+use rate_gateway_lib::{Api as _, RatesPairPostResponse};
+use swagger::{AuthData, ContextBuilder, DropContextService, EmptyContext, Push, XSpanIdString};
+
+mod error;
+
+pub use error::{ClientError, ClientResult};
+pub use forecast_server_lib::models::Horizon;
+
+// rate-gateway が受け付ける日時の形式（UTC）
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+type ClientContext = swagger::make_context_ty!(
+    ContextBuilder,
+    EmptyContext,
+    Option<AuthData>,
+    XSpanIdString
+);
+type ForecastClient = forecast_server_lib::Client<
+    DropContextService<forecast_server_lib::client::HyperClient, ClientContext>,
+    ClientContext,
+>;
+type GatewayClient = rate_gateway_lib::Client<
+    DropContextService<rate_gateway_lib::client::HyperClient, ClientContext>,
+    ClientContext,
+>;
+
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    // 例: http://localhost:8081
+    pub gateway_url: String,
+    pub forecast_url: String,
+    // 1リクエストあたりの待ち時間の上限
+    pub request_timeout: Duration,
+    // 通信エラー・タイムアウト・503 の場合の再試行回数
+    pub max_retries: u32,
+    // 再試行までの待ち時間（再試行ごとに2倍にする、503 で Retry-After がある場合はそれに従う）
+    pub retry_backoff: Duration,
+    // await_forecast で予想の完了を確認する間隔と、打ち切るまでの時間
+    pub poll_interval: Duration,
+    pub forecast_timeout: Duration,
+}
+
+impl ClientConfig {
+    pub fn new(gateway_url: &str, forecast_url: &str) -> Self {
+        ClientConfig {
+            gateway_url: gateway_url.to_string(),
+            forecast_url: forecast_url.to_string(),
+            request_timeout: Duration::from_secs(10),
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(500),
+            poll_interval: Duration::from_secs(1),
+            forecast_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostRatesResult {
+    pub count: i64,
+    pub inserted: i64,
+    pub skipped: i64,
+    // 書き込みキューに追加され、登録は非同期に行われる場合は true（inserted, skipped は 0）
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Forecast {
+    pub pair: String,
+    pub complete: bool,
+    pub rate: Option<f64>,
+    pub rmse: Option<f64>,
+    // 予想値の推移（複数ステップで予想した場合のみ）
+    pub path: Option<Vec<f64>>,
+    // 予想値の10%, 50%, 90%分位点（分位点を予想できるモデルの場合のみ）
+    pub quantiles: Option<(f64, f64, f64)>,
+}
+
+// rate-gateway と forecast-server の生成クライアントをまとめたクライアント
+#[derive(Clone)]
+pub struct BinOptionClient {
+    gateway: GatewayClient,
+    forecast: ForecastClient,
+    config: ClientConfig,
+}
+
+impl BinOptionClient {
+    pub fn new(config: ClientConfig) -> ClientResult<Self> {
+        let gateway =
+            GatewayClient::try_new(&config.gateway_url).map_err(|err| ClientError::Init {
+                url: config.gateway_url.clone(),
+                memo: err.to_string(),
+            })?;
+        let forecast =
+            ForecastClient::try_new(&config.forecast_url).map_err(|err| ClientError::Init {
+                url: config.forecast_url.clone(),
+                memo: err.to_string(),
+            })?;
+        Ok(BinOptionClient {
+            gateway,
+            forecast,
+            config,
+        })
+    }
+
+    // レートを rate-gateway に登録する（登録済みのレートは無視されるため、再試行しても重複しない）
+    pub async fn post_rates(
+        &self,
+        pair: &str,
+        rates: &[(NaiveDateTime, f64)],
+    ) -> ClientResult<PostRatesResult> {
+        let rates: Vec<rate_gateway_lib::models::Rate> = rates
+            .iter()
+            .map(|(time, value)| {
+                rate_gateway_lib::models::Rate::new(time.format(TIME_FORMAT).to_string(), *value)
+            })
+            .collect();
+        let rates = &rates;
+
+        self.with_retry("post_rates", || async move {
+            let response = self
+                .call(
+                    self.gateway
+                        .rates_pair_post(pair.to_string(), rates, None, &context()),
+                )
+                .await?;
+            match response {
+                RatesPairPostResponse::Status201(body) => Ok(PostRatesResult {
+                    count: body.count,
+                    inserted: body.inserted,
+                    skipped: body.skipped,
+                    accepted: false,
+                }),
+                RatesPairPostResponse::Status202(body) => Ok(PostRatesResult {
+                    count: body.count,
+                    inserted: 0,
+                    skipped: 0,
+                    accepted: true,
+                }),
+                RatesPairPostResponse::Status400(e) => {
+                    Err(ClientError::InvalidRequest { message: e.message })
+                }
+                RatesPairPostResponse::Status404(e) => {
+                    Err(ClientError::NotFound { message: e.message })
+                }
+                RatesPairPostResponse::Status413 { body, x_max_rates } => {
+                    Err(ClientError::TooManyRates {
+                        message: body.message,
+                        max_rates: x_max_rates,
+                    })
+                }
+                RatesPairPostResponse::Status500(e) => {
+                    Err(ClientError::Server { message: e.message })
+                }
+                RatesPairPostResponse::Status503 { body, retry_after } => {
+                    Err(ClientError::unavailable(body.message, retry_after))
+                }
+            }
+        })
+        .await
+    }
+
+    // 予想に使うレートを forecast-server に登録し、予想の取得に使う rate_id を返す
+    // タイムアウト後の再試行では、先のリクエストが登録済みの場合も別の rate_id で登録される
+    pub async fn request_forecast(
+        &self,
+        pair: &str,
+        rate_histories: &[f64],
+        expire_minutes: Option<i64>,
+    ) -> ClientResult<String> {
+        let mut history =
+            forecast_server_lib::models::History::new(pair.to_string(), rate_histories.to_vec());
+        history.expire_minutes = expire_minutes;
+        let history = &history;
+
+        self.with_retry("request_forecast", || async move {
+            let response = self
+                .call(self.forecast.rates_post(history.clone(), &context()))
+                .await?;
+            match response {
+                RatesPostResponse::Status201(body) => Ok(body.rate_id),
+                RatesPostResponse::Status400(e) => {
+                    Err(ClientError::InvalidRequest { message: e.message })
+                }
+                RatesPostResponse::Status404(e) => {
+                    Err(ClientError::NotFound { message: e.message })
+                }
+                RatesPostResponse::Status409(e) => {
+                    Err(ClientError::Conflict { message: e.message })
+                }
+                RatesPostResponse::Status500(e) => Err(ClientError::Server { message: e.message }),
+                RatesPostResponse::Status503 { body, retry_after } => {
+                    Err(ClientError::unavailable(body.message, retry_after))
+                }
+            }
+        })
+        .await
+    }
+
+    // 予想結果を取得する（未完了の場合は complete が false の結果を返し、予想に失敗した場合はエラーにする）
+    pub async fn get_forecast(
+        &self,
+        horizon: Horizon,
+        rate_id: &str,
+        model_no: i32,
+        pair: Option<&str>,
+    ) -> ClientResult<Forecast> {
+        self.with_retry("get_forecast", || async move {
+            let response = self
+                .call(self.forecast.forecast_horizon_rate_id_model_no_get(
+                    horizon,
+                    rate_id.to_string(),
+                    model_no,
+                    pair.map(|p| p.to_string()),
+                    &context(),
+                ))
+                .await?;
+            match response {
+                ForecastHorizonRateIdModelNoGetResponse::Status200(body) => match body.result {
+                    Some(forecast_server_lib::models::ForecastResult {
+                        error: Some(e), ..
+                    }) => Err(ClientError::ForecastFailed {
+                        rate_id: e.rate_id,
+                        model_no: e.model_no,
+                        summary: e.summary,
+                        detail: e.detail,
+                    }),
+                    Some(r) => Ok(Forecast {
+                        pair: r.pair,
+                        complete: r.complete,
+                        rate: r.rate,
+                        rmse: r.rmse,
+                        path: r.path,
+                        quantiles: r.quantiles.map(|q| (q.p10, q.p50, q.p90)),
+                    }),
+                    None => Err(ClientError::Server {
+                        message: "forecast result is empty".to_string(),
+                    }),
+                },
+                ForecastHorizonRateIdModelNoGetResponse::Status404(e) => {
+                    Err(ClientError::NotFound { message: e.message })
+                }
+                ForecastHorizonRateIdModelNoGetResponse::Status500(e) => {
+                    Err(ClientError::Server { message: e.message })
+                }
+                ForecastHorizonRateIdModelNoGetResponse::Status503 { body, retry_after } => {
+                    Err(ClientError::unavailable(body.message, retry_after))
+                }
+            }
+        })
+        .await
+    }
+
+    // 予想が完了するまで poll_interval ごとに確認し、forecast_timeout を過ぎた場合はエラーにする
+    pub async fn await_forecast(
+        &self,
+        horizon: Horizon,
+        rate_id: &str,
+        model_no: i32,
+        pair: Option<&str>,
+    ) -> ClientResult<Forecast> {
+        let deadline = tokio::time::Instant::now() + self.config.forecast_timeout;
+        loop {
+            let forecast = self.get_forecast(horizon, rate_id, model_no, pair).await?;
+            if forecast.complete {
+                return Ok(forecast);
+            }
+            if tokio::time::Instant::now() + self.config.poll_interval > deadline {
+                return Err(ClientError::ForecastNotCompleted {
+                    rate_id: rate_id.to_string(),
+                    model_no,
+                });
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    async fn call<T, F>(&self, future: F) -> ClientResult<T>
+    where
+        F: Future<Output = Result<T, swagger::ApiError>>,
+    {
+        match tokio::time::timeout(self.config.request_timeout, future).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(err)) => Err(ClientError::Request {
+                memo: err.to_string(),
+            }),
+            Err(_) => Err(ClientError::Timeout {
+                timeout: self.config.request_timeout,
+            }),
+        }
+    }
+
+    async fn with_retry<T, F, Fut>(&self, name: &str, mut f: F) -> ClientResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ClientResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match f().await {
+                Err(err) if err.is_retryable() && attempt < self.config.max_retries => {
+                    let wait = match &err {
+                        ClientError::Unavailable {
+                            retry_after: Some(d),
+                            ..
+                        } => *d,
+                        _ => self.config.retry_backoff * 2u32.pow(attempt),
+                    };
+                    attempt += 1;
+                    warn!(
+                        "retry {}, attempt: {}, wait: {:?}, error: {}",
+                        name, attempt, wait, err
+                    );
+                    tokio::time::sleep(wait).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+// リクエストごとに新しい X-Span-ID を付与する
+fn context() -> ClientContext {
+    swagger::make_context!(
+        ContextBuilder,
+        EmptyContext,
+        None as Option<AuthData>,
+        XSpanIdString::default()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use swagger::Has;
+
+    use super::*;
+
+    fn client(max_retries: u32) -> BinOptionClient {
+        let mut config = ClientConfig::new("http://localhost:8081", "http://localhost:8082");
+        config.max_retries = max_retries;
+        config.retry_backoff = Duration::from_millis(1);
+        BinOptionClient::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_for_with_retry() {
+        // 一時的なエラーは max_retries 回まで再試行する
+        let count = &Cell::new(0);
+        let result: ClientResult<()> = client(2)
+            .with_retry("test", || async move {
+                count.set(count.get() + 1);
+                Err(ClientError::unavailable("unavailable".to_string(), None))
+            })
+            .await;
+        assert!(matches!(result, Err(ClientError::Unavailable { .. })));
+        assert_eq!(count.get(), 3);
+
+        // 再試行で解消するエラーでなければすぐに返す
+        let count = &Cell::new(0);
+        let result: ClientResult<()> = client(2)
+            .with_retry("test", || async move {
+                count.set(count.get() + 1);
+                Err(ClientError::NotFound {
+                    message: "not found".to_string(),
+                })
+            })
+            .await;
+        assert!(matches!(result, Err(ClientError::NotFound { .. })));
+        assert_eq!(count.get(), 1);
+
+        let count = &Cell::new(0);
+        let result = client(2)
+            .with_retry("test", || async move {
+                count.set(count.get() + 1);
+                if count.get() < 2 {
+                    Err(ClientError::Request {
+                        memo: "connection refused".to_string(),
+                    })
+                } else {
+                    Ok(count.get())
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_for_context() {
+        let c1 = context();
+        let c2 = context();
+        let id1 = Has::<XSpanIdString>::get(&c1).0.clone();
+        let id2 = Has::<XSpanIdString>::get(&c2).0.clone();
+        assert!(!id1.is_empty());
+        assert_ne!(id1, id2);
+        assert!(Has::<Option<AuthData>>::get(&c1).is_none());
+    }
+}
@@ -0,0 +1,15 @@
+CREATE TABLE training_runs (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    status TINYINT UNSIGNED NOT NULL COMMENT '状態（0:要求済, 1:実行中, 2:成功, 3:失敗）',
+    memo TEXT COMMENT 'メモ',
+    result TEXT COMMENT '実行結果',
+    started_at DATETIME COMMENT '開始日時',
+    finished_at DATETIME COMMENT '終了日時',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    INDEX idx_training_runs_pair_status(pair, status)
+)
+COMMENT='学習の実行履歴'
+;
@@ -0,0 +1,17 @@
+CREATE TABLE forecast_evaluations (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    forecast_result_id CHAR(36) NOT NULL COMMENT '評価した予測結果のID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    model_no INTEGER NOT NULL COMMENT '予測を行ったモデルのモデルNo',
+    forecast_type TINYINT UNSIGNED NOT NULL COMMENT '予測種別',
+    forecast DECIMAL(15,4) NOT NULL COMMENT '予測値',
+    actual DECIMAL(15,4) NOT NULL COMMENT '実績値',
+    target_at DATETIME NOT NULL COMMENT '実績値の記録日時',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    UNIQUE KEY uk_forecast_evaluations_forecast_result_id(forecast_result_id),
+    INDEX idx_forecast_evaluations_model(pair, model_no, forecast_type, target_at)
+)
+COMMENT='予測結果の実績評価'
+;
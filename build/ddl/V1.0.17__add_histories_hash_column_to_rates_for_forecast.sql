@@ -0,0 +1,2 @@
+ALTER TABLE binopt.rates_for_forecast ADD histories_hash CHAR(64) NOT NULL DEFAULT '' COMMENT 'レート履歴のハッシュ値' AFTER histories;
+ALTER TABLE binopt.rates_for_forecast ADD INDEX idx_rates_for_forecast_pair_histories_hash(pair, histories_hash, created_at);
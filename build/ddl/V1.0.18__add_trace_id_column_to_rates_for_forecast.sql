@@ -0,0 +1 @@
+ALTER TABLE binopt.rates_for_forecast ADD trace_id VARCHAR(64) COMMENT '登録リクエストのトレースID' AFTER memo;
@@ -0,0 +1,14 @@
+CREATE TABLE rate_gaps (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    begin_at DATETIME NOT NULL COMMENT '欠損直前のレートの記録日時（分単位）',
+    end_at DATETIME NOT NULL COMMENT '欠損直後のレートの記録日時（分単位）',
+    missing_minutes INTEGER NOT NULL COMMENT '欠損している分数',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    UNIQUE KEY uk_rate_gaps_pair_begin_at(pair, begin_at),
+    INDEX idx_rate_gaps_pair_end_at(pair, end_at)
+)
+COMMENT='学習用レートの欠損期間'
+;
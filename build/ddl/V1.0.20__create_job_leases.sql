@@ -0,0 +1,11 @@
+CREATE TABLE job_leases (
+    name VARCHAR(100) NOT NULL COMMENT 'ジョブ名',
+    owner VARCHAR(255) NOT NULL COMMENT '実行中のインスタンス',
+    scheduled_at DATETIME NOT NULL COMMENT '実行中または最後に実行した予定時刻',
+    expires_at DATETIME NOT NULL COMMENT 'リースの有効期限（実行終了時に現在日時へ更新）',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(name)
+)
+COMMENT='バッチのジョブ実行リース（複数インスタンスでの重複実行防止）'
+;
@@ -0,0 +1,14 @@
+CREATE TABLE job_runs (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    job_name VARCHAR(100) NOT NULL COMMENT 'ジョブ名',
+    status TINYINT UNSIGNED NOT NULL COMMENT '状態（0:実行中, 1:成功, 2:失敗）',
+    message TEXT COMMENT 'エラーメッセージ',
+    started_at DATETIME NOT NULL COMMENT '開始日時',
+    finished_at DATETIME COMMENT '終了日時',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    INDEX idx_job_runs_job_name_started_at(job_name, started_at)
+)
+COMMENT='バッチのジョブ実行履歴'
+;
@@ -0,0 +1,9 @@
+CREATE TABLE runtime_settings (
+    name VARCHAR(100) NOT NULL COMMENT '設定名（pairs, max_model_mse, rate_limit_per_minute）',
+    value VARCHAR(1000) NOT NULL COMMENT '設定値（空文字の場合は未設定）',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(name)
+)
+COMMENT='再起動せずに変更できる設定（SIGHUPまたは一定間隔で再読み込みする）'
+;
@@ -0,0 +1,12 @@
+CREATE TABLE currency_pairs (
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    pip_size DOUBLE NOT NULL COMMENT '1pipの大きさ（USDJPYなら0.01）',
+    enabled BOOLEAN NOT NULL DEFAULT TRUE COMMENT '取り扱う場合は TRUE',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(pair)
+)
+COMMENT='取り扱う通貨ペア（実行時設定と同じタイミングで再読み込みする）'
+;
+
+INSERT INTO currency_pairs (pair, pip_size, enabled) VALUES ('USDJPY', 0.01, TRUE);
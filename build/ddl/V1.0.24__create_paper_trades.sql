@@ -0,0 +1,21 @@
+CREATE TABLE paper_trades (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    forecast_result_id CHAR(36) NOT NULL COMMENT '取引の根拠にした予測結果のID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    model_no INTEGER NOT NULL COMMENT '予測を行ったモデルのモデルNo',
+    direction TINYINT UNSIGNED NOT NULL COMMENT '取引の方向（0: 取引なし, 1: HIGH, 2: LOW）',
+    entry_rate DECIMAL(15,4) NOT NULL COMMENT '予測時点のレート',
+    forecast DECIMAL(15,4) NOT NULL COMMENT '予測値',
+    exit_rate DECIMAL(15,4) NOT NULL COMMENT '判定時刻のレート',
+    stake DECIMAL(15,4) NOT NULL COMMENT '掛金',
+    profit DECIMAL(15,4) NOT NULL COMMENT '損益',
+    entry_at DATETIME NOT NULL COMMENT '予測日時',
+    exit_at DATETIME NOT NULL COMMENT '判定時刻のレートの記録日時',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    UNIQUE KEY uk_paper_trades_forecast_result_id(forecast_result_id),
+    INDEX idx_paper_trades_model(pair, model_no, exit_at)
+)
+COMMENT='予測に従った仮想取引の結果'
+;
@@ -0,0 +1,19 @@
+CREATE TABLE job_requests (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    job_name VARCHAR(100) NOT NULL COMMENT 'ジョブ名',
+    status TINYINT UNSIGNED NOT NULL COMMENT '状態（0:待機中, 1:実行可能, 2:実行中, 3:成功, 4:失敗）',
+    attempts INTEGER NOT NULL DEFAULT 0 COMMENT '試行回数',
+    max_attempts INTEGER NOT NULL COMMENT '最大試行回数',
+    owner VARCHAR(255) COMMENT '実行したワーカー',
+    message TEXT COMMENT 'エラーメッセージ',
+    available_at DATETIME NOT NULL COMMENT '実行可能になる日時（再試行の待ち時間）',
+    started_at DATETIME COMMENT '開始日時',
+    finished_at DATETIME COMMENT '終了日時',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    INDEX idx_job_requests_job_name_status(job_name, status),
+    INDEX idx_job_requests_created_at(created_at)
+)
+COMMENT='オーケストレーターからバッチへのジョブ実行要求'
+;
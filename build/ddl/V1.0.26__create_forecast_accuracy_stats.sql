@@ -0,0 +1,18 @@
+CREATE TABLE forecast_accuracy_stats (
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    model_no INTEGER NOT NULL COMMENT '予測を行ったモデルのモデルNo',
+    forecast_type TINYINT UNSIGNED NOT NULL COMMENT '予測種別',
+    bucket VARCHAR(10) NOT NULL COMMENT '集計期間の単位（hour, day）',
+    bucket_at DATETIME NOT NULL COMMENT '集計期間の開始日時',
+    count INTEGER NOT NULL COMMENT '評価件数',
+    mae DOUBLE NOT NULL COMMENT '平均絶対誤差',
+    mse DOUBLE NOT NULL COMMENT '平均二乗誤差',
+    rmse DOUBLE NOT NULL COMMENT '二乗平均平方根誤差',
+    bias DOUBLE NOT NULL COMMENT '予測値 - 実績値 の平均',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(pair, model_no, forecast_type, bucket, bucket_at),
+    INDEX idx_forecast_accuracy_stats_bucket_at(bucket, bucket_at)
+)
+COMMENT='予測精度の集計期間ごとの集計結果'
+;
@@ -0,0 +1,13 @@
+CREATE TABLE rate_anomalies (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    recorded_at DATETIME NOT NULL COMMENT '異常と判定したレートの記録日時',
+    rate DECIMAL(15,4) NOT NULL COMMENT 'レート',
+    kind VARCHAR(15) NOT NULL COMMENT '異常の種類（jump, stale, out_of_hours）',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    UNIQUE KEY uk_rate_anomalies_pair_recorded_at_kind(pair, recorded_at, kind)
+)
+COMMENT='学習用レートの異常値'
+;
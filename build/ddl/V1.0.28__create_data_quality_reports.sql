@@ -0,0 +1,19 @@
+CREATE TABLE data_quality_reports (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    measured_at DATETIME NOT NULL COMMENT '計測日時',
+    begin_at DATETIME NOT NULL COMMENT '集計範囲（開始）',
+    end_at DATETIME NOT NULL COMMENT '集計範囲（終了）',
+    coverage DOUBLE NOT NULL COMMENT '取引時間中の分のうち、レートがある分の割合',
+    gap_count INTEGER NOT NULL COMMENT '欠損期間の件数',
+    duplicate_count INTEGER NOT NULL COMMENT '同じ分に記録された2件目以降のレート数',
+    latest_recorded_at DATETIME COMMENT '最新のレートの記録日時',
+    lag_seconds BIGINT COMMENT '最新のレートの記録日時からの経過秒数',
+    problems JSON NOT NULL COMMENT '基準を満たさなかった項目',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    INDEX idx_data_quality_reports_pair_measured_at(pair, measured_at)
+)
+COMMENT='レートのデータ品質'
+;
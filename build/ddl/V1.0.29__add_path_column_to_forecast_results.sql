@@ -0,0 +1 @@
+ALTER TABLE binopt.forecast_results ADD path JSON COMMENT '予想値の推移（複数ステップで予想した場合のみ）' AFTER result;
@@ -0,0 +1 @@
+ALTER TABLE binopt.forecast_results ADD quantiles JSON COMMENT '予想値の分位点（分位点を予想できるモデルの場合のみ）' AFTER path;
@@ -0,0 +1,16 @@
+CREATE TABLE forecast_explanations (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    rate_id CHAR(36) NOT NULL COMMENT '予測用のレートID',
+    model_no INTEGER NOT NULL COMMENT 'モデルNo',
+    forecast_type TINYINT UNSIGNED NOT NULL COMMENT '予測種別',
+    method VARCHAR(20) NOT NULL COMMENT '算出方法（linear: 係数×特徴量の値, occlusion: 特徴量を置き換えたときの予想値の変化）',
+    base_value DOUBLE NOT NULL COMMENT '寄与の基準値（基準値と寄与の合計がおおよそ予想値になる）',
+    contributions JSON NOT NULL COMMENT '特徴量ごとの寄与',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    UNIQUE KEY uk_forecast_explanations_rate_id_model_no_forecast_type(rate_id, model_no, forecast_type),
+    FOREIGN KEY fk_forecast_explanations_rate_id(rate_id) REFERENCES rates_for_forecast(id)
+)
+COMMENT='予測結果の根拠（特徴量ごとの予想値への寄与）'
+;
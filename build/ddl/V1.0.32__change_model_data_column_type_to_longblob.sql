@@ -0,0 +1 @@
+ALTER TABLE binopt.forecast_models MODIFY COLUMN model_data LONGBLOB NOT NULL COMMENT 'モデルデータ';
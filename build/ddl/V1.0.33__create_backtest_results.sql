@@ -0,0 +1,23 @@
+CREATE TABLE backtest_results (
+    id CHAR(36) NOT NULL DEFAULT (UUID()) COMMENT 'ID',
+    pair VARCHAR(15) NOT NULL COMMENT '通貨ペア',
+    model_no INTEGER NOT NULL COMMENT 'モデルNo',
+    model_memo TEXT NOT NULL COMMENT '検証時のモデルのメモ',
+    begin_at DATETIME NOT NULL COMMENT '検証範囲（開始）',
+    end_at DATETIME NOT NULL COMMENT '検証範囲（終了）',
+    forecast_offset_minutes INTEGER UNSIGNED NOT NULL COMMENT '予想対象の時間（分後）',
+    forecast_count BIGINT UNSIGNED NOT NULL COMMENT '予想した回数',
+    hit_rate DOUBLE NOT NULL COMMENT '予想した方向（上昇・下落）が的中した割合',
+    mae DOUBLE NOT NULL COMMENT '予想値の平均絶対誤差',
+    trade_count BIGINT UNSIGNED NOT NULL COMMENT '取引した回数',
+    win_rate DOUBLE NOT NULL COMMENT '引き分けを除いた勝率',
+    expected_value DOUBLE NOT NULL COMMENT '1回の取引あたりの期待値',
+    total_profit DOUBLE NOT NULL COMMENT '掛金1で取引した場合の損益の合計',
+    max_drawdown DOUBLE NOT NULL COMMENT '損益の累計の最大値からの最大下落幅',
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '作成日時',
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT '更新日時',
+    PRIMARY KEY(id),
+    INDEX idx_backtest_results_pair_model_no(pair, model_no, created_at)
+)
+COMMENT='過去のレートでのモデルの検証結果'
+;
@@ -0,0 +1,7 @@
+-- 予測種別を予測対象の時間（分）で表すよう変更する（0: 30分後, 1: 5分後）
+-- 予測種別を導入する前の予測は常に forecast_type = 0, memo = 'after5min' で登録していたため、memo から予測時間を判断する
+UPDATE binopt.forecast_results SET forecast_type = 5 WHERE forecast_type = 1 OR (forecast_type = 0 AND memo = 'after5min');
+UPDATE binopt.forecast_results SET forecast_type = 30 WHERE forecast_type = 0;
+UPDATE binopt.forecast_evaluations e INNER JOIN binopt.forecast_results r ON r.id = e.forecast_result_id SET e.forecast_type = r.forecast_type WHERE e.forecast_type IN (0, 1);
+UPDATE binopt.forecast_explanations SET forecast_type = CASE forecast_type WHEN 1 THEN 5 ELSE 30 END WHERE forecast_type IN (0, 1);
+UPDATE binopt.forecast_accuracy_stats SET forecast_type = CASE forecast_type WHEN 1 THEN 5 ELSE 30 END WHERE forecast_type IN (0, 1);
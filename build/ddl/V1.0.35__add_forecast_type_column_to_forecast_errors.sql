@@ -0,0 +1,6 @@
+-- 予想の失敗を予測種別（予測対象の時間（分））ごとに記録するよう変更する
+ALTER TABLE binopt.forecast_errors ADD forecast_type TINYINT UNSIGNED NOT NULL DEFAULT 30 COMMENT '予測種別' AFTER model_no;
+-- 変更前の記録はどちらの予測時間の予想も止めていたため、5分後の予想の記録としても残す
+INSERT INTO binopt.forecast_errors (rate_id, model_no, forecast_type, summary, detail, created_at, updated_at)
+SELECT rate_id, model_no, 5, summary, detail, created_at, updated_at FROM binopt.forecast_errors WHERE forecast_type = 30;
+ALTER TABLE binopt.forecast_errors ALTER COLUMN forecast_type DROP DEFAULT;
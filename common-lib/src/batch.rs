@@ -1 +1,3 @@
+pub mod health;
 pub mod util;
+pub mod worker;
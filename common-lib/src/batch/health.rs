@@ -0,0 +1,246 @@
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use chrono::{DateTime, Utc};
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use log::{error, info};
+use serde::Serialize;
+
+use crate::{error::MyResult, metrics};
+
+use super::util::Config;
+
+// 直近のジョブ実行の状態
+#[derive(Serialize, Debug, Clone, Default)]
+struct HealthState {
+    running: bool,
+    runs: u64,
+    failures: u64,
+    // 直近で連続して失敗した回数（成功すると0に戻る）
+    consecutive_failures: u64,
+    // 直近の実行にかかった秒数
+    last_duration_seconds: f64,
+    last_started_at: Option<DateTime<Utc>>,
+    last_finished_at: Option<DateTime<Utc>>,
+    last_succeeded_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+struct HealthResponse {
+    job: String,
+    status: String,
+    #[serde(flatten)]
+    state: HealthState,
+}
+
+// 追加のエンドポイントでJSONを返す処理
+pub type JsonHandler = Arc<dyn Fn() -> MyResult<String> + Send + Sync>;
+
+// ジョブの実行状態を記録し、HTTPで公開する
+#[derive(Clone)]
+pub struct HealthReporter {
+    job_name: String,
+    state: Arc<Mutex<HealthState>>,
+    routes: Vec<(String, JsonHandler)>,
+}
+
+impl HealthReporter {
+    pub fn new(job_name: &str) -> Self {
+        // 初回の実行前でもジョブのメトリクスを出力する
+        metrics::init();
+        let labels = [job_name];
+        metrics::BATCH_JOB_RUNNING.with_label_values(&labels).set(0);
+        metrics::BATCH_JOB_RUNS_TOTAL.with_label_values(&labels);
+        metrics::BATCH_JOB_FAILURES_TOTAL.with_label_values(&labels);
+        metrics::BATCH_JOB_CONSECUTIVE_FAILURES
+            .with_label_values(&labels)
+            .set(0);
+
+        HealthReporter {
+            job_name: job_name.to_string(),
+            state: Arc::new(Mutex::new(HealthState::default())),
+            routes: vec![],
+        }
+    }
+
+    // /health, /metrics と同じポートで公開するエンドポイントを追加する（serve より前に呼ぶ）
+    pub fn with_route<F>(mut self, path: &str, handler: F) -> Self
+    where
+        F: Fn() -> MyResult<String> + Send + Sync + 'static,
+    {
+        self.routes.push((path.to_string(), Arc::new(handler)));
+        self
+    }
+
+    // 環境変数のポートで公開する
+    pub fn serve_from_env(&self) -> MyResult<()> {
+        let config = crate::config::load::<Config>()?;
+        self.serve(config.batch_health_port)
+    }
+
+    // 別スレッドで /health と /metrics を公開する（port が 0 の場合は公開しない）
+    pub fn serve(&self, port: u16) -> MyResult<()> {
+        if port == 0 {
+            return Ok(());
+        }
+
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let builder = {
+            let _guard = runtime.enter();
+            Server::try_bind(&addr)?
+        };
+
+        let reporter = self.clone();
+        std::thread::spawn(move || {
+            runtime.block_on(async move {
+                let service = make_service_fn(move |_| {
+                    let reporter = reporter.clone();
+                    async move {
+                        Ok::<_, Infallible>(service_fn(move |request| {
+                            let response = reporter.respond(&request);
+                            async move { Ok::<_, Infallible>(response) }
+                        }))
+                    }
+                });
+                if let Err(err) = builder.serve(service).await {
+                    error!("health endpoint stopped, error: {}", err);
+                }
+            });
+        });
+        info!("health endpoint started, port: {}", port);
+        Ok(())
+    }
+
+    // ジョブを実行し、その結果を記録する
+    pub fn track<F>(&self, f: F) -> MyResult<()>
+    where
+        F: FnOnce() -> MyResult<()>,
+    {
+        let labels = [self.job_name.as_str()];
+        {
+            let mut state = self.state.lock().unwrap();
+            let now = Utc::now();
+            state.running = true;
+            state.last_started_at = Some(now);
+            metrics::BATCH_JOB_RUNNING.with_label_values(&labels).set(1);
+            metrics::BATCH_JOB_LAST_STARTED_TIMESTAMP_SECONDS
+                .with_label_values(&labels)
+                .set(now.timestamp() as f64);
+        }
+
+        let started = Instant::now();
+        let result = f();
+        let duration = started.elapsed();
+
+        let mut state = self.state.lock().unwrap();
+        let now = Utc::now();
+        state.running = false;
+        state.runs += 1;
+        state.last_duration_seconds = duration.as_secs_f64();
+        state.last_finished_at = Some(now);
+        match &result {
+            Ok(_) => {
+                state.consecutive_failures = 0;
+                state.last_succeeded_at = Some(now);
+                state.last_error = None;
+            }
+            Err(err) => {
+                state.failures += 1;
+                state.consecutive_failures += 1;
+                state.last_error = Some(err.to_string());
+            }
+        }
+        metrics::BATCH_JOB_RUNNING.with_label_values(&labels).set(0);
+        metrics::BATCH_JOB_RUNS_TOTAL
+            .with_label_values(&labels)
+            .inc();
+        metrics::BATCH_JOB_DURATION_SECONDS
+            .with_label_values(&labels)
+            .observe(state.last_duration_seconds);
+        metrics::BATCH_JOB_LAST_DURATION_SECONDS
+            .with_label_values(&labels)
+            .set(state.last_duration_seconds);
+        metrics::BATCH_JOB_LAST_FINISHED_TIMESTAMP_SECONDS
+            .with_label_values(&labels)
+            .set(now.timestamp() as f64);
+        metrics::BATCH_JOB_CONSECUTIVE_FAILURES
+            .with_label_values(&labels)
+            .set(state.consecutive_failures as i64);
+        if result.is_ok() {
+            metrics::BATCH_JOB_LAST_SUCCESS_TIMESTAMP_SECONDS
+                .with_label_values(&labels)
+                .set(now.timestamp() as f64);
+        } else {
+            metrics::BATCH_JOB_FAILURES_TOTAL
+                .with_label_values(&labels)
+                .inc();
+        }
+        info!(
+            "job finished, job: {}, duration: {:.3}s, consecutive failures: {}",
+            self.job_name, state.last_duration_seconds, state.consecutive_failures
+        );
+        drop(state);
+
+        result
+    }
+
+    fn respond(&self, request: &Request<Body>) -> Response<Body> {
+        let path = request.uri().path();
+        if let Some((_, handler)) = self.routes.iter().find(|(p, _)| p == path) {
+            return match handler() {
+                Ok(body) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(body))
+                    .expect("Unable to create response"),
+                Err(err) => {
+                    error!("failed to respond, path: {}, error: {}", path, err);
+                    Response::builder()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .body(Body::empty())
+                        .expect("Unable to create Internal Server Error response")
+                }
+            };
+        }
+
+        let state = self.state.lock().unwrap().clone();
+        match path {
+            "/health" => {
+                let status = match (&state.last_finished_at, &state.last_error) {
+                    (None, _) => "waiting",
+                    (Some(_), None) => "ok",
+                    (Some(_), Some(_)) => "failed",
+                };
+                let body = HealthResponse {
+                    job: self.job_name.clone(),
+                    status: status.to_string(),
+                    state,
+                };
+                Response::builder()
+                    .status(StatusCode::OK)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(
+                        serde_json::to_string(&body).expect("impossible to fail to serialize"),
+                    ))
+                    .expect("Unable to create health response")
+            }
+            "/metrics" => metrics::response(),
+            _ => Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty())
+                .expect("Unable to create Not Found response"),
+        }
+    }
+}
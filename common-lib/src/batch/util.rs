@@ -1,27 +1,999 @@
-use job_scheduler::{Job, JobScheduler};
-use log::info;
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
 
-use crate::error::MyResult;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use job_scheduler::Schedule;
+use log::{error, info, warn};
+use rand::Rng;
+use serde::Deserialize;
+use signal_hook::consts::{SIGINT, SIGTERM};
 
+use crate::{
+    domain::model::{JOB_RUN_STATUS_FAILED, JOB_RUN_STATUS_SUCCEEDED},
+    error::{MyError, MyResult},
+    error_report,
+    mysql::client::{Client, DefaultClient},
+    notify::{self, NotifyEvent},
+};
+
+// 前回の実行が次の予定時刻を過ぎて終わった場合の扱い
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OverlapPolicy {
+    // 実行中に過ぎた予定時刻の分は実行しない
+    Skip,
+    // 実行中に過ぎた予定時刻の分を終了後に1回だけ実行する
+    #[default]
+    Queue,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    #[serde(default)]
+    pub scheduler_overlap_policy: OverlapPolicy,
+    // 複数インスタンスで実行する場合に、ジョブごとのリースを取得できたインスタンスだけが実行するか
+    #[serde(default)]
+    pub scheduler_lock_enabled: bool,
+    // リースの有効期限（秒）、異常終了したインスタンスのリースは期限を過ぎると他のインスタンスが取得できる
+    #[serde(default = "default_scheduler_lock_seconds")]
+    pub scheduler_lock_seconds: i64,
+    // ジョブが失敗した場合の最大試行回数（1の場合は再試行しない）
+    #[serde(default = "default_scheduler_retry_attempts")]
+    pub scheduler_retry_attempts: u32,
+    // 再試行までの待ち時間（ミリ秒）、再試行のたびに2倍にする
+    #[serde(default = "default_scheduler_retry_backoff_ms")]
+    pub scheduler_retry_backoff_ms: u64,
+    // DBに接続できないなどの一時的なエラーの場合のみ再試行するか
+    #[serde(default = "default_scheduler_retry_retryable_only")]
+    pub scheduler_retry_retryable_only: bool,
+    // 実行前にランダムに待つ最大秒数（0の場合は待たない）、複数のバッチが同時にDBへアクセスしないようにする
+    #[serde(default)]
+    pub scheduler_jitter_seconds: u64,
+    // /health と /metrics を公開するポート（0の場合は公開しない）
+    #[serde(default)]
+    pub batch_health_port: u16,
+    // スケジュールではなく、オーケストレーターからのジョブ実行要求を受けて実行するか
+    #[serde(default)]
+    pub batch_worker_mode: bool,
+    // ジョブ実行要求を確認する間隔（ミリ秒）
+    #[serde(default = "default_batch_worker_poll_interval_ms")]
+    pub batch_worker_poll_interval_ms: u64,
+}
+
+fn default_scheduler_lock_seconds() -> i64 {
+    3600
+}
+
+fn default_scheduler_retry_attempts() -> u32 {
+    1
+}
+
+fn default_scheduler_retry_backoff_ms() -> u64 {
+    1000
+}
+
+fn default_scheduler_retry_retryable_only() -> bool {
+    true
+}
+
+fn default_batch_worker_poll_interval_ms() -> u64 {
+    5000
+}
+
+pub fn overlap_policy_from_env() -> MyResult<OverlapPolicy> {
+    Ok(crate::config::load::<Config>()?.scheduler_overlap_policy)
+}
+
+pub fn worker_mode_from_env() -> MyResult<bool> {
+    Ok(crate::config::load::<Config>()?.batch_worker_mode)
+}
+
+// リースやジョブ実行要求の所有者として記録するインスタンスの識別子
+pub(crate) fn instance_id() -> String {
+    let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_string());
+    format!("{}:{}", host, std::process::id())
+}
+
+// ジョブの再試行の設定
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub attempts: u32,
+    pub backoff_ms: u64,
+    pub retryable_only: bool,
+}
+
+impl RetryPolicy {
+    pub fn from_env() -> MyResult<Self> {
+        let config = crate::config::load::<Config>()?;
+        Ok(RetryPolicy {
+            attempts: config.scheduler_retry_attempts.max(1),
+            backoff_ms: config.scheduler_retry_backoff_ms,
+            retryable_only: config.scheduler_retry_retryable_only,
+        })
+    }
+
+    // 失敗した場合は待ち時間を置いて再試行し、最後の結果を返す
+    pub fn run<F>(&self, job_name: &str, f: F) -> MyResult<()>
+    where
+        F: Fn() -> MyResult<()>,
+    {
+        self.run_with(job_name, f, std::thread::sleep)
+    }
+
+    // 待ち時間の処理を差し替えて実行する
+    fn run_with<F, S>(&self, job_name: &str, f: F, mut sleep: S) -> MyResult<()>
+    where
+        F: Fn() -> MyResult<()>,
+        S: FnMut(std::time::Duration),
+    {
+        let mut backoff = self.backoff_ms;
+        let mut attempt = 1;
+        loop {
+            let err = match f() {
+                Ok(_) => return Ok(()),
+                Err(err) => err,
+            };
+            if attempt >= self.attempts || (self.retryable_only && !err.is_retryable()) {
+                return Err(err);
+            }
+
+            warn!(
+                "job failed, retry after {}ms, job: {}, attempt: {}/{}, error: {}",
+                backoff, job_name, attempt, self.attempts, err
+            );
+            sleep(std::time::Duration::from_millis(backoff));
+            backoff = backoff.saturating_mul(2);
+            attempt += 1;
+        }
+    }
+}
+
+// スケジューラの設定
+pub struct SchedulerOptions<'a> {
+    pub overlap_policy: OverlapPolicy,
+    pub lock: Option<JobLock<'a>>,
+    pub jitter_seconds: u64,
+}
+
+impl<'a> SchedulerOptions<'a> {
+    // ロックを使わない設定を環境変数から読み込む
+    pub fn from_env_without_lock() -> MyResult<Self> {
+        let config = crate::config::load::<Config>()?;
+        Ok(SchedulerOptions {
+            overlap_policy: config.scheduler_overlap_policy,
+            lock: None,
+            jitter_seconds: config.scheduler_jitter_seconds,
+        })
+    }
+
+    // 環境変数から読み込む、ロックが有効な場合は job_name をロック名に使う
+    pub fn from_env(job_name: &str, mysql_cli: &'a DefaultClient) -> MyResult<Self> {
+        let config = crate::config::load::<Config>()?;
+        let lock = if config.scheduler_lock_enabled {
+            Some(JobLock::new(
+                mysql_cli,
+                job_name,
+                config.scheduler_lock_seconds,
+            ))
+        } else {
+            None
+        };
+        Ok(SchedulerOptions {
+            overlap_policy: config.scheduler_overlap_policy,
+            lock,
+            jitter_seconds: config.scheduler_jitter_seconds,
+        })
+    }
+}
+
+// ジョブのリースの保存先（複数のスケジューラのスレッドから使う）
+pub trait LeaseStore: Sync {
+    // 同じ予定時刻を他のインスタンスが実行済み、または実行中（期限内）の場合は false を返す
+    fn acquire_lease(
+        &self,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+        lease_seconds: i64,
+    ) -> MyResult<bool>;
+    // 期限を現在日時にして、次の予定時刻のリースを他のインスタンスが取得できるようにする
+    fn release_lease(&self, name: &str, owner: &str, scheduled_at: &NaiveDateTime) -> MyResult<()>;
+}
+
+impl LeaseStore for DefaultClient {
+    fn acquire_lease(
+        &self,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+        lease_seconds: i64,
+    ) -> MyResult<bool> {
+        self.with_transaction(|tx| {
+            self.acquire_job_lease(tx, name, owner, scheduled_at, lease_seconds)
+        })
+    }
+
+    fn release_lease(&self, name: &str, owner: &str, scheduled_at: &NaiveDateTime) -> MyResult<()> {
+        self.with_transaction(|tx| self.release_job_lease(tx, name, owner, scheduled_at))
+    }
+}
+
+// DBのリースによるジョブのロック
+// 予定時刻ごとに1つのインスタンスだけがリースを取得できる
+#[derive(Clone)]
+pub struct JobLock<'a> {
+    store: &'a dyn LeaseStore,
+    name: String,
+    owner: String,
+    lease_seconds: i64,
+}
+
+impl<'a> JobLock<'a> {
+    pub fn new(store: &'a dyn LeaseStore, name: &str, lease_seconds: i64) -> Self {
+        JobLock {
+            store,
+            name: name.to_string(),
+            owner: instance_id(),
+            lease_seconds,
+        }
+    }
+
+    fn with_suffix(&self, suffix: &str) -> Self {
+        JobLock {
+            name: format!("{}#{}", self.name, suffix),
+            ..self.clone()
+        }
+    }
+
+    pub fn acquire(&self, scheduled_at: &NaiveDateTime) -> MyResult<bool> {
+        self.store
+            .acquire_lease(&self.name, &self.owner, scheduled_at, self.lease_seconds)
+    }
+
+    pub fn release(&self, scheduled_at: &NaiveDateTime) -> MyResult<()> {
+        self.store
+            .release_lease(&self.name, &self.owner, scheduled_at)
+    }
+}
+
+// ジョブを実行し、開始・終了日時と結果をジョブ実行履歴に記録する
+// 履歴の記録に失敗してもジョブは実行し、ジョブの結果をそのまま返す
+pub fn record_job_run<F>(mysql_cli: &DefaultClient, job_name: &str, f: F) -> MyResult<()>
+where
+    F: FnOnce() -> MyResult<()>,
+{
+    let _span = tracing::info_span!("batch.job", job = job_name).entered();
+
+    let id = match mysql_cli.with_transaction(|tx| mysql_cli.insert_job_run(tx, job_name)) {
+        Ok(id) => Some(id),
+        Err(err) => {
+            error!(
+                "failed to record job run started, job: {}, error: {}",
+                job_name, err
+            );
+            None
+        }
+    };
+
+    let result = f();
+    if let Err(err) = &result {
+        error_report::capture(err, &[("job", job_name)]);
+        notify::notify_from_env(
+            NotifyEvent::BatchFailed,
+            &format!("job: {}, error: {}", job_name, err),
+        );
+    }
+
+    if let Some(id) = id {
+        let (status, message) = match &result {
+            Ok(_) => (JOB_RUN_STATUS_SUCCEEDED, None),
+            Err(err) => (JOB_RUN_STATUS_FAILED, Some(err.to_string())),
+        };
+        if let Err(err) = mysql_cli.with_transaction(|tx| {
+            mysql_cli.update_job_run_finished(tx, &id, status, message.as_deref())
+        }) {
+            error!(
+                "failed to record job run finished, job: {}, id: {}, error: {}",
+                job_name, id, err
+            );
+        }
+    }
+
+    result
+}
+
+// スケジューラの停止要求
+// 実行中のジョブは中断せず、ジョブの合間に停止する
+#[derive(Clone, Default)]
+pub struct ShutdownHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl ShutdownHandle {
+    pub fn new() -> Self {
+        ShutdownHandle::default()
+    }
+
+    // SIGTERM/SIGINT を受けたら停止を要求する
+    pub fn register_signals(&self) -> MyResult<()> {
+        for signal in [SIGTERM, SIGINT] {
+            signal_hook::flag::register(signal, Arc::clone(&self.requested))?;
+        }
+        Ok(())
+    }
+
+    pub fn shutdown(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+}
+
+// シグナルを受けるまでスケジュールに従ってジョブを実行する
 pub fn start_scheduler<F>(cron_schedule: &str, f: F) -> MyResult<()>
 where
     F: Fn(),
+{
+    let options = SchedulerOptions::from_env_without_lock()?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_scheduler_with(cron_schedule, f, options, &shutdown)
+}
+
+// 複数インスタンスで実行する場合は job_name のロックを取得できたインスタンスだけが実行する
+pub fn start_scheduler_with_lock<F>(
+    job_name: &str,
+    mysql_cli: &DefaultClient,
+    cron_schedule: &str,
+    f: F,
+) -> MyResult<()>
+where
+    F: Fn(),
+{
+    let options = SchedulerOptions::from_env(job_name, mysql_cli)?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_scheduler_with(cron_schedule, f, options, &shutdown)
+}
+
+// 同期のジョブは非同期のスケジューラ上で実行する（実行中は他のジョブを待たせる）
+pub fn start_scheduler_with<F>(
+    cron_schedule: &str,
+    f: F,
+    options: SchedulerOptions,
+    shutdown: &ShutdownHandle,
+) -> MyResult<()>
+where
+    F: Fn(),
+{
+    let f = &f;
+    block_on(start_async_scheduler_with(
+        cron_schedule,
+        move || async move { tokio::task::block_in_place(f) },
+        options,
+        shutdown,
+    ))
+}
+
+pub fn start_multi_scheduler(jobs: &[(&str, &(dyn Fn() + Sync))]) -> MyResult<()> {
+    let options = SchedulerOptions::from_env_without_lock()?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_multi_scheduler_with(jobs, options, &shutdown)
+}
+
+pub fn start_multi_scheduler_with_lock(
+    job_name: &str,
+    mysql_cli: &DefaultClient,
+    jobs: &[(&str, &(dyn Fn() + Sync))],
+) -> MyResult<()> {
+    let options = SchedulerOptions::from_env(job_name, mysql_cli)?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_multi_scheduler_with(jobs, options, &shutdown)
+}
+
+// ジョブごとに重なりを判定し、ロックはジョブの番号を付けた名前で取得する
+// 実行に時間のかかるジョブが他のジョブを待たせないよう、ジョブごとに別のスレッドで動かす
+pub fn start_multi_scheduler_with(
+    jobs: &[(&str, &(dyn Fn() + Sync))],
+    options: SchedulerOptions,
+    shutdown: &ShutdownHandle,
+) -> MyResult<()> {
+    thread::scope(|s| {
+        let handles: Vec<_> = jobs
+            .iter()
+            .enumerate()
+            .map(|(i, (cron_schedule, f))| {
+                let options = SchedulerOptions {
+                    overlap_policy: options.overlap_policy,
+                    lock: options
+                        .lock
+                        .as_ref()
+                        .map(|lock| lock.with_suffix(&i.to_string())),
+                    jitter_seconds: options.jitter_seconds,
+                };
+                s.spawn(move || {
+                    let result = start_scheduler_with(cron_schedule, f, options, shutdown);
+                    // 1つのジョブを開始できない場合は、他のジョブも止めてエラーを返す
+                    if result.is_err() {
+                        shutdown.shutdown();
+                    }
+                    result
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("scheduler thread panicked"))
+            .collect::<MyResult<Vec<()>>>()
+            .map(|_| ())
+    })
+}
+
+// 同期のジョブ内でブロッキング処理（DBアクセスや reqwest::blocking）を使えるよう、
+// block_in_place を利用できるマルチスレッドのランタイムで動かす
+fn block_on<T>(f: impl Future<Output = MyResult<T>>) -> MyResult<T> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?
+        .block_on(f)
+}
+
+// 非同期のジョブをシグナルを受けるまでスケジュールに従って実行する
+// 呼び出し元のランタイム上で動くため、ジョブ内で非同期のDBアクセスやHTTP通信をそのまま使える
+pub async fn start_async_scheduler<F, Fut>(cron_schedule: &str, f: F) -> MyResult<()>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let options = SchedulerOptions::from_env_without_lock()?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_async_scheduler_with(cron_schedule, f, options, &shutdown).await
+}
+
+pub async fn start_async_scheduler_with<F, Fut>(
+    cron_schedule: &str,
+    f: F,
+    options: SchedulerOptions<'_>,
+    shutdown: &ShutdownHandle,
+) -> MyResult<()>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ()>,
 {
     if cron_schedule.is_empty() {
         info!("run onece only, cron schedule is empty");
-        f();
+        f().await;
         return Ok(());
     }
 
-    let mut sched = JobScheduler::new();
+    let schedule: Schedule = cron_schedule.parse().map_err(|err| MyError::ParseError {
+        param_name: "cron_schedule".to_string(),
+        value: cron_schedule.to_string(),
+        memo: format!("{}", err),
+    })?;
+    info!(
+        "set cron schedule: {}, overlap policy: {:?}, lock: {}, jitter: {}s",
+        cron_schedule,
+        options.overlap_policy,
+        options.lock.is_some(),
+        options.jitter_seconds
+    );
 
-    info!("set cron schedule: {}", cron_schedule);
-    sched.add(Job::new(cron_schedule.parse()?, || {
-        f();
-    }));
+    let mut last = Utc::now();
+    while let Some(scheduled_at) = schedule.after(&last).next() {
+        // ロックの取得もDBへのアクセスとなるため、予定時刻からずらして待つ
+        if !sleep_until(scheduled_at + jitter(options.jitter_seconds), shutdown).await {
+            break;
+        }
+
+        // ロックはインスタンス間で同じ値になるよう、実行日時ではなく予定時刻で取得する
+        let locked = match &options.lock {
+            Some(lock) => match lock.acquire(&scheduled_at.naive_utc()) {
+                Ok(true) => true,
+                Ok(false) => {
+                    info!(
+                        "skip job, another instance has the lock: {}, scheduled at: {}",
+                        lock.name, scheduled_at
+                    );
+                    last = scheduled_at;
+                    continue;
+                }
+                Err(err) => {
+                    error!(
+                        "failed to acquire job lock, name: {}, error: {}",
+                        lock.name, err
+                    );
+                    last = scheduled_at;
+                    continue;
+                }
+            },
+            None => false,
+        };
+
+        f().await;
+
+        if let (true, Some(lock)) = (locked, &options.lock) {
+            if let Err(err) = lock.release(&scheduled_at.naive_utc()) {
+                error!(
+                    "failed to release job lock, name: {}, error: {}",
+                    lock.name, err
+                );
+            }
+        }
+
+        last = next_base(
+            &schedule,
+            &scheduled_at,
+            &Utc::now(),
+            options.overlap_policy,
+        );
+    }
 
+    info!("shutdown requested, scheduler stopped");
+    Ok(())
+}
+
+// 予定時刻からずらして待つ時間（0〜max_seconds秒）
+fn jitter(max_seconds: u64) -> Duration {
+    if max_seconds == 0 {
+        return Duration::zero();
+    }
+    Duration::milliseconds(rand::thread_rng().gen_range(0..=max_seconds * 1000) as i64)
+}
+
+// 次の予定時刻を探す基準の日時を返す
+// 実行中に過ぎた予定時刻は、Queue の場合は最後の1回分だけ続けて実行し、Skip の場合は実行しない
+fn next_base(
+    schedule: &Schedule,
+    scheduled_at: &DateTime<Utc>,
+    now: &DateTime<Utc>,
+    overlap_policy: OverlapPolicy,
+) -> DateTime<Utc> {
+    match schedule.after(scheduled_at).take_while(|t| t <= now).last() {
+        Some(missed) if overlap_policy == OverlapPolicy::Queue => {
+            missed - Duration::milliseconds(1)
+        }
+        Some(missed) => {
+            info!(
+                "skip job, previous run overlapped the schedule, missed: {}",
+                missed
+            );
+            *now
+        }
+        None => *now,
+    }
+}
+
+// 停止が要求された場合は false を返す
+async fn sleep_until(at: DateTime<Utc>, shutdown: &ShutdownHandle) -> bool {
     loop {
-        sched.tick();
-        std::thread::sleep(std::time::Duration::from_millis(500));
+        if shutdown.is_shutdown() {
+            return false;
+        }
+        let remaining = match (at - Utc::now()).to_std() {
+            Ok(remaining) if !remaining.is_zero() => remaining,
+            _ => return true,
+        };
+        tokio::time::sleep(remaining.min(std::time::Duration::from_millis(500))).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        collections::HashMap,
+        sync::{atomic::AtomicUsize, Mutex},
+    };
+
+    use crate::clock::{Clock, FixedClock};
+
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    // job_leases テーブルと同じ条件でリースを管理する
+    struct MemoryLeaseStore {
+        clock: FixedClock,
+        // name => (owner, scheduled_at, expires_at)
+        leases: Mutex<HashMap<String, (String, NaiveDateTime, NaiveDateTime)>>,
+    }
+
+    impl MemoryLeaseStore {
+        fn new(now: &str) -> Self {
+            MemoryLeaseStore {
+                clock: FixedClock::new(utc(now)),
+                leases: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    impl LeaseStore for MemoryLeaseStore {
+        fn acquire_lease(
+            &self,
+            name: &str,
+            owner: &str,
+            scheduled_at: &NaiveDateTime,
+            lease_seconds: i64,
+        ) -> MyResult<bool> {
+            let now = self.clock.now().naive_utc();
+            let mut leases = self.leases.lock().unwrap();
+            let lease = leases.entry(name.to_string()).or_insert((
+                "".to_string(),
+                NaiveDateTime::default(),
+                NaiveDateTime::default(),
+            ));
+            if lease.1 < *scheduled_at && lease.2 <= now {
+                *lease = (
+                    owner.to_string(),
+                    *scheduled_at,
+                    now + Duration::seconds(lease_seconds),
+                );
+                return Ok(true);
+            }
+            Ok(false)
+        }
+
+        fn release_lease(
+            &self,
+            name: &str,
+            owner: &str,
+            scheduled_at: &NaiveDateTime,
+        ) -> MyResult<()> {
+            let now = self.clock.now().naive_utc();
+            if let Some(lease) = self.leases.lock().unwrap().get_mut(name) {
+                if lease.0 == owner && lease.1 == *scheduled_at {
+                    lease.2 = now;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn lock<'a>(store: &'a MemoryLeaseStore, owner: &str) -> JobLock<'a> {
+        JobLock {
+            owner: owner.to_string(),
+            ..JobLock::new(store, "job", 60)
+        }
+    }
+
+    #[test]
+    fn test_for_overlap_policy_default() {
+        assert_eq!(OverlapPolicy::default(), OverlapPolicy::Queue);
+    }
+
+    #[test]
+    fn test_for_next_base() {
+        // 毎分0秒に実行する
+        let schedule: Schedule = "0 * * * * *".parse().unwrap();
+        let scheduled_at = utc("2022-01-01T10:00:00Z");
+
+        struct Param {
+            title: String,
+            now: DateTime<Utc>,
+            overlap_policy: OverlapPolicy,
+            expected_next: DateTime<Utc>,
+        }
+        let params = vec![
+            Param {
+                title: "次の予定時刻までに終わった場合は次の予定時刻に実行する（Queue）"
+                    .to_string(),
+                now: utc("2022-01-01T10:00:30Z"),
+                overlap_policy: OverlapPolicy::Queue,
+                expected_next: utc("2022-01-01T10:01:00Z"),
+            },
+            Param {
+                title: "次の予定時刻までに終わった場合は次の予定時刻に実行する（Skip）".to_string(),
+                now: utc("2022-01-01T10:00:30Z"),
+                overlap_policy: OverlapPolicy::Skip,
+                expected_next: utc("2022-01-01T10:01:00Z"),
+            },
+            Param {
+                title: "Queue の場合は過ぎた予定時刻のうち最後の1回分をすぐに実行する".to_string(),
+                now: utc("2022-01-01T10:03:30Z"),
+                overlap_policy: OverlapPolicy::Queue,
+                expected_next: utc("2022-01-01T10:03:00Z"),
+            },
+            Param {
+                title: "Skip の場合は過ぎた予定時刻は実行せず次の予定時刻に実行する".to_string(),
+                now: utc("2022-01-01T10:03:30Z"),
+                overlap_policy: OverlapPolicy::Skip,
+                expected_next: utc("2022-01-01T10:04:00Z"),
+            },
+        ];
+
+        for p in params {
+            let base = next_base(&schedule, &scheduled_at, &p.now, p.overlap_policy);
+            let next = schedule.after(&base).next().unwrap();
+            assert_eq!(next, p.expected_next, "{}", p.title);
+        }
+    }
+
+    #[test]
+    fn test_for_jitter() {
+        assert_eq!(jitter(0), Duration::zero());
+        for _ in 0..1000 {
+            let j = jitter(2);
+            assert!(j >= Duration::zero(), "jitter: {}", j);
+            assert!(j <= Duration::seconds(2), "jitter: {}", j);
+        }
+    }
+
+    #[test]
+    fn test_for_retry_policy() {
+        let retryable = || MyError::Io(std::io::Error::from(std::io::ErrorKind::TimedOut));
+        let not_retryable = || MyError::Io(std::io::Error::from(std::io::ErrorKind::Other));
+
+        struct Param {
+            title: String,
+            retryable_only: bool,
+            // 各試行の結果（true: 成功、false: 失敗）、足りない分は失敗とする
+            results: Vec<bool>,
+            retryable_error: bool,
+            expected_ok: bool,
+            expected_calls: usize,
+            expected_sleeps_ms: Vec<u64>,
+        }
+        let params = vec![
+            Param {
+                title: "成功した場合は再試行しない".to_string(),
+                retryable_only: true,
+                results: vec![true],
+                retryable_error: true,
+                expected_ok: true,
+                expected_calls: 1,
+                expected_sleeps_ms: vec![],
+            },
+            Param {
+                title: "失敗するたびに待ち時間を2倍にして最大回数まで再試行する".to_string(),
+                retryable_only: true,
+                results: vec![],
+                retryable_error: true,
+                expected_ok: false,
+                expected_calls: 4,
+                expected_sleeps_ms: vec![100, 200, 400],
+            },
+            Param {
+                title: "再試行で成功した場合はそこで終了する".to_string(),
+                retryable_only: true,
+                results: vec![false, false, true],
+                retryable_error: true,
+                expected_ok: true,
+                expected_calls: 3,
+                expected_sleeps_ms: vec![100, 200],
+            },
+            Param {
+                title: "一時的でないエラーは再試行しない".to_string(),
+                retryable_only: true,
+                results: vec![],
+                retryable_error: false,
+                expected_ok: false,
+                expected_calls: 1,
+                expected_sleeps_ms: vec![],
+            },
+            Param {
+                title: "retryable_only が false の場合は一時的でないエラーも再試行する".to_string(),
+                retryable_only: false,
+                results: vec![],
+                retryable_error: false,
+                expected_ok: false,
+                expected_calls: 4,
+                expected_sleeps_ms: vec![100, 200, 400],
+            },
+        ];
+
+        for p in params {
+            let policy = RetryPolicy {
+                attempts: 4,
+                backoff_ms: 100,
+                retryable_only: p.retryable_only,
+            };
+            let calls = AtomicUsize::new(0);
+            let sleeps = RefCell::new(vec![]);
+            let result = policy.run_with(
+                "job",
+                || {
+                    let i = calls.fetch_add(1, Ordering::SeqCst);
+                    match p.results.get(i) {
+                        Some(true) => Ok(()),
+                        _ if p.retryable_error => Err(retryable()),
+                        _ => Err(not_retryable()),
+                    }
+                },
+                |d| sleeps.borrow_mut().push(d.as_millis() as u64),
+            );
+
+            assert_eq!(result.is_ok(), p.expected_ok, "{}", p.title);
+            assert_eq!(calls.into_inner(), p.expected_calls, "{}", p.title);
+            assert_eq!(sleeps.into_inner(), p.expected_sleeps_ms, "{}", p.title);
+        }
+    }
+
+    #[test]
+    fn test_for_job_lock() {
+        let store = MemoryLeaseStore::new("2022-01-01T10:00:05Z");
+        let a = lock(&store, "a");
+        let b = lock(&store, "b");
+        let t1 = utc("2022-01-01T10:00:00Z").naive_utc();
+        let t2 = utc("2022-01-01T10:01:00Z").naive_utc();
+        let t3 = utc("2022-01-01T10:02:00Z").naive_utc();
+
+        // 同じ予定時刻は1つのインスタンスだけが取得できる
+        assert!(a.acquire(&t1).unwrap());
+        assert!(!b.acquire(&t1).unwrap());
+
+        // 実行中（期限内）の場合は次の予定時刻も取得できない
+        store.clock.set(utc("2022-01-01T10:01:00Z"));
+        assert!(!b.acquire(&t2).unwrap());
+
+        // 異常終了などで解放されなかったリースは、期限を過ぎると他のインスタンスが取得できる
+        store.clock.set(utc("2022-01-01T10:01:05Z"));
+        assert!(b.acquire(&t2).unwrap());
+
+        // 実行済みの予定時刻は期限を過ぎても取得できない
+        b.release(&t2).unwrap();
+        assert!(!a.acquire(&t2).unwrap());
+
+        // 解放したリースは期限前でも次の予定時刻を取得できる
+        store.clock.set(utc("2022-01-01T10:02:00Z"));
+        assert!(a.acquire(&t3).unwrap());
+
+        // 他のインスタンスのリースは解放できない
+        b.release(&t3).unwrap();
+        store.clock.set(utc("2022-01-01T10:02:30Z"));
+        assert!(!b.acquire(&utc("2022-01-01T10:02:30Z").naive_utc()).unwrap());
+    }
+
+    #[test]
+    fn test_for_job_lock_with_suffix() {
+        let store = MemoryLeaseStore::new("2022-01-01T10:00:05Z");
+        let t1 = utc("2022-01-01T10:00:00Z").naive_utc();
+
+        // ジョブごとに別のリースになる
+        assert!(lock(&store, "a").with_suffix("0").acquire(&t1).unwrap());
+        assert!(lock(&store, "b").with_suffix("1").acquire(&t1).unwrap());
+        assert!(!lock(&store, "b").with_suffix("0").acquire(&t1).unwrap());
+    }
+
+    #[test]
+    fn test_for_shutdown_before_start() {
+        let shutdown = ShutdownHandle::new();
+        shutdown.shutdown();
+        let runs = AtomicUsize::new(0);
+
+        block_on(start_async_scheduler_with(
+            "* * * * * *",
+            || async {
+                runs.fetch_add(1, Ordering::SeqCst);
+            },
+            SchedulerOptions {
+                overlap_policy: OverlapPolicy::Queue,
+                lock: None,
+                jitter_seconds: 0,
+            },
+            &shutdown,
+        ))
+        .unwrap();
+
+        assert_eq!(runs.into_inner(), 0);
+    }
+
+    #[test]
+    fn test_for_shutdown_while_running() {
+        let shutdown = ShutdownHandle::new();
+        let runs = AtomicUsize::new(0);
+        let finished = AtomicUsize::new(0);
+
+        // 実行中に停止を要求しても、実行中のジョブは最後まで実行してから停止する
+        block_on(start_async_scheduler_with(
+            "* * * * * *",
+            || async {
+                runs.fetch_add(1, Ordering::SeqCst);
+                shutdown.shutdown();
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                finished.fetch_add(1, Ordering::SeqCst);
+            },
+            SchedulerOptions {
+                overlap_policy: OverlapPolicy::Queue,
+                lock: None,
+                jitter_seconds: 0,
+            },
+            &shutdown,
+        ))
+        .unwrap();
+
+        assert_eq!(runs.into_inner(), 1);
+        assert_eq!(finished.into_inner(), 1);
+    }
+
+    #[test]
+    fn test_for_multi_scheduler() {
+        let shutdown = ShutdownHandle::new();
+        let long_running = AtomicBool::new(false);
+        let runs_while_long_running = AtomicUsize::new(0);
+
+        // 実行に時間のかかるジョブの実行中も、他のジョブはスケジュールどおりに実行する
+        let long_job = || {
+            long_running.store(true, Ordering::SeqCst);
+            let started = std::time::Instant::now();
+            while runs_while_long_running.load(Ordering::SeqCst) < 2
+                && started.elapsed() < std::time::Duration::from_secs(5)
+            {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            long_running.store(false, Ordering::SeqCst);
+            shutdown.shutdown();
+        };
+        let short_job = || {
+            if long_running.load(Ordering::SeqCst) {
+                runs_while_long_running.fetch_add(1, Ordering::SeqCst);
+            }
+        };
+        start_multi_scheduler_with(
+            &[("* * * * * *", &long_job), ("* * * * * *", &short_job)],
+            SchedulerOptions {
+                overlap_policy: OverlapPolicy::Skip,
+                lock: None,
+                jitter_seconds: 0,
+            },
+            &shutdown,
+        )
+        .unwrap();
+
+        assert!(runs_while_long_running.into_inner() >= 2);
+    }
+
+    #[test]
+    fn test_for_scheduler_with_lock() {
+        let store = MemoryLeaseStore::new("2000-01-01T00:00:00Z");
+        let shutdown = ShutdownHandle::new();
+        let runs = AtomicUsize::new(0);
+
+        // 他のインスタンスがリースを持っている予定時刻は実行せず、次の予定時刻で実行する
+        let other = lock(&store, "other");
+        let now = Utc::now();
+        let next = "* * * * * *"
+            .parse::<Schedule>()
+            .unwrap()
+            .after(&now)
+            .next()
+            .unwrap();
+        assert!(other.acquire(&next.naive_utc()).unwrap());
+        other.release(&next.naive_utc()).unwrap();
+
+        block_on(start_async_scheduler_with(
+            "* * * * * *",
+            || async {
+                runs.fetch_add(1, Ordering::SeqCst);
+                shutdown.shutdown();
+            },
+            SchedulerOptions {
+                overlap_policy: OverlapPolicy::Queue,
+                lock: Some(lock(&store, "self")),
+                jitter_seconds: 0,
+            },
+            &shutdown,
+        ))
+        .unwrap();
+
+        assert_eq!(runs.into_inner(), 1);
+        let leases = store.leases.lock().unwrap();
+        let (owner, scheduled_at, _) = leases.get("job").unwrap();
+        assert_eq!(owner, "self");
+        assert!(*scheduled_at > next.naive_utc());
     }
 }
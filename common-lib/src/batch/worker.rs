@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::{
+    batch::util::{self, ShutdownHandle},
+    domain::model::{JOB_REQUEST_STATUS_FAILED, JOB_REQUEST_STATUS_SUCCEEDED},
+    error::MyResult,
+    mysql::client::{Client, DefaultClient},
+};
+
+// シグナルを受けるまでオーケストレーターからのジョブ実行要求を待ち、取得できた要求ごとにジョブを実行する
+// 失敗した場合の再試行や他のジョブとの実行順序はオーケストレーターが管理する
+pub fn start_worker<F>(job_name: &str, mysql_cli: &DefaultClient, f: F) -> MyResult<()>
+where
+    F: Fn() -> MyResult<()>,
+{
+    let config = crate::config::load::<util::Config>()?;
+    let shutdown = ShutdownHandle::new();
+    shutdown.register_signals()?;
+    start_worker_with(
+        job_name,
+        mysql_cli,
+        f,
+        Duration::from_millis(config.batch_worker_poll_interval_ms),
+        &shutdown,
+    )
+}
+
+pub fn start_worker_with<F>(
+    job_name: &str,
+    mysql_cli: &DefaultClient,
+    f: F,
+    poll_interval: Duration,
+    shutdown: &ShutdownHandle,
+) -> MyResult<()>
+where
+    F: Fn() -> MyResult<()>,
+{
+    let owner = util::instance_id();
+    info!(
+        "start worker, job: {}, owner: {}, poll interval: {:?}",
+        job_name, owner, poll_interval
+    );
+
+    while !shutdown.is_shutdown() {
+        let id = match mysql_cli
+            .with_transaction(|tx| mysql_cli.claim_job_request(tx, job_name, &owner))
+        {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                sleep(poll_interval, shutdown);
+                continue;
+            }
+            Err(err) => {
+                error!(
+                    "failed to claim job request, job: {}, error: {}",
+                    job_name, err
+                );
+                sleep(poll_interval, shutdown);
+                continue;
+            }
+        };
+
+        info!("start job request, job: {}, id: {}", job_name, id);
+        let result = f();
+        let (status, message) = match &result {
+            Ok(_) => (JOB_REQUEST_STATUS_SUCCEEDED, None),
+            Err(err) => (JOB_REQUEST_STATUS_FAILED, Some(err.to_string())),
+        };
+        if let Err(err) = mysql_cli.with_transaction(|tx| {
+            mysql_cli.update_job_request_finished(tx, &id, status, message.as_deref())
+        }) {
+            error!(
+                "failed to record job request finished, job: {}, id: {}, error: {}",
+                job_name, id, err
+            );
+        }
+    }
+
+    info!("shutdown requested, worker stopped");
+    Ok(())
+}
+
+// 停止の要求にすぐ応じられるよう、短い間隔で確認しながら待つ
+fn sleep(duration: Duration, shutdown: &ShutdownHandle) {
+    let step = Duration::from_millis(500);
+    let mut remaining = duration;
+    while !remaining.is_zero() && !shutdown.is_shutdown() {
+        let d = remaining.min(step);
+        std::thread::sleep(d);
+        remaining -= d;
+    }
+}
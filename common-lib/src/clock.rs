@@ -0,0 +1,78 @@
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+
+// 現在日時の取得元
+// 日時に依存する処理をテストできるよう、Utc::now() を直接呼ばずにこれを経由する
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+// 複数のスレッドから参照する現在日時の取得元
+pub type SharedClock = Arc<dyn Clock>;
+
+// システムの現在日時を返す
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// 指定した日時を返す（テスト用）
+#[derive(Debug)]
+pub struct FixedClock {
+    now: RwLock<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        FixedClock {
+            now: RwLock::new(now),
+        }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.write().unwrap() = now;
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.write().unwrap();
+        *now += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.read().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_for_fixed_clock() {
+        let clock = FixedClock::new(datetime("2022-01-02T03:04:05Z"));
+        assert_eq!(clock.now(), datetime("2022-01-02T03:04:05Z"));
+
+        clock.advance(Duration::minutes(30));
+        assert_eq!(clock.now(), datetime("2022-01-02T03:34:05Z"));
+
+        clock.set(datetime("2023-01-01T00:00:00Z"));
+        assert_eq!(clock.now(), datetime("2023-01-01T00:00:00Z"));
+    }
+}
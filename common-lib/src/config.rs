@@ -0,0 +1,553 @@
+use std::{collections::HashMap, fmt::Display, path::Path, time::Duration};
+
+use job_scheduler::Schedule;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    error::{MyError, MyResult},
+    mysql::client::PoolOptions,
+};
+
+// 設定ファイルのパスを指定する環境変数
+const CONFIG_FILE_ENV: &str = "CONFIG_FILE";
+
+// 「項目名_FILE」でファイルから値を読み込める項目（Docker/Kubernetes の secret をファイルとしてマウントする場合に使う）
+const SECRET_KEYS: [&str; 5] = [
+    "DB_USER_NAME",
+    "DB_PASSWORD",
+    "ADMIN_API_KEY",
+    "SENTRY_DSN",
+    "NOTIFY_WEBHOOK_URL",
+];
+
+// 設定ファイル（CONFIG_FILE で指定した TOML/YAML）と環境変数から設定を読み込む
+// 同じ項目は環境変数の値を優先し、secret の項目は「項目名_FILE」で指定したファイルの内容を優先する
+// 設定ファイルの項目名は環境変数と同じ名前にする（テーブルは「テーブル名_項目名」、配列はカンマ区切りとして扱う）
+pub fn load<T: DeserializeOwned>() -> MyResult<T> {
+    let values = match std::env::var(CONFIG_FILE_ENV) {
+        Ok(path) if !path.is_empty() => read_file(&path)?,
+        _ => HashMap::new(),
+    };
+    merge(values, std::env::vars())
+}
+
+// 環境変数の代わりに指定した値から設定を読み込む（設定ファイルは読み込まない）
+pub fn load_from<T: DeserializeOwned>(envs: &[(&str, &str)]) -> MyResult<T> {
+    merge(
+        HashMap::new(),
+        envs.iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+}
+
+// DBの接続設定（DBを使うすべてのバイナリで共通）
+#[derive(Deserialize, Debug, Clone)]
+pub struct DbConfig {
+    pub db_host: String,
+    pub db_port: u16,
+    pub db_name: String,
+    pub db_user_name: String,
+    pub db_password: String,
+    // コネクションプールで保持する接続数の最小値と最大値
+    #[serde(default = "default_db_pool_min_size")]
+    pub db_pool_min_size: usize,
+    #[serde(default = "default_db_pool_max_size")]
+    pub db_pool_max_size: usize,
+    // DBへの接続の待ち時間の上限（ミリ秒）
+    #[serde(default = "default_db_connect_timeout_ms")]
+    pub db_connect_timeout_ms: u64,
+    // DBに接続できない場合の最大試行回数（1の場合は再試行しない）
+    #[serde(default = "default_db_retry_attempts")]
+    pub db_retry_attempts: u32,
+    // 再試行までの待ち時間（ミリ秒）、再試行のたびに2倍にする
+    #[serde(default = "default_db_retry_backoff_ms")]
+    pub db_retry_backoff_ms: u64,
+    // APIサーバーのリクエスト処理とヘルスチェックで使う接続の待ち時間の上限と最大試行回数
+    // DBに接続できない場合に503をすぐ返せるよう、バッチより短くする
+    #[serde(default = "default_db_server_connect_timeout_ms")]
+    pub db_server_connect_timeout_ms: u64,
+    #[serde(default = "default_db_server_retry_attempts")]
+    pub db_server_retry_attempts: u32,
+}
+
+fn default_db_pool_min_size() -> usize {
+    10
+}
+
+fn default_db_pool_max_size() -> usize {
+    100
+}
+
+fn default_db_connect_timeout_ms() -> u64 {
+    10000
+}
+
+fn default_db_retry_attempts() -> u32 {
+    5
+}
+
+fn default_db_retry_backoff_ms() -> u64 {
+    1000
+}
+
+fn default_db_server_connect_timeout_ms() -> u64 {
+    2000
+}
+
+fn default_db_server_retry_attempts() -> u32 {
+    1
+}
+
+impl DbConfig {
+    pub fn pool_options(&self) -> PoolOptions {
+        PoolOptions {
+            min_size: self.db_pool_min_size,
+            max_size: self.db_pool_max_size,
+            connect_timeout: Duration::from_millis(self.db_connect_timeout_ms),
+            retry_attempts: self.db_retry_attempts,
+            retry_backoff: Duration::from_millis(self.db_retry_backoff_ms),
+        }
+    }
+
+    // APIサーバー用（リクエストを長時間待たせないよう、接続の待ち時間と試行回数を抑える）
+    pub fn server_pool_options(&self) -> PoolOptions {
+        PoolOptions {
+            connect_timeout: Duration::from_millis(self.db_server_connect_timeout_ms),
+            retry_attempts: self.db_server_retry_attempts,
+            ..self.pool_options()
+        }
+    }
+
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_not_empty("db_host", &self.db_host);
+        v.check_port("db_port", self.db_port as i64);
+        v.check_not_empty("db_name", &self.db_name);
+        v.check_not_empty("db_user_name", &self.db_user_name);
+        v.check_positive("db_pool_max_size", self.db_pool_max_size);
+        v.check(
+            self.db_pool_min_size <= self.db_pool_max_size,
+            format!(
+                "db_pool_min_size must be less than or equal to db_pool_max_size, min: {}, max: {}",
+                self.db_pool_min_size, self.db_pool_max_size
+            ),
+        );
+        v.check_positive("db_connect_timeout_ms", self.db_connect_timeout_ms);
+        v.check_positive("db_retry_attempts", self.db_retry_attempts);
+        v.check_positive(
+            "db_server_connect_timeout_ms",
+            self.db_server_connect_timeout_ms,
+        );
+        v.check_positive("db_server_retry_attempts", self.db_server_retry_attempts);
+        v.finish()
+    }
+}
+
+// HTTPサーバーの設定（forecast-server と rate-gateway で共通）
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpConfig {
+    pub server_host: String,
+    pub server_port: i32,
+    // リクエストボディの最大サイズ（バイト）
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: u64,
+    // 証明書と秘密鍵のファイル（PEM）、空の場合はHTTPで待ち受ける
+    #[serde(default)]
+    pub tls_cert_file: String,
+    #[serde(default)]
+    pub tls_key_file: String,
+}
+
+fn default_max_body_bytes() -> u64 {
+    1024 * 1024
+}
+
+impl HttpConfig {
+    pub fn get_address(&self) -> String {
+        format!("{}:{}", self.server_host, self.server_port)
+    }
+
+    pub fn is_tls_enabled(&self) -> bool {
+        !self.tls_cert_file.is_empty()
+    }
+
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_not_empty("server_host", &self.server_host);
+        v.check_port("server_port", self.server_port as i64);
+        v.check_positive("max_body_bytes", self.max_body_bytes);
+        v.check(
+            self.tls_cert_file.is_empty() == self.tls_key_file.is_empty(),
+            "tls_cert_file and tls_key_file must be set together".to_string(),
+        );
+        v.finish()
+    }
+}
+
+// 設定値の検証
+// 起動時に見つかった問題をまとめて報告するため、最初の問題で止めずにすべて確認する
+#[derive(Debug, Default)]
+pub struct Validator {
+    problems: Vec<String>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Validator::default()
+    }
+
+    pub fn check(&mut self, ok: bool, problem: String) {
+        if !ok {
+            self.problems.push(problem);
+        }
+    }
+
+    pub fn check_not_empty(&mut self, name: &str, value: &str) {
+        self.check(!value.is_empty(), format!("{} must not be empty", name));
+    }
+
+    pub fn check_positive<T: PartialOrd + Default + Display>(&mut self, name: &str, value: T) {
+        self.check(
+            value > T::default(),
+            format!("{} must be greater than 0, value: {}", name, value),
+        );
+    }
+
+    pub fn check_range<T: PartialOrd + Display>(&mut self, name: &str, value: T, min: T, max: T) {
+        self.check(
+            min <= value && value <= max,
+            format!(
+                "{} must be between {} and {}, value: {}",
+                name, min, max, value
+            ),
+        );
+    }
+
+    pub fn check_port(&mut self, name: &str, value: i64) {
+        self.check_range(name, value, 1, 65535);
+    }
+
+    // 空の場合は定期実行しないため問題としない
+    pub fn check_cron(&mut self, name: &str, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+        if let Err(err) = value.parse::<Schedule>() {
+            self.problems
+                .push(format!("{} is invalid, value: {}, {}", name, value, err));
+        }
+    }
+
+    // 問題があればすべての問題を含むエラーを返す
+    pub fn finish(self) -> MyResult<()> {
+        if self.problems.is_empty() {
+            Ok(())
+        } else {
+            Err(MyError::InvalidConfig {
+                problems: self.problems,
+            })
+        }
+    }
+}
+
+fn merge<T: DeserializeOwned>(
+    mut values: HashMap<String, String>,
+    envs: impl Iterator<Item = (String, String)>,
+) -> MyResult<T> {
+    values.extend(envs.map(|(key, value)| (key.to_uppercase(), value)));
+    resolve_secrets(&mut values)?;
+    Ok(envy::from_iter(values)?)
+}
+
+fn resolve_secrets(values: &mut HashMap<String, String>) -> MyResult<()> {
+    for key in SECRET_KEYS {
+        let path = match values.get(&format!("{}_FILE", key)) {
+            Some(path) if !path.is_empty() => path.clone(),
+            _ => continue,
+        };
+        // ファイル末尾の改行は値に含めない
+        let secret = std::fs::read_to_string(&path)?;
+        values.insert(
+            key.to_string(),
+            secret.trim_end_matches(&['\r', '\n'][..]).to_string(),
+        );
+    }
+    Ok(())
+}
+
+fn read_file(path: &str) -> MyResult<HashMap<String, String>> {
+    let text = std::fs::read_to_string(path)?;
+    parse(path, &text)
+}
+
+fn parse(path: &str, text: &str) -> MyResult<HashMap<String, String>> {
+    let value: Value = match Path::new(path).extension().and_then(|e| e.to_str()) {
+        Some("toml") => toml::from_str(text)?,
+        Some("yaml") | Some("yml") => serde_yaml::from_str(text)?,
+        _ => {
+            return Err(MyError::UnsupportedConfigFile {
+                path: path.to_string(),
+            })
+        }
+    };
+
+    let mut values = HashMap::new();
+    flatten("", &value, &mut values);
+    Ok(values)
+}
+
+fn flatten(prefix: &str, value: &Value, values: &mut HashMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.to_uppercase()
+                } else {
+                    format!("{}_{}", prefix, key.to_uppercase())
+                };
+                flatten(&key, value, values);
+            }
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(to_string).collect();
+            values.insert(prefix.to_string(), items.join(","));
+        }
+        Value::Null => {}
+        value => {
+            values.insert(prefix.to_string(), to_string(value));
+        }
+    }
+}
+
+fn to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct TestConfig {
+        currency_pair: String,
+        scheduler_retry_attempts: u32,
+        model_types: Vec<String>,
+        #[serde(default)]
+        threshold: f64,
+    }
+
+    #[test]
+    fn test_for_parse_toml() {
+        let text = r#"
+currency_pair = "USDJPY"
+model_types = ["rf", "knn"]
+
+[scheduler]
+retry_attempts = 3
+"#;
+        let values = parse("config.toml", text).unwrap();
+        assert_eq!(values.get("CURRENCY_PAIR"), Some(&"USDJPY".to_string()));
+        assert_eq!(values.get("MODEL_TYPES"), Some(&"rf,knn".to_string()));
+        assert_eq!(
+            values.get("SCHEDULER_RETRY_ATTEMPTS"),
+            Some(&"3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_for_parse_yaml() {
+        let text = r#"
+currency_pair: EURJPY
+threshold: 0.5
+scheduler:
+  retry_attempts: 2
+"#;
+        let values = parse("config.yaml", text).unwrap();
+        assert_eq!(values.get("CURRENCY_PAIR"), Some(&"EURJPY".to_string()));
+        assert_eq!(values.get("THRESHOLD"), Some(&"0.5".to_string()));
+        assert_eq!(
+            values.get("SCHEDULER_RETRY_ATTEMPTS"),
+            Some(&"2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_for_parse_unsupported() {
+        assert!(parse("config.json", "{}").is_err());
+    }
+
+    #[test]
+    fn test_for_get_address() {
+        let config = HttpConfig {
+            server_host: "127.0.0.1".to_string(),
+            server_port: 8888,
+            max_body_bytes: 1048576,
+            tls_cert_file: "".to_string(),
+            tls_key_file: "".to_string(),
+        };
+        assert_eq!(config.get_address(), "127.0.0.1:8888".to_string());
+        assert!(!config.is_tls_enabled());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_for_validate_db_config() {
+        let mut config = DbConfig {
+            db_host: "db".to_string(),
+            db_port: 3306,
+            db_name: "binopt".to_string(),
+            db_user_name: "bot".to_string(),
+            db_password: "".to_string(),
+            db_pool_min_size: default_db_pool_min_size(),
+            db_pool_max_size: default_db_pool_max_size(),
+            db_connect_timeout_ms: default_db_connect_timeout_ms(),
+            db_retry_attempts: default_db_retry_attempts(),
+            db_retry_backoff_ms: default_db_retry_backoff_ms(),
+            db_server_connect_timeout_ms: default_db_server_connect_timeout_ms(),
+            db_server_retry_attempts: default_db_server_retry_attempts(),
+        };
+        assert!(config.validate().is_ok());
+
+        config.db_pool_min_size = 200;
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("db_pool_min_size"));
+    }
+
+    #[test]
+    fn test_for_server_pool_options() {
+        let config: DbConfig = serde_json::from_value(serde_json::json!({
+            "db_host": "db",
+            "db_port": 3306,
+            "db_name": "binopt",
+            "db_user_name": "bot",
+            "db_password": "",
+        }))
+        .unwrap();
+
+        // バッチはDBの再起動を待てるよう再試行する
+        let options = config.pool_options();
+        assert_eq!(options.retry_attempts, 5);
+        assert_eq!(options.connect_timeout, Duration::from_secs(10));
+
+        // APIサーバーは再試行せず、すぐに503を返す
+        let options = config.server_pool_options();
+        assert_eq!(options.retry_attempts, 1);
+        assert_eq!(options.connect_timeout, Duration::from_secs(2));
+        assert_eq!(options.max_size, config.db_pool_max_size);
+    }
+
+    #[test]
+    fn test_for_default_http_config() {
+        let config: HttpConfig = serde_json::from_value(serde_json::json!({
+            "server_host": "127.0.0.1",
+            "server_port": 8888,
+        }))
+        .unwrap();
+        assert_eq!(config.max_body_bytes, 1048576);
+        assert!(!config.is_tls_enabled());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_for_validate_http_config() {
+        let config = HttpConfig {
+            server_host: "127.0.0.1".to_string(),
+            server_port: 0,
+            max_body_bytes: 1048576,
+            tls_cert_file: "cert.pem".to_string(),
+            tls_key_file: "".to_string(),
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("server_port"));
+        assert!(err.contains("tls_cert_file"));
+    }
+
+    #[test]
+    fn test_for_validator() {
+        let mut v = Validator::new();
+        v.check_not_empty("a", "x");
+        v.check_positive("b", 1);
+        v.check_port("c", 8080);
+        v.check_cron("e", "");
+        v.check_cron("f", "0 * * * * *");
+        assert!(v.finish().is_ok());
+
+        let mut v = Validator::new();
+        v.check_not_empty("a", "");
+        v.check_positive("b", 0.0);
+        v.check_port("c", 0);
+        v.check_cron("e", "invalid");
+        let err = v.finish().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct SecretConfig {
+        db_password: String,
+        tls_cert_file: String,
+    }
+
+    #[test]
+    fn test_for_merge_secrets() {
+        let path = std::env::temp_dir().join(format!("db_password_{}", std::process::id()));
+        std::fs::write(&path, "secret\n").unwrap();
+
+        let envs = vec![
+            ("DB_PASSWORD".to_string(), "plain".to_string()),
+            (
+                "DB_PASSWORD_FILE".to_string(),
+                path.to_str().unwrap().to_string(),
+            ),
+            ("TLS_CERT_FILE".to_string(), "cert.pem".to_string()),
+        ];
+        let config: SecretConfig = merge(HashMap::new(), envs.into_iter()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            config,
+            SecretConfig {
+                db_password: "secret".to_string(),
+                tls_cert_file: "cert.pem".to_string(),
+            }
+        );
+
+        let envs = vec![(
+            "DB_PASSWORD_FILE".to_string(),
+            "/not/found/db_password".to_string(),
+        )];
+        assert!(merge::<SecretConfig>(HashMap::new(), envs.into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_for_merge() {
+        let text = r#"
+currency_pair = "USDJPY"
+model_types = ["rf", "knn"]
+threshold = 0.5
+
+[scheduler]
+retry_attempts = 3
+"#;
+        let values = parse("config.toml", text).unwrap();
+        let envs = vec![("SCHEDULER_RETRY_ATTEMPTS".to_string(), "5".to_string())];
+
+        let config: TestConfig = merge(values, envs.into_iter()).unwrap();
+        assert_eq!(
+            config,
+            TestConfig {
+                currency_pair: "USDJPY".to_string(),
+                scheduler_retry_attempts: 5,
+                model_types: vec!["rf".to_string(), "knn".to_string()],
+                threshold: 0.5,
+            }
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    domain::model::RateForTraining,
+    error::{MyError, MyResult},
+};
+
+// 登録できずに捨てたレートを後から登録し直すためのファイル（CSV）
+// import-rates でも取り込めるよう、通貨ペアの列を含めて書き出す
+const HEADER: &str = "pair,time,rate";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+// 登録し直す間に追記されたレートを失わないよう、名前を変えてから読み込む
+const TAKEN_SUFFIX: &str = ".requeue";
+
+// ファイルの末尾にレートを追記する（ファイルが無い場合は作成する）
+pub fn append(path: &Path, rates: &[RateForTraining]) -> MyResult<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut lines = String::new();
+    if file.metadata()?.len() == 0 {
+        lines.push_str(HEADER);
+        lines.push('\n');
+    }
+    for r in rates {
+        lines.push_str(&format!(
+            "{},{},{}\n",
+            r.pair,
+            r.recorded_at.format(DATETIME_FORMAT),
+            r.rate
+        ));
+    }
+    file.write_all(lines.as_bytes())?;
+    Ok(())
+}
+
+// 登録し直すファイルを追記先から切り離し、そのパスを返す（レートが無い場合はNone）
+// 前回の登録し直しが途中で失敗していた場合は、そのファイルを先に使う
+pub fn take(path: &Path) -> MyResult<Option<PathBuf>> {
+    let taken = taken_path(path);
+    if !taken.exists() {
+        if !path.exists() {
+            return Ok(None);
+        }
+        fs::rename(path, &taken)?;
+    }
+    Ok(Some(taken))
+}
+
+// take で切り離したファイルのパス
+pub fn taken_path(path: &Path) -> PathBuf {
+    let mut taken = path.as_os_str().to_owned();
+    taken.push(TAKEN_SUFFIX);
+    PathBuf::from(taken)
+}
+
+pub fn read(path: &Path) -> MyResult<Vec<RateForTraining>> {
+    let mut rates = vec![];
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.is_empty() || (i == 0 && line == HEADER) {
+            continue;
+        }
+
+        let parse_error = |memo: String| MyError::ParseError {
+            param_name: format!("line {}", i + 1),
+            value: line.to_string(),
+            memo,
+        };
+        let columns: Vec<&str> = line.split(',').collect();
+        if columns.len() != 3 {
+            return Err(parse_error(format!(
+                "number of columns must be 3, count: {}",
+                columns.len()
+            )));
+        }
+        let rate: f64 = columns[2]
+            .parse()
+            .map_err(|err| parse_error(format!("invalid rate, {}", err)))?;
+        let rate = RateForTraining::new(columns[0], columns[1], rate)
+            .map_err(|err| parse_error(err.to_string()))?;
+        rates.push(rate);
+    }
+    Ok(rates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dead_letter_{}_{}", name, std::process::id()))
+    }
+
+    fn rates() -> Vec<RateForTraining> {
+        vec![
+            RateForTraining::new("USDJPY", "2022-01-01 00:00:00", 100.123).unwrap(),
+            RateForTraining::new("EURJPY", "2022-01-01 00:01:00", 130.5).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_for_append_and_read() {
+        let path = path("append");
+        let _ = fs::remove_file(&path);
+
+        // 追記してもヘッダは先頭の1行だけ
+        let rates = rates();
+        append(&path, &rates[..1]).unwrap();
+        append(&path, &rates[1..]).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "pair,time,rate\n\
+             USDJPY,2022-01-01 00:00:00,100.123\n\
+             EURJPY,2022-01-01 00:01:00,130.5\n"
+        );
+
+        let actual = read(&path).unwrap();
+        assert_eq!(actual.len(), 2);
+        for (actual, expected) in actual.iter().zip(rates.iter()) {
+            assert_eq!(actual.pair, expected.pair);
+            assert_eq!(actual.recorded_at, expected.recorded_at);
+            assert_eq!(actual.rate, expected.rate);
+        }
+
+        fs::write(&path, "pair,time,rate\nUSDJPY,2022-01-01 00:00:00\n").unwrap();
+        assert!(read(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_for_take() {
+        let path = path("take");
+        let _ = fs::remove_file(&path);
+        assert!(take(&path).unwrap().is_none());
+
+        append(&path, &rates()).unwrap();
+        let taken = take(&path).unwrap().unwrap();
+        assert!(!path.exists());
+        assert_eq!(read(&taken).unwrap().len(), 2);
+
+        // 切り離した後に追記されたレートは、切り離したファイルを使い終わるまで残しておく
+        append(&path, &rates()[..1]).unwrap();
+        assert_eq!(take(&path).unwrap().unwrap(), taken);
+        assert!(path.exists());
+
+        fs::remove_file(&taken).unwrap();
+        let taken = take(&path).unwrap().unwrap();
+        assert_eq!(read(&taken).unwrap().len(), 1);
+        fs::remove_file(&taken).unwrap();
+    }
+}
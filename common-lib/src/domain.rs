@@ -1,2 +1,16 @@
+pub mod accuracy;
+pub mod anomaly;
+pub mod backtest;
+pub mod boosting;
+pub mod challenger;
+pub mod ensemble;
+pub mod explanation;
 pub mod model;
+pub mod pair;
+pub mod quality;
+pub mod quantile;
+pub mod refresh;
+pub mod scaler;
+pub mod series;
 pub mod service;
+pub mod trade;
@@ -0,0 +1,162 @@
+use chrono::{NaiveDateTime, Timelike};
+use serde::Serialize;
+
+use crate::error::{MyError, MyResult};
+
+// 予測精度を集計する期間の単位
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccuracyBucket {
+    Hour,
+    Day,
+}
+
+impl AccuracyBucket {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccuracyBucket::Hour => "hour",
+            AccuracyBucket::Day => "day",
+        }
+    }
+
+    pub fn parse(value: &str) -> MyResult<Self> {
+        match value {
+            "hour" => Ok(AccuracyBucket::Hour),
+            "day" => Ok(AccuracyBucket::Day),
+            _ => Err(MyError::UnsupportedAccuracyBucket {
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    // 日時を含む集計期間の開始日時を返す
+    pub fn truncate(&self, t: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            AccuracyBucket::Hour => t.date().and_hms_opt(t.hour(), 0, 0).unwrap(),
+            AccuracyBucket::Day => t.date().and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+}
+
+// 予測の実績誤差を集計期間ごとに集計したもの
+#[derive(Debug, Clone, Serialize)]
+pub struct ForecastAccuracyStat {
+    pub pair: String,
+    pub model_no: i32,
+    pub forecast_type: i32,
+    // 集計期間の開始日時
+    pub bucket_at: NaiveDateTime,
+    pub count: i64,
+    pub mae: f64,
+    pub mse: f64,
+    pub rmse: f64,
+    // 予測値 - 実績値 の平均（正の場合は高めに予測している）
+    pub bias: f64,
+}
+
+// 複数の集計期間をまとめた実績誤差
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccuracySummary {
+    pub count: i64,
+    pub mae: f64,
+    pub mse: f64,
+    pub rmse: f64,
+    pub bias: f64,
+    // 集計期間ごとのMAEの最小・最大（期間による精度のばらつきの確認用）
+    pub best_mae: f64,
+    pub worst_mae: f64,
+}
+
+impl AccuracySummary {
+    // 件数で重み付けして平均する（集計期間が無い場合は None）
+    pub fn summarize(stats: &[ForecastAccuracyStat]) -> Option<Self> {
+        let count: i64 = stats.iter().map(|s| s.count).sum();
+        if count == 0 {
+            return None;
+        }
+        let weighted = |f: fn(&ForecastAccuracyStat) -> f64| {
+            stats.iter().map(|s| f(s) * s.count as f64).sum::<f64>() / count as f64
+        };
+        let mse = weighted(|s| s.mse);
+        Some(AccuracySummary {
+            count,
+            mae: weighted(|s| s.mae),
+            mse,
+            rmse: mse.sqrt(),
+            bias: weighted(|s| s.bias),
+            best_mae: stats.iter().map(|s| s.mae).fold(f64::INFINITY, f64::min),
+            worst_mae: stats
+                .iter()
+                .map(|s| s.mae)
+                .fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn stat(count: i64, mae: f64, mse: f64, bias: f64) -> ForecastAccuracyStat {
+        ForecastAccuracyStat {
+            pair: "USD/JPY".to_string(),
+            model_no: 1,
+            forecast_type: 0,
+            bucket_at: NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            count,
+            mae,
+            mse,
+            rmse: mse.sqrt(),
+            bias,
+        }
+    }
+
+    #[test]
+    fn test_for_parse() {
+        assert_eq!(AccuracyBucket::parse("hour").unwrap(), AccuracyBucket::Hour);
+        assert_eq!(AccuracyBucket::parse("day").unwrap(), AccuracyBucket::Day);
+        assert!(AccuracyBucket::parse("week").is_err());
+    }
+
+    #[test]
+    fn test_for_truncate() {
+        let t = NaiveDate::from_ymd_opt(2022, 1, 2)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap();
+        assert_eq!(
+            AccuracyBucket::Hour.truncate(t),
+            NaiveDate::from_ymd_opt(2022, 1, 2)
+                .unwrap()
+                .and_hms_opt(3, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(
+            AccuracyBucket::Day.truncate(t),
+            NaiveDate::from_ymd_opt(2022, 1, 2)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_for_summarize() {
+        assert_eq!(AccuracySummary::summarize(&[]), None);
+
+        let summary =
+            AccuracySummary::summarize(&[stat(1, 0.4, 0.25, 0.4), stat(3, 0.2, 0.05, -0.2)])
+                .unwrap();
+        assert_eq!(summary.count, 4);
+        assert!((summary.mae - 0.25).abs() < 1e-9);
+        assert!((summary.mse - 0.1).abs() < 1e-9);
+        assert!((summary.rmse - 0.1_f64.sqrt()).abs() < 1e-9);
+        assert!((summary.bias - (-0.05)).abs() < 1e-9);
+        assert_eq!(summary.best_mae, 0.2);
+        assert_eq!(summary.worst_mae, 0.4);
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDateTime, Timelike, Weekday};
+
+use crate::error::{MyError, MyResult};
+
+use super::model::RateForTraining;
+
+// 異常なレートの種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnomalyKind {
+    // 前後のレートから大きく外れた値
+    Jump,
+    // 同じ値が続いている（配信が止まっている）
+    Stale,
+    // 市場が閉まっている時間帯のレート
+    OutOfHours,
+}
+
+impl AnomalyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnomalyKind::Jump => "jump",
+            AnomalyKind::Stale => "stale",
+            AnomalyKind::OutOfHours => "out_of_hours",
+        }
+    }
+
+    pub fn parse(value: &str) -> MyResult<Self> {
+        match value {
+            "jump" => Ok(AnomalyKind::Jump),
+            "stale" => Ok(AnomalyKind::Stale),
+            "out_of_hours" => Ok(AnomalyKind::OutOfHours),
+            _ => Err(MyError::UnsupportedAnomalyKind {
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+// 異常と判定したレート
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateAnomaly {
+    pub pair: String,
+    pub recorded_at: NaiveDateTime,
+    pub rate: f64,
+    pub kind: AnomalyKind,
+}
+
+// 異常と判定する条件
+#[derive(Debug, Clone)]
+pub struct AnomalyRules {
+    // 直前・直後のレートとの変化率の上限（例: 0.01 の場合は1%より大きく外れて戻った値を異常とする）
+    pub max_jump_ratio: f64,
+    // 同じ値が連続してよい件数（0の場合は判定しない）
+    pub max_stale_count: usize,
+    // 週末の取引停止時間帯（UTC）、金曜の begin_hour 時から日曜の end_hour 時まで（None の場合は判定しない）
+    pub weekend_hours: Option<(u32, u32)>,
+}
+
+// 週末の取引停止時間帯（UTC）、金曜の begin_hour 時から日曜の end_hour 時まで（None の場合は常に false）
+pub fn is_market_closed(t: &NaiveDateTime, weekend_hours: Option<(u32, u32)>) -> bool {
+    let (begin_hour, end_hour) = match weekend_hours {
+        Some(hours) => hours,
+        None => return false,
+    };
+    match t.weekday() {
+        Weekday::Fri => t.hour() >= begin_hour,
+        Weekday::Sat => true,
+        Weekday::Sun => t.hour() < end_hour,
+        _ => false,
+    }
+}
+
+// 記録日時の昇順に並んだレートから異常なレートを検出する
+// 1件だけ外れてすぐ戻る値を異常とし、レートの水準そのものが変わった場合は異常としない
+pub fn detect_anomalies(
+    pair: &str,
+    rates: &[RateForTraining],
+    rules: &AnomalyRules,
+) -> Vec<RateAnomaly> {
+    let anomaly = |r: &RateForTraining, kind: AnomalyKind| RateAnomaly {
+        pair: pair.to_string(),
+        recorded_at: r.recorded_at,
+        rate: r.rate,
+        kind,
+    };
+    let jumped =
+        |from: f64, to: f64| from != 0.0 && ((to - from) / from).abs() > rules.max_jump_ratio;
+
+    let mut anomalies: Vec<RateAnomaly> = vec![];
+    let mut stale_count = 0;
+    for (i, r) in rates.iter().enumerate() {
+        if is_market_closed(&r.recorded_at, rules.weekend_hours) {
+            anomalies.push(anomaly(r, AnomalyKind::OutOfHours));
+        }
+
+        if i > 0 && i + 1 < rates.len() {
+            let prev = rates[i - 1].rate;
+            let next = rates[i + 1].rate;
+            // 前後のレートと同じ向きに大きく外れている
+            if jumped(prev, r.rate)
+                && jumped(next, r.rate)
+                && (r.rate - prev) * (r.rate - next) > 0.0
+            {
+                anomalies.push(anomaly(r, AnomalyKind::Jump));
+            }
+        }
+
+        // 上限を超えて続いた分を異常とする
+        if i > 0 && rates[i - 1].rate == r.rate {
+            stale_count += 1;
+        } else {
+            stale_count = 0;
+        }
+        if rules.max_stale_count > 0 && stale_count > rules.max_stale_count {
+            anomalies.push(anomaly(r, AnomalyKind::Stale));
+        }
+    }
+    anomalies
+}
+
+// 異常なレートを取り除く
+pub fn exclude_anomalies(
+    rates: Vec<RateForTraining>,
+    anomalies: &[RateAnomaly],
+) -> Vec<RateForTraining> {
+    let excluded: HashSet<NaiveDateTime> = anomalies.iter().map(|a| a.recorded_at).collect();
+    rates
+        .into_iter()
+        .filter(|r| !excluded.contains(&r.recorded_at))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::test_support::{RateSeries, TEST_PAIR};
+
+    fn rules() -> AnomalyRules {
+        AnomalyRules {
+            max_jump_ratio: 0.01,
+            max_stale_count: 3,
+            weekend_hours: Some((22, 21)),
+        }
+    }
+
+    // 2022-01-03（月曜）から1分ごとの系列
+    fn weekday_series() -> RateSeries {
+        RateSeries::new(TEST_PAIR).begin(
+            NaiveDate::from_ymd_opt(2022, 1, 3)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+    }
+
+    fn kinds(anomalies: &[RateAnomaly]) -> Vec<(u32, AnomalyKind)> {
+        anomalies
+            .iter()
+            .map(|a| (a.recorded_at.minute(), a.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_for_parse() {
+        for kind in [
+            AnomalyKind::Jump,
+            AnomalyKind::Stale,
+            AnomalyKind::OutOfHours,
+        ] {
+            assert_eq!(AnomalyKind::parse(kind.as_str()).unwrap(), kind);
+        }
+        assert!(AnomalyKind::parse("unknown").is_err());
+    }
+
+    #[test]
+    fn test_for_detect_jump() {
+        let mut rates = weekday_series().trending(7, 100.0, 0.1);
+        // 1件だけ外れて戻る値は異常、水準が変わった後の値は異常としない
+        rates[2].rate = 110.0;
+        for r in rates.iter_mut().skip(5) {
+            r.rate += 5.0;
+        }
+
+        let anomalies = detect_anomalies(TEST_PAIR, &rates, &rules());
+        assert_eq!(kinds(&anomalies), vec![(2, AnomalyKind::Jump)]);
+        assert_eq!(anomalies[0].rate, 110.0);
+    }
+
+    #[test]
+    fn test_for_detect_stale() {
+        let mut rates = weekday_series().flat(6, 100.0);
+        rates.push(weekday_series().trending(7, 100.0, 0.1)[6].clone());
+
+        let anomalies = detect_anomalies(TEST_PAIR, &rates, &rules());
+        assert_eq!(
+            kinds(&anomalies),
+            vec![(4, AnomalyKind::Stale), (5, AnomalyKind::Stale)]
+        );
+
+        let no_stale = AnomalyRules {
+            max_stale_count: 0,
+            ..rules()
+        };
+        assert!(detect_anomalies(TEST_PAIR, &rates, &no_stale).is_empty());
+    }
+
+    #[test]
+    fn test_for_detect_out_of_hours() {
+        // 2022-01-07（金曜）21:58 から
+        let rates = RateSeries::new(TEST_PAIR)
+            .begin(
+                NaiveDate::from_ymd_opt(2022, 1, 7)
+                    .unwrap()
+                    .and_hms_opt(21, 58, 0)
+                    .unwrap(),
+            )
+            .trending(4, 100.0, 0.1);
+
+        let anomalies = detect_anomalies(TEST_PAIR, &rates, &rules());
+        assert_eq!(
+            kinds(&anomalies),
+            vec![(0, AnomalyKind::OutOfHours), (1, AnomalyKind::OutOfHours)]
+        );
+
+        let no_hours = AnomalyRules {
+            weekend_hours: None,
+            ..rules()
+        };
+        assert!(detect_anomalies(TEST_PAIR, &rates, &no_hours).is_empty());
+    }
+
+    #[test]
+    fn test_for_exclude_anomalies() {
+        let mut rates = weekday_series().trending(4, 100.0, 0.1);
+        rates[1].rate = 110.0;
+        let anomalies = detect_anomalies(TEST_PAIR, &rates, &rules());
+
+        let rates = exclude_anomalies(rates, &anomalies);
+        let minutes: Vec<u32> = rates.iter().map(|r| r.recorded_at.minute()).collect();
+        assert_eq!(minutes, vec![0, 2, 3]);
+    }
+}
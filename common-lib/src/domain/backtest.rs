@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+// 過去のレートでモデルを検証した結果（backtest で集計し、モデルの比較のために保存する）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BacktestResult {
+    pub pair: String,
+    pub model_no: i32,
+    // 検証時のモデルのメモ（同じ番号でも学習し直すとモデルが変わるため）
+    pub model_memo: String,
+    // 検証範囲（begin_at 以上 end_at 未満）
+    pub begin_at: NaiveDateTime,
+    pub end_at: NaiveDateTime,
+    pub forecast_offset_minutes: usize,
+    pub forecast_count: u64,
+    // 予想した方向（上昇・下落）が的中した割合
+    pub hit_rate: f64,
+    // 予想値の平均絶対誤差
+    pub mae: f64,
+    pub trade_count: u64,
+    pub win_rate: f64,
+    pub expected_value: f64,
+    pub total_profit: f64,
+    pub max_drawdown: f64,
+}
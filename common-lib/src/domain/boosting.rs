@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use smartcore::{
+    linalg::{naive::dense_matrix::DenseMatrix, BaseMatrix},
+    tree::decision_tree_regressor::{DecisionTreeRegressor, DecisionTreeRegressorParameters},
+};
+
+use crate::error::{MyError, MyResult};
+
+use super::model::FeatureData;
+
+#[derive(Debug, Clone)]
+pub struct GradientBoostingParameters {
+    // 木の数
+    pub n_estimators: usize,
+    // 各木の予想値に掛ける係数（小さいほど過学習しにくいが、木の数が多く必要になる）
+    pub learning_rate: f64,
+    pub max_depth: u16,
+    pub min_samples_leaf: usize,
+}
+
+impl Default for GradientBoostingParameters {
+    fn default() -> Self {
+        GradientBoostingParameters {
+            n_estimators: 100,
+            learning_rate: 0.1,
+            max_depth: 3,
+            min_samples_leaf: 5,
+        }
+    }
+}
+
+// 二乗誤差を損失とする勾配ブースティング（前の木までの予想の残差に次の木を当てはめる）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GradientBoostingRegressor {
+    // 最初の予想値（学習データの平均）
+    base: f64,
+    learning_rate: f64,
+    trees: Vec<DecisionTreeRegressor<f64>>,
+}
+
+impl GradientBoostingRegressor {
+    pub fn fit(
+        x: &[FeatureData],
+        y: &[f64],
+        params: &GradientBoostingParameters,
+    ) -> MyResult<Self> {
+        if x.is_empty() || y.is_empty() {
+            return Err(MyError::ArrayIsEmpty {
+                name: "train data".to_string(),
+            });
+        }
+        let n = x.len().min(y.len());
+        let matrix = DenseMatrix::from_2d_vec(&x[..n].to_vec());
+        let tree_params = DecisionTreeRegressorParameters {
+            max_depth: Some(params.max_depth),
+            min_samples_leaf: params.min_samples_leaf,
+            min_samples_split: params.min_samples_leaf * 2,
+        };
+
+        let mut m = GradientBoostingRegressor {
+            base: y[..n].iter().sum::<f64>() / n as f64,
+            learning_rate: params.learning_rate,
+            trees: vec![],
+        };
+        let mut predictions = vec![m.base; n];
+        for _ in 0..params.n_estimators {
+            // 二乗誤差の負の勾配は残差になる
+            let residuals: Vec<f64> = y[..n]
+                .iter()
+                .zip(&predictions)
+                .map(|(actual, predicted)| actual - predicted)
+                .collect();
+            let tree = DecisionTreeRegressor::fit(&matrix, &residuals, tree_params.clone())?;
+            for (p, r) in predictions.iter_mut().zip(tree.predict(&matrix)?) {
+                *p += m.learning_rate * r;
+            }
+            m.trees.push(tree);
+        }
+        Ok(m)
+    }
+
+    pub fn predict(&self, x: &DenseMatrix<f64>) -> MyResult<Vec<f64>> {
+        let mut y = vec![self.base; x.shape().0];
+        for tree in &self.trees {
+            for (v, r) in y.iter_mut().zip(tree.predict(x)?) {
+                *v += self.learning_rate * r;
+            }
+        }
+        Ok(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_gradient_boosting() {
+        // 線形モデルでは当てはまらない階段状のデータ
+        let x: Vec<FeatureData> = (0..200).map(|i| vec![i as f64 / 10.0]).collect();
+        let y: Vec<f64> = (0..200)
+            .map(|i| if (i / 50) % 2 == 0 { 100.0 } else { 101.0 })
+            .collect();
+
+        let m = GradientBoostingRegressor::fit(&x, &y, &Default::default()).unwrap();
+        let predicted = m.predict(&DenseMatrix::from_2d_vec(&x)).unwrap();
+        let mse = predicted
+            .iter()
+            .zip(&y)
+            .map(|(p, a)| (p - a).powi(2))
+            .sum::<f64>()
+            / y.len() as f64;
+        // 平均値で予想した場合のMSEは 0.25
+        assert!(mse < 0.01);
+
+        assert!(GradientBoostingRegressor::fit(&[], &[], &Default::default()).is_err());
+    }
+}
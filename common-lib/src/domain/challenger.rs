@@ -0,0 +1,141 @@
+use serde::Serialize;
+
+// 同じレートに対するチャンピオン（予測用モデル）とチャレンジャーの予想誤差（予測値 - 実績値）
+#[derive(Debug, Clone)]
+pub struct PairedForecastError {
+    pub champion: f64,
+    pub challenger: f64,
+}
+
+// チャレンジャーを昇格する条件
+#[derive(Debug, Clone)]
+pub struct ChallengeCriteria {
+    // 比較に必要な予想の件数
+    pub min_samples: usize,
+    // チャンピオンに対するMSEの改善率の下限（例: 0.05 の場合は5%以上小さいこと）
+    pub min_improvement: f64,
+    // 二乗誤差の差の検定統計量の下限（例: 1.96 の場合は片側約2.5%の有意水準）
+    pub z_threshold: f64,
+}
+
+// チャンピオンとチャレンジャーの実績誤差の比較結果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChallengeResult {
+    pub samples: usize,
+    pub champion_mse: f64,
+    pub challenger_mse: f64,
+    // (チャンピオンのMSE - チャレンジャーのMSE) / チャンピオンのMSE
+    pub improvement: f64,
+    // 二乗誤差の差（チャンピオン - チャレンジャー）の平均が0より大きいかの検定統計量（Diebold-Mariano検定）
+    pub z_score: f64,
+}
+
+impl ChallengeResult {
+    // 分散を求められない（2件未満の）場合は None
+    pub fn compare(errors: &[PairedForecastError]) -> Option<Self> {
+        if errors.len() < 2 {
+            return None;
+        }
+        let n = errors.len() as f64;
+        let champion_mse = errors.iter().map(|e| e.champion.powi(2)).sum::<f64>() / n;
+        let challenger_mse = errors.iter().map(|e| e.challenger.powi(2)).sum::<f64>() / n;
+
+        let diffs: Vec<f64> = errors
+            .iter()
+            .map(|e| e.champion.powi(2) - e.challenger.powi(2))
+            .collect();
+        let mean = diffs.iter().sum::<f64>() / n;
+        let variance = diffs.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let z_score = if variance > 0.0 {
+            mean / (variance / n).sqrt()
+        } else if mean > 0.0 {
+            f64::INFINITY
+        } else if mean < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            0.0
+        };
+
+        Some(ChallengeResult {
+            samples: errors.len(),
+            champion_mse,
+            challenger_mse,
+            improvement: if champion_mse > 0.0 {
+                (champion_mse - challenger_mse) / champion_mse
+            } else {
+                0.0
+            },
+            z_score,
+        })
+    }
+
+    pub fn challenger_wins(&self, criteria: &ChallengeCriteria) -> bool {
+        self.samples >= criteria.min_samples
+            && self.improvement >= criteria.min_improvement
+            && self.z_score >= criteria.z_threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(values: &[(f64, f64)]) -> Vec<PairedForecastError> {
+        values
+            .iter()
+            .map(|(champion, challenger)| PairedForecastError {
+                champion: *champion,
+                challenger: *challenger,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_for_compare() {
+        assert_eq!(ChallengeResult::compare(&errors(&[(1.0, 0.5)])), None);
+
+        // 二乗誤差の差は 0.75, 0.75, 0.0, 1.0（平均 0.625、不偏分散 0.1875）
+        let result =
+            ChallengeResult::compare(&errors(&[(1.0, 0.5), (-1.0, 0.5), (0.5, -0.5), (1.0, 0.0)]))
+                .unwrap();
+        assert_eq!(result.samples, 4);
+        assert!((result.champion_mse - 0.8125).abs() < 1e-9);
+        assert!((result.challenger_mse - 0.1875).abs() < 1e-9);
+        assert!((result.improvement - 0.625 / 0.8125).abs() < 1e-9);
+        assert!((result.z_score - 0.625 / (0.1875_f64 / 4.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_for_challenger_wins() {
+        let criteria = ChallengeCriteria {
+            min_samples: 4,
+            min_improvement: 0.05,
+            z_threshold: 1.96,
+        };
+        let result = ChallengeResult {
+            samples: 4,
+            champion_mse: 1.0,
+            challenger_mse: 0.9,
+            improvement: 0.1,
+            z_score: 2.0,
+        };
+        assert!(result.challenger_wins(&criteria));
+
+        // 件数・改善率・検定統計量のいずれかが足りない場合は昇格しない
+        assert!(!ChallengeResult {
+            samples: 3,
+            ..result.clone()
+        }
+        .challenger_wins(&criteria));
+        assert!(!ChallengeResult {
+            improvement: 0.01,
+            ..result.clone()
+        }
+        .challenger_wins(&criteria));
+        assert!(!ChallengeResult {
+            z_score: 1.0,
+            ..result
+        }
+        .challenger_wins(&criteria));
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use smartcore::{
+    ensemble::random_forest_regressor::RandomForestRegressor,
+    linalg::{naive::dense_matrix::DenseMatrix, BaseMatrix},
+    linear::{
+        elastic_net::ElasticNet, lasso::Lasso, linear_regression::LinearRegression,
+        logistic_regression::LogisticRegression, ridge_regression::RidgeRegression,
+    },
+    math::distance::euclidian,
+    neighbors::knn_regressor::KNNRegressor,
+    svm::{svr::SVR, RBFKernel},
+};
+
+use crate::error::{MyError, MyResult};
+
+use super::{
+    boosting::GradientBoostingRegressor,
+    model::{FeatureData, ForecastModel},
+    quantile::QuantileRegression,
+};
+
+// 予想値の重み付けの方法
+// mean: 単純平均, inverse_mse: テストデータでのMSEの逆数で重み付けした平均
+pub const ENSEMBLE_WEIGHTING_MEAN: &str = "mean";
+pub const ENSEMBLE_WEIGHTING_INVERSE_MSE: &str = "inverse_mse";
+
+pub fn validate_weighting(weighting: &str) -> MyResult<()> {
+    match weighting {
+        ENSEMBLE_WEIGHTING_MEAN | ENSEMBLE_WEIGHTING_INVERSE_MSE => Ok(()),
+        _ => Err(MyError::UnsupportedEnsembleWeighting {
+            value: weighting.to_string(),
+        }),
+    }
+}
+
+// アンサンブルを構成する学習済みのモデル（アンサンブルの入れ子はできない）
+#[derive(Serialize, Deserialize)]
+pub enum EnsembleMemberModel {
+    RandomForest(RandomForestRegressor<f64>),
+    KNN(KNNRegressor<f64, euclidian::Euclidian>),
+    Linear(LinearRegression<f64, DenseMatrix<f64>>),
+    Ridge(RidgeRegression<f64, DenseMatrix<f64>>),
+    LASSO(Lasso<f64, DenseMatrix<f64>>),
+    ElasticNet(ElasticNet<f64, DenseMatrix<f64>>),
+    Logistic(LogisticRegression<f64, DenseMatrix<f64>>),
+    SVR(SVR<f64, DenseMatrix<f64>, RBFKernel<f64>>),
+    Quantile(QuantileRegression),
+    GradientBoosting(GradientBoostingRegressor),
+}
+
+impl EnsembleMemberModel {
+    fn predict(&self, x: &DenseMatrix<f64>) -> MyResult<Vec<f64>> {
+        match self {
+            EnsembleMemberModel::RandomForest(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::KNN(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::Linear(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::Ridge(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::LASSO(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::ElasticNet(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::Logistic(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::SVR(model) => Ok(model.predict(x)?),
+            EnsembleMemberModel::Quantile(model) => {
+                let rows: Vec<FeatureData> =
+                    (0..x.shape().0).map(|i| x.get_row_as_vec(i)).collect();
+                Ok(model.predict(&rows))
+            }
+            EnsembleMemberModel::GradientBoosting(model) => model.predict(x),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EnsembleMember {
+    // 元のモデルのメモ（どのモデルを組み合わせたかの確認用）
+    pub memo: String,
+    pub performance_mse: f64,
+    // 重みの合計は 1 になる
+    pub weight: f64,
+    pub model: EnsembleMemberModel,
+}
+
+// 複数のモデルの予想値を重み付けして平均する
+#[derive(Serialize, Deserialize)]
+pub struct EnsembleModel {
+    weighting: String,
+    members: Vec<EnsembleMember>,
+}
+
+impl EnsembleModel {
+    // 同じ特徴量の設定で学習したモデルのみ組み合わせられる
+    pub fn new(models: Vec<ForecastModel>, weighting: &str) -> MyResult<Self> {
+        validate_weighting(weighting)?;
+        if models.is_empty() {
+            return Err(MyError::ArrayIsEmpty {
+                name: "ensemble members".to_string(),
+            });
+        }
+
+        let feature_params_hash = models[0].get_feature_params()?.to_hash()?;
+        let input_data_size = models[0].get_input_data_size()?;
+        let mut members: Vec<EnsembleMember> = vec![];
+        for m in models {
+            if m.get_feature_params()?.to_hash()? != feature_params_hash
+                || m.get_input_data_size()? != input_data_size
+            {
+                return Err(MyError::UnmatchEnsembleMember { memo: m.get_memo() });
+            }
+            members.push(to_member(m)?);
+        }
+
+        // MSEが0のモデルで重みが無限大にならないようにする
+        let raw: Vec<f64> = members
+            .iter()
+            .map(|m| match weighting {
+                ENSEMBLE_WEIGHTING_INVERSE_MSE => 1.0 / m.performance_mse.max(f64::EPSILON),
+                _ => 1.0,
+            })
+            .collect();
+        let total: f64 = raw.iter().sum();
+        for (m, w) in members.iter_mut().zip(raw) {
+            m.weight = w / total;
+        }
+
+        Ok(EnsembleModel {
+            weighting: weighting.to_string(),
+            members,
+        })
+    }
+
+    pub fn weighting(&self) -> &str {
+        &self.weighting
+    }
+
+    pub fn members(&self) -> &[EnsembleMember] {
+        &self.members
+    }
+
+    pub fn predict(&self, x: &DenseMatrix<f64>) -> MyResult<Vec<f64>> {
+        let mut y = vec![0.0; x.shape().0];
+        for member in &self.members {
+            for (v, p) in y.iter_mut().zip(member.model.predict(x)?) {
+                *v += member.weight * p;
+            }
+        }
+        Ok(y)
+    }
+}
+
+fn to_member(m: ForecastModel) -> MyResult<EnsembleMember> {
+    let memo = m.get_memo();
+    let performance_mse = m.get_performance_mse();
+    let model = match m {
+        ForecastModel::RandomForest { model, .. } => EnsembleMemberModel::RandomForest(model),
+        ForecastModel::KNN { model, .. } => EnsembleMemberModel::KNN(model),
+        ForecastModel::Linear { model, .. } => EnsembleMemberModel::Linear(model),
+        ForecastModel::Ridge { model, .. } => EnsembleMemberModel::Ridge(model),
+        ForecastModel::LASSO { model, .. } => EnsembleMemberModel::LASSO(model),
+        ForecastModel::ElasticNet { model, .. } => EnsembleMemberModel::ElasticNet(model),
+        ForecastModel::Logistic { model, .. } => EnsembleMemberModel::Logistic(model),
+        ForecastModel::SVR { model, .. } => EnsembleMemberModel::SVR(model),
+        ForecastModel::Quantile { model, .. } => EnsembleMemberModel::Quantile(model),
+        ForecastModel::GradientBoosting { model, .. } => {
+            EnsembleMemberModel::GradientBoosting(model)
+        }
+        ForecastModel::Ensemble { .. } => {
+            return Err(MyError::UnmatchEnsembleMember { memo });
+        }
+    };
+    Ok(EnsembleMember {
+        memo,
+        performance_mse,
+        weight: 0.0,
+        model,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::service::convert_to_feature,
+        test_support::{feature_params, forecast_model, TEST_PAIR},
+    };
+
+    #[test]
+    fn test_for_ensemble_model() {
+        let mut m1 = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let mut m2 = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let feature = convert_to_feature(&vec![100.0; 30], &feature_params()).unwrap();
+        let expected = m1.predict(&feature).unwrap();
+
+        // MSE が 1 と 4 の場合、重みは 4:1 になる
+        m1.update_performance(&vec![feature.clone()], &vec![expected + 1.0])
+            .unwrap();
+        m2.update_performance(&vec![feature.clone()], &vec![expected + 2.0])
+            .unwrap();
+        let e = EnsembleModel::new(vec![m1, m2], ENSEMBLE_WEIGHTING_INVERSE_MSE).unwrap();
+        assert!((e.members()[0].weight - 0.8).abs() < 1e-9);
+        assert!((e.members()[1].weight - 0.2).abs() < 1e-9);
+
+        // 同じモデルの組み合わせは、重みに関わらず元のモデルと同じ予想値になる
+        let x = DenseMatrix::from_2d_vec(&vec![feature]);
+        assert!((e.predict(&x).unwrap()[0] - expected).abs() < 1e-9);
+
+        // 入力データ数が異なるモデルは組み合わせられない
+        let m3 = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let m4 = forecast_model(TEST_PAIR, 1, 20).unwrap();
+        assert!(EnsembleModel::new(vec![m3, m4], ENSEMBLE_WEIGHTING_MEAN).is_err());
+        assert!(EnsembleModel::new(vec![], ENSEMBLE_WEIGHTING_MEAN).is_err());
+        assert!(validate_weighting("median").is_err());
+    }
+}
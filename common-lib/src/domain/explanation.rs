@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::MyResult;
+
+use super::model::{FeatureData, FeatureParams};
+
+// 線形モデルの係数×特徴量の値
+pub const EXPLANATION_METHOD_LINEAR: &str = "linear";
+// 特徴量を1つずつ同じ種類の特徴量の平均値に置き換えたときの予想値の変化（係数を持たないモデルの近似）
+pub const EXPLANATION_METHOD_OCCLUSION: &str = "occlusion";
+
+// 特徴量ごとの予想値への寄与
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureContribution {
+    pub name: String,
+    pub value: f64,
+    pub contribution: f64,
+}
+
+// 予想値の根拠（base_value と寄与の合計がおおよそ予想値になる）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub method: String,
+    pub base_value: f64,
+    pub contributions: Vec<FeatureContribution>,
+}
+
+impl Explanation {
+    pub fn new(
+        method: &str,
+        names: &[String],
+        values: &FeatureData,
+        base_value: f64,
+        contributions: &[f64],
+    ) -> Self {
+        Explanation {
+            method: method.to_string(),
+            base_value,
+            contributions: values
+                .iter()
+                .zip(contributions)
+                .enumerate()
+                .map(|(j, (value, contribution))| FeatureContribution {
+                    name: feature_name(names, j),
+                    value: *value,
+                    contribution: *contribution,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn linear(
+        names: &[String],
+        values: &FeatureData,
+        coefficients: &[f64],
+        intercept: f64,
+    ) -> Self {
+        let contributions: Vec<f64> = values
+            .iter()
+            .zip(coefficients)
+            .map(|(v, c)| v * c)
+            .collect();
+        Self::new(
+            EXPLANATION_METHOD_LINEAR,
+            names,
+            values,
+            intercept,
+            &contributions,
+        )
+    }
+
+    // 特徴量は group_size 件ずつ同じ種類（レート、MACDなど）が並んでいるものとする
+    pub fn occlusion<F>(
+        names: &[String],
+        values: &FeatureData,
+        group_size: usize,
+        predict: F,
+    ) -> MyResult<Self>
+    where
+        F: Fn(&FeatureData) -> MyResult<f64>,
+    {
+        let group_size = group_size.max(1);
+        let means: Vec<f64> = values
+            .chunks(group_size)
+            .map(|group| group.iter().sum::<f64>() / group.len() as f64)
+            .collect();
+
+        let prediction = predict(values)?;
+        let contributions = (0..values.len())
+            .map(|j| {
+                let mut occluded = values.clone();
+                occluded[j] = means[j / group_size];
+                Ok(prediction - predict(&occluded)?)
+            })
+            .collect::<MyResult<Vec<f64>>>()?;
+        let total: f64 = contributions.iter().sum();
+        Ok(Self::new(
+            EXPLANATION_METHOD_OCCLUSION,
+            names,
+            values,
+            prediction - total,
+            &contributions,
+        ))
+    }
+}
+
+// 予想結果の根拠
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastExplanation {
+    pub rate_id: String,
+    pub model_no: i32,
+    pub forecast_type: i32,
+    pub explanation: Explanation,
+}
+
+// convert_to_feature が作る特徴量の名前（t-0 が最新）
+pub fn feature_names(p: &FeatureParams) -> Vec<String> {
+    let mut kinds = vec!["rate", "macd_histogram", "bb_upper", "bb_lower"];
+    if p.uses_rsi() {
+        kinds.push("rsi");
+    }
+    if p.uses_stochastic() {
+        kinds.push("stoch_k");
+        kinds.push("stoch_d");
+    }
+    kinds
+        .iter()
+        .flat_map(|kind| {
+            (0..p.feature_size)
+                .rev()
+                .map(move |i| format!("{}(t-{})", kind, i))
+        })
+        .collect()
+}
+
+fn feature_name(names: &[String], j: usize) -> String {
+    names
+        .get(j)
+        .cloned()
+        .unwrap_or_else(|| format!("feature[{}]", j))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names() -> Vec<String> {
+        feature_names(&FeatureParams {
+            feature_size: 2,
+            ..FeatureParams::new_default()
+        })
+    }
+
+    #[test]
+    fn test_for_feature_names() {
+        assert_eq!(
+            names(),
+            vec![
+                "rate(t-1)",
+                "rate(t-0)",
+                "macd_histogram(t-1)",
+                "macd_histogram(t-0)",
+                "bb_upper(t-1)",
+                "bb_upper(t-0)",
+                "bb_lower(t-1)",
+                "bb_lower(t-0)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_for_feature_names_with_oscillators() {
+        let names = feature_names(&FeatureParams {
+            feature_size: 2,
+            rsi_period: 6,
+            stoch_period: 5,
+            stoch_d_period: 3,
+            ..FeatureParams::new_default()
+        });
+        assert_eq!(names.len(), 14);
+        assert_eq!(names[8], "rsi(t-1)");
+        assert_eq!(names[10], "stoch_k(t-1)");
+        assert_eq!(names[13], "stoch_d(t-0)");
+    }
+
+    #[test]
+    fn test_for_linear() {
+        let values = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let coefficients = [0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0];
+        let e = Explanation::linear(&names(), &values, &coefficients, 10.0);
+        assert_eq!(e.method, EXPLANATION_METHOD_LINEAR);
+        assert_eq!(e.base_value, 10.0);
+        assert_eq!(e.contributions.len(), 8);
+        assert_eq!(
+            e.contributions[0],
+            FeatureContribution {
+                name: "rate(t-1)".to_string(),
+                value: 1.0,
+                contribution: 0.5,
+            }
+        );
+        assert_eq!(e.contributions[7].contribution, -8.0);
+    }
+
+    #[test]
+    fn test_for_occlusion() {
+        // 最新のレートのみを使うモデル
+        let predict = |x: &FeatureData| -> MyResult<f64> { Ok(x[1] * 2.0) };
+        let values = vec![1.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let e = Explanation::occlusion(&names(), &values, 2, predict).unwrap();
+        assert_eq!(e.method, EXPLANATION_METHOD_OCCLUSION);
+        // レートの平均値 2.0 に置き換えると予想値が 6.0 から 4.0 になる
+        assert_eq!(e.contributions[1].contribution, 2.0);
+        assert!(e
+            .contributions
+            .iter()
+            .enumerate()
+            .all(|(j, c)| j == 1 || c.contribution == 0.0));
+        assert_eq!(e.base_value, 4.0);
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use smartcore::{
     ensemble::random_forest_regressor::RandomForestRegressor,
-    linalg::naive::dense_matrix::DenseMatrix,
+    linalg::{naive::dense_matrix::DenseMatrix, BaseMatrix},
     linear::{
         elastic_net::ElasticNet, lasso::Lasso, linear_regression::LinearRegression,
         logistic_regression::LogisticRegression, ridge_regression::RidgeRegression,
@@ -18,6 +18,16 @@ use smartcore::{
 
 use crate::error::{MyError, MyResult};
 
+use super::{
+    boosting::GradientBoostingRegressor,
+    ensemble::EnsembleModel,
+    explanation::{feature_names, Explanation, EXPLANATION_METHOD_LINEAR},
+    quantile::{
+        to_forecast_quantiles, ForecastQuantiles, QuantileRegression, QuantileRegressionParameters,
+    },
+    scaler::FeatureScaler,
+};
+
 pub type InputData = Vec<f64>;
 pub type FeatureData = Vec<f64>;
 
@@ -38,11 +48,11 @@ impl RateForTraining {
                 recored_at = v;
             }
             Err(err) => {
-                return Err(Box::new(MyError::ParseError {
+                return Err(MyError::ParseError {
                     param_name: "time".to_string(),
                     value: time.to_string(),
                     memo: format!("{}", err),
-                }));
+                });
             }
         }
         Ok(RateForTraining {
@@ -62,6 +72,18 @@ pub struct FeatureParams {
     pub slow_period: usize,
     pub signal_period: usize,
     pub bb_period: usize,
+    // RSIの期間（0の場合はRSIを特徴量に使わない）
+    #[serde(default)]
+    pub rsi_period: usize,
+    // ストキャスティクス %K の期間（0の場合は %K, %D を特徴量に使わない）
+    #[serde(default)]
+    pub stoch_period: usize,
+    // ストキャスティクス %D（%K の移動平均）の期間
+    #[serde(default)]
+    pub stoch_d_period: usize,
+    // 特徴量の尺度を揃えるための変換（None の場合は変換しない）
+    #[serde(default)]
+    pub scaler: Option<FeatureScaler>,
 }
 
 impl FeatureParams {
@@ -72,11 +94,37 @@ impl FeatureParams {
             slow_period: 6,
             signal_period: 4,
             bb_period: 3,
+            rsi_period: 0,
+            stoch_period: 0,
+            stoch_d_period: 0,
+            scaler: None,
         }
     }
 
+    pub fn uses_rsi(&self) -> bool {
+        self.rsi_period > 0
+    }
+
+    pub fn uses_stochastic(&self) -> bool {
+        self.stoch_period > 0
+    }
+
     pub fn to_hash(&self) -> MyResult<String> {
-        let s = format!("{:?}", self);
+        // 追加前に登録したモデルとハッシュが一致するよう、使わない設定は追加前の形式と同じく含めない
+        let mut s = format!(
+            "FeatureParams {{ feature_size: {}, fast_period: {}, slow_period: {}, signal_period: {}, bb_period: {}",
+            self.feature_size, self.fast_period, self.slow_period, self.signal_period, self.bb_period
+        );
+        if self.uses_rsi() || self.uses_stochastic() {
+            s.push_str(&format!(
+                ", rsi_period: {}, stoch_period: {}, stoch_d_period: {}",
+                self.rsi_period, self.stoch_period, self.stoch_d_period
+            ));
+        }
+        if let Some(scaler) = &self.scaler {
+            s.push_str(&format!(", scaler: {:?}", scaler));
+        }
+        s.push_str(" }");
 
         let mut hasher = Sha256::new();
         hasher.update(s.as_bytes());
@@ -167,6 +215,37 @@ pub enum ForecastModel {
         performance_rmse: f64,
         memo: String,
     },
+    Quantile {
+        pair: String,
+        no: i32,
+        model: QuantileRegression,
+        input_data_size: usize,
+        feature_params: FeatureParams,
+        performance_mse: f64,
+        performance_rmse: f64,
+        memo: String,
+    },
+    GradientBoosting {
+        pair: String,
+        no: i32,
+        model: GradientBoostingRegressor,
+        input_data_size: usize,
+        feature_params: FeatureParams,
+        performance_mse: f64,
+        performance_rmse: f64,
+        memo: String,
+    },
+    // 同じ特徴量の設定で学習した複数のモデルの予想値を重み付けして平均する
+    Ensemble {
+        pair: String,
+        no: i32,
+        model: EnsembleModel,
+        input_data_size: usize,
+        feature_params: FeatureParams,
+        performance_mse: f64,
+        performance_rmse: f64,
+        memo: String,
+    },
 }
 
 impl ForecastModel {
@@ -180,6 +259,9 @@ impl ForecastModel {
             ForecastModel::ElasticNet { pair, .. } => Ok(pair.to_string()),
             ForecastModel::Logistic { pair, .. } => Ok(pair.to_string()),
             ForecastModel::SVR { pair, .. } => Ok(pair.to_string()),
+            ForecastModel::Quantile { pair, .. } => Ok(pair.to_string()),
+            ForecastModel::GradientBoosting { pair, .. } => Ok(pair.to_string()),
+            ForecastModel::Ensemble { pair, .. } => Ok(pair.to_string()),
         }
     }
 
@@ -193,6 +275,9 @@ impl ForecastModel {
             ForecastModel::ElasticNet { no, .. } => Ok(*no),
             ForecastModel::Logistic { no, .. } => Ok(*no),
             ForecastModel::SVR { no, .. } => Ok(*no),
+            ForecastModel::Quantile { no, .. } => Ok(*no),
+            ForecastModel::GradientBoosting { no, .. } => Ok(*no),
+            ForecastModel::Ensemble { no, .. } => Ok(*no),
         }
     }
 
@@ -222,6 +307,15 @@ impl ForecastModel {
             ForecastModel::SVR {
                 input_data_size, ..
             } => Ok(*input_data_size),
+            ForecastModel::Quantile {
+                input_data_size, ..
+            } => Ok(*input_data_size),
+            ForecastModel::GradientBoosting {
+                input_data_size, ..
+            } => Ok(*input_data_size),
+            ForecastModel::Ensemble {
+                input_data_size, ..
+            } => Ok(*input_data_size),
         }
     }
 
@@ -235,6 +329,9 @@ impl ForecastModel {
             ForecastModel::ElasticNet { feature_params, .. } => Ok(feature_params.clone()),
             ForecastModel::Logistic { feature_params, .. } => Ok(feature_params.clone()),
             ForecastModel::SVR { feature_params, .. } => Ok(feature_params.clone()),
+            ForecastModel::Quantile { feature_params, .. } => Ok(feature_params.clone()),
+            ForecastModel::GradientBoosting { feature_params, .. } => Ok(feature_params.clone()),
+            ForecastModel::Ensemble { feature_params, .. } => Ok(feature_params.clone()),
         }
     }
 
@@ -264,6 +361,15 @@ impl ForecastModel {
             ForecastModel::SVR {
                 performance_mse, ..
             } => *performance_mse,
+            ForecastModel::Quantile {
+                performance_mse, ..
+            } => *performance_mse,
+            ForecastModel::GradientBoosting {
+                performance_mse, ..
+            } => *performance_mse,
+            ForecastModel::Ensemble {
+                performance_mse, ..
+            } => *performance_mse,
         }
     }
 
@@ -293,10 +399,36 @@ impl ForecastModel {
             ForecastModel::SVR {
                 performance_rmse, ..
             } => *performance_rmse,
+            ForecastModel::Quantile {
+                performance_rmse, ..
+            } => *performance_rmse,
+            ForecastModel::GradientBoosting {
+                performance_rmse, ..
+            } => *performance_rmse,
+            ForecastModel::Ensemble {
+                performance_rmse, ..
+            } => *performance_rmse,
         }
     }
 
-    fn set_performance_mse(&mut self, v: f64) -> MyResult<()> {
+    pub fn get_memo(&self) -> String {
+        match self {
+            ForecastModel::RandomForest { memo, .. } => memo.clone(),
+            ForecastModel::KNN { memo, .. } => memo.clone(),
+            ForecastModel::Linear { memo, .. } => memo.clone(),
+            ForecastModel::Ridge { memo, .. } => memo.clone(),
+            ForecastModel::LASSO { memo, .. } => memo.clone(),
+            ForecastModel::ElasticNet { memo, .. } => memo.clone(),
+            ForecastModel::Logistic { memo, .. } => memo.clone(),
+            ForecastModel::SVR { memo, .. } => memo.clone(),
+            ForecastModel::Quantile { memo, .. } => memo.clone(),
+            ForecastModel::GradientBoosting { memo, .. } => memo.clone(),
+            ForecastModel::Ensemble { memo, .. } => memo.clone(),
+        }
+    }
+
+    // テストデータ以外で評価した性能（交差検証など）を設定する
+    pub fn set_performance_mse(&mut self, v: f64) -> MyResult<()> {
         match self {
             ForecastModel::RandomForest {
                 performance_mse,
@@ -362,6 +494,30 @@ impl ForecastModel {
                 *performance_mse = v;
                 *performance_rmse = v.sqrt();
             }
+            ForecastModel::Quantile {
+                performance_mse,
+                performance_rmse,
+                ..
+            } => {
+                *performance_mse = v;
+                *performance_rmse = v.sqrt();
+            }
+            ForecastModel::GradientBoosting {
+                performance_mse,
+                performance_rmse,
+                ..
+            } => {
+                *performance_mse = v;
+                *performance_rmse = v.sqrt();
+            }
+            ForecastModel::Ensemble {
+                performance_mse,
+                performance_rmse,
+                ..
+            } => {
+                *performance_mse = v;
+                *performance_rmse = v.sqrt();
+            }
         }
         Ok(())
     }
@@ -371,13 +527,39 @@ impl ForecastModel {
         test_x: &Vec<FeatureData>,
         test_y: &Vec<f64>,
     ) -> MyResult<()> {
-        let matrix = DenseMatrix::from_2d_vec(test_x);
-        let y = self.predict_for_training(&matrix)?;
-        let mse = mean_squared_error(test_y, &y);
+        let mse = self.evaluate_mse(test_x, test_y)?;
         self.set_performance_mse(mse)?;
         Ok(())
     }
 
+    // テストデータでのMSE（モデルの性能は更新しない）
+    pub fn evaluate_mse(&self, test_x: &Vec<FeatureData>, test_y: &Vec<f64>) -> MyResult<f64> {
+        let matrix = DenseMatrix::from_2d_vec(test_x);
+        let y = self.predict_for_training(&matrix)?;
+        Ok(mean_squared_error(test_y, &y))
+    }
+
+    // 学習済みのモデルに新しいデータを追加で学習できるか
+    pub fn supports_incremental_update(&self) -> bool {
+        matches!(self, ForecastModel::Quantile { .. })
+    }
+
+    // 新しいデータを追加で学習する（追加で学習できないモデルは何もせず false を返す）
+    pub fn update_incrementally(
+        &mut self,
+        x: &[FeatureData],
+        y: &[f64],
+        params: &QuantileRegressionParameters,
+    ) -> MyResult<bool> {
+        match self {
+            ForecastModel::Quantile { model, .. } => {
+                model.update(x, y, params)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     fn predict_for_training(&self, x: &DenseMatrix<f64>) -> MyResult<Vec<f64>> {
         match self {
             ForecastModel::RandomForest { model, .. } => Ok(model.predict(x)?),
@@ -388,6 +570,13 @@ impl ForecastModel {
             ForecastModel::ElasticNet { model, .. } => Ok(model.predict(x)?),
             ForecastModel::Logistic { model, .. } => Ok(model.predict(x)?),
             ForecastModel::SVR { model, .. } => Ok(model.predict(x)?),
+            ForecastModel::Quantile { model, .. } => {
+                let rows: Vec<FeatureData> =
+                    (0..x.shape().0).map(|i| x.get_row_as_vec(i)).collect();
+                Ok(model.predict(&rows))
+            }
+            ForecastModel::GradientBoosting { model, .. } => model.predict(x),
+            ForecastModel::Ensemble { model, .. } => model.predict(x),
         }
     }
 
@@ -398,6 +587,63 @@ impl ForecastModel {
         Ok(y[0])
     }
 
+    // 分位点を予想できるモデルのみ、予想値の分位点を返す
+    pub fn predict_quantiles(&self, rates: &FeatureData) -> MyResult<Option<ForecastQuantiles>> {
+        match self {
+            ForecastModel::Quantile { model, .. } => {
+                Ok(to_forecast_quantiles(&model.predict_quantiles(rates)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // 予想値の根拠（線形モデルは係数×特徴量の値、それ以外のモデルは特徴量を置き換えたときの予想値の変化）
+    pub fn explain(&self, rates: &FeatureData) -> MyResult<Explanation> {
+        let feature_params = self.get_feature_params()?;
+        let names = feature_names(&feature_params);
+        let coefficients =
+            |c: &DenseMatrix<f64>| -> Vec<f64> { (0..c.shape().0).map(|j| c.get(j, 0)).collect() };
+        match self {
+            ForecastModel::Linear { model, .. } => Ok(Explanation::linear(
+                &names,
+                rates,
+                &coefficients(model.coefficients()),
+                model.intercept(),
+            )),
+            ForecastModel::Ridge { model, .. } => Ok(Explanation::linear(
+                &names,
+                rates,
+                &coefficients(model.coefficients()),
+                model.intercept(),
+            )),
+            ForecastModel::LASSO { model, .. } => Ok(Explanation::linear(
+                &names,
+                rates,
+                &coefficients(model.coefficients()),
+                model.intercept(),
+            )),
+            ForecastModel::ElasticNet { model, .. } => Ok(Explanation::linear(
+                &names,
+                rates,
+                &coefficients(model.coefficients()),
+                model.intercept(),
+            )),
+            ForecastModel::Quantile { model, .. } => {
+                let (intercept, contributions) = model.contributions(rates);
+                Ok(Explanation::new(
+                    EXPLANATION_METHOD_LINEAR,
+                    &names,
+                    rates,
+                    intercept,
+                    &contributions,
+                ))
+            }
+            _ => Explanation::occlusion(&names, rates, feature_params.feature_size, |x| {
+                self.predict(x)
+            }),
+        }
+    }
+
     pub fn serialize_model_data(&self) -> MyResult<Vec<u8>> {
         match self {
             ForecastModel::RandomForest { model, .. } => Ok(bincode::serialize(&model)?),
@@ -408,6 +654,9 @@ impl ForecastModel {
             ForecastModel::ElasticNet { model, .. } => Ok(bincode::serialize(&model)?),
             ForecastModel::Logistic { model, .. } => Ok(bincode::serialize(&model)?),
             ForecastModel::SVR { model, .. } => Ok(bincode::serialize(&model)?),
+            ForecastModel::Quantile { model, .. } => Ok(bincode::serialize(&model)?),
+            ForecastModel::GradientBoosting { model, .. } => Ok(bincode::serialize(&model)?),
+            ForecastModel::Ensemble { model, .. } => Ok(bincode::serialize(&model)?),
         }
     }
 }
@@ -535,10 +784,67 @@ impl fmt::Display for ForecastModel {
                     pair, no, feature_params, performance_mse, performance_rmse, memo
                 )
             }
+            ForecastModel::Quantile {
+                pair,
+                no,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Quantile(pair: {}, no: {}, feature_params: {:?}, mse: {}, rmse: {}, memo: {})",
+                    pair, no, feature_params, performance_mse, performance_rmse, memo
+                )
+            }
+            ForecastModel::GradientBoosting {
+                pair,
+                no,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                write!(
+                    f,
+                    "GradientBoosting(pair: {}, no: {}, feature_params: {:?}, mse: {}, rmse: {}, memo: {})",
+                    pair, no, feature_params, performance_mse, performance_rmse, memo
+                )
+            }
+            ForecastModel::Ensemble {
+                pair,
+                no,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Ensemble(pair: {}, no: {}, feature_params: {:?}, mse: {}, rmse: {}, memo: {})",
+                    pair, no, feature_params, performance_mse, performance_rmse, memo
+                )
+            }
         }
     }
 }
 
+// 予測種別（予測対象の時間を分で表す、DBには TINYINT UNSIGNED で保存する）
+pub const FORECAST_TYPE_AFTER_30MIN: i32 = 30;
+pub const FORECAST_TYPE_AFTER_5MIN: i32 = 5;
+const FORECAST_TYPE_MAX: usize = u8::MAX as usize;
+
+pub fn forecast_type_from_offset_minutes(minutes: usize) -> MyResult<i32> {
+    if minutes == 0 || minutes > FORECAST_TYPE_MAX {
+        return Err(MyError::UnsupportedForecastOffset { minutes });
+    }
+    Ok(minutes as i32)
+}
+
 #[derive(Debug, Clone)]
 pub struct ForecastResult {
     pub id: String,
@@ -546,6 +852,10 @@ pub struct ForecastResult {
     pub model_no: i32,
     pub forecast_type: i32,
     pub result: f64,
+    // 予想値の推移（複数ステップで予想した場合のみ、1件目は result と同じ）
+    pub path: Option<Vec<f64>>,
+    // 予想値の分位点（分位点を予想できるモデルの場合のみ）
+    pub quantiles: Option<ForecastQuantiles>,
     pub memo: Option<String>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
@@ -567,6 +877,8 @@ impl ForecastResult {
             model_no,
             forecast_type,
             result,
+            path: None,
+            quantiles: None,
             memo: Some(memo),
             created_at: dummy.clone(),
             updated_at: dummy.clone(),
@@ -579,16 +891,24 @@ pub struct ForecastError {
     pub id: String,
     pub rate_id: String,
     pub model_no: i32,
+    pub forecast_type: i32,
     pub summary: String,
     pub detail: String,
 }
 
 impl ForecastError {
-    pub fn new(rate_id: String, model_no: i32, summary: String, detail: String) -> MyResult<Self> {
+    pub fn new(
+        rate_id: String,
+        model_no: i32,
+        forecast_type: i32,
+        summary: String,
+        detail: String,
+    ) -> MyResult<Self> {
         Ok(ForecastError {
             id: "".to_string(),
             rate_id,
             model_no,
+            forecast_type,
             summary,
             detail,
         })
@@ -598,12 +918,44 @@ impl fmt::Display for ForecastError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}, {}, rate_id: {}, model_no: {}",
-            self.summary, self.detail, self.rate_id, self.model_no
+            "{}, {}, rate_id: {}, model_no: {}, forecast_type: {}",
+            self.summary, self.detail, self.rate_id, self.model_no, self.forecast_type
         )
     }
 }
 
+// 学習用レートの欠損期間（begin_at と end_at の間のレートが存在しない）
+#[derive(Debug, Clone)]
+pub struct RateGap {
+    pub pair: String,
+    pub begin_at: NaiveDateTime,
+    pub end_at: NaiveDateTime,
+    pub missing_minutes: i64,
+}
+
+// 予測結果と実績値の組
+#[derive(Debug, Clone)]
+pub struct ForecastEvaluation {
+    pub id: String,
+    pub forecast_result_id: String,
+    pub pair: String,
+    pub model_no: i32,
+    pub forecast_type: i32,
+    pub forecast: f64,
+    pub actual: f64,
+    pub target_at: chrono::NaiveDateTime,
+}
+
+// 予測モデルの一定期間ごとの実績誤差
+#[derive(Debug, Clone)]
+pub struct ModelPerformance {
+    pub time: chrono::NaiveDateTime,
+    pub count: i64,
+    pub mae: f64,
+    pub mse: f64,
+    pub rmse: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct RateForForecast {
     pub id: String,
@@ -611,6 +963,7 @@ pub struct RateForForecast {
     pub histories: Vec<f64>,
     pub expire: chrono::NaiveDateTime,
     pub memo: String,
+    pub trace_id: Option<String>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -621,6 +974,7 @@ impl RateForForecast {
         histories: Vec<f64>,
         expire: NaiveDateTime,
         memo: String,
+        trace_id: Option<String>,
     ) -> MyResult<Self> {
         Ok(RateForForecast {
             id: "".to_string(),
@@ -628,10 +982,22 @@ impl RateForForecast {
             histories: histories,
             expire: expire,
             memo: memo,
+            trace_id: trace_id,
             created_at: NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0),
             updated_at: NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0),
         })
     }
+
+    // 重複登録の検出用
+    pub fn histories_hash(&self) -> String {
+        let s = format!("{:?}", self.histories);
+
+        let mut hasher = Sha256::new();
+        hasher.update(s.as_bytes());
+        let hash = hasher.finalize();
+
+        format!("{:02x}", hash)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -654,3 +1020,110 @@ impl TrainingDataset {
         })
     }
 }
+
+// 学習実行の状態
+pub const TRAINING_RUN_STATUS_REQUESTED: u8 = 0;
+pub const TRAINING_RUN_STATUS_RUNNING: u8 = 1;
+pub const TRAINING_RUN_STATUS_SUCCEEDED: u8 = 2;
+pub const TRAINING_RUN_STATUS_FAILED: u8 = 3;
+
+#[derive(Debug, Clone)]
+pub struct TrainingRun {
+    pub id: String,
+    pub pair: String,
+    pub status: u8,
+    pub memo: Option<String>,
+    pub result: Option<String>,
+    pub started_at: Option<NaiveDateTime>,
+    pub finished_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TrainingRun {
+    pub fn new(pair: String, status: u8, memo: String) -> MyResult<Self> {
+        let dummy = NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        Ok(TrainingRun {
+            id: "".to_string(),
+            pair,
+            status,
+            memo: Some(memo),
+            result: None,
+            started_at: None,
+            finished_at: None,
+            created_at: dummy.clone(),
+            updated_at: dummy.clone(),
+        })
+    }
+}
+
+// ジョブ実行の状態
+pub const JOB_RUN_STATUS_RUNNING: u8 = 0;
+pub const JOB_RUN_STATUS_SUCCEEDED: u8 = 1;
+pub const JOB_RUN_STATUS_FAILED: u8 = 2;
+
+#[derive(Debug, Clone)]
+pub struct JobRun {
+    pub id: String,
+    pub job_name: String,
+    pub status: u8,
+    pub message: Option<String>,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+// ジョブ実行要求の状態
+// 待機中の要求は、依存するジョブが実行中でなくなった時点でオーケストレーターが実行可能にする
+pub const JOB_REQUEST_STATUS_PENDING: u8 = 0;
+pub const JOB_REQUEST_STATUS_READY: u8 = 1;
+pub const JOB_REQUEST_STATUS_RUNNING: u8 = 2;
+pub const JOB_REQUEST_STATUS_SUCCEEDED: u8 = 3;
+pub const JOB_REQUEST_STATUS_FAILED: u8 = 4;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobRequest {
+    pub id: String,
+    pub job_name: String,
+    pub status: u8,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub owner: Option<String>,
+    pub message: Option<String>,
+    pub available_at: NaiveDateTime,
+    pub started_at: Option<NaiveDateTime>,
+    pub finished_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_forecast_type_from_offset_minutes() {
+        assert_eq!(
+            forecast_type_from_offset_minutes(5).unwrap(),
+            FORECAST_TYPE_AFTER_5MIN
+        );
+        assert_eq!(
+            forecast_type_from_offset_minutes(30).unwrap(),
+            FORECAST_TYPE_AFTER_30MIN
+        );
+        // 5分・30分以外の予測時間も予測種別として扱える
+        assert_eq!(forecast_type_from_offset_minutes(15).unwrap(), 15);
+        assert_eq!(forecast_type_from_offset_minutes(255).unwrap(), 255);
+
+        // DBの予測種別（TINYINT UNSIGNED）で表せない値はエラー
+        for minutes in [0, 256] {
+            let err = forecast_type_from_offset_minutes(minutes).unwrap_err();
+            assert!(matches!(err, MyError::UnsupportedForecastOffset { .. }));
+        }
+    }
+}
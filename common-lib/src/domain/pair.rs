@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+// 通貨ペア（currency_pairs テーブルの1件）
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurrencyPair {
+    pub pair: String,
+    // 1pipの大きさ（USDJPYなら0.01）
+    pub pip_size: f64,
+    // 取り扱う場合は true
+    pub enabled: bool,
+}
+
+// 取り扱う通貨ペアの一覧
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PairRegistry {
+    pairs: BTreeMap<String, CurrencyPair>,
+}
+
+impl PairRegistry {
+    pub fn new(pairs: Vec<CurrencyPair>) -> Self {
+        PairRegistry {
+            pairs: pairs.into_iter().map(|p| (p.pair.clone(), p)).collect(),
+        }
+    }
+
+    pub fn get(&self, pair: &str) -> Option<&CurrencyPair> {
+        self.pairs.get(pair)
+    }
+
+    // 登録されていて有効な通貨ペアか
+    pub fn is_supported_pair(&self, pair: &str) -> bool {
+        self.get(pair).map(|p| p.enabled).unwrap_or(false)
+    }
+
+    // 有効な通貨ペアの一覧（通貨ペア名の昇順）
+    pub fn enabled_pairs(&self) -> Vec<String> {
+        self.pairs
+            .values()
+            .filter(|p| p.enabled)
+            .map(|p| p.pair.clone())
+            .collect()
+    }
+}
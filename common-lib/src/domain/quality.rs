@@ -0,0 +1,226 @@
+use chrono::{Duration, NaiveDateTime};
+use serde::Serialize;
+
+use super::{anomaly::is_market_closed, model::RateForTraining, series};
+
+// データ品質の基準
+#[derive(Debug, Clone)]
+pub struct DataQualityRules {
+    // 取引時間中の分のうち、レートがある分の割合の下限（0.0〜1.0）
+    pub min_coverage: f64,
+    // 欠損期間の件数の上限
+    pub max_gap_count: i64,
+    // 同じ分に記録されたレート数の上限
+    pub max_duplicate_count: i64,
+    // 最新のレートの記録日時からの経過秒数の上限（取引停止時間帯は判定しない）
+    pub max_lag_seconds: i64,
+    // 週末の取引停止時間帯（UTC）、金曜の begin_hour 時から日曜の end_hour 時まで（None の場合は常に取引時間とする）
+    pub weekend_hours: Option<(u32, u32)>,
+}
+
+// 通貨ペアごとのレートの品質
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataQualityReport {
+    pub pair: String,
+    pub measured_at: NaiveDateTime,
+    // 集計範囲（begin_at 以上 end_at 未満）
+    pub begin_at: NaiveDateTime,
+    pub end_at: NaiveDateTime,
+    // 取引時間中の分のうち、レートがある分の割合
+    pub coverage: f64,
+    // 取引時間中に始まった欠損期間の件数
+    pub gap_count: i64,
+    // 同じ分に記録された2件目以降のレート数
+    pub duplicate_count: i64,
+    pub latest_recorded_at: Option<NaiveDateTime>,
+    // 最新のレートの記録日時から measured_at までの経過秒数
+    pub lag_seconds: Option<i64>,
+    // 基準を満たさなかった項目（空の場合は問題なし）
+    pub problems: Vec<String>,
+}
+
+impl DataQualityReport {
+    // 記録日時の昇順に並んだ集計範囲のレートから品質を計測する
+    pub fn measure(
+        pair: &str,
+        rates: &[RateForTraining],
+        begin_at: NaiveDateTime,
+        end_at: NaiveDateTime,
+        measured_at: NaiveDateTime,
+        rules: &DataQualityRules,
+    ) -> Self {
+        let is_open = |t: &NaiveDateTime| !is_market_closed(t, rules.weekend_hours);
+
+        let mut expected_minutes = 0;
+        let mut t = series::truncate_to_minute(&begin_at);
+        while t < end_at {
+            if is_open(&t) {
+                expected_minutes += 1;
+            }
+            t += Duration::minutes(1);
+        }
+
+        let points = series::resample_minutely(rates);
+        let recorded_minutes = points.iter().filter(|p| p.rate.is_some()).count();
+        let covered_minutes = points
+            .iter()
+            .filter(|p| p.rate.is_some() && is_open(&p.at))
+            .count();
+        let coverage = if expected_minutes > 0 {
+            (covered_minutes as f64 / expected_minutes as f64).min(1.0)
+        } else {
+            1.0
+        };
+
+        // 取引停止時間帯に始まった欠損は数えない
+        let gap_count = series::detect_gaps(pair, &points, 1)
+            .iter()
+            .filter(|g| is_open(&(g.begin_at + Duration::minutes(1))))
+            .count() as i64;
+
+        let latest_recorded_at = rates.last().map(|r| r.recorded_at);
+        let mut report = DataQualityReport {
+            pair: pair.to_string(),
+            measured_at,
+            begin_at,
+            end_at,
+            coverage,
+            gap_count,
+            duplicate_count: (rates.len() - recorded_minutes) as i64,
+            latest_recorded_at,
+            lag_seconds: latest_recorded_at.map(|t| (measured_at - t).num_seconds()),
+            problems: vec![],
+        };
+        report.problems = report.check(rules, is_open(&measured_at));
+        report
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    fn check(&self, rules: &DataQualityRules, market_open: bool) -> Vec<String> {
+        let mut problems: Vec<String> = vec![];
+        if self.coverage < rules.min_coverage {
+            problems.push(format!(
+                "coverage is below {:.3}, value: {:.3}",
+                rules.min_coverage, self.coverage
+            ));
+        }
+        if self.gap_count > rules.max_gap_count {
+            problems.push(format!(
+                "gap_count is above {}, value: {}",
+                rules.max_gap_count, self.gap_count
+            ));
+        }
+        if self.duplicate_count > rules.max_duplicate_count {
+            problems.push(format!(
+                "duplicate_count is above {}, value: {}",
+                rules.max_duplicate_count, self.duplicate_count
+            ));
+        }
+        if market_open {
+            match self.lag_seconds {
+                Some(lag) if lag > rules.max_lag_seconds => problems.push(format!(
+                    "lag_seconds is above {}, value: {}",
+                    rules.max_lag_seconds, lag
+                )),
+                Some(_) => {}
+                None => problems.push("no rates".to_string()),
+            }
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::test_support::{RateSeries, TEST_PAIR};
+
+    fn rules() -> DataQualityRules {
+        DataQualityRules {
+            min_coverage: 0.9,
+            max_gap_count: 1,
+            max_duplicate_count: 0,
+            max_lag_seconds: 120,
+            weekend_hours: Some((22, 21)),
+        }
+    }
+
+    fn at(day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_for_measure() {
+        // 2022-01-03（月曜）00:00 〜 00:09 のうち 00:03 〜 00:04 が欠損し、00:05 に2件記録されている
+        let mut rates = RateSeries::new(TEST_PAIR)
+            .begin(at(3, 0, 0, 0))
+            .trending(10, 100.0, 0.1);
+        let duplicated = RateForTraining {
+            recorded_at: at(3, 0, 5, 30),
+            ..rates[5].clone()
+        };
+        rates.insert(6, duplicated);
+        rates.remove(4);
+        rates.remove(3);
+
+        let report = DataQualityReport::measure(
+            TEST_PAIR,
+            &rates,
+            at(3, 0, 0, 0),
+            at(3, 0, 10, 0),
+            at(3, 0, 10, 0),
+            &rules(),
+        );
+        assert!((report.coverage - 0.8).abs() < 1e-9);
+        assert_eq!(report.gap_count, 1);
+        assert_eq!(report.duplicate_count, 1);
+        assert_eq!(report.latest_recorded_at, Some(at(3, 0, 9, 0)));
+        assert_eq!(report.lag_seconds, Some(60));
+        assert_eq!(report.problems.len(), 2);
+        assert!(!report.is_ok());
+    }
+
+    #[test]
+    fn test_for_measure_weekend() {
+        // 2022-01-07（金曜）21:55 〜 22:05 のうち、取引停止時間帯の 22:00 以降はレートが無くても問題としない
+        let rates = RateSeries::new(TEST_PAIR)
+            .begin(at(7, 21, 55, 0))
+            .trending(5, 100.0, 0.1);
+
+        let report = DataQualityReport::measure(
+            TEST_PAIR,
+            &rates,
+            at(7, 21, 55, 0),
+            at(7, 22, 5, 0),
+            at(7, 22, 5, 0),
+            &rules(),
+        );
+        assert!((report.coverage - 1.0).abs() < 1e-9);
+        assert_eq!(report.gap_count, 0);
+        assert_eq!(report.lag_seconds, Some(360));
+        assert!(report.is_ok());
+
+        // 取引時間中にレートが無い場合は問題とする
+        let report = DataQualityReport::measure(
+            TEST_PAIR,
+            &[],
+            at(3, 0, 0, 0),
+            at(3, 0, 10, 0),
+            at(3, 0, 10, 0),
+            &rules(),
+        );
+        assert_eq!(report.coverage, 0.0);
+        assert_eq!(
+            report.problems,
+            vec!["coverage is below 0.900, value: 0.000", "no rates"]
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MyError, MyResult};
+
+use super::model::FeatureData;
+
+// 予想する分位点（10%, 50%, 90%）
+pub const QUANTILES: [f64; 3] = [0.1, 0.5, 0.9];
+
+// 予想値の分位点
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForecastQuantiles {
+    pub p10: f64,
+    pub p50: f64,
+    pub p90: f64,
+}
+
+impl ForecastQuantiles {
+    // 80%予測区間の幅（予想の不確実さの目安）
+    pub fn spread(&self) -> f64 {
+        self.p90 - self.p10
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuantileRegressionParameters {
+    // 学習率（エポックごとに 1/√エポック数 で小さくする）
+    pub learning_rate: f64,
+    pub epochs: usize,
+}
+
+impl Default for QuantileRegressionParameters {
+    fn default() -> Self {
+        QuantileRegressionParameters {
+            learning_rate: 0.5,
+            epochs: 300,
+        }
+    }
+}
+
+// ピンボール損失を最小化する線形の分位点回帰（分位点ごとに重みを持つ）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantileRegression {
+    // 特徴量の標準化に使う平均と標準偏差
+    means: Vec<f64>,
+    scales: Vec<f64>,
+    // QUANTILES の順に並んだ重み（末尾は切片）
+    weights: Vec<Vec<f64>>,
+}
+
+impl QuantileRegression {
+    pub fn fit(
+        x: &[FeatureData],
+        y: &[f64],
+        params: &QuantileRegressionParameters,
+    ) -> MyResult<Self> {
+        if x.is_empty() || y.is_empty() {
+            return Err(MyError::ArrayIsEmpty {
+                name: "train data".to_string(),
+            });
+        }
+        let n = x.len().min(y.len());
+        let size = x[0].len();
+
+        let means: Vec<f64> = (0..size)
+            .map(|j| x[..n].iter().map(|row| row[j]).sum::<f64>() / n as f64)
+            .collect();
+        let scales: Vec<f64> = means
+            .iter()
+            .enumerate()
+            .map(|(j, mean)| {
+                let variance = x[..n]
+                    .iter()
+                    .map(|row| (row[j] - mean).powi(2))
+                    .sum::<f64>()
+                    / n as f64;
+                // 値が変化しない特徴量はそのまま使う
+                if variance > 0.0 {
+                    variance.sqrt()
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        let mut m = QuantileRegression {
+            means,
+            scales,
+            weights: vec![],
+        };
+        let z: Vec<Vec<f64>> = x[..n].iter().map(|row| m.standardize(row)).collect();
+
+        let mut sorted_y = y[..n].to_vec();
+        sorted_y.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        for q in QUANTILES {
+            // 切片は学習データの分位点から始める
+            let mut w = vec![0.0; size + 1];
+            w[size] = sorted_y[((q * n as f64) as usize).min(n - 1)];
+            Self::descend(&mut w, &z, y, q, params);
+            m.weights.push(w);
+        }
+        Ok(m)
+    }
+
+    // 学習済みの重みから続けて新しいデータで学習する（標準化に使う平均と標準偏差は変えない）
+    pub fn update(
+        &mut self,
+        x: &[FeatureData],
+        y: &[f64],
+        params: &QuantileRegressionParameters,
+    ) -> MyResult<()> {
+        if x.is_empty() || y.is_empty() {
+            return Err(MyError::ArrayIsEmpty {
+                name: "update data".to_string(),
+            });
+        }
+        let z: Vec<Vec<f64>> = x.iter().map(|row| self.standardize(row)).collect();
+        for (w, q) in self.weights.iter_mut().zip(QUANTILES) {
+            Self::descend(w, &z, y, q, params);
+        }
+        Ok(())
+    }
+
+    // ピンボール損失を劣勾配法で小さくする
+    fn descend(
+        w: &mut [f64],
+        z: &[Vec<f64>],
+        y: &[f64],
+        q: f64,
+        params: &QuantileRegressionParameters,
+    ) {
+        let size = w.len() - 1;
+        let n = z.len().min(y.len()) as f64;
+        for epoch in 0..params.epochs {
+            let step = params.learning_rate / ((epoch + 1) as f64).sqrt();
+            let mut grad = vec![0.0; size + 1];
+            for (zi, yi) in z.iter().zip(y) {
+                // ピンボール損失の予想値についての劣勾配
+                let g = if *yi > Self::dot(w, zi) { -q } else { 1.0 - q };
+                for (gj, zij) in grad.iter_mut().zip(zi) {
+                    *gj += g * zij;
+                }
+                grad[size] += g;
+            }
+            for (wj, gj) in w.iter_mut().zip(&grad) {
+                *wj -= step * gj / n;
+            }
+        }
+    }
+
+    // QUANTILES の順の予想値（分位点が逆転しないよう昇順に並べ替える）
+    pub fn predict_quantiles(&self, x: &FeatureData) -> Vec<f64> {
+        let z = self.standardize(x);
+        let mut values: Vec<f64> = self.weights.iter().map(|w| Self::dot(w, &z)).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        values
+    }
+
+    // 中央値を予想値とする
+    pub fn predict(&self, x: &[FeatureData]) -> Vec<f64> {
+        x.iter()
+            .map(|row| self.predict_quantiles(row)[QUANTILES.len() / 2])
+            .collect()
+    }
+
+    // 中央値の予想値の切片と、特徴量ごとの寄与（重み×標準化した値、学習データの平均からの寄与）
+    pub fn contributions(&self, x: &FeatureData) -> (f64, Vec<f64>) {
+        let w = &self.weights[QUANTILES.len() / 2];
+        let z = self.standardize(x);
+        let contributions = z.iter().zip(w).map(|(a, b)| a * b).collect();
+        (w[w.len() - 1], contributions)
+    }
+
+    fn standardize(&self, x: &FeatureData) -> Vec<f64> {
+        x.iter()
+            .zip(self.means.iter().zip(&self.scales))
+            .map(|(v, (mean, scale))| (v - mean) / scale)
+            .collect()
+    }
+
+    fn dot(w: &[f64], z: &[f64]) -> f64 {
+        z.iter().zip(w).map(|(a, b)| a * b).sum::<f64>() + w[w.len() - 1]
+    }
+}
+
+// 予想値の分位点へ変換する
+pub fn to_forecast_quantiles(values: &[f64]) -> Option<ForecastQuantiles> {
+    match values {
+        [p10, p50, p90] => Some(ForecastQuantiles {
+            p10: *p10,
+            p50: *p50,
+            p90: *p90,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_quantile_regression() {
+        // 傾き 0.1 の直線に ±0.5 の一様なばらつきを加えたデータ
+        let x: Vec<FeatureData> = (0..200).map(|i| vec![i as f64 / 10.0]).collect();
+        let y: Vec<f64> = (0..200)
+            .map(|i| 100.0 + 0.01 * i as f64 + ((i * 37) % 100) as f64 / 100.0 - 0.5)
+            .collect();
+
+        let m = QuantileRegression::fit(&x, &y, &Default::default()).unwrap();
+        // 各分位点を下回る実績値の割合がおおよそ分位点と一致する
+        for (k, q) in QUANTILES.iter().enumerate() {
+            let below = x
+                .iter()
+                .zip(&y)
+                .filter(|(row, actual)| **actual < m.predict_quantiles(row)[k])
+                .count();
+            assert!((below as f64 / y.len() as f64 - q).abs() < 0.05);
+        }
+
+        let quantiles = to_forecast_quantiles(&m.predict_quantiles(&x[100])).unwrap();
+        assert!(quantiles.p10 < quantiles.p50 && quantiles.p50 < quantiles.p90);
+        assert!((quantiles.spread() - 0.8).abs() < 0.1);
+        assert_eq!(m.predict(&x[100..101]), vec![quantiles.p50]);
+
+        assert!(QuantileRegression::fit(&[], &[], &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_for_update() {
+        let x: Vec<FeatureData> = (0..100).map(|i| vec![i as f64 / 10.0]).collect();
+        let y: Vec<f64> = (0..100).map(|i| 100.0 + 0.01 * i as f64).collect();
+        let mut m = QuantileRegression::fit(&x, &y, &Default::default()).unwrap();
+        let before = m.predict(&x[50..51])[0];
+
+        // 水準が上がった新しいデータで学習すると、予想値も上がる
+        let shifted: Vec<f64> = y.iter().map(|v| v + 1.0).collect();
+        m.update(&x, &shifted, &Default::default()).unwrap();
+        let after = m.predict(&x[50..51])[0];
+        assert!(after > before + 0.5);
+
+        assert!(m.update(&[], &[], &Default::default()).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::NaiveDateTime;
+
+use super::{model::InputData, series::MinuteRate};
+
+// 追加学習に使うデータと、追加学習の前後で性能を比較するための評価データ
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RefreshDataset {
+    pub train_x: Vec<InputData>,
+    pub train_y: Vec<f64>,
+    pub holdout_x: Vec<InputData>,
+    pub holdout_y: Vec<f64>,
+}
+
+impl RefreshDataset {
+    // 1分間隔に並べたレートから入力データを作る
+    // 予想対象の時間が holdout_begin より前のものを追加学習用、以降のものを評価用とする
+    // レートが無い分を含む入力データは作らない
+    pub fn make(
+        points: &[MinuteRate],
+        input_size: usize,
+        offset_minutes: usize,
+        holdout_begin: NaiveDateTime,
+    ) -> Self {
+        let mut dataset = RefreshDataset::default();
+        if input_size == 0 {
+            return dataset;
+        }
+        let span = input_size + offset_minutes;
+        for begin in 0..points.len().saturating_sub(span - 1) {
+            let truth = &points[begin + span - 1];
+            let data: Option<InputData> = points[begin..begin + input_size]
+                .iter()
+                .map(|p| p.rate)
+                .collect();
+            let (data, truth_rate) = match (data, truth.rate) {
+                (Some(d), Some(t)) => (d, t),
+                _ => continue,
+            };
+
+            if truth.at < holdout_begin {
+                dataset.train_x.push(data);
+                dataset.train_y.push(truth_rate);
+            } else {
+                dataset.holdout_x.push(data);
+                dataset.holdout_y.push(truth_rate);
+            }
+        }
+        dataset
+    }
+}
+
+// 追加学習後の評価データでのMSEが、追加学習前より max_degradation の割合を超えて悪化したか
+pub fn is_degraded(before_mse: f64, after_mse: f64, max_degradation: f64) -> bool {
+    !after_mse.is_finite() || after_mse > before_mse * (1.0 + max_degradation)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::{
+        domain::series,
+        test_support::{RateSeries, TEST_PAIR},
+    };
+
+    fn at(min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 1, 3)
+            .unwrap()
+            .and_hms_opt(0, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_for_make() {
+        // 00:00 〜 00:09 のうち 00:07 が欠損している
+        let mut rates = RateSeries::new(TEST_PAIR)
+            .begin(at(0))
+            .trending(10, 100.0, 1.0);
+        rates.remove(7);
+        let points = series::resample_minutely(&rates);
+
+        let dataset = RefreshDataset::make(&points, 3, 2, at(6));
+        // 予想対象の時間が 00:04, 00:05 のものは追加学習用
+        assert_eq!(
+            dataset.train_x,
+            vec![vec![100.0, 101.0, 102.0], vec![101.0, 102.0, 103.0]]
+        );
+        assert_eq!(dataset.train_y, vec![104.0, 105.0]);
+        // 00:07 が欠損しているため、予想対象の時間が 00:06 と 00:08 のもののみ評価用
+        assert_eq!(
+            dataset.holdout_x,
+            vec![vec![102.0, 103.0, 104.0], vec![104.0, 105.0, 106.0]]
+        );
+        assert_eq!(dataset.holdout_y, vec![106.0, 108.0]);
+
+        // 入力データを作れるだけのレートが無い場合は空
+        let dataset = RefreshDataset::make(&points[..4], 3, 2, at(6));
+        assert_eq!(dataset, RefreshDataset::default());
+    }
+
+    #[test]
+    fn test_for_is_degraded() {
+        assert!(!is_degraded(1.0, 0.9, 0.05));
+        assert!(!is_degraded(1.0, 1.05, 0.05));
+        assert!(is_degraded(1.0, 1.06, 0.05));
+        assert!(is_degraded(1.0, f64::NAN, 0.05));
+    }
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MyError, MyResult};
+
+use super::model::FeatureData;
+
+// 特徴量の尺度の揃え方
+// none: 変換しない, zscore: 平均0・標準偏差1, minmax: 最小値0・最大値1（学習データでの値）
+pub const FEATURE_SCALING_NONE: &str = "none";
+pub const FEATURE_SCALING_ZSCORE: &str = "zscore";
+pub const FEATURE_SCALING_MIN_MAX: &str = "minmax";
+
+pub fn validate_scaling(method: &str) -> MyResult<()> {
+    match method {
+        FEATURE_SCALING_NONE | FEATURE_SCALING_ZSCORE | FEATURE_SCALING_MIN_MAX => Ok(()),
+        _ => Err(MyError::UnsupportedFeatureScaling {
+            value: method.to_string(),
+        }),
+    }
+}
+
+// 学習データから求めた特徴量ごとの変換の値（予想時も同じ値で変換する）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FeatureScaler {
+    pub method: String,
+    // 特徴量ごとに (値 - offset) / scale に変換する
+    pub offsets: Vec<f64>,
+    pub scales: Vec<f64>,
+}
+
+impl FeatureScaler {
+    // none の場合は変換しないため None を返す
+    pub fn fit(method: &str, features: &[FeatureData]) -> MyResult<Option<Self>> {
+        validate_scaling(method)?;
+        if method == FEATURE_SCALING_NONE {
+            return Ok(None);
+        }
+        if features.is_empty() {
+            return Err(MyError::ArrayIsEmpty {
+                name: "features".to_string(),
+            });
+        }
+
+        let size = features[0].len();
+        let n = features.len() as f64;
+        let mut offsets = vec![];
+        let mut scales = vec![];
+        for j in 0..size {
+            let column: Vec<f64> = features.iter().map(|f| f[j]).collect();
+            let (offset, scale) = if method == FEATURE_SCALING_ZSCORE {
+                let mean = column.iter().sum::<f64>() / n;
+                let variance = column.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+                (mean, variance.sqrt())
+            } else {
+                let min = column.iter().cloned().fold(f64::MAX, f64::min);
+                let max = column.iter().cloned().fold(f64::MIN, f64::max);
+                (min, max - min)
+            };
+            offsets.push(offset);
+            // 値が変化しない特徴量は0除算にならないよう、ずらすだけにする
+            scales.push(if scale > f64::EPSILON { scale } else { 1.0 });
+        }
+
+        Ok(Some(FeatureScaler {
+            method: method.to_string(),
+            offsets,
+            scales,
+        }))
+    }
+
+    pub fn transform(&self, feature: &FeatureData) -> MyResult<FeatureData> {
+        if feature.len() != self.offsets.len() {
+            return Err(MyError::UnmatchFeatureSize {
+                expected: self.offsets.len(),
+                actual: feature.len(),
+            });
+        }
+        Ok(feature
+            .iter()
+            .zip(self.offsets.iter().zip(&self.scales))
+            .map(|(v, (offset, scale))| (v - offset) / scale)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_feature_scaler() {
+        let features = vec![vec![100.0, -1.0, 5.0], vec![102.0, 1.0, 5.0]];
+
+        let s = FeatureScaler::fit(FEATURE_SCALING_ZSCORE, &features)
+            .unwrap()
+            .unwrap();
+        assert_eq!(s.transform(&features[0]).unwrap(), vec![-1.0, -1.0, 0.0]);
+        assert_eq!(s.transform(&features[1]).unwrap(), vec![1.0, 1.0, 0.0]);
+
+        let s = FeatureScaler::fit(FEATURE_SCALING_MIN_MAX, &features)
+            .unwrap()
+            .unwrap();
+        assert_eq!(s.transform(&features[0]).unwrap(), vec![0.0, 0.0, 0.0]);
+        assert_eq!(
+            s.transform(&vec![101.0, 0.0, 6.0]).unwrap(),
+            vec![0.5, 0.5, 1.0]
+        );
+        assert!(s.transform(&vec![101.0]).is_err());
+
+        assert!(FeatureScaler::fit(FEATURE_SCALING_NONE, &features)
+            .unwrap()
+            .is_none());
+        assert!(FeatureScaler::fit("robust", &features).is_err());
+    }
+}
@@ -0,0 +1,286 @@
+use chrono::{Duration, NaiveDateTime, Timelike};
+
+use crate::error::{MyError, MyResult};
+
+use super::model::{RateForTraining, RateGap};
+
+// 短い欠損の埋め方
+// forward: 直前のレートで埋める, linear: 前後のレートから線形補間する
+pub const GAP_FILL_FORWARD: &str = "forward";
+pub const GAP_FILL_LINEAR: &str = "linear";
+
+pub fn validate_gap_fill(method: &str) -> MyResult<()> {
+    match method {
+        GAP_FILL_FORWARD | GAP_FILL_LINEAR => Ok(()),
+        _ => Err(MyError::UnsupportedGapFillMethod {
+            value: method.to_string(),
+        }),
+    }
+}
+
+// 1分間隔に並べたレート（レートが無い分は None）
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinuteRate {
+    pub at: NaiveDateTime,
+    pub rate: Option<f64>,
+}
+
+// 秒以下を切り捨てる
+pub fn truncate_to_minute(t: &NaiveDateTime) -> NaiveDateTime {
+    t.date().and_hms_opt(t.hour(), t.minute(), 0).unwrap()
+}
+
+// 記録日時の昇順に並んだレートを1分間隔に並べ直す
+// 同じ分に複数のレートがある場合は最後のレートを使い、昇順に並んでいないレートは無視する
+pub fn resample_minutely(rates: &[RateForTraining]) -> Vec<MinuteRate> {
+    let mut points: Vec<MinuteRate> = vec![];
+    for rate in rates {
+        let at = truncate_to_minute(&rate.recorded_at);
+        if let Some(last) = points.last_mut() {
+            if at == last.at {
+                last.rate = Some(rate.rate);
+                continue;
+            }
+            if at < last.at {
+                continue;
+            }
+
+            let mut missing = last.at + Duration::minutes(1);
+            while missing < at {
+                points.push(MinuteRate {
+                    at: missing,
+                    rate: None,
+                });
+                missing += Duration::minutes(1);
+            }
+        }
+        points.push(MinuteRate {
+            at,
+            rate: Some(rate.rate),
+        });
+    }
+    points
+}
+
+// max_gap_minutes 分以下の欠損を直前のレートで埋める（それより長い欠損は埋めない）
+pub fn forward_fill(points: &mut [MinuteRate], max_gap_minutes: usize) {
+    let mut i = 0;
+    while i < points.len() {
+        if points[i].rate.is_some() {
+            i += 1;
+            continue;
+        }
+
+        let begin = i;
+        while i < points.len() && points[i].rate.is_none() {
+            i += 1;
+        }
+        // 先頭の欠損は直前のレートが無いため埋めない
+        if begin > 0 && i - begin <= max_gap_minutes {
+            let prev = points[begin - 1].rate;
+            for p in &mut points[begin..i] {
+                p.rate = prev;
+            }
+        }
+    }
+}
+
+// max_gap_minutes 分以下の欠損を前後のレートから線形補間する（それより長い欠損は埋めない）
+pub fn interpolate_linear(points: &mut [MinuteRate], max_gap_minutes: usize) {
+    let mut i = 0;
+    while i < points.len() {
+        if points[i].rate.is_some() {
+            i += 1;
+            continue;
+        }
+
+        let begin = i;
+        while i < points.len() && points[i].rate.is_none() {
+            i += 1;
+        }
+        // 先頭・末尾の欠損は前後のどちらかのレートが無いため埋めない
+        if begin == 0 || i == points.len() || i - begin > max_gap_minutes {
+            continue;
+        }
+        let (prev, next) = match (points[begin - 1].rate, points[i].rate) {
+            (Some(prev), Some(next)) => (prev, next),
+            _ => continue,
+        };
+        let steps = (i - begin + 1) as f64;
+        for (n, p) in points[begin..i].iter_mut().enumerate() {
+            p.rate = Some(prev + (next - prev) * (n + 1) as f64 / steps);
+        }
+    }
+}
+
+// method に応じて max_gap_minutes 分以下の欠損を埋める
+pub fn fill_gaps(points: &mut [MinuteRate], method: &str, max_gap_minutes: usize) -> MyResult<()> {
+    validate_gap_fill(method)?;
+    if method == GAP_FILL_LINEAR {
+        interpolate_linear(points, max_gap_minutes);
+    } else {
+        forward_fill(points, max_gap_minutes);
+    }
+    Ok(())
+}
+
+// min_missing_minutes 分以上レートが無い期間を欠損として検出する
+// 欠損期間の前後でレートが存在する分を begin_at, end_at とする
+pub fn detect_gaps(pair: &str, points: &[MinuteRate], min_missing_minutes: i64) -> Vec<RateGap> {
+    let mut gaps: Vec<RateGap> = vec![];
+    let mut prev: Option<NaiveDateTime> = None;
+    for p in points.iter().filter(|p| p.rate.is_some()) {
+        if let Some(prev_at) = prev {
+            let missing_minutes = (p.at - prev_at).num_minutes() - 1;
+            if missing_minutes > 0 && missing_minutes >= min_missing_minutes {
+                gaps.push(RateGap {
+                    pair: pair.to_string(),
+                    begin_at: prev_at,
+                    end_at: p.at,
+                    missing_minutes,
+                });
+            }
+        }
+        prev = Some(p.at);
+    }
+    gaps
+}
+
+// 欠損を含まない区間ごとのレート
+pub fn continuous_segments(points: &[MinuteRate]) -> Vec<Vec<f64>> {
+    points
+        .split(|p| p.rate.is_none())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.iter().filter_map(|p| p.rate).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::test_support::RateSeries;
+
+    fn at(hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_for_resample_minutely() {
+        let mut rates = RateSeries::new("USDJPY").trending(6, 100.0, 1.0);
+        // 00:00:30 に 00:00 のレートを更新し、00:02 〜 00:03 を欠損させる
+        rates[1].recorded_at = at(0, 0, 30);
+        rates.remove(3);
+        rates.remove(2);
+
+        let points = resample_minutely(&rates);
+        let rates: Vec<Option<f64>> = points.iter().map(|p| p.rate).collect();
+        assert_eq!(
+            rates,
+            vec![Some(101.0), None, None, None, Some(104.0), Some(105.0)]
+        );
+        assert_eq!(points[0].at, at(0, 0, 0));
+        assert_eq!(points[5].at, at(0, 5, 0));
+    }
+
+    #[test]
+    fn test_for_forward_fill_and_segments() {
+        let rate = |min: u32, rate: Option<f64>| MinuteRate {
+            at: at(0, min, 0),
+            rate,
+        };
+        let mut points = vec![
+            rate(0, None),
+            rate(1, Some(100.0)),
+            rate(2, None),
+            rate(3, Some(101.0)),
+            rate(4, None),
+            rate(5, None),
+            rate(6, None),
+            rate(7, Some(102.0)),
+        ];
+        forward_fill(&mut points, 2);
+
+        let rates: Vec<Option<f64>> = points.iter().map(|p| p.rate).collect();
+        assert_eq!(
+            rates,
+            vec![
+                None,
+                Some(100.0),
+                Some(100.0),
+                Some(101.0),
+                None,
+                None,
+                None,
+                Some(102.0)
+            ]
+        );
+        assert_eq!(
+            continuous_segments(&points),
+            vec![vec![100.0, 100.0, 101.0], vec![102.0]]
+        );
+
+        let gaps = detect_gaps("USDJPY", &points, 1);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].begin_at, at(0, 3, 0));
+        assert_eq!(gaps[0].end_at, at(0, 7, 0));
+        assert_eq!(gaps[0].missing_minutes, 3);
+        assert!(detect_gaps("USDJPY", &points, 4).is_empty());
+    }
+
+    #[test]
+    fn test_for_interpolate_linear() {
+        let rate = |min: u32, rate: Option<f64>| MinuteRate {
+            at: at(0, min, 0),
+            rate,
+        };
+        let mut points = vec![
+            rate(0, None),
+            rate(1, Some(100.0)),
+            rate(2, None),
+            rate(3, None),
+            rate(4, None),
+            rate(5, Some(102.0)),
+            rate(6, None),
+            rate(7, None),
+            rate(8, None),
+            rate(9, Some(103.0)),
+            rate(10, None),
+        ];
+        fill_gaps(&mut points, GAP_FILL_LINEAR, 3).unwrap();
+
+        // 3分以下の欠損のみ補間し、先頭・末尾の欠損は埋めない
+        let rates: Vec<Option<f64>> = points.iter().map(|p| p.rate).collect();
+        assert_eq!(
+            rates,
+            vec![
+                None,
+                Some(100.0),
+                Some(100.5),
+                Some(101.0),
+                Some(101.5),
+                Some(102.0),
+                Some(102.25),
+                Some(102.5),
+                Some(102.75),
+                Some(103.0),
+                None
+            ]
+        );
+
+        let mut points = vec![
+            rate(0, Some(100.0)),
+            rate(1, None),
+            rate(2, None),
+            rate(3, Some(101.0)),
+        ];
+        interpolate_linear(&mut points, 1);
+        assert!(points[1].rate.is_none());
+
+        assert!(fill_gaps(&mut points, "spline", 1).is_err());
+    }
+}
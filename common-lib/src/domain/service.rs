@@ -1,11 +1,14 @@
 use ta::{
-    indicators::{BollingerBands, MovingAverageConvergenceDivergence},
+    indicators::{
+        BollingerBands, FastStochastic, MovingAverageConvergenceDivergence, RelativeStrengthIndex,
+        SimpleMovingAverage,
+    },
     Next,
 };
 
 use crate::error::MyResult;
 
-use super::model::{FeatureData, FeatureParams, InputData};
+use super::model::{FeatureData, FeatureParams, ForecastModel, InputData};
 
 pub fn convert_to_feature(rates_org: &InputData, p: &FeatureParams) -> MyResult<FeatureData> {
     let size = rates_org.len();
@@ -13,19 +16,44 @@ pub fn convert_to_feature(rates_org: &InputData, p: &FeatureParams) -> MyResult<
     let mut macd =
         MovingAverageConvergenceDivergence::new(p.fast_period, p.slow_period, p.signal_period)?;
     let mut bb = BollingerBands::new(p.bb_period, 2.0_f64)?;
+    // 期間が0の指標は特徴量に使わない
+    let mut rsi = if p.uses_rsi() {
+        Some(RelativeStrengthIndex::new(p.rsi_period)?)
+    } else {
+        None
+    };
+    let mut stoch = if p.uses_stochastic() {
+        Some((
+            FastStochastic::new(p.stoch_period)?,
+            SimpleMovingAverage::new(p.stoch_d_period)?,
+        ))
+    } else {
+        None
+    };
 
     // 特徴量1から順に配列へと格納
     // 特徴量1: レート
     // 特徴量2: MACD（histogram）
     // 特徴量3: BB（Upper）
     // 特徴量4: BB（Lower）
+    // 特徴量5: RSI（rsi_period が0の場合はなし）
+    // 特徴量6: ストキャスティクス（%K）（stoch_period が0の場合はなし）
+    // 特徴量7: ストキャスティクス（%D）（stoch_period が0の場合はなし）
     let mut rates = vec![];
     let mut histograms = vec![];
     let mut bb_uppers = vec![];
     let mut bb_lowers = vec![];
+    let mut rsis = vec![];
+    let mut stoch_ks = vec![];
+    let mut stoch_ds = vec![];
     for (i, rate) in rates_org.iter().enumerate() {
         let macd_output = macd.next(*rate);
         let bb_output = bb.next(*rate);
+        let rsi_output = rsi.as_mut().map(|r| r.next(*rate));
+        let stoch_output = stoch.as_mut().map(|(k, d)| {
+            let k_value = k.next(*rate);
+            (k_value, d.next(k_value))
+        });
         if i >= size - p.feature_size {
             rates.push(*rate);
 
@@ -33,6 +61,14 @@ pub fn convert_to_feature(rates_org: &InputData, p: &FeatureParams) -> MyResult<
 
             bb_uppers.push(bb_output.upper);
             bb_lowers.push(bb_output.lower);
+
+            if let Some(v) = rsi_output {
+                rsis.push(v);
+            }
+            if let Some((k_value, d_value)) = stoch_output {
+                stoch_ks.push(k_value);
+                stoch_ds.push(d_value);
+            }
         }
     }
 
@@ -41,7 +77,15 @@ pub fn convert_to_feature(rates_org: &InputData, p: &FeatureParams) -> MyResult<
     converted.extend(&histograms);
     converted.extend(&bb_uppers);
     converted.extend(&bb_lowers);
-    Ok(converted)
+    converted.extend(&rsis);
+    converted.extend(&stoch_ks);
+    converted.extend(&stoch_ds);
+
+    // 学習時と同じ変換で尺度を揃える
+    match &p.scaler {
+        Some(scaler) => scaler.transform(&converted),
+        None => Ok(converted),
+    }
 }
 
 pub fn convert_to_features(
@@ -57,3 +101,95 @@ pub fn convert_to_features(
 
     Ok(features)
 }
+
+// 予想値を入力の末尾に加えながら再帰的に予想し、step_minutes 分後ごとの予想値を steps 件返す
+// モデルは step_minutes 分後のレートを予想するため、予想値までの間の分は直線で補間する
+pub fn forecast_path(
+    model: &ForecastModel,
+    histories: &InputData,
+    steps: usize,
+    step_minutes: usize,
+) -> MyResult<Vec<f64>> {
+    let params = model.get_feature_params()?;
+    let mut inputs = histories.clone();
+    let mut path = vec![];
+    for _ in 0..steps {
+        let predicted = model.predict(&convert_to_feature(&inputs, &params)?)?;
+        path.push(predicted);
+        inputs = extend_histories(&inputs, predicted, step_minutes);
+    }
+    Ok(path)
+}
+
+// 末尾の値から next までを minutes 分かけて直線で補間して加え、件数が変わらないよう古い値を取り除く
+fn extend_histories(histories: &InputData, next: f64, minutes: usize) -> InputData {
+    let last = match histories.last() {
+        Some(v) => *v,
+        None => return histories.clone(),
+    };
+    let mut extended = histories.clone();
+    for i in 1..=minutes {
+        extended.push(last + (next - last) * i as f64 / minutes as f64);
+    }
+    extended.split_off(extended.len() - histories.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{forecast_model, to_input_data, RateSeries, TEST_PAIR};
+
+    #[test]
+    fn test_for_extend_histories() {
+        let histories = vec![1.0, 2.0, 3.0, 4.0];
+        assert_eq!(
+            extend_histories(&histories, 6.0, 2),
+            vec![3.0, 4.0, 5.0, 6.0]
+        );
+        assert_eq!(extend_histories(&histories, 6.0, 0), histories);
+    }
+
+    #[test]
+    fn test_for_convert_to_feature() {
+        let histories = to_input_data(&RateSeries::new(TEST_PAIR).noisy(30, 100.0, 1.0, 2));
+        let mut p = FeatureParams {
+            feature_size: 5,
+            ..FeatureParams::new_default()
+        };
+        // RSI・ストキャスティクスを使わない場合はレート、MACD、BB（Upper, Lower）のみ
+        assert_eq!(convert_to_feature(&histories, &p).unwrap().len(), 5 * 4);
+
+        p.rsi_period = 6;
+        p.stoch_period = 5;
+        p.stoch_d_period = 3;
+        let features = convert_to_feature(&histories, &p).unwrap();
+        assert_eq!(features.len(), 5 * 7);
+        // RSI, %K, %D はいずれも 0〜100 の値になる
+        assert!(features[5 * 4..].iter().all(|v| (0.0..=100.0).contains(v)));
+        // 最新の %K は直近の高値・安値の範囲内での位置
+        let recent = &histories[histories.len() - 5..];
+        let high = recent.iter().cloned().fold(f64::MIN, f64::max);
+        let low = recent.iter().cloned().fold(f64::MAX, f64::min);
+        let expected = (histories[histories.len() - 1] - low) / (high - low) * 100.0;
+        assert!((features[5 * 6 - 1] - expected).abs() < 1e-9);
+
+        // 期間が不正な場合はエラー
+        p.stoch_d_period = 0;
+        assert!(convert_to_feature(&histories, &p).is_err());
+    }
+
+    #[test]
+    fn test_for_forecast_path() {
+        let model = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let histories = to_input_data(&RateSeries::new(TEST_PAIR).noisy(30, 100.0, 1.0, 2));
+
+        let path = forecast_path(&model, &histories, 3, 5).unwrap();
+        assert_eq!(path.len(), 3);
+        // 1件目は通常の予想と同じ
+        let features =
+            convert_to_feature(&histories, &model.get_feature_params().unwrap()).unwrap();
+        assert_eq!(path[0], model.predict(&features).unwrap());
+
+        assert!(forecast_path(&model, &histories, 0, 5).unwrap().is_empty());
+    }
+}
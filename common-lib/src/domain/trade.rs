@@ -0,0 +1,165 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+// バイナリーオプションの取引条件
+#[derive(Debug, Clone)]
+pub struct PayoutModel {
+    // 的中した場合の払戻率（掛金に対する利益の割合）
+    pub payout_ratio: f64,
+    // 取引する予想変化幅の下限
+    pub trade_threshold: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    High,
+    Low,
+}
+
+pub const TRADE_DIRECTION_NONE: u8 = 0;
+pub const TRADE_DIRECTION_HIGH: u8 = 1;
+pub const TRADE_DIRECTION_LOW: u8 = 2;
+
+impl PayoutModel {
+    // 予想レートが現在レートから閾値以上離れている場合のみ取引する
+    pub fn decide(&self, current: f64, forecast: f64) -> Option<Direction> {
+        let diff = forecast - current;
+        if diff.abs() < self.trade_threshold || diff == 0.0 {
+            None
+        } else if diff > 0.0 {
+            Some(Direction::High)
+        } else {
+            Some(Direction::Low)
+        }
+    }
+
+    // 掛金1あたりの損益（判定時刻のレートが同じ場合は引き分けとして掛金を返す）
+    pub fn profit(&self, direction: Direction, current: f64, truth: f64) -> f64 {
+        if truth == current {
+            return 0.0;
+        }
+        let won = match direction {
+            Direction::High => truth > current,
+            Direction::Low => truth < current,
+        };
+        if won {
+            self.payout_ratio
+        } else {
+            -1.0
+        }
+    }
+}
+
+// 取引の判定時刻を過ぎた予想（判定時刻のレートが記録済みのもの）
+#[derive(Debug, Clone)]
+pub struct PaperTradeCandidate {
+    pub forecast_result_id: String,
+    pub pair: String,
+    pub model_no: i32,
+    // 予想時点の最新のレート
+    pub entry_rate: f64,
+    pub forecast: f64,
+    pub exit_rate: f64,
+    pub entry_at: NaiveDateTime,
+    pub exit_at: NaiveDateTime,
+}
+
+// 予想に従って仮想的に取引した結果（取引しなかった予想も記録する）
+#[derive(Debug, Clone)]
+pub struct PaperTrade {
+    pub forecast_result_id: String,
+    pub pair: String,
+    pub model_no: i32,
+    pub direction: u8,
+    pub entry_rate: f64,
+    pub forecast: f64,
+    pub exit_rate: f64,
+    pub stake: f64,
+    pub profit: f64,
+    pub entry_at: NaiveDateTime,
+    pub exit_at: NaiveDateTime,
+}
+
+impl PaperTrade {
+    pub fn settle(candidate: PaperTradeCandidate, payout: &PayoutModel, stake: f64) -> Self {
+        let (direction, profit) = match payout.decide(candidate.entry_rate, candidate.forecast) {
+            Some(d) => (
+                match d {
+                    Direction::High => TRADE_DIRECTION_HIGH,
+                    Direction::Low => TRADE_DIRECTION_LOW,
+                },
+                stake * payout.profit(d, candidate.entry_rate, candidate.exit_rate),
+            ),
+            None => (TRADE_DIRECTION_NONE, 0.0),
+        };
+        PaperTrade {
+            forecast_result_id: candidate.forecast_result_id,
+            pair: candidate.pair,
+            model_no: candidate.model_no,
+            direction,
+            entry_rate: candidate.entry_rate,
+            forecast: candidate.forecast,
+            exit_rate: candidate.exit_rate,
+            stake,
+            profit,
+            entry_at: candidate.entry_at,
+            exit_at: candidate.exit_at,
+        }
+    }
+}
+
+// モデルごとの仮想取引の損益
+#[derive(Debug, Clone, Serialize)]
+pub struct PaperTradeSummary {
+    pub pair: String,
+    pub model_no: i32,
+    pub forecasts: i64,
+    pub trades: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub draws: i64,
+    // 引き分けを除いた勝率（取引が無い場合は0）
+    pub win_rate: f64,
+    pub total_profit: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_for_settle() {
+        let payout = PayoutModel {
+            payout_ratio: 0.8,
+            trade_threshold: 0.05,
+        };
+        let at = NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let candidate = |forecast: f64, exit_rate: f64| PaperTradeCandidate {
+            forecast_result_id: "id".to_string(),
+            pair: "USDJPY".to_string(),
+            model_no: 1,
+            entry_rate: 100.0,
+            forecast,
+            exit_rate,
+            entry_at: at,
+            exit_at: at,
+        };
+
+        let t = PaperTrade::settle(candidate(100.1, 100.2), &payout, 10.0);
+        assert_eq!(t.direction, TRADE_DIRECTION_HIGH);
+        assert!((t.profit - 8.0).abs() < 1e-9);
+
+        let t = PaperTrade::settle(candidate(99.9, 100.2), &payout, 10.0);
+        assert_eq!(t.direction, TRADE_DIRECTION_LOW);
+        assert!((t.profit - (-10.0)).abs() < 1e-9);
+
+        let t = PaperTrade::settle(candidate(100.01, 100.2), &payout, 10.0);
+        assert_eq!(t.direction, TRADE_DIRECTION_NONE);
+        assert_eq!(t.profit, 0.0);
+    }
+}
@@ -1,5 +1,22 @@
-use std::error::Error;
-pub type MyResult<T> = Result<T, Box<dyn Error>>;
+use std::num::{ParseFloatError, ParseIntError};
+
+use crate::mysql;
+
+// スレッド間で受け渡せるよう、エラーは Send + Sync な MyError にまとめる
+pub type MyResult<T> = Result<T, MyError>;
+
+// エラーの分類（再試行やHTTPステータスの判断に使う）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorCategory {
+    // DBに接続できないなどの一時的なエラー（再試行で解消する可能性がある）
+    Transient,
+    // 対象のデータが存在しない
+    NotFound,
+    // 入力値や設定値が不正
+    Validation,
+    // 上記以外（データの破損やバグなど）
+    Internal,
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum MyError {
@@ -24,9 +41,235 @@ pub enum MyError {
     #[error("unmatch feature params hash, pair:{}, model_no:{}", pair, model_no)]
     UnmatchFeatureParamsHash { pair: String, model_no: i32 },
 
+    #[error("forecast model is not found, pair:{}, model_no:{}", pair, model_no)]
+    ForecastModelNotFound { pair: String, model_no: i32 },
+
+    #[error("unsupported model archive version, version:{}", version)]
+    UnsupportedModelArchiveVersion { version: u32 },
+
+    #[error("unsupported ensemble weighting, value:{}", value)]
+    UnsupportedEnsembleWeighting { value: String },
+
+    #[error("model can not be combined into ensemble, memo:{}", memo)]
+    UnmatchEnsembleMember { memo: String },
+
+    #[error("unsupported feature scaling, value:{}", value)]
+    UnsupportedFeatureScaling { value: String },
+
+    #[error("unsupported gap fill method, value:{}", value)]
+    UnsupportedGapFillMethod { value: String },
+
+    #[error("unmatch feature size, expected:{}, actual:{}", expected, actual)]
+    UnmatchFeatureSize { expected: usize, actual: usize },
+
     #[error("input data is too little, count:{}, require:{}", count, require)]
     InputDataIsTooLittle { count: usize, require: usize },
 
     #[error("{} is empty", name)]
     ArrayIsEmpty { name: String },
+
+    #[error("body is too large, max_bytes:{}", max_bytes)]
+    BodyTooLarge { max_bytes: u64 },
+
+    #[error(
+        "unsupported forecast offset, minutes:{}, must be between 1 and 255",
+        minutes
+    )]
+    UnsupportedForecastOffset { minutes: usize },
+
+    #[error("unsupported rate source, name:{}", name)]
+    UnsupportedRateSource { name: String },
+
+    #[error("unsupported accuracy bucket, value:{}", value)]
+    UnsupportedAccuracyBucket { value: String },
+
+    #[error("unsupported anomaly kind, value:{}", value)]
+    UnsupportedAnomalyKind { value: String },
+
+    #[error("failed to fetch rate, source:{}, memo:{}", name, memo)]
+    FetchRateFailed { name: String, memo: String },
+
+    #[error("unsupported config file, path:{}", path)]
+    UnsupportedConfigFile { path: String },
+
+    #[error("invalid config, problems:[{}]", problems.join(", "))]
+    InvalidConfig { problems: Vec<String> },
+
+    // 以下は外部のライブラリのエラー（? で変換する）
+    #[error("database error, {0}")]
+    Database(#[from] ::mysql::Error),
+
+    #[error("io error, {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to serialize model, {0}")]
+    ModelSerialize(#[from] bincode::Error),
+
+    #[error("model error, {0}")]
+    Model(#[from] smartcore::error::Failed),
+
+    #[error("indicator error, {0}")]
+    Indicator(#[from] ta::errors::TaError),
+
+    #[error("http client error, {0}")]
+    HttpClient(#[from] reqwest::Error),
+
+    #[error("http server error, {0}")]
+    HttpServer(#[from] hyper::Error),
+
+    #[error("tls error, {0}")]
+    Tls(#[from] openssl::error::ErrorStack),
+
+    #[error("json error, {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid integer, {0}")]
+    ParseInt(#[from] ParseIntError),
+
+    #[error("invalid number, {0}")]
+    ParseFloat(#[from] ParseFloatError),
+
+    #[error("invalid datetime, {0}")]
+    ParseDateTime(#[from] chrono::ParseError),
+
+    #[error("invalid address, {0}")]
+    ParseAddr(#[from] std::net::AddrParseError),
+
+    #[error("invalid environment variable, {0}")]
+    Env(#[from] envy::Error),
+
+    #[error("invalid toml, {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("invalid yaml, {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("invalid sentry dsn, {0}")]
+    SentryDsn(#[from] sentry::types::ParseDsnError),
+
+    #[error("trace error, {0}")]
+    Trace(#[from] opentelemetry::trace::TraceError),
+
+    #[error("failed to set trace subscriber, {0}")]
+    TraceSubscriber(#[from] tracing::subscriber::SetGlobalDefaultError),
+
+    #[error("metrics error, {0}")]
+    Metrics(#[from] prometheus::Error),
+}
+
+impl MyError {
+    // エラーを分類する
+    // 外部のライブラリのエラーはDB（mysql）、通信（reqwest）などの元のエラーの種類で判断する
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            MyError::ParseError { .. }
+            | MyError::UnsupportedModelTypeEnum { .. }
+            | MyError::InputDataIsTooLittle { .. }
+            | MyError::ArrayIsEmpty { .. }
+            | MyError::BodyTooLarge { .. }
+            | MyError::UnsupportedForecastOffset { .. }
+            | MyError::UnsupportedRateSource { .. }
+            | MyError::UnsupportedAccuracyBucket { .. }
+            | MyError::UnsupportedAnomalyKind { .. }
+            | MyError::UnsupportedModelArchiveVersion { .. }
+            | MyError::UnsupportedEnsembleWeighting { .. }
+            | MyError::UnmatchEnsembleMember { .. }
+            | MyError::UnsupportedFeatureScaling { .. }
+            | MyError::UnsupportedGapFillMethod { .. }
+            | MyError::UnsupportedConfigFile { .. }
+            | MyError::InvalidConfig { .. }
+            | MyError::ParseInt(_)
+            | MyError::ParseFloat(_)
+            | MyError::ParseDateTime(_)
+            | MyError::ParseAddr(_)
+            | MyError::Env(_)
+            | MyError::Toml(_)
+            | MyError::Yaml(_)
+            | MyError::SentryDsn(_) => ErrorCategory::Validation,
+            MyError::ForecastModelNotFound { .. } => ErrorCategory::NotFound,
+            MyError::FetchRateFailed { .. } => ErrorCategory::Transient,
+            MyError::Database(e) => mysql_error_category(self, e),
+            MyError::HttpClient(e) if e.is_timeout() || e.is_connect() => ErrorCategory::Transient,
+            MyError::Io(e) => match e.kind() {
+                std::io::ErrorKind::NotFound => ErrorCategory::NotFound,
+                std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::Interrupted
+                | std::io::ErrorKind::ConnectionRefused
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted => ErrorCategory::Transient,
+                _ => ErrorCategory::Internal,
+            },
+            // モデルの復元（bincode）や学習・予想（smartcore）の失敗は、保存済みデータの破損やバグによるもの
+            MyError::UnknownModelType { .. }
+            | MyError::UnmatchFeatureParamsHash { .. }
+            | MyError::UnmatchFeatureSize { .. }
+            | MyError::ModelSerialize(_)
+            | MyError::Model(_)
+            | MyError::Indicator(_)
+            | MyError::HttpClient(_)
+            | MyError::HttpServer(_)
+            | MyError::Tls(_)
+            | MyError::Json(_)
+            | MyError::Trace(_)
+            | MyError::TraceSubscriber(_)
+            | MyError::Metrics(_) => ErrorCategory::Internal,
+        }
+    }
+
+    // 再試行で解消する可能性があるエラーか
+    pub fn is_retryable(&self) -> bool {
+        self.category() == ErrorCategory::Transient
+    }
+}
+
+fn mysql_error_category(err: &MyError, e: &::mysql::Error) -> ErrorCategory {
+    if mysql::util::is_connection_error(err) {
+        return ErrorCategory::Transient;
+    }
+    match e {
+        // 1205: Lock wait timeout exceeded, 1213: Deadlock found
+        ::mysql::Error::MySqlError(e) if matches!(e.code, 1205 | 1213) => ErrorCategory::Transient,
+        // 1062: Duplicate entry, 1406: Data too long, 1452: Foreign key constraint fails
+        ::mysql::Error::MySqlError(e) if matches!(e.code, 1062 | 1406 | 1452) => {
+            ErrorCategory::Validation
+        }
+        _ => ErrorCategory::Internal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_category() {
+        let err = MyError::FetchRateFailed {
+            name: "gmo_coin".to_string(),
+            memo: "timeout".to_string(),
+        };
+        assert_eq!(err.category(), ErrorCategory::Transient);
+        assert!(err.is_retryable());
+
+        let err = MyError::ArrayIsEmpty {
+            name: "rates".to_string(),
+        };
+        assert_eq!(err.category(), ErrorCategory::Validation);
+        assert!(!err.is_retryable());
+
+        let err: MyError =
+            std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused").into();
+        assert_eq!(err.category(), ErrorCategory::Transient);
+
+        let err: MyError = "abc".parse::<i32>().unwrap_err().into();
+        assert_eq!(err.category(), ErrorCategory::Validation);
+
+        let err: MyError = bincode::deserialize::<String>(&[1]).unwrap_err().into();
+        assert_eq!(err.category(), ErrorCategory::Internal);
+    }
+
+    #[test]
+    fn test_for_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MyError>();
+    }
 }
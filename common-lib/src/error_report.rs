@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use log::info;
+use serde::Deserialize;
+
+use crate::{error::MyResult, logger};
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // エラーの送信先（SentryのDSN）、空の場合は送信しない
+    #[serde(default)]
+    pub sentry_dsn: String,
+    #[serde(default)]
+    pub sentry_environment: String,
+}
+
+// 破棄時に未送信のエラーを送信する
+pub struct ErrorReportGuard {
+    _guard: Option<sentry::ClientInitGuard>,
+}
+
+// エラーの送信を開始する
+// DSNが設定されていない場合は送信しない
+pub fn init(service: &str) -> MyResult<ErrorReportGuard> {
+    let config = crate::config::load::<Config>()?;
+    if config.sentry_dsn.is_empty() {
+        return Ok(ErrorReportGuard { _guard: None });
+    }
+
+    let dsn: sentry::types::Dsn = config.sentry_dsn.parse()?;
+    let environment = if config.sentry_environment.is_empty() {
+        None
+    } else {
+        Some(config.sentry_environment.into())
+    };
+    let guard = sentry::init(sentry::ClientOptions {
+        dsn: Some(dsn),
+        environment,
+        ..Default::default()
+    });
+    set_tag("service", service);
+
+    info!("error report started, service: {}", service);
+    Ok(ErrorReportGuard {
+        _guard: Some(guard),
+    })
+}
+
+// 以降に送信するすべてのエラーにタグを付与する
+pub fn set_tag(key: &str, value: &str) {
+    sentry::configure_scope(|scope| scope.set_tag(key, value));
+}
+
+// エラーを送信する（送信が無効な場合は何もしない）
+// 処理中のリクエストがあればスパンIDもタグに付与する
+pub fn capture(err: &(dyn Error + 'static), tags: &[(&str, &str)]) {
+    sentry::with_scope(
+        |scope| {
+            if let Some(span_id) = logger::current_span_id() {
+                scope.set_tag("span_id", span_id);
+            }
+            for (key, value) in tags {
+                scope.set_tag(key, value);
+            }
+        },
+        || sentry::capture_error(err),
+    );
+}
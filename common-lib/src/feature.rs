@@ -0,0 +1,112 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{error::MyResult, settings::SharedSettings};
+
+// 環境ごとに有効/無効を切り替える機能
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Feature {
+    // 複数モデルの予想結果をまとめて予想する
+    EnsembleForecast,
+    // 予想精度の劣化（ドリフト）を検知する
+    DriftDetection,
+    // 変化率ではなく上昇/下降の分類で予想する
+    ClassificationMode,
+}
+
+pub const ALL_FEATURES: [Feature; 3] = [
+    Feature::EnsembleForecast,
+    Feature::DriftDetection,
+    Feature::ClassificationMode,
+];
+
+impl Feature {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::EnsembleForecast => "ensemble_forecast",
+            Feature::DriftDetection => "drift_detection",
+            Feature::ClassificationMode => "classification_mode",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Feature> {
+        ALL_FEATURES.iter().copied().find(|f| f.name() == name)
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// 機能フラグの初期値（runtime_settings の feature.<機能名> が設定されている場合はそちらを優先する）
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct Config {
+    #[serde(default)]
+    pub feature_ensemble_forecast: bool,
+    #[serde(default)]
+    pub feature_drift_detection: bool,
+    #[serde(default)]
+    pub feature_classification_mode: bool,
+}
+
+impl Config {
+    fn is_enabled(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::EnsembleForecast => self.feature_ensemble_forecast,
+            Feature::DriftDetection => self.feature_drift_detection,
+            Feature::ClassificationMode => self.feature_classification_mode,
+        }
+    }
+}
+
+// 機能フラグ
+// 実行時設定を読み込み直していない場合は環境変数の値のみで判定する
+#[derive(Debug, Clone, Default)]
+pub struct FeatureFlags {
+    defaults: Config,
+    settings: SharedSettings,
+}
+
+impl FeatureFlags {
+    pub fn new(defaults: Config, settings: SharedSettings) -> Self {
+        FeatureFlags { defaults, settings }
+    }
+
+    pub fn from_env(settings: SharedSettings) -> MyResult<Self> {
+        let defaults = crate::config::load::<Config>()?;
+        Ok(FeatureFlags::new(defaults, settings))
+    }
+
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        match self.settings.get().features.get(&feature) {
+            Some(enabled) => *enabled,
+            None => self.defaults.is_enabled(feature),
+        }
+    }
+}
+
+impl fmt::Display for FeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags: Vec<String> = ALL_FEATURES
+            .iter()
+            .map(|feature| format!("{}={}", feature, self.is_enabled(*feature)))
+            .collect();
+        write!(f, "{}", flags.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_from_name() {
+        for feature in ALL_FEATURES {
+            assert_eq!(Feature::from_name(feature.name()), Some(feature));
+        }
+        assert_eq!(Feature::from_name("unknown"), None);
+    }
+}
@@ -0,0 +1,274 @@
+// 予想用レートをモデルで予想し、予想結果を登録する処理
+// forecast-batch の定期実行と、予想用レートの登録直後の予想（forecast-server, rate-gateway）で共通して使う
+use log::{info, warn};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        explanation::ForecastExplanation,
+        model::{
+            forecast_type_from_offset_minutes, ForecastError, ForecastModel, ForecastResult,
+            RateForForecast,
+        },
+        service::{convert_to_feature, forecast_path},
+    },
+    error::MyResult,
+    metrics,
+    mysql::client::{Client, DefaultClient, Transaction},
+    settings::RuntimeSettings,
+};
+
+// 予想用レートの登録直後に予想するかの設定
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    #[serde(default)]
+    pub forecast_on_ingest_enabled: bool,
+    #[serde(default)]
+    pub forecast_offset_minutes: usize,
+    // チャレンジャーのモデルNo（0の場合はA/Bテストを行わない）
+    #[serde(default)]
+    pub challenger_model_no: i32,
+    // 予想値の推移を何ステップ分予想するか（1以下の場合は予想対象の時間の1点のみ）
+    #[serde(default)]
+    pub forecast_path_steps: usize,
+    // 予想結果の根拠（特徴量ごとの寄与）も登録するか
+    #[serde(default)]
+    pub forecast_explanation_enabled: bool,
+}
+
+// 登録直後に予想しない場合は None
+pub fn on_ingest_from_env() -> MyResult<Option<Forecaster>> {
+    let config = crate::config::load::<Config>()?;
+    if !config.forecast_on_ingest_enabled {
+        return Ok(None);
+    }
+    Ok(Some(
+        Forecaster::new(config.forecast_offset_minutes, config.challenger_model_no)?
+            .with_path_steps(config.forecast_path_steps)
+            .with_explanation(config.forecast_explanation_enabled),
+    ))
+}
+
+// 予想した件数
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForecastOutcome {
+    pub rate_count: usize,
+    pub result_count: usize,
+    pub error_count: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Forecaster {
+    offset_minutes: usize,
+    forecast_type: i32,
+    // チャレンジャーのモデルNo（0の場合はA/Bテストを行わない）
+    challenger_model_no: i32,
+    // 予想値の推移を予想するステップ数（1ステップは予想対象の時間）
+    path_steps: usize,
+    // 予想結果の根拠も登録するか
+    explanation_enabled: bool,
+}
+
+impl Forecaster {
+    pub fn new(offset_minutes: usize, challenger_model_no: i32) -> MyResult<Self> {
+        Ok(Forecaster {
+            offset_minutes,
+            forecast_type: forecast_type_from_offset_minutes(offset_minutes)?,
+            challenger_model_no,
+            path_steps: 1,
+            explanation_enabled: false,
+        })
+    }
+
+    // 2以上の場合、予想値を入力に加えて再帰的に予想し、予想値の推移も登録する
+    pub fn with_path_steps(mut self, steps: usize) -> Self {
+        self.path_steps = steps.max(1);
+        self
+    }
+
+    // 有効にした場合、特徴量ごとの予想値への寄与も登録する
+    pub fn with_explanation(mut self, enabled: bool) -> Self {
+        self.explanation_enabled = enabled;
+        self
+    }
+
+    pub fn forecast_type(&self) -> i32 {
+        self.forecast_type
+    }
+
+    // 未予想の予想用レートをまとめて予想する
+    // 登録から min_age_seconds 秒経っていないレートは、登録直後の予想に任せて対象外とする
+    pub fn forecast_unforecasted(
+        &self,
+        mysql_cli: &DefaultClient,
+        tx: &mut Transaction,
+        settings: &RuntimeSettings,
+        pair: &str,
+        min_age_seconds: i64,
+    ) -> MyResult<ForecastOutcome> {
+        let models = self.select_models(mysql_cli, tx, settings, pair)?;
+        let rates = mysql_cli.select_rates_for_forecast_unforecasted(tx, pair, min_age_seconds)?;
+        info!(
+            "model count: {}, rates count: {}",
+            models.len(),
+            rates.len()
+        );
+        self.forecast_rates(mysql_cli, tx, &models, &rates)
+    }
+
+    // 登録直後の予想用レートを予想する
+    // 予想済みの場合や、他で予想中の場合は何もしない
+    pub fn forecast_rate(
+        &self,
+        mysql_cli: &DefaultClient,
+        settings: &RuntimeSettings,
+        rate_id: &str,
+    ) -> MyResult<ForecastOutcome> {
+        mysql_cli.with_transaction(|tx| {
+            let rate =
+                match mysql_cli.select_rate_for_forecast_unforecasted_for_update(tx, rate_id)? {
+                    Some(rate) => rate,
+                    None => return Ok(ForecastOutcome::default()),
+                };
+            let models = self.select_models(mysql_cli, tx, settings, &rate.pair)?;
+            self.forecast_rates(mysql_cli, tx, &models, &[rate])
+        })
+    }
+
+    // MSEが上限を超えるモデルは予想に使わない
+    // ただしチャレンジャーは予測用モデルとの比較に実績誤差が必要なため、常に予想に使う
+    fn select_models(
+        &self,
+        mysql_cli: &DefaultClient,
+        tx: &mut Transaction,
+        settings: &RuntimeSettings,
+        pair: &str,
+    ) -> MyResult<Vec<ForecastModel>> {
+        Ok(mysql_cli
+            .select_forecast_models(tx, pair)?
+            .into_iter()
+            .filter(|m| {
+                let is_challenger = self.challenger_model_no > 0
+                    && m.get_no().ok() == Some(self.challenger_model_no);
+                is_challenger || settings.is_model_allowed(m.get_performance_mse())
+            })
+            .collect())
+    }
+
+    fn forecast_rates(
+        &self,
+        mysql_cli: &DefaultClient,
+        tx: &mut Transaction,
+        models: &[ForecastModel],
+        rates: &[RateForForecast],
+    ) -> MyResult<ForecastOutcome> {
+        let mut results: Vec<ForecastResult> = vec![];
+        let mut errors: Vec<ForecastError> = vec![];
+        let mut explanations: Vec<ForecastExplanation> = vec![];
+        for rate in rates {
+            let rate_size = rate.histories.len();
+            for model in models {
+                let model_no = model.get_no()?;
+                if let Some(e) = mysql_cli.select_forecast_errors_by_rate_id_and_model_no(
+                    tx,
+                    &rate.id,
+                    model_no,
+                    self.forecast_type,
+                )? {
+                    warn!(
+                        "forecast skipped, error exists. id:{}, rate_id:{}, model_no:{}",
+                        e.id, &rate.id, model_no
+                    );
+                    continue;
+                }
+
+                let input_data_size = model.get_input_data_size()?;
+                if input_data_size != rate_size {
+                    let record = ForecastError::new(
+                        rate.id.clone(),
+                        model.get_no()?,
+                        self.forecast_type,
+                        "input data size is not supported".to_string(),
+                        format!(
+                            "size(model): {}, size(input data): {}",
+                            input_data_size, rate_size
+                        ),
+                    )?;
+                    warn!(
+                        "forecast skipped, {}, trace_id: {}",
+                        record,
+                        rate.trace_id.as_deref().unwrap_or("-")
+                    );
+                    errors.push(record);
+
+                    continue;
+                }
+
+                let features = convert_to_feature(&rate.histories, &model.get_feature_params()?)?;
+
+                let mut result = ForecastResult::new(
+                    rate.id.to_string(),
+                    model.get_no()?,
+                    self.forecast_type,
+                    model.predict(&features)?,
+                    format!("after{}min", self.offset_minutes),
+                )?;
+                result.quantiles = model.predict_quantiles(&features)?;
+                if self.path_steps > 1 {
+                    result.path = Some(forecast_path(
+                        model,
+                        &rate.histories,
+                        self.path_steps,
+                        self.offset_minutes,
+                    )?);
+                }
+                info!(
+                    "forecast succeeded. pair: {}, model_no: {}, rate_id: {}, result: {}, trace_id: {}",
+                    model.get_pair()?,
+                    result.model_no,
+                    result.rate_id,
+                    result.result,
+                    rate.trace_id.as_deref().unwrap_or("-")
+                );
+
+                // 根拠を求められなくても予想結果は登録する
+                if self.explanation_enabled {
+                    match model.explain(&features) {
+                        Ok(explanation) => explanations.push(ForecastExplanation {
+                            rate_id: rate.id.clone(),
+                            model_no: result.model_no,
+                            forecast_type: self.forecast_type,
+                            explanation,
+                        }),
+                        Err(err) => warn!(
+                            "failed to explain forecast, rate_id: {}, model_no: {}, error: {}",
+                            rate.id, result.model_no, err
+                        ),
+                    }
+                }
+
+                results.push(result);
+            }
+        }
+
+        mysql_cli.insert_forecast_results(tx, &results)?;
+        mysql_cli.insert_forecast_errors(tx, &errors)?;
+        mysql_cli.insert_forecast_explanations(tx, &explanations)?;
+
+        Ok(ForecastOutcome {
+            rate_count: rates.len(),
+            result_count: results.len(),
+            error_count: errors.len(),
+        })
+    }
+}
+
+// 予想した件数を通貨ペアごとに数える
+pub fn observe(pair: &str, outcome: &ForecastOutcome) {
+    metrics::FORECAST_RESULTS_TOTAL
+        .with_label_values(&[pair])
+        .inc_by(outcome.result_count as u64);
+    metrics::FORECAST_ERRORS_TOTAL
+        .with_label_values(&[pair])
+        .inc_by(outcome.error_count as u64);
+}
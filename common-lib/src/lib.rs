@@ -1,4 +1,18 @@
 pub mod batch;
+pub mod clock;
+pub mod config;
+pub mod dead_letter;
 pub mod domain;
 pub mod error;
+pub mod error_report;
+pub mod feature;
+pub mod forecast;
+pub mod logger;
+pub mod metrics;
 pub mod mysql;
+pub mod notify;
+pub mod server;
+pub mod settings;
+pub mod telemetry;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
@@ -0,0 +1,112 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    future::Future,
+    io::Write,
+    pin::Pin,
+    sync::RwLock,
+    task::{Context, Poll},
+};
+
+use chrono::{SecondsFormat, Utc};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+// ログの出力形式
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    // env_logger の標準の形式
+    #[default]
+    Text,
+    // 1行1件のJSON形式
+    Json,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    #[serde(default)]
+    pub log_format: LogFormat,
+}
+
+// すべてのログに付与する項目（通貨ペアやジョブ名など）
+static FIELDS: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+thread_local! {
+    // 処理中のリクエストのスパンID
+    static SPAN_ID: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// ロガーを初期化する
+// ログレベルは RUST_LOG、出力形式は LOG_FORMAT（text/json）で指定する
+pub fn init(service: &str) {
+    let format = match crate::config::load::<Config>() {
+        Ok(config) => config.log_format,
+        Err(err) => {
+            eprintln!("failed to load log config, use text format, error: {}", err);
+            LogFormat::Text
+        }
+    };
+    set_field("service", service);
+
+    let mut builder = env_logger::Builder::from_default_env();
+    if format == LogFormat::Json {
+        builder.format(|buf, record| {
+            let mut line = Map::new();
+            line.insert(
+                "timestamp".to_string(),
+                Value::from(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)),
+            );
+            line.insert("level".to_string(), Value::from(record.level().as_str()));
+            line.insert("target".to_string(), Value::from(record.target()));
+            for (key, value) in FIELDS.read().unwrap().iter() {
+                line.insert(key.to_string(), Value::from(value.as_str()));
+            }
+            if let Some(span_id) = current_span_id() {
+                line.insert("span_id".to_string(), Value::from(span_id));
+            }
+            line.insert(
+                "message".to_string(),
+                Value::from(record.args().to_string()),
+            );
+            writeln!(buf, "{}", Value::Object(line))
+        });
+    }
+    builder.init();
+}
+
+// 以降のすべてのログに項目を付与する（JSON形式の場合のみ）
+pub fn set_field(key: &str, value: &str) {
+    FIELDS
+        .write()
+        .unwrap()
+        .insert(key.to_string(), value.to_string());
+}
+
+pub fn current_span_id() -> Option<String> {
+    SPAN_ID.with(|span_id| span_id.borrow().clone())
+}
+
+// Future の実行中に出力したログにスパンIDを付与する
+pub fn with_span_id<F: Future>(span_id: String, inner: F) -> WithSpanId<F> {
+    WithSpanId {
+        span_id,
+        inner: Box::pin(inner),
+    }
+}
+
+pub struct WithSpanId<F> {
+    span_id: String,
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithSpanId<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let previous = SPAN_ID.with(|span_id| span_id.replace(Some(self.span_id.clone())));
+        let result = self.inner.as_mut().poll(cx);
+        SPAN_ID.with(|span_id| *span_id.borrow_mut() = previous);
+        result
+    }
+}
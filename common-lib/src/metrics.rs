@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+
+use hyper::{header::CONTENT_TYPE, Body, Response, StatusCode};
+use lazy_static::lazy_static;
+use log::error;
+use prometheus::{
+    core::Collector, Encoder, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+
+use crate::{
+    domain::{model::RateForTraining, quality::DataQualityReport},
+    error::MyResult,
+};
+
+// メトリクスのラベル名（各サーバー・バッチで同じ名前を使う）
+pub const LABEL_JOB: &str = "job";
+pub const LABEL_METHOD: &str = "method";
+pub const LABEL_STATUS: &str = "status";
+pub const LABEL_PAIR: &str = "pair";
+
+// 処理時間のヒストグラムの区切り（秒）
+const HTTP_DURATION_BUCKETS: [f64; 10] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+const JOB_DURATION_BUCKETS: [f64; 10] =
+    [0.1, 0.5, 1.0, 5.0, 10.0, 30.0, 60.0, 300.0, 900.0, 3600.0];
+
+lazy_static! {
+    // 各サーバー・バッチで共通のレジストリ
+    static ref REGISTRY: Registry = Registry::new();
+
+    // HTTPサーバー
+    pub static ref HTTP_REQUESTS_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of HTTP requests."),
+            &[LABEL_METHOD, LABEL_STATUS],
+        )
+        .unwrap()
+    );
+    pub static ref HTTP_REQUEST_DURATION_SECONDS: HistogramVec = register(
+        HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency.")
+                .buckets(HTTP_DURATION_BUCKETS.to_vec()),
+            &[LABEL_METHOD],
+        )
+        .unwrap()
+    );
+
+    // バッチのジョブ
+    pub static ref BATCH_JOB_RUNNING: IntGaugeVec = register(
+        IntGaugeVec::new(
+            Opts::new("batch_job_running", "Whether the job is running (1) or not (0)."),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_RUNS_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("batch_job_runs_total", "Number of job runs."),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_FAILURES_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("batch_job_failures_total", "Number of failed job runs."),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_CONSECUTIVE_FAILURES: IntGaugeVec = register(
+        IntGaugeVec::new(
+            Opts::new(
+                "batch_job_consecutive_failures",
+                "Number of consecutive failed job runs.",
+            ),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_DURATION_SECONDS: HistogramVec = register(
+        HistogramVec::new(
+            HistogramOpts::new("batch_job_duration_seconds", "Job run duration.")
+                .buckets(JOB_DURATION_BUCKETS.to_vec()),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_LAST_DURATION_SECONDS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "batch_job_last_duration_seconds",
+                "Duration of the last job run.",
+            ),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_LAST_STARTED_TIMESTAMP_SECONDS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "batch_job_last_started_timestamp_seconds",
+                "Unix time the last job run started.",
+            ),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_LAST_FINISHED_TIMESTAMP_SECONDS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "batch_job_last_finished_timestamp_seconds",
+                "Unix time the last job run finished.",
+            ),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+    pub static ref BATCH_JOB_LAST_SUCCESS_TIMESTAMP_SECONDS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "batch_job_last_success_timestamp_seconds",
+                "Unix time the last job run succeeded.",
+            ),
+            &[LABEL_JOB],
+        )
+        .unwrap()
+    );
+
+    // レート・予想
+    pub static ref RATES_RECEIVED_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new(
+                "rates_received_total",
+                "Number of rates written to the database, including duplicates.",
+            ),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref FORECAST_RESULTS_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("forecast_results_total", "Number of forecast results."),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref FORECAST_ERRORS_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("forecast_errors_total", "Number of failed forecasts."),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref MODELS_TRAINED_TOTAL: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new("models_trained_total", "Number of trained models."),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref TRAINING_DURATION_SECONDS: HistogramVec = register(
+        HistogramVec::new(
+            HistogramOpts::new("training_duration_seconds", "Training duration per pair.")
+                .buckets(JOB_DURATION_BUCKETS.to_vec()),
+            &[LABEL_PAIR, LABEL_STATUS],
+        )
+        .unwrap()
+    );
+
+    // DB
+    pub static ref DB_TRANSACTION_DURATION_SECONDS: HistogramVec = register(
+        HistogramVec::new(
+            HistogramOpts::new(
+                "db_transaction_duration_seconds",
+                "Database transaction latency, including commit.",
+            )
+            .buckets(HTTP_DURATION_BUCKETS.to_vec()),
+            &[LABEL_STATUS],
+        )
+        .unwrap()
+    );
+
+    // データ品質
+    pub static ref DATA_QUALITY_COVERAGE_RATIO: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "data_quality_coverage_ratio",
+                "Ratio of trading minutes that have a rate.",
+            ),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref DATA_QUALITY_GAPS: IntGaugeVec = register(
+        IntGaugeVec::new(
+            Opts::new("data_quality_gaps", "Number of gaps in rates."),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref DATA_QUALITY_DUPLICATES: IntGaugeVec = register(
+        IntGaugeVec::new(
+            Opts::new(
+                "data_quality_duplicates",
+                "Number of rates recorded in an already recorded minute.",
+            ),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+    pub static ref DATA_QUALITY_LAG_SECONDS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "data_quality_lag_seconds",
+                "Seconds since the latest rate was recorded.",
+            ),
+            &[LABEL_PAIR],
+        )
+        .unwrap()
+    );
+}
+
+// 名前の重複など、登録に失敗するのは定義の誤りのため起動時に落とす
+fn register<T: Collector + Clone + 'static>(collector: T) -> T {
+    REGISTRY
+        .register(Box::new(collector.clone()))
+        .expect("failed to register metrics");
+    collector
+}
+
+// すべてのメトリクスを登録する（一度も記録していないメトリクスも出力されるようにする）
+pub fn init() {
+    lazy_static::initialize(&HTTP_REQUESTS_TOTAL);
+    lazy_static::initialize(&HTTP_REQUEST_DURATION_SECONDS);
+    lazy_static::initialize(&BATCH_JOB_RUNNING);
+    lazy_static::initialize(&BATCH_JOB_RUNS_TOTAL);
+    lazy_static::initialize(&BATCH_JOB_FAILURES_TOTAL);
+    lazy_static::initialize(&BATCH_JOB_CONSECUTIVE_FAILURES);
+    lazy_static::initialize(&BATCH_JOB_DURATION_SECONDS);
+    lazy_static::initialize(&BATCH_JOB_LAST_DURATION_SECONDS);
+    lazy_static::initialize(&BATCH_JOB_LAST_STARTED_TIMESTAMP_SECONDS);
+    lazy_static::initialize(&BATCH_JOB_LAST_FINISHED_TIMESTAMP_SECONDS);
+    lazy_static::initialize(&BATCH_JOB_LAST_SUCCESS_TIMESTAMP_SECONDS);
+    lazy_static::initialize(&RATES_RECEIVED_TOTAL);
+    lazy_static::initialize(&FORECAST_RESULTS_TOTAL);
+    lazy_static::initialize(&FORECAST_ERRORS_TOTAL);
+    lazy_static::initialize(&MODELS_TRAINED_TOTAL);
+    lazy_static::initialize(&TRAINING_DURATION_SECONDS);
+    lazy_static::initialize(&DB_TRANSACTION_DURATION_SECONDS);
+    lazy_static::initialize(&DATA_QUALITY_COVERAGE_RATIO);
+    lazy_static::initialize(&DATA_QUALITY_GAPS);
+    lazy_static::initialize(&DATA_QUALITY_DUPLICATES);
+    lazy_static::initialize(&DATA_QUALITY_LAG_SECONDS);
+}
+
+// 登録したレート数を通貨ペアごとに数える
+pub fn observe_rates(rates: &[RateForTraining]) {
+    let mut counts: BTreeMap<&str, u64> = BTreeMap::new();
+    for rate in rates {
+        *counts.entry(rate.pair.as_str()).or_default() += 1;
+    }
+    for (pair, count) in counts {
+        RATES_RECEIVED_TOTAL
+            .with_label_values(&[pair])
+            .inc_by(count);
+    }
+}
+
+// 処理結果のラベル
+pub fn status_label<T, E>(result: &Result<T, E>) -> &'static str {
+    if result.is_ok() {
+        "ok"
+    } else {
+        "error"
+    }
+}
+
+// 通貨ペアごとのデータ品質を記録する
+pub fn observe_data_quality(report: &DataQualityReport) {
+    let labels = [report.pair.as_str()];
+    DATA_QUALITY_COVERAGE_RATIO
+        .with_label_values(&labels)
+        .set(report.coverage);
+    DATA_QUALITY_GAPS
+        .with_label_values(&labels)
+        .set(report.gap_count);
+    DATA_QUALITY_DUPLICATES
+        .with_label_values(&labels)
+        .set(report.duplicate_count);
+    if let Some(lag) = report.lag_seconds {
+        DATA_QUALITY_LAG_SECONDS
+            .with_label_values(&labels)
+            .set(lag as f64);
+    }
+}
+
+// Prometheus のテキスト形式
+pub fn encode() -> MyResult<String> {
+    let mut buffer = vec![];
+    TextEncoder::new().encode(&REGISTRY.gather(), &mut buffer)?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+// /metrics のレスポンス
+pub fn response() -> Response<Body> {
+    match encode() {
+        Ok(body) => Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, TextEncoder::new().format_type())
+            .body(Body::from(body))
+            .expect("Unable to create metrics response"),
+        Err(err) => {
+            error!("failed to encode metrics, error: {}", err);
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::empty())
+                .expect("Unable to create Internal Server Error response")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::RateSeries;
+
+    #[test]
+    fn test_for_encode() {
+        let mut rates = RateSeries::new("METRICS1").flat(3, 100.0);
+        rates.extend(RateSeries::new("METRICS2").flat(2, 100.0));
+        observe_rates(&rates);
+        assert_eq!(
+            RATES_RECEIVED_TOTAL.with_label_values(&["METRICS1"]).get(),
+            3
+        );
+
+        let body = encode().unwrap();
+        assert!(body.contains("rates_received_total{pair=\"METRICS1\"} 3"));
+        assert!(body.contains("rates_received_total{pair=\"METRICS2\"} 2"));
+    }
+}
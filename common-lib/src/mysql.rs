@@ -1,3 +1,4 @@
 pub mod client;
 pub mod model;
+pub mod transfer;
 pub mod util;
@@ -1,15 +1,34 @@
+use std::time::{Duration, Instant};
+
 use chrono::NaiveDateTime;
+use log::warn;
+pub use mysql::Transaction;
 use mysql::{
     from_row, from_value, params, prelude::Queryable, Deserialized, OptsBuilder, Pool, Serialized,
-    Transaction, TxOpts,
+    TxOpts, Value,
 };
 
 use crate::{
-    domain::model::{
-        ForecastError, ForecastModel, ForecastResult, RateForForecast, RateForTraining,
-        TrainingDataset,
+    domain::{
+        accuracy::{AccuracyBucket, ForecastAccuracyStat},
+        anomaly::{AnomalyKind, RateAnomaly},
+        backtest::BacktestResult,
+        challenger::PairedForecastError,
+        explanation::{Explanation, FeatureContribution, ForecastExplanation},
+        model::{
+            ForecastError, ForecastEvaluation, ForecastModel, ForecastResult, JobRequest, JobRun,
+            ModelPerformance, RateForForecast, RateForTraining, RateGap, TrainingDataset,
+            TrainingRun, JOB_REQUEST_STATUS_FAILED, JOB_REQUEST_STATUS_PENDING,
+            JOB_REQUEST_STATUS_READY, JOB_REQUEST_STATUS_RUNNING, JOB_RUN_STATUS_RUNNING,
+            TRAINING_RUN_STATUS_REQUESTED, TRAINING_RUN_STATUS_RUNNING,
+        },
+        pair::CurrencyPair,
+        quality::DataQualityReport,
+        quantile::ForecastQuantiles,
+        trade::{PaperTrade, PaperTradeCandidate, PaperTradeSummary},
     },
     error::MyResult,
+    metrics,
     mysql::model::{FeatureParamsValue, ForecastModelRecord},
 };
 
@@ -18,23 +37,48 @@ static TABLE_NAME_FORECAST_MODEL: &str = "forecast_models";
 static TABLE_NAME_RATE_FOR_FORECAST: &str = "rates_for_forecast";
 static TABLE_NAME_FORECAST_RESULT: &str = "forecast_results";
 static TABLE_NAME_FORECAST_ERRORS: &str = "forecast_errors";
+static TABLE_NAME_FORECAST_EXPLANATIONS: &str = "forecast_explanations";
 static TABLE_NAME_TRAINING_DATASETS: &str = "training_datasets";
+static TABLE_NAME_TRAINING_RUNS: &str = "training_runs";
+static TABLE_NAME_FORECAST_EVALUATIONS: &str = "forecast_evaluations";
+static TABLE_NAME_FORECAST_ACCURACY_STATS: &str = "forecast_accuracy_stats";
+static TABLE_NAME_RATE_GAPS: &str = "rate_gaps";
+static TABLE_NAME_RATE_ANOMALIES: &str = "rate_anomalies";
+static TABLE_NAME_DATA_QUALITY_REPORTS: &str = "data_quality_reports";
+static TABLE_NAME_BACKTEST_RESULTS: &str = "backtest_results";
+static TABLE_NAME_JOB_LEASES: &str = "job_leases";
+static TABLE_NAME_JOB_RUNS: &str = "job_runs";
+static TABLE_NAME_JOB_REQUESTS: &str = "job_requests";
+static TABLE_NAME_RUNTIME_SETTINGS: &str = "runtime_settings";
+static TABLE_NAME_CURRENCY_PAIRS: &str = "currency_pairs";
+static TABLE_NAME_PAPER_TRADES: &str = "paper_trades";
 
 pub trait Client {
     fn with_transaction<F, T>(&self, f: F) -> MyResult<T>
     where
         F: FnMut(&mut Transaction) -> MyResult<T>;
 
-    fn insert_rates_for_training(
+    // DBに接続できるか確認する
+    fn ping(&self) -> MyResult<()>;
+
+    // 同じ通貨ペア・日時のレートが登録済みの場合は無視し、新規に登録した件数を返す
+    fn bulk_insert_rates_for_training(
         &self,
         tx: &mut Transaction,
-        rates: &Vec<RateForTraining>,
-    ) -> MyResult<()>;
+        rates: &[RateForTraining],
+    ) -> MyResult<u64>;
     fn delete_old_rates_for_training(
         &self,
         tx: &mut Transaction,
         border: &NaiveDateTime,
     ) -> MyResult<()>;
+    fn delete_rates_for_training_range(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: &NaiveDateTime,
+        end: &NaiveDateTime,
+    ) -> MyResult<u64>;
     fn select_rates_for_training(
         &self,
         tx: &mut Transaction,
@@ -42,6 +86,137 @@ pub trait Client {
         begin: Option<NaiveDateTime>,
         end: Option<NaiveDateTime>,
     ) -> MyResult<Vec<RateForTraining>>;
+    fn select_rates_for_training_paged(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<RateForTraining>>;
+    fn select_latest_rates_for_training(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        count: u32,
+    ) -> MyResult<Vec<RateForTraining>>;
+    fn replace_rate_gaps(&self, tx: &mut Transaction, pair: &str, gaps: &[RateGap])
+        -> MyResult<()>;
+    fn select_rate_gaps(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<RateGap>>;
+    fn replace_rate_anomalies(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        anomalies: &[RateAnomaly],
+    ) -> MyResult<()>;
+    fn select_rate_anomalies(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<RateAnomaly>>;
+    fn insert_data_quality_report(
+        &self,
+        tx: &mut Transaction,
+        report: &DataQualityReport,
+    ) -> MyResult<()>;
+    fn select_latest_data_quality_report(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Option<DataQualityReport>>;
+    fn delete_old_data_quality_reports(
+        &self,
+        tx: &mut Transaction,
+        border: &NaiveDateTime,
+    ) -> MyResult<()>;
+
+    fn insert_backtest_result(&self, tx: &mut Transaction, result: &BacktestResult)
+        -> MyResult<()>;
+
+    fn acquire_job_lease(
+        &self,
+        tx: &mut Transaction,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+        lease_seconds: i64,
+    ) -> MyResult<bool>;
+    fn release_job_lease(
+        &self,
+        tx: &mut Transaction,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+    ) -> MyResult<()>;
+
+    fn insert_job_run(&self, tx: &mut Transaction, job_name: &str) -> MyResult<String>;
+    fn update_job_run_finished(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+        status: u8,
+        message: Option<&str>,
+    ) -> MyResult<()>;
+    fn select_job_runs(
+        &self,
+        tx: &mut Transaction,
+        job_name: Option<&str>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<JobRun>>;
+
+    fn insert_job_request(
+        &self,
+        tx: &mut Transaction,
+        job_name: &str,
+        max_attempts: i32,
+    ) -> MyResult<String>;
+    fn select_job_requests(
+        &self,
+        tx: &mut Transaction,
+        job_name: Option<&str>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<JobRequest>>;
+    fn select_active_job_requests(&self, tx: &mut Transaction) -> MyResult<Vec<JobRequest>>;
+    fn update_job_request_ready(&self, tx: &mut Transaction, id: &str) -> MyResult<bool>;
+    fn claim_job_request(
+        &self,
+        tx: &mut Transaction,
+        job_name: &str,
+        owner: &str,
+    ) -> MyResult<Option<String>>;
+    fn update_job_request_finished(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+        status: u8,
+        message: Option<&str>,
+    ) -> MyResult<()>;
+    fn fail_timed_out_job_requests(
+        &self,
+        tx: &mut Transaction,
+        timeout_seconds: i64,
+    ) -> MyResult<u64>;
+    fn requeue_failed_job_requests(
+        &self,
+        tx: &mut Transaction,
+        backoff_seconds: i64,
+    ) -> MyResult<u64>;
+
+    fn select_runtime_settings(&self, tx: &mut Transaction) -> MyResult<Vec<(String, String)>>;
+    fn select_currency_pairs(&self, tx: &mut Transaction) -> MyResult<Vec<CurrencyPair>>;
 
     fn upsert_forecast_model(&self, tx: &mut Transaction, m: &ForecastModel) -> MyResult<()>;
     fn copy_forecast_model(
@@ -57,22 +232,51 @@ pub trait Client {
         pair: &str,
         no: i32,
     ) -> MyResult<Option<ForecastModel>>;
+    fn select_forecast_model_record(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        no: i32,
+    ) -> MyResult<Option<ForecastModelRecord>>;
     fn select_forecast_models(
         &self,
         tx: &mut Transaction,
         pair: &str,
     ) -> MyResult<Vec<ForecastModel>>;
+    fn select_forecast_model_records(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Vec<ForecastModelRecord>>;
+    fn delete_forecast_model(&self, tx: &mut Transaction, pair: &str, no: i32) -> MyResult<bool>;
 
     fn insert_rates_for_forecast(
         &self,
         tx: &mut Transaction,
         rate: &RateForForecast,
     ) -> MyResult<String>;
+    fn insert_rates_for_forecast_batch(
+        &self,
+        tx: &mut Transaction,
+        rates: &[RateForForecast],
+    ) -> MyResult<Vec<String>>;
+    fn select_rates_for_forecast_duplicated(
+        &self,
+        tx: &mut Transaction,
+        rate: &RateForForecast,
+        window_minutes: i64,
+    ) -> MyResult<Option<String>>;
     fn select_rates_for_forecast_unforecasted(
         &self,
         tx: &mut Transaction,
         pair: &str,
+        min_age_seconds: i64,
     ) -> MyResult<Vec<RateForForecast>>;
+    fn select_rate_for_forecast_unforecasted_for_update(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+    ) -> MyResult<Option<RateForForecast>>;
     fn select_rates_for_forecast_by_id(
         &self,
         tx: &mut Transaction,
@@ -90,7 +294,17 @@ pub trait Client {
         tx: &mut Transaction,
         rate_id: &str,
         model_no: i32,
+        forecast_type: i32,
     ) -> MyResult<Option<ForecastResult>>;
+    fn select_forecast_results_paged(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastResult>>;
     fn delete_forecast_results_expired(&self, tx: &mut Transaction) -> MyResult<()>;
 
     fn insert_forecast_errors(
@@ -103,20 +317,156 @@ pub trait Client {
         tx: &mut Transaction,
         rate_id: &str,
         model_no: i32,
+        forecast_type: i32,
     ) -> MyResult<Option<ForecastError>>;
+    fn select_forecast_errors(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastError>>;
     fn delete_forecast_errors_expired(&self, tx: &mut Transaction) -> MyResult<()>;
 
+    fn insert_forecast_explanations(
+        &self,
+        tx: &mut Transaction,
+        records: &Vec<ForecastExplanation>,
+    ) -> MyResult<()>;
+    fn select_forecast_explanation(
+        &self,
+        tx: &mut Transaction,
+        rate_id: &str,
+        model_no: i32,
+        forecast_type: i32,
+    ) -> MyResult<Option<ForecastExplanation>>;
+    fn delete_forecast_explanations_expired(&self, tx: &mut Transaction) -> MyResult<()>;
+
+    fn insert_forecast_evaluations(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        forecast_type: i32,
+        offset_minutes: usize,
+    ) -> MyResult<u64>;
+    fn select_model_performance(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        model_no: i32,
+        forecast_type: i32,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<ModelPerformance>>;
+    fn select_forecast_evaluations_paged(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastEvaluation>>;
+    fn upsert_forecast_accuracy_stats(
+        &self,
+        tx: &mut Transaction,
+        bucket: AccuracyBucket,
+        begin: NaiveDateTime,
+    ) -> MyResult<u64>;
+    #[allow(clippy::too_many_arguments)]
+    fn select_forecast_accuracy_stats(
+        &self,
+        tx: &mut Transaction,
+        pair: Option<&str>,
+        model_no: Option<i32>,
+        forecast_type: Option<i32>,
+        bucket: AccuracyBucket,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<ForecastAccuracyStat>>;
+    fn select_paired_forecast_errors(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        forecast_type: i32,
+        champion_no: i32,
+        challenger_no: i32,
+        begin: NaiveDateTime,
+    ) -> MyResult<Vec<PairedForecastError>>;
+
+    fn select_paper_trade_candidates(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        forecast_type: i32,
+        offset_minutes: usize,
+        limit: u32,
+    ) -> MyResult<Vec<PaperTradeCandidate>>;
+    fn insert_paper_trades(&self, tx: &mut Transaction, trades: &[PaperTrade]) -> MyResult<()>;
+    fn select_paper_trade_summaries(
+        &self,
+        tx: &mut Transaction,
+        begin: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<PaperTradeSummary>>;
+
     fn insert_training_datasets(
         &self,
         tx: &mut Transaction,
         datasets: &Vec<TrainingDataset>,
     ) -> MyResult<()>;
     fn truncate_training_datasets(&self, tx: &mut Transaction) -> MyResult<()>;
+
+    fn insert_training_run(&self, tx: &mut Transaction, run: &TrainingRun) -> MyResult<String>;
+    fn select_training_run_by_id(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+    ) -> MyResult<Option<TrainingRun>>;
+    fn select_training_runs_requested(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Vec<TrainingRun>>;
+    fn update_training_run_started(&self, tx: &mut Transaction, id: &str) -> MyResult<()>;
+    fn update_training_run_finished(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+        status: u8,
+        result: &str,
+    ) -> MyResult<()>;
+}
+
+// コネクションプールと接続の再試行の設定
+#[derive(Clone, Debug)]
+pub struct PoolOptions {
+    pub min_size: usize,
+    pub max_size: usize,
+    // DBへの接続（TCP）の待ち時間の上限
+    pub connect_timeout: Duration,
+    // トランザクションを開始できない場合の最大試行回数（1の場合は再試行しない）
+    pub retry_attempts: u32,
+    // 再試行までの待ち時間、再試行のたびに2倍にする
+    pub retry_backoff: Duration,
+}
+
+impl Default for PoolOptions {
+    // mysql クレートの Pool::new と同じプールの大きさ
+    fn default() -> Self {
+        PoolOptions {
+            min_size: 10,
+            max_size: 100,
+            connect_timeout: Duration::from_secs(10),
+            retry_attempts: 1,
+            retry_backoff: Duration::from_millis(500),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct DefaultClient {
     pool: Pool,
+    options: PoolOptions,
 }
 
 impl DefaultClient {
@@ -126,18 +476,74 @@ impl DefaultClient {
         host: &str,
         port: u16,
         database: &str,
+        options: PoolOptions,
     ) -> MyResult<DefaultClient> {
         let opts = OptsBuilder::new()
             .user(Some(user))
             .pass(Some(password))
             .ip_or_hostname(Some(host))
             .tcp_port(port)
-            .db_name(Some(database));
+            .db_name(Some(database))
+            .tcp_connect_timeout(Some(options.connect_timeout));
 
         Ok(DefaultClient {
-            pool: Pool::new(opts)?,
+            pool: Pool::new_manual(options.min_size, options.max_size, opts)?,
+            options,
         })
     }
+
+    // DBの再起動中など、一時的に接続できない場合は待ち時間を置いて再試行する
+    // 処理の途中で失敗したトランザクションは、処理を二重に実行しないよう再試行しない
+    fn start_transaction(&self) -> MyResult<Transaction<'static>> {
+        retry_transient(&self.options, "start transaction", || {
+            Ok(self.pool.start_transaction(TxOpts::default())?)
+        })
+    }
+
+    fn run_transaction<F, T>(&self, mut f: F) -> MyResult<T>
+    where
+        F: FnMut(&mut Transaction) -> MyResult<T>,
+    {
+        match self.start_transaction() {
+            Ok(mut tx) => match f(&mut tx) {
+                Ok(v) => {
+                    if let Err(err) = tx.commit() {
+                        Err(err.into())
+                    } else {
+                        Ok(v)
+                    }
+                }
+                Err(err) => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// 一時的なエラーの場合のみ、最大試行回数まで待ち時間を2倍にしながら再試行する
+fn retry_transient<T, F>(options: &PoolOptions, name: &str, mut f: F) -> MyResult<T>
+where
+    F: FnMut() -> MyResult<T>,
+{
+    let mut backoff = options.retry_backoff;
+    let mut attempt = 1;
+    loop {
+        let err = match f() {
+            Ok(v) => return Ok(v),
+            Err(err) => err,
+        };
+        if attempt >= options.retry_attempts || !err.is_retryable() {
+            return Err(err);
+        }
+
+        warn!(
+            "failed to {}, retry after {:?}, attempt: {}/{}, error: {}",
+            name, backoff, attempt, options.retry_attempts, err
+        );
+        std::thread::sleep(backoff);
+        backoff = backoff.saturating_mul(2);
+        attempt += 1;
+    }
 }
 
 impl Client for DefaultClient {
@@ -146,9 +552,10 @@ impl Client for DefaultClient {
     // use crate::common_lib::error::MyResult;
     // use crate::common_lib::mysql::client::DefaultClient;
     // use crate::common_lib::mysql::client::Client;
+    // use crate::common_lib::mysql::client::PoolOptions;
     //
     // fn main() -> MyResult<()> {
-    //     let client = DefaultClient::new("user", "pass", "127.0.0.1", 3306, "db")?;
+    //     let client = DefaultClient::new("user", "pass", "127.0.0.1", 3306, "db", PoolOptions::default())?;
     //     client.with_transaction(
     //         |tx| -> MyResult<()> {
     //             // 任意のDB操作
@@ -157,45 +564,56 @@ impl Client for DefaultClient {
     //     )
     // }
     // ```
-    fn with_transaction<F, T>(&self, mut f: F) -> MyResult<T>
+    #[tracing::instrument(name = "db.transaction", skip_all, err)]
+    fn with_transaction<F, T>(&self, f: F) -> MyResult<T>
     where
         F: FnMut(&mut Transaction) -> MyResult<T>,
     {
-        match self.pool.get_conn()?.start_transaction(TxOpts::default()) {
-            Ok(mut tx) => match f(&mut tx) {
-                Ok(v) => {
-                    if let Err(err) = tx.commit() {
-                        Err(Box::new(err))
-                    } else {
-                        Ok(v)
-                    }
-                }
-                Err(err) => Err(err),
-            },
-            Err(err) => Err(Box::new(err)),
-        }
+        let started = Instant::now();
+        let result = self.run_transaction(f);
+        metrics::DB_TRANSACTION_DURATION_SECONDS
+            .with_label_values(&[metrics::status_label(&result)])
+            .observe(started.elapsed().as_secs_f64());
+        result
     }
 
-    fn insert_rates_for_training(
+    fn ping(&self) -> MyResult<()> {
+        self.pool.get_conn()?.query_drop("SELECT 1")?;
+        Ok(())
+    }
+
+    fn bulk_insert_rates_for_training(
         &self,
         tx: &mut Transaction,
-        rates: &Vec<RateForTraining>,
-    ) -> MyResult<()> {
-        tx.exec_batch(
+        rates: &[RateForTraining],
+    ) -> MyResult<u64> {
+        if rates.is_empty() {
+            return Ok(0);
+        }
+
+        // 1件ずつ実行すると往復回数が多くなるため、1つのINSERT文にまとめる
+        // 同じ日時のレートが登録済みの場合は無視し、新規に登録した件数を返す
+        let placeholders = vec!["(?, ?, ?)"; rates.len()].join(", ");
+        let mut values: Vec<Value> = Vec::with_capacity(rates.len() * 3);
+        for rate in rates {
+            values.push(rate.pair.clone().into());
+            values.push(
+                rate.recorded_at
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+                    .into(),
+            );
+            values.push(rate.rate.into());
+        }
+        tx.exec_drop(
             format!(
-                "INSERT INTO {} (pair, recorded_at, rate) VALUES (:pair, :recorded_at, :rate);",
-                TABLE_NAME_RATE_FOR_TRAINING
+                "INSERT IGNORE INTO {} (pair, recorded_at, rate) VALUES {};",
+                TABLE_NAME_RATE_FOR_TRAINING, placeholders
             ),
-            rates.iter().map(|rate| {
-                params! {
-                    "pair" => &rate.pair,
-                    "recorded_at" => rate.recorded_at.format("%Y-%m-%d %H:%M:%S").to_string(),
-                    "rate" => &rate.rate,
-                }
-            }),
+            values,
         )?;
 
-        Ok(())
+        Ok(tx.affected_rows())
     }
 
     fn delete_old_rates_for_training(
@@ -216,6 +634,28 @@ impl Client for DefaultClient {
         Ok(())
     }
 
+    fn delete_rates_for_training_range(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: &NaiveDateTime,
+        end: &NaiveDateTime,
+    ) -> MyResult<u64> {
+        tx.exec_drop(
+            format!(
+                "DELETE FROM {} WHERE pair = :pair AND recorded_at >= :begin AND recorded_at <= :end;",
+                TABLE_NAME_RATE_FOR_TRAINING
+            ),
+            params! {
+                "pair" => pair,
+                "begin" => begin.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "end" => end.format("%Y-%m-%d %H:%M:%S").to_string(),
+            },
+        )?;
+
+        Ok(tx.affected_rows())
+    }
+
     fn select_rates_for_training(
         &self,
         tx: &mut Transaction,
@@ -261,29 +701,996 @@ impl Client for DefaultClient {
         Ok(result?)
     }
 
-    fn upsert_forecast_model(&self, tx: &mut Transaction, m: &ForecastModel) -> MyResult<()> {
+    fn select_rates_for_training_paged(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<RateForTraining>> {
+        let mut conditions: Vec<String> = vec!["pair = :pair".to_string()];
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "recorded_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "recorded_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
         let q = format!(
             r#"
-                INSERT INTO {}
-                    (pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo)
-                VALUES
-                    (:pair, :no, :type, :data, :input_data_size, :feature_params, :feature_params_hash, :performance_mse, :performance_rmse, :memo)
-                ON DUPLICATE KEY UPDATE
-                    model_type = :type,
-                    model_data = :data,
-                    input_data_size = :input_data_size,
-                    feature_params = :feature_params,
-                    feature_params_hash = :feature_params_hash,
-                    performance_mse = :performance_mse,
-                    performance_rmse = :performance_rmse,
-                    memo = :memo;
+                SELECT pair, recorded_at, rate, created_at, updated_at
+                FROM {}
+                WHERE {}
+                ORDER BY recorded_at ASC
+                LIMIT :limit OFFSET :offset;
             "#,
-            TABLE_NAME_FORECAST_MODEL
+            TABLE_NAME_RATE_FOR_TRAINING,
+            conditions.join(" AND "),
         );
-        let p = match m {
-            ForecastModel::RandomForest {
-                pair,
-                no,
+        let p = params! {
+            "pair" => pair,
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, pair: {}, limit: {}, offset: {}",
+            q,
+            pair,
+            limit,
+            offset
+        );
+
+        let mut rates: Vec<RateForTraining> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, recorded_at, rate, created_at, updated_at) = from_row(row?);
+                rates.push(RateForTraining {
+                    pair,
+                    recorded_at,
+                    rate,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+
+        Ok(rates)
+    }
+
+    fn select_latest_rates_for_training(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        count: u32,
+    ) -> MyResult<Vec<RateForTraining>> {
+        // 新しい順に指定件数を取り出してから古い順に並べ直す
+        let q = format!(
+            r#"
+                SELECT pair, recorded_at, rate, created_at, updated_at
+                FROM (
+                    SELECT pair, recorded_at, rate, created_at, updated_at
+                    FROM {}
+                    WHERE pair = :pair
+                    ORDER BY recorded_at DESC
+                    LIMIT :count
+                ) latest
+                ORDER BY recorded_at ASC;
+            "#,
+            TABLE_NAME_RATE_FOR_TRAINING,
+        );
+        let p = params! {
+            "pair" => pair,
+            "count" => count,
+        };
+        log::debug!("query: {}, pair: {}, count: {}", q, pair, count);
+
+        let mut rates: Vec<RateForTraining> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, recorded_at, rate, created_at, updated_at) = from_row(row?);
+                rates.push(RateForTraining {
+                    pair,
+                    recorded_at,
+                    rate,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+
+        Ok(rates)
+    }
+
+    fn replace_rate_gaps(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        gaps: &[RateGap],
+    ) -> MyResult<()> {
+        tx.exec_drop(
+            format!("DELETE FROM {} WHERE pair = :pair;", TABLE_NAME_RATE_GAPS),
+            params! { "pair" => pair },
+        )?;
+
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (pair, begin_at, end_at, missing_minutes) VALUES (:pair, :begin_at, :end_at, :missing_minutes);",
+                TABLE_NAME_RATE_GAPS
+            ),
+            gaps.iter().map(|gap| {
+                params! {
+                    "pair" => &gap.pair,
+                    "begin_at" => gap.begin_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "end_at" => gap.end_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "missing_minutes" => gap.missing_minutes,
+                }
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn select_rate_gaps(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<RateGap>> {
+        let mut conditions: Vec<String> = vec!["pair = :pair".to_string()];
+        if let Some(value) = begin {
+            conditions.push(format!("end_at >= '{}'", value.format("%Y-%m-%d %H:%M:%S")));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "begin_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
+        let q = format!(
+            r#"
+                SELECT pair, begin_at, end_at, missing_minutes
+                FROM {}
+                WHERE {}
+                ORDER BY begin_at ASC
+                LIMIT :limit OFFSET :offset;
+            "#,
+            TABLE_NAME_RATE_GAPS,
+            conditions.join(" AND "),
+        );
+        let p = params! {
+            "pair" => pair,
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, pair: {}, limit: {}, offset: {}",
+            q,
+            pair,
+            limit,
+            offset
+        );
+
+        let mut gaps: Vec<RateGap> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, begin_at, end_at, missing_minutes) = from_row(row?);
+                gaps.push(RateGap {
+                    pair,
+                    begin_at,
+                    end_at,
+                    missing_minutes,
+                });
+            }
+        }
+
+        Ok(gaps)
+    }
+
+    fn replace_rate_anomalies(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        anomalies: &[RateAnomaly],
+    ) -> MyResult<()> {
+        tx.exec_drop(
+            format!(
+                "DELETE FROM {} WHERE pair = :pair;",
+                TABLE_NAME_RATE_ANOMALIES
+            ),
+            params! { "pair" => pair },
+        )?;
+
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (pair, recorded_at, rate, kind) VALUES (:pair, :recorded_at, :rate, :kind);",
+                TABLE_NAME_RATE_ANOMALIES
+            ),
+            anomalies.iter().map(|anomaly| {
+                params! {
+                    "pair" => &anomaly.pair,
+                    "recorded_at" => anomaly.recorded_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "rate" => anomaly.rate,
+                    "kind" => anomaly.kind.as_str(),
+                }
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn select_rate_anomalies(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<RateAnomaly>> {
+        let mut conditions: Vec<String> = vec!["pair = :pair".to_string()];
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "recorded_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "recorded_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
+        let q = format!(
+            r#"
+                SELECT pair, recorded_at, rate, kind
+                FROM {}
+                WHERE {}
+                ORDER BY recorded_at ASC;
+            "#,
+            TABLE_NAME_RATE_ANOMALIES,
+            conditions.join(" AND "),
+        );
+        let p = params! { "pair" => pair };
+        log::debug!("query: {}, pair: {}", q, pair);
+
+        let mut anomalies: Vec<RateAnomaly> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, recorded_at, rate, kind): (String, NaiveDateTime, f64, String) =
+                    from_row(row?);
+                anomalies.push(RateAnomaly {
+                    pair,
+                    recorded_at,
+                    rate,
+                    kind: AnomalyKind::parse(&kind)?,
+                });
+            }
+        }
+
+        Ok(anomalies)
+    }
+
+    fn insert_data_quality_report(
+        &self,
+        tx: &mut Transaction,
+        report: &DataQualityReport,
+    ) -> MyResult<()> {
+        let q = format!(
+            r#"
+                INSERT INTO {} (
+                    pair, measured_at, begin_at, end_at, coverage, gap_count, duplicate_count,
+                    latest_recorded_at, lag_seconds, problems
+                ) VALUES (
+                    :pair, :measured_at, :begin_at, :end_at, :coverage, :gap_count, :duplicate_count,
+                    :latest_recorded_at, :lag_seconds, :problems
+                );
+            "#,
+            TABLE_NAME_DATA_QUALITY_REPORTS
+        );
+        log::debug!("query: {}, report: {:?}", q, report);
+        tx.exec_drop(
+            q,
+            params! {
+                "pair" => &report.pair,
+                "measured_at" => report.measured_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "begin_at" => report.begin_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "end_at" => report.end_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "coverage" => report.coverage,
+                "gap_count" => report.gap_count,
+                "duplicate_count" => report.duplicate_count,
+                "latest_recorded_at" => report
+                    .latest_recorded_at
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()),
+                "lag_seconds" => report.lag_seconds,
+                "problems" => Serialized(&report.problems),
+            },
+        )?;
+        Ok(())
+    }
+
+    fn delete_old_data_quality_reports(
+        &self,
+        tx: &mut Transaction,
+        border: &NaiveDateTime,
+    ) -> MyResult<()> {
+        tx.exec_drop(
+            format!(
+                "DELETE FROM {} WHERE measured_at < :border;",
+                TABLE_NAME_DATA_QUALITY_REPORTS
+            ),
+            params! {
+                "border" => border.format("%Y-%m-%d %H:%M:%S").to_string(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    fn insert_backtest_result(
+        &self,
+        tx: &mut Transaction,
+        result: &BacktestResult,
+    ) -> MyResult<()> {
+        let q = format!(
+            r#"
+                INSERT INTO {} (
+                    pair, model_no, model_memo, begin_at, end_at, forecast_offset_minutes,
+                    forecast_count, hit_rate, mae, trade_count, win_rate, expected_value,
+                    total_profit, max_drawdown
+                ) VALUES (
+                    :pair, :model_no, :model_memo, :begin_at, :end_at, :forecast_offset_minutes,
+                    :forecast_count, :hit_rate, :mae, :trade_count, :win_rate, :expected_value,
+                    :total_profit, :max_drawdown
+                );
+            "#,
+            TABLE_NAME_BACKTEST_RESULTS
+        );
+        log::debug!("query: {}, result: {:?}", q, result);
+        tx.exec_drop(
+            q,
+            params! {
+                "pair" => &result.pair,
+                "model_no" => result.model_no,
+                "model_memo" => &result.model_memo,
+                "begin_at" => result.begin_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "end_at" => result.end_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "forecast_offset_minutes" => result.forecast_offset_minutes,
+                "forecast_count" => result.forecast_count,
+                "hit_rate" => result.hit_rate,
+                "mae" => result.mae,
+                "trade_count" => result.trade_count,
+                "win_rate" => result.win_rate,
+                "expected_value" => result.expected_value,
+                "total_profit" => result.total_profit,
+                "max_drawdown" => result.max_drawdown,
+            },
+        )?;
+        Ok(())
+    }
+
+    fn select_latest_data_quality_report(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Option<DataQualityReport>> {
+        let q = format!(
+            r#"
+                SELECT
+                    pair, measured_at, begin_at, end_at, coverage, gap_count, duplicate_count,
+                    latest_recorded_at, lag_seconds, problems
+                FROM {}
+                WHERE pair = :pair
+                ORDER BY measured_at DESC
+                LIMIT 1;
+            "#,
+            TABLE_NAME_DATA_QUALITY_REPORTS
+        );
+        log::debug!("query: {}, pair: {}", q, pair);
+
+        let row: Option<(
+            String,
+            NaiveDateTime,
+            NaiveDateTime,
+            NaiveDateTime,
+            f64,
+            i64,
+            i64,
+            Option<NaiveDateTime>,
+            Option<i64>,
+            Value,
+        )> = tx.exec_first(q, params! { "pair" => pair })?;
+        Ok(row.map(
+            |(
+                pair,
+                measured_at,
+                begin_at,
+                end_at,
+                coverage,
+                gap_count,
+                duplicate_count,
+                latest_recorded_at,
+                lag_seconds,
+                problems_raw,
+            )| {
+                let Deserialized(problems): Deserialized<Vec<String>> = from_value(problems_raw);
+                DataQualityReport {
+                    pair,
+                    measured_at,
+                    begin_at,
+                    end_at,
+                    coverage,
+                    gap_count,
+                    duplicate_count,
+                    latest_recorded_at,
+                    lag_seconds,
+                    problems,
+                }
+            },
+        ))
+    }
+
+    fn acquire_job_lease(
+        &self,
+        tx: &mut Transaction,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+        lease_seconds: i64,
+    ) -> MyResult<bool> {
+        tx.exec_drop(
+            format!(
+                "INSERT IGNORE INTO {} (name, owner, scheduled_at, expires_at) VALUES (:name, '', '1970-01-01 00:00:00', '1970-01-01 00:00:00');",
+                TABLE_NAME_JOB_LEASES
+            ),
+            params! {
+                "name" => name,
+            },
+        )?;
+
+        // 同じ予定時刻を他のインスタンスが実行済み、または実行中（期限内）の場合は取得できない
+        tx.exec_drop(
+            format!(
+                r#"
+                    UPDATE {}
+                    SET owner = :owner, scheduled_at = :scheduled_at, expires_at = DATE_ADD(NOW(), INTERVAL :lease_seconds SECOND)
+                    WHERE name = :name AND scheduled_at < :scheduled_at AND expires_at <= NOW();
+                "#,
+                TABLE_NAME_JOB_LEASES
+            ),
+            params! {
+                "name" => name,
+                "owner" => owner,
+                "scheduled_at" => scheduled_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                "lease_seconds" => lease_seconds,
+            },
+        )?;
+
+        Ok(tx.affected_rows() == 1)
+    }
+
+    fn release_job_lease(
+        &self,
+        tx: &mut Transaction,
+        name: &str,
+        owner: &str,
+        scheduled_at: &NaiveDateTime,
+    ) -> MyResult<()> {
+        tx.exec_drop(
+            format!(
+                "UPDATE {} SET expires_at = NOW() WHERE name = :name AND owner = :owner AND scheduled_at = :scheduled_at;",
+                TABLE_NAME_JOB_LEASES
+            ),
+            params! {
+                "name" => name,
+                "owner" => owner,
+                "scheduled_at" => scheduled_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            },
+        )?;
+
+        Ok(())
+    }
+
+    fn insert_job_run(&self, tx: &mut Transaction, job_name: &str) -> MyResult<String> {
+        let id: Option<String> = tx.query_first("SELECT UUID();")?;
+        tx.exec_drop(
+            format!(
+                "INSERT INTO {} (id, job_name, status, started_at) VALUES (:id, :job_name, :status, CURRENT_TIMESTAMP());",
+                TABLE_NAME_JOB_RUNS
+            ),
+            params! {
+                "id" => &id,
+                "job_name" => job_name,
+                "status" => JOB_RUN_STATUS_RUNNING,
+            },
+        )?;
+        Ok(id.unwrap())
+    }
+
+    fn update_job_run_finished(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+        status: u8,
+        message: Option<&str>,
+    ) -> MyResult<()> {
+        let q = format!(
+            "UPDATE {} SET status = :status, message = :message, finished_at = CURRENT_TIMESTAMP() WHERE id = :id;",
+            TABLE_NAME_JOB_RUNS
+        );
+        let p = params! {
+            "id" => id,
+            "status" => status,
+            "message" => message,
+        };
+        log::debug!("query: {}, id: {}", q, id);
+        tx.exec_drop(q, p)?;
+
+        Ok(())
+    }
+
+    fn select_job_runs(
+        &self,
+        tx: &mut Transaction,
+        job_name: Option<&str>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<JobRun>> {
+        let q = format!(
+            r#"
+                SELECT id, job_name, status, message, started_at, finished_at, created_at, updated_at
+                FROM {}
+                WHERE :job_name = '' OR job_name = :job_name
+                ORDER BY started_at DESC
+                LIMIT :limit OFFSET :offset;
+            "#,
+            TABLE_NAME_JOB_RUNS,
+        );
+        let p = params! {
+            "job_name" => job_name.unwrap_or(""),
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, job_name: {:?}, limit: {}, offset: {}",
+            q,
+            job_name,
+            limit,
+            offset
+        );
+
+        let mut runs: Vec<JobRun> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (
+                    id,
+                    job_name,
+                    status,
+                    message,
+                    started_at,
+                    finished_at,
+                    created_at,
+                    updated_at,
+                ) = from_row(row?);
+                runs.push(JobRun {
+                    id,
+                    job_name,
+                    status,
+                    message,
+                    started_at,
+                    finished_at,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+        Ok(runs)
+    }
+
+    fn insert_job_request(
+        &self,
+        tx: &mut Transaction,
+        job_name: &str,
+        max_attempts: i32,
+    ) -> MyResult<String> {
+        let id: Option<String> = tx.query_first("SELECT UUID();")?;
+        tx.exec_drop(
+            format!(
+                "INSERT INTO {} (id, job_name, status, max_attempts, available_at) VALUES (:id, :job_name, :status, :max_attempts, CURRENT_TIMESTAMP());",
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "id" => &id,
+                "job_name" => job_name,
+                "status" => JOB_REQUEST_STATUS_PENDING,
+                "max_attempts" => max_attempts,
+            },
+        )?;
+        Ok(id.unwrap())
+    }
+
+    fn select_job_requests(
+        &self,
+        tx: &mut Transaction,
+        job_name: Option<&str>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<JobRequest>> {
+        let q = format!(
+            r#"
+                SELECT id, job_name, status, attempts, max_attempts, owner, message, available_at, started_at, finished_at, created_at, updated_at
+                FROM {}
+                WHERE :job_name = '' OR job_name = :job_name
+                ORDER BY created_at DESC
+                LIMIT :limit OFFSET :offset;
+            "#,
+            TABLE_NAME_JOB_REQUESTS,
+        );
+        let p = params! {
+            "job_name" => job_name.unwrap_or(""),
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, job_name: {:?}, limit: {}, offset: {}",
+            q,
+            job_name,
+            limit,
+            offset
+        );
+
+        select_job_requests_with(tx, q, p)
+    }
+
+    // 待機中・実行可能・実行中の要求を作成日時の順に返す
+    fn select_active_job_requests(&self, tx: &mut Transaction) -> MyResult<Vec<JobRequest>> {
+        let q = format!(
+            r#"
+                SELECT id, job_name, status, attempts, max_attempts, owner, message, available_at, started_at, finished_at, created_at, updated_at
+                FROM {}
+                WHERE status IN (:pending, :ready, :running)
+                ORDER BY created_at;
+            "#,
+            TABLE_NAME_JOB_REQUESTS,
+        );
+        let p = params! {
+            "pending" => JOB_REQUEST_STATUS_PENDING,
+            "ready" => JOB_REQUEST_STATUS_READY,
+            "running" => JOB_REQUEST_STATUS_RUNNING,
+        };
+        log::debug!("query: {}", q);
+
+        select_job_requests_with(tx, q, p)
+    }
+
+    fn update_job_request_ready(&self, tx: &mut Transaction, id: &str) -> MyResult<bool> {
+        tx.exec_drop(
+            format!(
+                "UPDATE {} SET status = :ready WHERE id = :id AND status = :pending;",
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "id" => id,
+                "ready" => JOB_REQUEST_STATUS_READY,
+                "pending" => JOB_REQUEST_STATUS_PENDING,
+            },
+        )?;
+        Ok(tx.affected_rows() == 1)
+    }
+
+    // 実行可能な要求を1件取得して実行中にする（複数のワーカーが同じ要求を取得しないよう行をロックする）
+    fn claim_job_request(
+        &self,
+        tx: &mut Transaction,
+        job_name: &str,
+        owner: &str,
+    ) -> MyResult<Option<String>> {
+        let id: Option<String> = tx.exec_first(
+            format!(
+                "SELECT id FROM {} WHERE job_name = :job_name AND status = :ready ORDER BY created_at LIMIT 1 FOR UPDATE SKIP LOCKED;",
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "job_name" => job_name,
+                "ready" => JOB_REQUEST_STATUS_READY,
+            },
+        )?;
+        let id = match id {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        tx.exec_drop(
+            format!(
+                r#"
+                    UPDATE {}
+                    SET status = :running, owner = :owner, attempts = attempts + 1, message = NULL, started_at = CURRENT_TIMESTAMP(), finished_at = NULL
+                    WHERE id = :id;
+                "#,
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "id" => &id,
+                "running" => JOB_REQUEST_STATUS_RUNNING,
+                "owner" => owner,
+            },
+        )?;
+        Ok(Some(id))
+    }
+
+    // タイムアウトで失敗扱いになった要求は更新しない
+    fn update_job_request_finished(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+        status: u8,
+        message: Option<&str>,
+    ) -> MyResult<()> {
+        let q = format!(
+            "UPDATE {} SET status = :status, message = :message, finished_at = CURRENT_TIMESTAMP() WHERE id = :id AND status = :running;",
+            TABLE_NAME_JOB_REQUESTS
+        );
+        let p = params! {
+            "id" => id,
+            "status" => status,
+            "message" => message,
+            "running" => JOB_REQUEST_STATUS_RUNNING,
+        };
+        log::debug!("query: {}, id: {}", q, id);
+        tx.exec_drop(q, p)?;
+
+        Ok(())
+    }
+
+    fn fail_timed_out_job_requests(
+        &self,
+        tx: &mut Transaction,
+        timeout_seconds: i64,
+    ) -> MyResult<u64> {
+        tx.exec_drop(
+            format!(
+                r#"
+                    UPDATE {}
+                    SET status = :failed, message = 'timed out', finished_at = CURRENT_TIMESTAMP()
+                    WHERE status = :running AND started_at < DATE_SUB(NOW(), INTERVAL :timeout_seconds SECOND);
+                "#,
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "failed" => JOB_REQUEST_STATUS_FAILED,
+                "running" => JOB_REQUEST_STATUS_RUNNING,
+                "timeout_seconds" => timeout_seconds,
+            },
+        )?;
+        Ok(tx.affected_rows())
+    }
+
+    // 試行回数が上限に達していない失敗した要求を、待ち時間（試行ごとに2倍）を置いて待機中に戻す
+    fn requeue_failed_job_requests(
+        &self,
+        tx: &mut Transaction,
+        backoff_seconds: i64,
+    ) -> MyResult<u64> {
+        tx.exec_drop(
+            format!(
+                r#"
+                    UPDATE {}
+                    SET status = :pending, available_at = DATE_ADD(NOW(), INTERVAL :backoff_seconds * POW(2, attempts - 1) SECOND)
+                    WHERE status = :failed AND attempts < max_attempts;
+                "#,
+                TABLE_NAME_JOB_REQUESTS
+            ),
+            params! {
+                "pending" => JOB_REQUEST_STATUS_PENDING,
+                "failed" => JOB_REQUEST_STATUS_FAILED,
+                "backoff_seconds" => backoff_seconds,
+            },
+        )?;
+        Ok(tx.affected_rows())
+    }
+
+    fn select_runtime_settings(&self, tx: &mut Transaction) -> MyResult<Vec<(String, String)>> {
+        let q = format!(
+            "SELECT name, value FROM {} ORDER BY name;",
+            TABLE_NAME_RUNTIME_SETTINGS
+        );
+        log::debug!("query: {}", q);
+
+        let settings: Vec<(String, String)> = tx.query(q)?;
+        Ok(settings)
+    }
+
+    fn select_currency_pairs(&self, tx: &mut Transaction) -> MyResult<Vec<CurrencyPair>> {
+        let q = format!(
+            "SELECT pair, pip_size, enabled FROM {} ORDER BY pair;",
+            TABLE_NAME_CURRENCY_PAIRS
+        );
+        log::debug!("query: {}", q);
+
+        let pairs = tx.query_map(q, |(pair, pip_size, enabled)| CurrencyPair {
+            pair,
+            pip_size,
+            enabled,
+        })?;
+        Ok(pairs)
+    }
+
+    fn upsert_forecast_model(&self, tx: &mut Transaction, m: &ForecastModel) -> MyResult<()> {
+        let q = format!(
+            r#"
+                INSERT INTO {}
+                    (pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo)
+                VALUES
+                    (:pair, :no, :type, :data, :input_data_size, :feature_params, :feature_params_hash, :performance_mse, :performance_rmse, :memo)
+                ON DUPLICATE KEY UPDATE
+                    model_type = :type,
+                    model_data = :data,
+                    input_data_size = :input_data_size,
+                    feature_params = :feature_params,
+                    feature_params_hash = :feature_params_hash,
+                    performance_mse = :performance_mse,
+                    performance_rmse = :performance_rmse,
+                    memo = :memo;
+            "#,
+            TABLE_NAME_FORECAST_MODEL
+        );
+        let p = match m {
+            ForecastModel::RandomForest {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_RANDOM_FOREST,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::KNN {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_KNN,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::Linear {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_LINEAR,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::Ridge {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_RIDGE,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::LASSO {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_LASSO,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::ElasticNet {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_ELASTIC_NET,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::Logistic {
+                pair,
+                no,
                 input_data_size,
                 feature_params,
                 performance_mse,
@@ -294,7 +1701,7 @@ impl Client for DefaultClient {
                 params! {
                     "pair" => pair,
                     "no" => no,
-                    "type" => super::model::MODEL_TYPE_RANDOM_FOREST,
+                    "type" => super::model::MODEL_TYPE_LOGISTIC,
                     "data" => m.serialize_model_data()?,
                     "input_data_size" => input_data_size,
                     "feature_params" => Serialized(feature_params),
@@ -304,7 +1711,7 @@ impl Client for DefaultClient {
                     "memo" => memo,
                 }
             }
-            ForecastModel::KNN {
+            ForecastModel::SVR {
                 pair,
                 no,
                 input_data_size,
@@ -317,7 +1724,7 @@ impl Client for DefaultClient {
                 params! {
                     "pair" => pair,
                     "no" => no,
-                    "type" => super::model::MODEL_TYPE_KNN,
+                    "type" => super::model::MODEL_TYPE_SVR,
                     "data" => m.serialize_model_data()?,
                     "input_data_size" => input_data_size,
                     "feature_params" => Serialized(feature_params),
@@ -327,7 +1734,7 @@ impl Client for DefaultClient {
                     "memo" => memo,
                 }
             }
-            ForecastModel::Linear {
+            ForecastModel::Quantile {
                 pair,
                 no,
                 input_data_size,
@@ -340,7 +1747,7 @@ impl Client for DefaultClient {
                 params! {
                     "pair" => pair,
                     "no" => no,
-                    "type" => super::model::MODEL_TYPE_LINEAR,
+                    "type" => super::model::MODEL_TYPE_QUANTILE,
                     "data" => m.serialize_model_data()?,
                     "input_data_size" => input_data_size,
                     "feature_params" => Serialized(feature_params),
@@ -350,7 +1757,30 @@ impl Client for DefaultClient {
                     "memo" => memo,
                 }
             }
-            ForecastModel::Ridge {
+            ForecastModel::GradientBoosting {
+                pair,
+                no,
+                input_data_size,
+                feature_params,
+                performance_mse,
+                performance_rmse,
+                memo,
+                ..
+            } => {
+                params! {
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_GRADIENT_BOOSTING,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+            ForecastModel::Ensemble {
                 pair,
                 no,
                 input_data_size,
@@ -361,420 +1791,1379 @@ impl Client for DefaultClient {
                 ..
             } => {
                 params! {
-                    "pair" => pair,
-                    "no" => no,
-                    "type" => super::model::MODEL_TYPE_RIDGE,
-                    "data" => m.serialize_model_data()?,
-                    "input_data_size" => input_data_size,
-                    "feature_params" => Serialized(feature_params),
-                    "feature_params_hash" => feature_params.to_hash()?,
-                    "performance_mse" => performance_mse,
-                    "performance_rmse" => performance_rmse,
-                    "memo" => memo,
+                    "pair" => pair,
+                    "no" => no,
+                    "type" => super::model::MODEL_TYPE_ENSEMBLE,
+                    "data" => m.serialize_model_data()?,
+                    "input_data_size" => input_data_size,
+                    "feature_params" => Serialized(feature_params),
+                    "feature_params_hash" => feature_params.to_hash()?,
+                    "performance_mse" => performance_mse,
+                    "performance_rmse" => performance_rmse,
+                    "memo" => memo,
+                }
+            }
+        };
+        log::debug!("query: {}, param: {}", q, m);
+
+        tx.exec_drop(q, p)?;
+
+        Ok(())
+    }
+
+    fn copy_forecast_model(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        model_no_from: i32,
+        model_no_to: i32,
+    ) -> MyResult<()> {
+        let q = format!(
+            r#"
+                INSERT INTO {0}
+                    (pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo)
+                SELECT
+                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo
+                FROM (
+                    SELECT
+                        pair, :model_no_to model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo
+                    FROM {0}
+                    WHERE pair = :pair AND model_no = :model_no_from
+                ) t
+                ON DUPLICATE KEY UPDATE
+                    model_type = t.model_type,
+                    model_data = t.model_data,
+                    input_data_size = t.input_data_size,
+                    feature_params = t.feature_params,
+                    feature_params_hash = t.feature_params_hash,
+                    performance_mse = t.performance_mse,
+                    performance_rmse = t.performance_rmse,
+                    memo = t.memo;
+            "#,
+            TABLE_NAME_FORECAST_MODEL
+        );
+        let p = params! {
+            "pair" => pair,
+            "model_no_from" => model_no_from,
+            "model_no_to" => model_no_to,
+        };
+
+        log::debug!("query: {}, {:?}", q, p);
+
+        tx.exec_drop(q, p)?;
+
+        Ok(())
+    }
+
+    fn select_forecast_model(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        no: i32,
+    ) -> MyResult<Option<ForecastModel>> {
+        match self.select_forecast_model_record(tx, pair, no)? {
+            Some(record) => {
+                if let Err(err) = record.validate_feature_params() {
+                    log::warn!("model not found, {}", err);
+                    return Ok(None);
+                }
+                Ok(Some(record.to_domain()?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    // 特徴量の設定のハッシュを確認せず、保存されている内容のまま取得する
+    fn select_forecast_model_record(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        no: i32,
+    ) -> MyResult<Option<ForecastModelRecord>> {
+        let q = format!(
+            r#"
+                SELECT
+                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo, created_at, updated_at
+                FROM {}
+                WHERE
+                    pair = :pair AND model_no = :no;
+            "#,
+            TABLE_NAME_FORECAST_MODEL
+        );
+        let p = params! {
+            "pair" => pair,
+            "no" => no,
+        };
+        log::debug!("query: {}, pair: {}, no: {}", q, pair, no);
+
+        if let Some((
+            pair,
+            model_no,
+            model_type,
+            model_data,
+            input_data_size,
+            feature_params_raw,
+            feature_params_hash,
+            performance_mse,
+            performance_rmse,
+            memo,
+            created_at,
+            updated_at,
+        )) = tx.exec_first(q, p)?
+        {
+            let Deserialized(feature_params_value): Deserialized<FeatureParamsValue> =
+                from_value(feature_params_raw);
+            Ok(Some(ForecastModelRecord {
+                pair,
+                model_no,
+                model_type,
+                model_data,
+                input_data_size,
+                feature_params: feature_params_value.to_domain()?,
+                feature_params_hash,
+                performance_mse,
+                performance_rmse,
+                memo,
+                created_at,
+                updated_at,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn select_forecast_models(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Vec<ForecastModel>> {
+        let mut models: Vec<ForecastModel> = vec![];
+        for record in self.select_forecast_model_records(tx, pair)? {
+            if let Err(err) = record.validate_feature_params() {
+                log::warn!("model not found, {}", err);
+                continue;
+            }
+            models.push(record.to_domain()?);
+        }
+        Ok(models)
+    }
+
+    // 特徴量の設定のハッシュを確認せず、保存されている内容のまま取得する
+    fn select_forecast_model_records(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Vec<ForecastModelRecord>> {
+        let q = format!(
+            r#"
+                SELECT
+                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo, created_at, updated_at
+                FROM {}
+                WHERE
+                    pair = :pair
+                ORDER BY model_no
+            "#,
+            TABLE_NAME_FORECAST_MODEL
+        );
+        let p = params! {
+            "pair" => pair,
+        };
+        log::debug!("query: {}, pair: {}", q, pair);
+
+        let mut records: Vec<ForecastModelRecord> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (
+                    pair,
+                    model_no,
+                    model_type,
+                    model_data,
+                    input_data_size,
+                    feature_params_raw,
+                    feature_params_hash,
+                    performance_mse,
+                    performance_rmse,
+                    memo,
+                    created_at,
+                    updated_at,
+                ) = from_row(row?);
+                let Deserialized(feature_params_value): Deserialized<FeatureParamsValue> =
+                    from_value(feature_params_raw);
+                records.push(ForecastModelRecord {
+                    pair,
+                    model_no,
+                    model_type,
+                    model_data,
+                    input_data_size,
+                    feature_params: feature_params_value.to_domain()?,
+                    feature_params_hash,
+                    performance_mse,
+                    performance_rmse,
+                    memo,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+        Ok(records)
+    }
+
+    // 削除した場合は true を返す
+    fn delete_forecast_model(&self, tx: &mut Transaction, pair: &str, no: i32) -> MyResult<bool> {
+        let q = format!(
+            "DELETE FROM {} WHERE pair = :pair AND model_no = :no;",
+            TABLE_NAME_FORECAST_MODEL
+        );
+        log::debug!("query: {}, pair: {}, no: {}", q, pair, no);
+        tx.exec_drop(q, params! { "pair" => pair, "no" => no })?;
+
+        Ok(tx.affected_rows() > 0)
+    }
+
+    fn insert_rates_for_forecast(
+        &self,
+        tx: &mut Transaction,
+        rate: &RateForForecast,
+    ) -> MyResult<String> {
+        let id: Option<String> = tx.query_first("SELECT UUID();")?;
+        tx.exec_drop(
+            format!(
+                "INSERT INTO {} (id, pair, histories, histories_hash, expire, memo, trace_id) VALUES (:id, :pair, :histories, :histories_hash, :expire, :memo, :trace_id);",
+                TABLE_NAME_RATE_FOR_FORECAST
+            ),
+            params! {
+                "id" => &id,
+                "pair" => &rate.pair,
+                "histories" => Serialized(&rate.histories),
+                "histories_hash" => rate.histories_hash(),
+                "expire" => &rate.expire,
+                "memo" => &rate.memo,
+                "trace_id" => &rate.trace_id,
+            },
+        )?;
+        Ok(id.unwrap())
+    }
+
+    // 登録したIDを rates と同じ順で返す
+    fn insert_rates_for_forecast_batch(
+        &self,
+        tx: &mut Transaction,
+        rates: &[RateForForecast],
+    ) -> MyResult<Vec<String>> {
+        let mut ids: Vec<String> = vec![];
+        for _ in rates {
+            let id: Option<String> = tx.query_first("SELECT UUID();")?;
+            ids.push(id.unwrap());
+        }
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (id, pair, histories, histories_hash, expire, memo, trace_id) VALUES (:id, :pair, :histories, :histories_hash, :expire, :memo, :trace_id);",
+                TABLE_NAME_RATE_FOR_FORECAST
+            ),
+            ids.iter().zip(rates).map(|(id, rate)| {
+                params! {
+                    "id" => id,
+                    "pair" => &rate.pair,
+                    "histories" => Serialized(&rate.histories),
+                    "histories_hash" => rate.histories_hash(),
+                    "expire" => &rate.expire,
+                    "memo" => &rate.memo,
+                    "trace_id" => &rate.trace_id,
                 }
+            }),
+        )?;
+        Ok(ids)
+    }
+
+    fn select_rates_for_forecast_duplicated(
+        &self,
+        tx: &mut Transaction,
+        rate: &RateForForecast,
+        window_minutes: i64,
+    ) -> MyResult<Option<String>> {
+        let q = format!(
+            r#"
+                SELECT id
+                FROM {}
+                WHERE pair = :pair AND histories_hash = :histories_hash AND created_at >= DATE_SUB(CURRENT_TIMESTAMP(), INTERVAL :window_minutes MINUTE) AND expire >= CURRENT_TIMESTAMP()
+                ORDER BY created_at DESC
+                LIMIT 1;
+            "#,
+            TABLE_NAME_RATE_FOR_FORECAST,
+        );
+        let histories_hash = rate.histories_hash();
+        let p = params! {
+            "pair" => &rate.pair,
+            "histories_hash" => &histories_hash,
+            "window_minutes" => window_minutes,
+        };
+        log::debug!(
+            "query: {}, pair: {}, histories_hash: {}, window_minutes: {}",
+            q,
+            rate.pair,
+            histories_hash,
+            window_minutes
+        );
+
+        Ok(tx.exec_first(q, p)?)
+    }
+
+    // 登録直後の予想と重複しないよう、他のトランザクションで予想中（ロック中）のレートは除く
+    fn select_rates_for_forecast_unforecasted(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        min_age_seconds: i64,
+    ) -> MyResult<Vec<RateForForecast>> {
+        let q = format!(
+            r#"
+                WITH forecasted AS (
+                    SELECT DISTINCT rate_id FROM {}
+                )
+                SELECT f.id, f.pair, f.histories, f.expire, f.memo, f.trace_id, f.created_at, f.updated_at
+                FROM {} f
+                LEFT OUTER JOIN forecasted ON f.id = forecasted.rate_id
+                WHERE
+                    f.pair = :pair AND forecasted.rate_id IS NULL
+                    AND f.created_at <= DATE_SUB(CURRENT_TIMESTAMP(), INTERVAL :min_age_seconds SECOND)
+                FOR UPDATE OF f SKIP LOCKED
+            "#,
+            TABLE_NAME_FORECAST_RESULT, TABLE_NAME_RATE_FOR_FORECAST,
+        );
+        let p = params! {
+            "pair" => pair,
+            "min_age_seconds" => min_age_seconds,
+        };
+        log::debug!(
+            "query: {}, pair: {}, min_age_seconds: {}",
+            q,
+            pair,
+            min_age_seconds
+        );
+
+        let mut rates: Vec<RateForForecast> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (id, pair, histories_raw, expire, memo, trace_id, created_at, updated_at) =
+                    from_row(row?);
+                let Deserialized(histories): Deserialized<Vec<f64>> = from_value(histories_raw);
+                let record = RateForForecast {
+                    id,
+                    pair,
+                    histories,
+                    expire,
+                    memo,
+                    trace_id,
+                    created_at,
+                    updated_at,
+                };
+                rates.push(record);
             }
-            ForecastModel::LASSO {
+        }
+        Ok(rates)
+    }
+
+    // 予想が終わるまで他のトランザクションから予想されないよう、レートをロックして取得する
+    fn select_rate_for_forecast_unforecasted_for_update(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+    ) -> MyResult<Option<RateForForecast>> {
+        let q = format!(
+            r#"
+                SELECT f.id, f.pair, f.histories, f.expire, f.memo, f.trace_id, f.created_at, f.updated_at
+                FROM {} f
+                WHERE
+                    f.id = :id
+                    AND NOT EXISTS (SELECT 1 FROM {} r WHERE r.rate_id = f.id)
+                FOR UPDATE;
+            "#,
+            TABLE_NAME_RATE_FOR_FORECAST, TABLE_NAME_FORECAST_RESULT,
+        );
+        let p = params! {
+            "id" => id,
+        };
+        log::debug!("query: {}, id: {}", q, id);
+
+        if let Some((id, pair, histories_raw, expire, memo, trace_id, created_at, updated_at)) =
+            tx.exec_first(q, p)?
+        {
+            let Deserialized(histories) = from_value(histories_raw);
+            let record = RateForForecast {
+                id,
                 pair,
-                no,
-                input_data_size,
-                feature_params,
-                performance_mse,
-                performance_rmse,
+                histories,
+                expire,
                 memo,
-                ..
-            } => {
-                params! {
-                    "pair" => pair,
-                    "no" => no,
-                    "type" => super::model::MODEL_TYPE_LASSO,
-                    "data" => m.serialize_model_data()?,
-                    "input_data_size" => input_data_size,
-                    "feature_params" => Serialized(feature_params),
-                    "feature_params_hash" => feature_params.to_hash()?,
-                    "performance_mse" => performance_mse,
-                    "performance_rmse" => performance_rmse,
-                    "memo" => memo,
-                }
-            }
-            ForecastModel::ElasticNet {
+                trace_id,
+                created_at,
+                updated_at,
+            };
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn select_rates_for_forecast_by_id(
+        &self,
+        tx: &mut Transaction,
+        id: &str,
+    ) -> MyResult<Option<RateForForecast>> {
+        let q = format!(
+            r#"
+                SELECT id, pair, histories, expire, memo, trace_id, created_at, updated_at
+                FROM {}
+                WHERE id = :id AND expire >= CURRENT_TIMESTAMP();
+            "#,
+            TABLE_NAME_RATE_FOR_FORECAST,
+        );
+        let p = params! {
+            "id" => id,
+        };
+        log::debug!("query: {}, id: {}", q, id);
+
+        if let Some((id, pair, histories_raw, expire, memo, trace_id, created_at, updated_at)) =
+            tx.exec_first(q, p)?
+        {
+            let Deserialized(histories) = from_value(histories_raw);
+            let record = RateForForecast {
+                id,
                 pair,
-                no,
-                input_data_size,
-                feature_params,
-                performance_mse,
-                performance_rmse,
+                histories: histories,
+                expire,
                 memo,
-                ..
-            } => {
+                trace_id,
+                created_at,
+                updated_at,
+            };
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn delete_rates_for_forecast_expired(&self, tx: &mut Transaction) -> MyResult<()> {
+        let q = format!(
+            "DELETE FROM {} WHERE expire < CURRENT_TIMESTAMP();",
+            TABLE_NAME_RATE_FOR_FORECAST
+        );
+        tx.query_drop(q)?;
+
+        Ok(())
+    }
+
+    fn insert_forecast_results(
+        &self,
+        tx: &mut Transaction,
+        results: &Vec<ForecastResult>,
+    ) -> MyResult<()> {
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (rate_id, model_no, forecast_type, result, path, quantiles, memo) VALUES (:rate_id, :model_no, :forecast_type, :result, :path, :quantiles, :memo);",
+                TABLE_NAME_FORECAST_RESULT,
+            ),
+            results.iter().map(|result| {
                 params! {
-                    "pair" => pair,
-                    "no" => no,
-                    "type" => super::model::MODEL_TYPE_ELASTIC_NET,
-                    "data" => m.serialize_model_data()?,
-                    "input_data_size" => input_data_size,
-                    "feature_params" => Serialized(feature_params),
-                    "feature_params_hash" => feature_params.to_hash()?,
-                    "performance_mse" => performance_mse,
-                    "performance_rmse" => performance_rmse,
-                    "memo" => memo,
+                    "rate_id" => &result.rate_id,
+                    "model_no" => &result.model_no,
+                    "forecast_type" => &result.forecast_type,
+                    "result" => &result.result,
+                    "path" => result.path.as_ref().map(Serialized),
+                    "quantiles" => result.quantiles.as_ref().map(Serialized),
+                    "memo" => &result.memo,
                 }
-            }
-            ForecastModel::Logistic {
-                pair,
-                no,
-                input_data_size,
-                feature_params,
-                performance_mse,
-                performance_rmse,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn select_forecast_results_by_rate_id_and_model_no(
+        &self,
+        tx: &mut Transaction,
+        rate_id: &str,
+        model_no: i32,
+        forecast_type: i32,
+    ) -> MyResult<Option<ForecastResult>> {
+        let q = format!(
+            r#"
+                SELECT id, rate_id, model_no, forecast_type, result, path, quantiles, memo, created_at, updated_at
+                FROM {}
+                WHERE rate_id = :rate_id AND model_no = :model_no AND forecast_type = :forecast_type;
+            "#,
+            TABLE_NAME_FORECAST_RESULT,
+        );
+        let p = params! {
+            "rate_id" => rate_id,
+            "model_no" => model_no,
+            "forecast_type" => forecast_type,
+        };
+        log::debug!(
+            "query: {}, rate_id: {}, model_no: {}, forecast_type: {}",
+            q,
+            rate_id,
+            model_no,
+            forecast_type
+        );
+
+        if let Some((
+            id,
+            rate_id,
+            model_no,
+            forecast_type,
+            result,
+            path_raw,
+            quantiles_raw,
+            memo,
+            created_at,
+            updated_at,
+        )) = tx.exec_first(q, p)?
+        {
+            let path: Option<Deserialized<Vec<f64>>> = from_value(path_raw);
+            let quantiles: Option<Deserialized<ForecastQuantiles>> = from_value(quantiles_raw);
+            let record = ForecastResult {
+                id,
+                rate_id,
+                model_no,
+                forecast_type,
+                result,
+                path: path.map(|Deserialized(v)| v),
+                quantiles: quantiles.map(|Deserialized(v)| v),
                 memo,
-                ..
-            } => {
+                created_at,
+                updated_at,
+            };
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // 予想日時（created_at）の範囲で取得する
+    fn select_forecast_results_paged(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastResult>> {
+        let mut conditions: Vec<String> = vec!["r.pair = :pair".to_string()];
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "fr.created_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "fr.created_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
+        let q = format!(
+            r#"
+                SELECT fr.id, fr.rate_id, fr.model_no, fr.forecast_type, fr.result, fr.path, fr.quantiles, fr.memo, fr.created_at, fr.updated_at
+                FROM {} fr
+                INNER JOIN {} r ON fr.rate_id = r.id
+                WHERE {}
+                ORDER BY fr.created_at ASC, fr.id
+                LIMIT :limit OFFSET :offset;
+            "#,
+            TABLE_NAME_FORECAST_RESULT,
+            TABLE_NAME_RATE_FOR_FORECAST,
+            conditions.join(" AND "),
+        );
+        let p = params! {
+            "pair" => pair,
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, pair: {}, limit: {}, offset: {}",
+            q,
+            pair,
+            limit,
+            offset
+        );
+
+        let mut records: Vec<ForecastResult> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (
+                    id,
+                    rate_id,
+                    model_no,
+                    forecast_type,
+                    result,
+                    path_raw,
+                    quantiles_raw,
+                    memo,
+                    created_at,
+                    updated_at,
+                ) = from_row(row?);
+                let path: Option<Deserialized<Vec<f64>>> = from_value(path_raw);
+                let quantiles: Option<Deserialized<ForecastQuantiles>> = from_value(quantiles_raw);
+                records.push(ForecastResult {
+                    id,
+                    rate_id,
+                    model_no,
+                    forecast_type,
+                    result,
+                    path: path.map(|Deserialized(v)| v),
+                    quantiles: quantiles.map(|Deserialized(v)| v),
+                    memo,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+
+        Ok(records)
+    }
+
+    fn delete_forecast_results_expired(&self, tx: &mut Transaction) -> MyResult<()> {
+        let q = format!(
+            r#"
+                DELETE FROM {} WHERE rate_id IN (
+                    SELECT id FROM {} WHERE expire < CURRENT_TIMESTAMP()
+                );
+            "#,
+            TABLE_NAME_FORECAST_RESULT, TABLE_NAME_RATE_FOR_FORECAST
+        );
+        tx.query_drop(q)?;
+
+        Ok(())
+    }
+
+    fn insert_forecast_errors(
+        &self,
+        tx: &mut Transaction,
+        records: &Vec<ForecastError>,
+    ) -> MyResult<()> {
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (rate_id, model_no, forecast_type, summary, detail) VALUES (:rate_id, :model_no, :forecast_type, :summary, :detail);",
+                TABLE_NAME_FORECAST_ERRORS,
+            ),
+            records.iter().map(|record| {
                 params! {
-                    "pair" => pair,
-                    "no" => no,
-                    "type" => super::model::MODEL_TYPE_LOGISTIC,
-                    "data" => m.serialize_model_data()?,
-                    "input_data_size" => input_data_size,
-                    "feature_params" => Serialized(feature_params),
-                    "feature_params_hash" => feature_params.to_hash()?,
-                    "performance_mse" => performance_mse,
-                    "performance_rmse" => performance_rmse,
-                    "memo" => memo,
+                    "rate_id" => &record.rate_id,
+                    "model_no" => &record.model_no,
+                    "forecast_type" => &record.forecast_type,
+                    "summary" => &record.summary,
+                    "detail" => &record.detail,
                 }
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn select_forecast_errors_by_rate_id_and_model_no(
+        &self,
+        tx: &mut Transaction,
+        rate_id: &str,
+        model_no: i32,
+        forecast_type: i32,
+    ) -> MyResult<Option<ForecastError>> {
+        let q = format!(
+            r#"
+                SELECT id, rate_id, model_no, forecast_type, summary, detail
+                FROM {}
+                WHERE rate_id = :rate_id AND model_no = :model_no AND forecast_type = :forecast_type;
+            "#,
+            TABLE_NAME_FORECAST_ERRORS,
+        );
+        let p = params! {
+            "rate_id" => rate_id,
+            "model_no" => model_no,
+            "forecast_type" => forecast_type,
+        };
+        log::debug!(
+            "query: {}, rate_id: {}, model_no: {}, forecast_type: {}",
+            q,
+            rate_id,
+            model_no,
+            forecast_type
+        );
+
+        if let Some((id, rate_id, model_no, forecast_type, summary, detail)) =
+            tx.exec_first(q, p)?
+        {
+            let record = ForecastError {
+                id,
+                rate_id,
+                model_no,
+                forecast_type,
+                summary,
+                detail,
+            };
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn select_forecast_errors(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastError>> {
+        let q = format!(
+            r#"
+                SELECT e.id, e.rate_id, e.model_no, e.forecast_type, e.summary, e.detail
+                FROM {} e
+                INNER JOIN {} r ON e.rate_id = r.id
+                WHERE r.pair = :pair
+                ORDER BY e.created_at DESC, e.id
+                LIMIT :limit OFFSET :offset;
+            "#,
+            TABLE_NAME_FORECAST_ERRORS, TABLE_NAME_RATE_FOR_FORECAST,
+        );
+        let p = params! {
+            "pair" => pair,
+            "limit" => limit,
+            "offset" => offset,
+        };
+        log::debug!(
+            "query: {}, pair: {}, limit: {}, offset: {}",
+            q,
+            pair,
+            limit,
+            offset
+        );
+
+        let mut records: Vec<ForecastError> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (id, rate_id, model_no, forecast_type, summary, detail) = from_row(row?);
+                records.push(ForecastError {
+                    id,
+                    rate_id,
+                    model_no,
+                    forecast_type,
+                    summary,
+                    detail,
+                });
             }
-            ForecastModel::SVR {
-                pair,
-                no,
-                input_data_size,
-                feature_params,
-                performance_mse,
-                performance_rmse,
-                memo,
-                ..
-            } => {
+        }
+
+        Ok(records)
+    }
+
+    fn delete_forecast_errors_expired(&self, tx: &mut Transaction) -> MyResult<()> {
+        let q = format!(
+            r#"
+                DELETE FROM {} WHERE rate_id IN (
+                    SELECT id FROM {} WHERE expire < CURRENT_TIMESTAMP()
+                );
+            "#,
+            TABLE_NAME_FORECAST_ERRORS, TABLE_NAME_RATE_FOR_FORECAST
+        );
+        tx.query_drop(q)?;
+
+        Ok(())
+    }
+
+    fn insert_forecast_explanations(
+        &self,
+        tx: &mut Transaction,
+        records: &Vec<ForecastExplanation>,
+    ) -> MyResult<()> {
+        tx.exec_batch(
+            format!(
+                "INSERT INTO {} (rate_id, model_no, forecast_type, method, base_value, contributions) VALUES (:rate_id, :model_no, :forecast_type, :method, :base_value, :contributions);",
+                TABLE_NAME_FORECAST_EXPLANATIONS,
+            ),
+            records.iter().map(|record| {
                 params! {
-                    "pair" => pair,
-                    "no" => no,
-                    "type" => super::model::MODEL_TYPE_SVR,
-                    "data" => m.serialize_model_data()?,
-                    "input_data_size" => input_data_size,
-                    "feature_params" => Serialized(feature_params),
-                    "feature_params_hash" => feature_params.to_hash()?,
-                    "performance_mse" => performance_mse,
-                    "performance_rmse" => performance_rmse,
-                    "memo" => memo,
+                    "rate_id" => &record.rate_id,
+                    "model_no" => &record.model_no,
+                    "forecast_type" => &record.forecast_type,
+                    "method" => &record.explanation.method,
+                    "base_value" => &record.explanation.base_value,
+                    "contributions" => Serialized(&record.explanation.contributions),
                 }
-            }
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn select_forecast_explanation(
+        &self,
+        tx: &mut Transaction,
+        rate_id: &str,
+        model_no: i32,
+        forecast_type: i32,
+    ) -> MyResult<Option<ForecastExplanation>> {
+        let q = format!(
+            r#"
+                SELECT rate_id, model_no, forecast_type, method, base_value, contributions
+                FROM {}
+                WHERE rate_id = :rate_id AND model_no = :model_no AND forecast_type = :forecast_type;
+            "#,
+            TABLE_NAME_FORECAST_EXPLANATIONS,
+        );
+        let p = params! {
+            "rate_id" => rate_id,
+            "model_no" => model_no,
+            "forecast_type" => forecast_type,
         };
-        log::debug!("query: {}, param: {}", q, m);
+        log::debug!(
+            "query: {}, rate_id: {}, model_no: {}, forecast_type: {}",
+            q,
+            rate_id,
+            model_no,
+            forecast_type
+        );
 
-        tx.exec_drop(q, p)?;
+        if let Some((rate_id, model_no, forecast_type, method, base_value, contributions_raw)) =
+            tx.exec_first(q, p)?
+        {
+            let Deserialized(contributions): Deserialized<Vec<FeatureContribution>> =
+                from_value(contributions_raw);
+            Ok(Some(ForecastExplanation {
+                rate_id,
+                model_no,
+                forecast_type,
+                explanation: Explanation {
+                    method,
+                    base_value,
+                    contributions,
+                },
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn delete_forecast_explanations_expired(&self, tx: &mut Transaction) -> MyResult<()> {
+        let q = format!(
+            r#"
+                DELETE FROM {} WHERE rate_id IN (
+                    SELECT id FROM {} WHERE expire < CURRENT_TIMESTAMP()
+                );
+            "#,
+            TABLE_NAME_FORECAST_EXPLANATIONS, TABLE_NAME_RATE_FOR_FORECAST
+        );
+        tx.query_drop(q)?;
 
         Ok(())
     }
 
-    fn copy_forecast_model(
+    // 未評価の予測結果について、予測対象時刻以降で最初に記録されたレートを実績値として記録する
+    fn insert_forecast_evaluations(
         &self,
         tx: &mut Transaction,
         pair: &str,
-        model_no_from: i32,
-        model_no_to: i32,
-    ) -> MyResult<()> {
+        forecast_type: i32,
+        offset_minutes: usize,
+    ) -> MyResult<u64> {
         let q = format!(
             r#"
-                INSERT INTO {0}
-                    (pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo)
-                SELECT
-                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo
-                FROM (
-                    SELECT
-                        pair, :model_no_to model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo
-                    FROM {0}
-                    WHERE pair = :pair AND model_no = :model_no_from
-                ) t
-                ON DUPLICATE KEY UPDATE
-                    model_type = t.model_type,
-                    model_data = t.model_data,
-                    input_data_size = t.input_data_size,
-                    feature_params = t.feature_params,
-                    feature_params_hash = t.feature_params_hash,
-                    performance_mse = t.performance_mse,
-                    performance_rmse = t.performance_rmse,
-                    memo = t.memo;
+                INSERT INTO {evaluations}
+                    (forecast_result_id, pair, model_no, forecast_type, forecast, actual, target_at)
+                SELECT fr.id, r.pair, fr.model_no, fr.forecast_type, fr.result, a.rate, a.recorded_at
+                FROM {results} fr
+                INNER JOIN {rates} r ON fr.rate_id = r.id
+                INNER JOIN {actuals} a ON a.pair = r.pair AND a.recorded_at = (
+                    SELECT MIN(t.recorded_at) FROM {actuals} t
+                    WHERE t.pair = r.pair AND t.recorded_at >= DATE_ADD(r.created_at, INTERVAL :offset_minutes MINUTE)
+                )
+                LEFT JOIN {evaluations} e ON e.forecast_result_id = fr.id
+                WHERE r.pair = :pair AND fr.forecast_type = :forecast_type AND e.id IS NULL;
             "#,
-            TABLE_NAME_FORECAST_MODEL
+            evaluations = TABLE_NAME_FORECAST_EVALUATIONS,
+            results = TABLE_NAME_FORECAST_RESULT,
+            rates = TABLE_NAME_RATE_FOR_FORECAST,
+            actuals = TABLE_NAME_RATE_FOR_TRAINING,
         );
         let p = params! {
             "pair" => pair,
-            "model_no_from" => model_no_from,
-            "model_no_to" => model_no_to,
+            "forecast_type" => forecast_type,
+            "offset_minutes" => offset_minutes,
         };
-
-        log::debug!("query: {}, {:?}", q, p);
-
+        log::debug!(
+            "query: {}, pair: {}, forecast_type: {}, offset_minutes: {}",
+            q,
+            pair,
+            forecast_type,
+            offset_minutes
+        );
         tx.exec_drop(q, p)?;
 
-        Ok(())
+        Ok(tx.affected_rows())
     }
 
-    fn select_forecast_model(
+    fn select_model_performance(
         &self,
         tx: &mut Transaction,
         pair: &str,
-        no: i32,
-    ) -> MyResult<Option<ForecastModel>> {
+        model_no: i32,
+        forecast_type: i32,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<ModelPerformance>> {
+        let mut conditions: Vec<String> = vec![];
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "target_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "target_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        let mut where_str =
+            "WHERE pair = :pair AND model_no = :model_no AND forecast_type = :forecast_type"
+                .to_string();
+        if !conditions.is_empty() {
+            where_str = format!("{} AND {}", where_str, conditions.join(" AND "));
+        };
+
         let q = format!(
             r#"
                 SELECT
-                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo, created_at, updated_at
+                    TIMESTAMP(DATE(target_at), MAKETIME(HOUR(target_at), 0, 0)) AS time,
+                    COUNT(*),
+                    AVG(ABS(forecast - actual)),
+                    AVG(POW(forecast - actual, 2)),
+                    SQRT(AVG(POW(forecast - actual, 2)))
                 FROM {}
-                WHERE
-                    pair = :pair AND model_no = :no;
+                {}
+                GROUP BY time
+                ORDER BY time;
             "#,
-            TABLE_NAME_FORECAST_MODEL
+            TABLE_NAME_FORECAST_EVALUATIONS, where_str,
         );
         let p = params! {
             "pair" => pair,
-            "no" => no,
+            "model_no" => model_no,
+            "forecast_type" => forecast_type,
         };
-        log::debug!("query: {}, pair: {}, no: {}", q, pair, no);
-
-        if let Some((
+        log::debug!(
+            "query: {}, pair: {}, model_no: {}, forecast_type: {}",
+            q,
             pair,
             model_no,
-            model_type,
-            model_data,
-            input_data_size,
-            feature_params_raw,
-            feature_params_hash,
-            performance_mse,
-            performance_rmse,
-            memo,
-            created_at,
-            updated_at,
-        )) = tx.exec_first(q, p)?
-        {
-            let Deserialized(feature_params_value): Deserialized<FeatureParamsValue> =
-                from_value(feature_params_raw);
-            let record = ForecastModelRecord {
-                pair,
-                model_no,
-                model_type,
-                model_data,
-                input_data_size,
-                feature_params: feature_params_value.to_domain()?,
-                feature_params_hash,
-                performance_mse,
-                performance_rmse,
-                memo,
-                created_at,
-                updated_at,
-            };
-            if let Err(err) = record.validate_feature_params() {
-                log::warn!("model not found, {}", err);
-                return Ok(None);
+            forecast_type
+        );
+
+        let mut performances: Vec<ModelPerformance> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (time, count, mae, mse, rmse) = from_row(row?);
+                performances.push(ModelPerformance {
+                    time,
+                    count,
+                    mae,
+                    mse,
+                    rmse,
+                });
             }
-            Ok(Some(record.to_domain()?))
-        } else {
-            Ok(None)
         }
+
+        Ok(performances)
     }
 
-    fn select_forecast_models(
+    // 実績値の記録日時（target_at）の範囲で取得する
+    fn select_forecast_evaluations_paged(
         &self,
         tx: &mut Transaction,
         pair: &str,
-    ) -> MyResult<Vec<ForecastModel>> {
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+        limit: u32,
+        offset: u32,
+    ) -> MyResult<Vec<ForecastEvaluation>> {
+        let mut conditions: Vec<String> = vec!["pair = :pair".to_string()];
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "target_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "target_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
         let q = format!(
             r#"
-                SELECT
-                    pair, model_no, model_type, model_data, input_data_size, feature_params, feature_params_hash, performance_mse, performance_rmse, memo, created_at, updated_at
+                SELECT id, forecast_result_id, pair, model_no, forecast_type, forecast, actual, target_at
                 FROM {}
-                WHERE
-                    pair = :pair
+                WHERE {}
+                ORDER BY target_at ASC, id
+                LIMIT :limit OFFSET :offset;
             "#,
-            TABLE_NAME_FORECAST_MODEL
+            TABLE_NAME_FORECAST_EVALUATIONS,
+            conditions.join(" AND "),
         );
         let p = params! {
             "pair" => pair,
+            "limit" => limit,
+            "offset" => offset,
         };
-        log::debug!("query: {}, pair: {}", q, pair);
+        log::debug!(
+            "query: {}, pair: {}, limit: {}, offset: {}",
+            q,
+            pair,
+            limit,
+            offset
+        );
 
-        let mut models: Vec<ForecastModel> = vec![];
+        let mut records: Vec<ForecastEvaluation> = vec![];
         let mut result = tx.exec_iter(q, p)?;
         while let Some(result_set) = result.next_set() {
             for row in result_set? {
                 let (
+                    id,
+                    forecast_result_id,
                     pair,
                     model_no,
-                    model_type,
-                    model_data,
-                    input_data_size,
-                    feature_params_raw,
-                    feature_params_hash,
-                    performance_mse,
-                    performance_rmse,
-                    memo,
-                    created_at,
-                    updated_at,
+                    forecast_type,
+                    forecast,
+                    actual,
+                    target_at,
                 ) = from_row(row?);
-                let Deserialized(feature_params_value): Deserialized<FeatureParamsValue> =
-                    from_value(feature_params_raw);
-                let record = ForecastModelRecord {
+                records.push(ForecastEvaluation {
+                    id,
+                    forecast_result_id,
                     pair,
                     model_no,
-                    model_type,
-                    model_data,
-                    input_data_size,
-                    feature_params: feature_params_value.to_domain()?,
-                    feature_params_hash,
-                    performance_mse,
-                    performance_rmse,
-                    memo,
-                    created_at,
-                    updated_at,
-                };
-                if let Err(err) = record.validate_feature_params() {
-                    log::warn!("model not found, {}", err);
-                    continue;
-                }
-                models.push(record.to_domain()?);
+                    forecast_type,
+                    forecast,
+                    actual,
+                    target_at,
+                });
             }
         }
-        Ok(models)
+
+        Ok(records)
     }
 
-    fn insert_rates_for_forecast(
+    // 実績値の記録日時が begin 以降の評価を集計期間ごとに集計し直す
+    // begin は集計期間の開始日時に揃えておくこと（途中からの場合はその期間の集計結果が欠ける）
+    fn upsert_forecast_accuracy_stats(
         &self,
         tx: &mut Transaction,
-        rate: &RateForForecast,
-    ) -> MyResult<String> {
-        let id: Option<String> = tx.query_first("SELECT UUID();")?;
-        tx.exec_drop(
-            format!(
-                "INSERT INTO {} (id, pair, histories, expire, memo) VALUES (:id, :pair, :histories, :expire, :memo);",
-                TABLE_NAME_RATE_FOR_FORECAST
-            ),
-            params! {
-                "id" => &id,
-                "pair" => &rate.pair,
-                "histories" => Serialized(&rate.histories),
-                "expire" => &rate.expire,
-                "memo" => &rate.memo,
-            },
-        )?;
-        Ok(id.unwrap())
+        bucket: AccuracyBucket,
+        begin: NaiveDateTime,
+    ) -> MyResult<u64> {
+        let bucket_at = match bucket {
+            AccuracyBucket::Hour => "TIMESTAMP(DATE(target_at), MAKETIME(HOUR(target_at), 0, 0))",
+            AccuracyBucket::Day => "TIMESTAMP(DATE(target_at))",
+        };
+        let q = format!(
+            r#"
+                INSERT INTO {stats}
+                    (pair, model_no, forecast_type, bucket, bucket_at, count, mae, mse, rmse, bias)
+                SELECT
+                    pair,
+                    model_no,
+                    forecast_type,
+                    :bucket,
+                    {bucket_at} AS bucket_at,
+                    COUNT(*),
+                    AVG(ABS(forecast - actual)),
+                    AVG(POW(forecast - actual, 2)),
+                    SQRT(AVG(POW(forecast - actual, 2))),
+                    AVG(forecast - actual)
+                FROM {evaluations}
+                WHERE target_at >= :begin
+                GROUP BY pair, model_no, forecast_type, bucket_at
+                ON DUPLICATE KEY UPDATE
+                    count = VALUES(count),
+                    mae = VALUES(mae),
+                    mse = VALUES(mse),
+                    rmse = VALUES(rmse),
+                    bias = VALUES(bias);
+            "#,
+            stats = TABLE_NAME_FORECAST_ACCURACY_STATS,
+            evaluations = TABLE_NAME_FORECAST_EVALUATIONS,
+            bucket_at = bucket_at,
+        );
+        let p = params! {
+            "bucket" => bucket.as_str(),
+            "begin" => begin.format("%Y-%m-%d %H:%M:%S").to_string(),
+        };
+        log::debug!(
+            "query: {}, bucket: {}, begin: {}",
+            q,
+            bucket.as_str(),
+            begin
+        );
+        tx.exec_drop(q, p)?;
+
+        Ok(tx.affected_rows())
     }
 
-    fn select_rates_for_forecast_unforecasted(
+    // 条件を指定しない項目（None）は全件を対象にする
+    fn select_forecast_accuracy_stats(
+        &self,
+        tx: &mut Transaction,
+        pair: Option<&str>,
+        model_no: Option<i32>,
+        forecast_type: Option<i32>,
+        bucket: AccuracyBucket,
+        begin: Option<NaiveDateTime>,
+        end: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<ForecastAccuracyStat>> {
+        let mut conditions: Vec<String> = vec!["bucket = :bucket".to_string()];
+        if pair.is_some() {
+            conditions.push("pair = :pair".to_string());
+        }
+        if model_no.is_some() {
+            conditions.push("model_no = :model_no".to_string());
+        }
+        if forecast_type.is_some() {
+            conditions.push("forecast_type = :forecast_type".to_string());
+        }
+        if let Some(value) = begin {
+            conditions.push(format!(
+                "bucket_at >= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        if let Some(value) = end {
+            conditions.push(format!(
+                "bucket_at <= '{}'",
+                value.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+
+        let q = format!(
+            r#"
+                SELECT pair, model_no, forecast_type, bucket_at, count, mae, mse, rmse, bias
+                FROM {}
+                WHERE {}
+                ORDER BY pair, model_no, forecast_type, bucket_at;
+            "#,
+            TABLE_NAME_FORECAST_ACCURACY_STATS,
+            conditions.join(" AND "),
+        );
+        let p = params! {
+            "bucket" => bucket.as_str(),
+            "pair" => pair.unwrap_or_default(),
+            "model_no" => model_no.unwrap_or_default(),
+            "forecast_type" => forecast_type.unwrap_or_default(),
+        };
+        log::debug!(
+            "query: {}, bucket: {}, pair: {:?}, model_no: {:?}, forecast_type: {:?}",
+            q,
+            bucket.as_str(),
+            pair,
+            model_no,
+            forecast_type
+        );
+
+        let mut stats: Vec<ForecastAccuracyStat> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, model_no, forecast_type, bucket_at, count, mae, mse, rmse, bias) =
+                    from_row::<(String, i32, i32, NaiveDateTime, i64, f64, f64, f64, f64)>(row?);
+                stats.push(ForecastAccuracyStat {
+                    pair,
+                    model_no,
+                    forecast_type,
+                    bucket_at,
+                    count,
+                    mae,
+                    mse,
+                    rmse,
+                    bias,
+                });
+            }
+        }
+
+        Ok(stats)
+    }
+
+    // 同じレートに対する2つのモデルの予想誤差を、実績値の記録日時が begin 以降のものについて取得する
+    // モデルを更新（昇格・再学習）する前の予想は比較に含めない
+    fn select_paired_forecast_errors(
         &self,
         tx: &mut Transaction,
         pair: &str,
-    ) -> MyResult<Vec<RateForForecast>> {
+        forecast_type: i32,
+        champion_no: i32,
+        challenger_no: i32,
+        begin: NaiveDateTime,
+    ) -> MyResult<Vec<PairedForecastError>> {
         let q = format!(
             r#"
-                WITH forecasted AS (
-                    SELECT DISTINCT rate_id FROM {}
-                )
-                SELECT f.id, f.pair, f.histories, f.expire, f.memo, f.created_at, f.updated_at
-                FROM {} f
-                LEFT OUTER JOIN forecasted ON f.id = forecasted.rate_id
-                WHERE
-                    f.pair = :pair AND forecasted.rate_id IS NULL
+                SELECT
+                    CAST(ce.forecast - ce.actual AS DOUBLE),
+                    CAST(te.forecast - te.actual AS DOUBLE)
+                FROM {evaluations} ce
+                INNER JOIN {results} cr ON cr.id = ce.forecast_result_id
+                INNER JOIN {results} tr
+                    ON tr.rate_id = cr.rate_id AND tr.forecast_type = cr.forecast_type AND tr.model_no = :challenger_no
+                INNER JOIN {evaluations} te ON te.forecast_result_id = tr.id
+                INNER JOIN {models} cm ON cm.pair = ce.pair AND cm.model_no = :champion_no
+                INNER JOIN {models} tm ON tm.pair = ce.pair AND tm.model_no = :challenger_no
+                WHERE ce.pair = :pair
+                    AND ce.model_no = :champion_no
+                    AND ce.forecast_type = :forecast_type
+                    AND ce.target_at >= :begin
+                    AND cr.created_at >= cm.updated_at
+                    AND tr.created_at >= tm.updated_at;
             "#,
-            TABLE_NAME_FORECAST_RESULT, TABLE_NAME_RATE_FOR_FORECAST,
+            evaluations = TABLE_NAME_FORECAST_EVALUATIONS,
+            results = TABLE_NAME_FORECAST_RESULT,
+            models = TABLE_NAME_FORECAST_MODEL,
         );
         let p = params! {
             "pair" => pair,
+            "forecast_type" => forecast_type,
+            "champion_no" => champion_no,
+            "challenger_no" => challenger_no,
+            "begin" => begin.format("%Y-%m-%d %H:%M:%S").to_string(),
         };
-        log::debug!("query: {}, pair: {}", q, pair);
+        log::debug!(
+            "query: {}, pair: {}, forecast_type: {}, champion_no: {}, challenger_no: {}, begin: {}",
+            q,
+            pair,
+            forecast_type,
+            champion_no,
+            challenger_no,
+            begin
+        );
 
-        let mut rates: Vec<RateForForecast> = vec![];
+        let mut errors: Vec<PairedForecastError> = vec![];
         let mut result = tx.exec_iter(q, p)?;
         while let Some(result_set) = result.next_set() {
             for row in result_set? {
-                let (id, pair, histories_raw, expire, memo, created_at, updated_at) =
-                    from_row(row?);
-                let Deserialized(histories): Deserialized<Vec<f64>> = from_value(histories_raw);
-                let record = RateForForecast {
-                    id,
-                    pair,
-                    histories,
-                    expire,
-                    memo,
-                    created_at,
-                    updated_at,
-                };
-                rates.push(record);
+                let (champion, challenger) = from_row::<(f64, f64)>(row?);
+                errors.push(PairedForecastError {
+                    champion,
+                    challenger,
+                });
             }
         }
-        Ok(rates)
+
+        Ok(errors)
     }
 
-    fn select_rates_for_forecast_by_id(
+    // 判定時刻（予想日時 + offset_minutes）以降のレートが記録済みで、未取引の予想を古い順に取得する
+    fn select_paper_trade_candidates(
         &self,
         tx: &mut Transaction,
-        id: &str,
-    ) -> MyResult<Option<RateForForecast>> {
+        pair: &str,
+        forecast_type: i32,
+        offset_minutes: usize,
+        limit: u32,
+    ) -> MyResult<Vec<PaperTradeCandidate>> {
         let q = format!(
             r#"
-                SELECT id, pair, histories, expire, memo, created_at, updated_at
-                FROM {}
-                WHERE id = :id AND expire >= CURRENT_TIMESTAMP();
+                SELECT fr.id, r.pair, fr.model_no, r.histories, fr.result, a.rate, r.created_at, a.recorded_at
+                FROM {results} fr
+                INNER JOIN {rates} r ON fr.rate_id = r.id
+                INNER JOIN {actuals} a ON a.pair = r.pair AND a.recorded_at = (
+                    SELECT MIN(t.recorded_at) FROM {actuals} t
+                    WHERE t.pair = r.pair AND t.recorded_at >= DATE_ADD(r.created_at, INTERVAL :offset_minutes MINUTE)
+                )
+                LEFT JOIN {trades} p ON p.forecast_result_id = fr.id
+                WHERE r.pair = :pair AND fr.forecast_type = :forecast_type AND p.id IS NULL
+                ORDER BY r.created_at ASC, fr.id
+                LIMIT :limit;
             "#,
-            TABLE_NAME_RATE_FOR_FORECAST,
+            results = TABLE_NAME_FORECAST_RESULT,
+            rates = TABLE_NAME_RATE_FOR_FORECAST,
+            actuals = TABLE_NAME_RATE_FOR_TRAINING,
+            trades = TABLE_NAME_PAPER_TRADES,
         );
         let p = params! {
-            "id" => id,
+            "pair" => pair,
+            "forecast_type" => forecast_type,
+            "offset_minutes" => offset_minutes,
+            "limit" => limit,
         };
-        log::debug!("query: {}, id: {}", q, id);
+        log::debug!(
+            "query: {}, pair: {}, forecast_type: {}, offset_minutes: {}, limit: {}",
+            q,
+            pair,
+            forecast_type,
+            offset_minutes,
+            limit
+        );
 
-        if let Some((id, pair, histories_raw, expire, memo, created_at, updated_at)) =
-            tx.exec_first(q, p)?
-        {
-            let Deserialized(histories) = from_value(histories_raw);
-            let record = RateForForecast {
-                id,
-                pair,
-                histories: histories,
-                expire,
-                memo,
-                created_at,
-                updated_at,
-            };
-            Ok(Some(record))
-        } else {
-            Ok(None)
+        let mut records: Vec<PaperTradeCandidate> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (
+                    forecast_result_id,
+                    pair,
+                    model_no,
+                    histories_raw,
+                    forecast,
+                    exit_rate,
+                    entry_at,
+                    exit_at,
+                ) = from_row(row?);
+                let Deserialized(histories): Deserialized<Vec<f64>> = from_value(histories_raw);
+                // 予想時点のレートが分からない予想は取引しない
+                if let Some(entry_rate) = histories.last() {
+                    records.push(PaperTradeCandidate {
+                        forecast_result_id,
+                        pair,
+                        model_no,
+                        entry_rate: *entry_rate,
+                        forecast,
+                        exit_rate,
+                        entry_at,
+                        exit_at,
+                    });
+                }
+            }
         }
-    }
-
-    fn delete_rates_for_forecast_expired(&self, tx: &mut Transaction) -> MyResult<()> {
-        let q = format!(
-            "DELETE FROM {} WHERE expire < CURRENT_TIMESTAMP();",
-            TABLE_NAME_RATE_FOR_FORECAST
-        );
-        tx.query_drop(q)?;
 
-        Ok(())
+        Ok(records)
     }
 
-    fn insert_forecast_results(
-        &self,
-        tx: &mut Transaction,
-        results: &Vec<ForecastResult>,
-    ) -> MyResult<()> {
+    fn insert_paper_trades(&self, tx: &mut Transaction, trades: &[PaperTrade]) -> MyResult<()> {
         tx.exec_batch(
             format!(
-                "INSERT INTO {} (rate_id, model_no, forecast_type, result, memo) VALUES (:rate_id, :model_no, :forecast_type, :result, :memo);",
-                TABLE_NAME_FORECAST_RESULT,
+                r#"
+                    INSERT INTO {}
+                        (forecast_result_id, pair, model_no, direction, entry_rate, forecast, exit_rate, stake, profit, entry_at, exit_at)
+                    VALUES
+                        (:forecast_result_id, :pair, :model_no, :direction, :entry_rate, :forecast, :exit_rate, :stake, :profit, :entry_at, :exit_at);
+                "#,
+                TABLE_NAME_PAPER_TRADES
             ),
-            results.iter().map(|result| {
+            trades.iter().map(|t| {
                 params! {
-                    "rate_id" => &result.rate_id,
-                    "model_no" => &result.model_no,
-                    "forecast_type" => &result.forecast_type,
-                    "result" => &result.result,
-                    "memo" => &result.memo,
+                    "forecast_result_id" => &t.forecast_result_id,
+                    "pair" => &t.pair,
+                    "model_no" => t.model_no,
+                    "direction" => t.direction,
+                    "entry_rate" => t.entry_rate,
+                    "forecast" => t.forecast,
+                    "exit_rate" => t.exit_rate,
+                    "stake" => t.stake,
+                    "profit" => t.profit,
+                    "entry_at" => t.entry_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "exit_at" => t.exit_at.format("%Y-%m-%d %H:%M:%S").to_string(),
                 }
             }),
         )?;
@@ -782,75 +3171,80 @@ impl Client for DefaultClient {
         Ok(())
     }
 
-    fn select_forecast_results_by_rate_id_and_model_no(
+    // 判定時刻が begin 以降の取引をモデルごとに集計する
+    fn select_paper_trade_summaries(
         &self,
         tx: &mut Transaction,
-        rate_id: &str,
-        model_no: i32,
-    ) -> MyResult<Option<ForecastResult>> {
+        begin: Option<NaiveDateTime>,
+    ) -> MyResult<Vec<PaperTradeSummary>> {
+        let condition = match begin {
+            Some(value) => format!("WHERE exit_at >= '{}'", value.format("%Y-%m-%d %H:%M:%S")),
+            None => "".to_string(),
+        };
         let q = format!(
             r#"
-                SELECT id, rate_id, model_no, forecast_type, result, memo, created_at, updated_at
+                SELECT
+                    pair,
+                    model_no,
+                    COUNT(*),
+                    CAST(SUM(direction <> 0) AS SIGNED),
+                    CAST(SUM(direction <> 0 AND profit > 0) AS SIGNED),
+                    CAST(SUM(direction <> 0 AND profit < 0) AS SIGNED),
+                    CAST(SUM(direction <> 0 AND profit = 0) AS SIGNED),
+                    CAST(SUM(profit) AS DOUBLE)
                 FROM {}
-                WHERE rate_id = :rate_id AND model_no = :model_no;
+                {}
+                GROUP BY pair, model_no
+                ORDER BY pair, model_no;
             "#,
-            TABLE_NAME_FORECAST_RESULT,
+            TABLE_NAME_PAPER_TRADES, condition,
         );
-        let p = params! {
-            "rate_id" => rate_id,
-            "model_no" => model_no,
-        };
-        log::debug!("query: {}, rate_id: {}, model_no: {}", q, rate_id, model_no);
+        log::debug!("query: {}", q);
 
-        if let Some((id, rate_id, model_no, forecast_type, result, memo, created_at, updated_at)) =
-            tx.exec_first(q, p)?
-        {
-            let record = ForecastResult {
-                id,
-                rate_id,
-                model_no,
-                forecast_type,
-                result,
-                memo,
-                created_at,
-                updated_at,
-            };
-            Ok(Some(record))
-        } else {
-            Ok(None)
+        let mut records: Vec<PaperTradeSummary> = vec![];
+        let mut result = tx.query_iter(q)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (pair, model_no, forecasts, trades, wins, losses, draws, total_profit) =
+                    from_row::<(String, i32, i64, i64, i64, i64, i64, f64)>(row?);
+                let decided = wins + losses;
+                records.push(PaperTradeSummary {
+                    pair,
+                    model_no,
+                    forecasts,
+                    trades,
+                    wins,
+                    losses,
+                    draws,
+                    win_rate: if decided > 0 {
+                        wins as f64 / decided as f64
+                    } else {
+                        0.0
+                    },
+                    total_profit,
+                });
+            }
         }
-    }
-
-    fn delete_forecast_results_expired(&self, tx: &mut Transaction) -> MyResult<()> {
-        let q = format!(
-            r#"
-                DELETE FROM {} WHERE rate_id IN (
-                    SELECT id FROM {} WHERE expire < CURRENT_TIMESTAMP()
-                );
-            "#,
-            TABLE_NAME_FORECAST_RESULT, TABLE_NAME_RATE_FOR_FORECAST
-        );
-        tx.query_drop(q)?;
 
-        Ok(())
+        Ok(records)
     }
 
-    fn insert_forecast_errors(
+    fn insert_training_datasets(
         &self,
         tx: &mut Transaction,
-        records: &Vec<ForecastError>,
+        datasets: &Vec<TrainingDataset>,
     ) -> MyResult<()> {
         tx.exec_batch(
             format!(
-                "INSERT INTO {} (rate_id, model_no, summary, detail) VALUES (:rate_id, :model_no, :summary, :detail);",
-                TABLE_NAME_FORECAST_ERRORS,
+                "INSERT INTO {} (pair, input_data, truth, memo) VALUES (:pair, :input_data, :truth, :memo);",
+                TABLE_NAME_TRAINING_DATASETS
             ),
-            records.iter().map(|record| {
+            datasets.iter().map(|dataset| {
                 params! {
-                    "rate_id" => &record.rate_id,
-                    "model_no" => &record.model_no,
-                    "summary" => &record.summary,
-                    "detail" => &record.detail,
+                    "pair" => &dataset.pair,
+                    "input_data" => Serialized(&dataset.input_data),
+                    "truth" => &dataset.truth,
+                    "memo" => &dataset.memo,
                 }
             }),
         )?;
@@ -858,33 +3252,70 @@ impl Client for DefaultClient {
         Ok(())
     }
 
-    fn select_forecast_errors_by_rate_id_and_model_no(
+    fn truncate_training_datasets(&self, tx: &mut Transaction) -> MyResult<()> {
+        let q = format!(" TRUNCATE TABLE {};", TABLE_NAME_TRAINING_DATASETS);
+        tx.query_drop(q)?;
+
+        Ok(())
+    }
+
+    fn insert_training_run(&self, tx: &mut Transaction, run: &TrainingRun) -> MyResult<String> {
+        let id: Option<String> = tx.query_first("SELECT UUID();")?;
+        tx.exec_drop(
+            format!(
+                "INSERT INTO {} (id, pair, status, memo) VALUES (:id, :pair, :status, :memo);",
+                TABLE_NAME_TRAINING_RUNS
+            ),
+            params! {
+                "id" => &id,
+                "pair" => &run.pair,
+                "status" => &run.status,
+                "memo" => &run.memo,
+            },
+        )?;
+        Ok(id.unwrap())
+    }
+
+    fn select_training_run_by_id(
         &self,
         tx: &mut Transaction,
-        rate_id: &str,
-        model_no: i32,
-    ) -> MyResult<Option<ForecastError>> {
+        id: &str,
+    ) -> MyResult<Option<TrainingRun>> {
         let q = format!(
             r#"
-                SELECT id, rate_id, model_no, summary, detail
+                SELECT id, pair, status, memo, result, started_at, finished_at, created_at, updated_at
                 FROM {}
-                WHERE rate_id = :rate_id AND model_no = :model_no;
+                WHERE id = :id;
             "#,
-            TABLE_NAME_FORECAST_ERRORS,
+            TABLE_NAME_TRAINING_RUNS,
         );
         let p = params! {
-            "rate_id" => rate_id,
-            "model_no" => model_no,
+            "id" => id,
         };
-        log::debug!("query: {}, rate_id: {}, model_no: {}", q, rate_id, model_no);
+        log::debug!("query: {}, id: {}", q, id);
 
-        if let Some((id, rate_id, model_no, summary, detail)) = tx.exec_first(q, p)? {
-            let record = ForecastError {
+        if let Some((
+            id,
+            pair,
+            status,
+            memo,
+            result,
+            started_at,
+            finished_at,
+            created_at,
+            updated_at,
+        )) = tx.exec_first(q, p)?
+        {
+            let record = TrainingRun {
                 id,
-                rate_id,
-                model_no,
-                summary,
-                detail,
+                pair,
+                status,
+                memo,
+                result,
+                started_at,
+                finished_at,
+                created_at,
+                updated_at,
             };
             Ok(Some(record))
         } else {
@@ -892,47 +3323,183 @@ impl Client for DefaultClient {
         }
     }
 
-    fn delete_forecast_errors_expired(&self, tx: &mut Transaction) -> MyResult<()> {
+    fn select_training_runs_requested(
+        &self,
+        tx: &mut Transaction,
+        pair: &str,
+    ) -> MyResult<Vec<TrainingRun>> {
         let q = format!(
             r#"
-                DELETE FROM {} WHERE rate_id IN (
-                    SELECT id FROM {} WHERE expire < CURRENT_TIMESTAMP()
-                );
+                SELECT id, pair, status, memo, result, started_at, finished_at, created_at, updated_at
+                FROM {}
+                WHERE pair = :pair AND status = :status
+                ORDER BY created_at
+                FOR UPDATE;
             "#,
-            TABLE_NAME_FORECAST_ERRORS, TABLE_NAME_RATE_FOR_FORECAST
+            TABLE_NAME_TRAINING_RUNS,
         );
-        tx.query_drop(q)?;
+        let p = params! {
+            "pair" => pair,
+            "status" => TRAINING_RUN_STATUS_REQUESTED,
+        };
+        log::debug!("query: {}, pair: {}", q, pair);
+
+        let mut runs: Vec<TrainingRun> = vec![];
+        let mut result = tx.exec_iter(q, p)?;
+        while let Some(result_set) = result.next_set() {
+            for row in result_set? {
+                let (
+                    id,
+                    pair,
+                    status,
+                    memo,
+                    result,
+                    started_at,
+                    finished_at,
+                    created_at,
+                    updated_at,
+                ) = from_row(row?);
+                runs.push(TrainingRun {
+                    id,
+                    pair,
+                    status,
+                    memo,
+                    result,
+                    started_at,
+                    finished_at,
+                    created_at,
+                    updated_at,
+                });
+            }
+        }
+        Ok(runs)
+    }
+
+    fn update_training_run_started(&self, tx: &mut Transaction, id: &str) -> MyResult<()> {
+        let q = format!(
+            "UPDATE {} SET status = :status, started_at = CURRENT_TIMESTAMP() WHERE id = :id;",
+            TABLE_NAME_TRAINING_RUNS
+        );
+        let p = params! {
+            "id" => id,
+            "status" => TRAINING_RUN_STATUS_RUNNING,
+        };
+        log::debug!("query: {}, id: {}", q, id);
+        tx.exec_drop(q, p)?;
 
         Ok(())
     }
 
-    fn insert_training_datasets(
+    fn update_training_run_finished(
         &self,
         tx: &mut Transaction,
-        datasets: &Vec<TrainingDataset>,
+        id: &str,
+        status: u8,
+        result: &str,
     ) -> MyResult<()> {
-        tx.exec_batch(
-            format!(
-                "INSERT INTO {} (pair, input_data, truth, memo) VALUES (:pair, :input_data, :truth, :memo);",
-                TABLE_NAME_TRAINING_DATASETS
-            ),
-            datasets.iter().map(|dataset| {
-                params! {
-                    "pair" => &dataset.pair,
-                    "input_data" => Serialized(&dataset.input_data),
-                    "truth" => &dataset.truth,
-                    "memo" => &dataset.memo,
-                }
-            }),
-        )?;
+        let q = format!(
+            "UPDATE {} SET status = :status, result = :result, finished_at = CURRENT_TIMESTAMP() WHERE id = :id;",
+            TABLE_NAME_TRAINING_RUNS
+        );
+        let p = params! {
+            "id" => id,
+            "status" => status,
+            "result" => result,
+        };
+        log::debug!("query: {}, id: {}, status: {}", q, id, status);
+        tx.exec_drop(q, p)?;
 
         Ok(())
     }
+}
 
-    fn truncate_training_datasets(&self, tx: &mut Transaction) -> MyResult<()> {
-        let q = format!(" TRUNCATE TABLE {};", TABLE_NAME_TRAINING_DATASETS);
-        tx.query_drop(q)?;
+fn select_job_requests_with(
+    tx: &mut Transaction,
+    q: String,
+    p: mysql::Params,
+) -> MyResult<Vec<JobRequest>> {
+    let mut requests: Vec<JobRequest> = vec![];
+    let mut result = tx.exec_iter(q, p)?;
+    while let Some(result_set) = result.next_set() {
+        for row in result_set? {
+            let (
+                id,
+                job_name,
+                status,
+                attempts,
+                max_attempts,
+                owner,
+                message,
+                available_at,
+                started_at,
+                finished_at,
+                created_at,
+                updated_at,
+            ) = from_row(row?);
+            requests.push(JobRequest {
+                id,
+                job_name,
+                status,
+                attempts,
+                max_attempts,
+                owner,
+                message,
+                available_at,
+                started_at,
+                finished_at,
+                created_at,
+                updated_at,
+            });
+        }
+    }
+    Ok(requests)
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::error::MyError;
+
+    #[test]
+    fn test_for_retry_transient() {
+        let options = PoolOptions {
+            retry_attempts: 3,
+            retry_backoff: Duration::from_millis(0),
+            ..PoolOptions::default()
+        };
+
+        // 一時的なエラーは最大試行回数まで再試行する
+        let count = Cell::new(0);
+        let result: MyResult<()> = retry_transient(&options, "test", || {
+            count.set(count.get() + 1);
+            Err(std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused").into())
+        });
+        assert!(result.is_err());
+        assert_eq!(count.get(), 3);
+
+        // 再試行中に成功した場合はその結果を返す
+        let count = Cell::new(0);
+        let result = retry_transient(&options, "test", || {
+            count.set(count.get() + 1);
+            if count.get() < 2 {
+                Err(std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused").into())
+            } else {
+                Ok(count.get())
+            }
+        });
+        assert_eq!(result.unwrap(), 2);
+
+        // 一時的でないエラーは再試行しない
+        let count = Cell::new(0);
+        let result: MyResult<()> = retry_transient(&options, "test", || {
+            count.set(count.get() + 1);
+            Err(MyError::ArrayIsEmpty {
+                name: "rates".to_string(),
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(count.get(), 1);
     }
 }
@@ -12,7 +12,10 @@ use smartcore::{
 };
 
 use crate::{
-    domain::{self, model::FeatureParams},
+    domain::{
+        self, boosting::GradientBoostingRegressor, ensemble::EnsembleModel, model::FeatureParams,
+        quantile::QuantileRegression, scaler::FeatureScaler,
+    },
     error::{MyError, MyResult},
 };
 
@@ -24,6 +27,9 @@ pub const MODEL_TYPE_LASSO: u8 = 4;
 pub const MODEL_TYPE_ELASTIC_NET: u8 = 5;
 pub const MODEL_TYPE_LOGISTIC: u8 = 6;
 pub const MODEL_TYPE_SVR: u8 = 7;
+pub const MODEL_TYPE_QUANTILE: u8 = 8;
+pub const MODEL_TYPE_GRADIENT_BOOSTING: u8 = 9;
+pub const MODEL_TYPE_ENSEMBLE: u8 = 10;
 
 #[derive(Debug, Clone)]
 pub struct ForecastModelRecord {
@@ -46,10 +52,10 @@ impl ForecastModelRecord {
         if self.feature_params.to_hash()? == self.feature_params_hash {
             return Ok(());
         }
-        Err(Box::new(MyError::UnmatchFeatureParamsHash {
+        Err(MyError::UnmatchFeatureParamsHash {
             pair: self.pair.to_string(),
             model_no: self.model_no,
-        }))
+        })
     }
 
     pub fn to_domain(&self) -> MyResult<domain::model::ForecastModel> {
@@ -144,11 +150,394 @@ impl ForecastModelRecord {
                 performance_rmse: self.performance_rmse,
                 memo: self.memo.clone(),
             }),
-            _ => Err(Box::new(MyError::UnknownModelType {
+            MODEL_TYPE_QUANTILE => Ok(domain::model::ForecastModel::Quantile {
+                pair: self.pair.clone(),
+                no: self.model_no,
+                model: bincode::deserialize::<QuantileRegression>(&self.model_data)?,
+                input_data_size: self.input_data_size,
+                feature_params: self.feature_params.clone(),
+                performance_mse: self.performance_mse,
+                performance_rmse: self.performance_rmse,
+                memo: self.memo.clone(),
+            }),
+            MODEL_TYPE_GRADIENT_BOOSTING => Ok(domain::model::ForecastModel::GradientBoosting {
+                pair: self.pair.clone(),
+                no: self.model_no,
+                model: bincode::deserialize::<GradientBoostingRegressor>(&self.model_data)?,
+                input_data_size: self.input_data_size,
+                feature_params: self.feature_params.clone(),
+                performance_mse: self.performance_mse,
+                performance_rmse: self.performance_rmse,
+                memo: self.memo.clone(),
+            }),
+            MODEL_TYPE_ENSEMBLE => Ok(domain::model::ForecastModel::Ensemble {
+                pair: self.pair.clone(),
+                no: self.model_no,
+                model: bincode::deserialize::<EnsembleModel>(&self.model_data)?,
+                input_data_size: self.input_data_size,
+                feature_params: self.feature_params.clone(),
+                performance_mse: self.performance_mse,
+                performance_rmse: self.performance_rmse,
+                memo: self.memo.clone(),
+            }),
+            _ => Err(MyError::UnknownModelType {
                 value: self.model_type,
-            })),
+            }),
+        }
+    }
+}
+
+// モデルの種類の値
+pub fn model_type_of(m: &domain::model::ForecastModel) -> u8 {
+    match m {
+        domain::model::ForecastModel::RandomForest { .. } => MODEL_TYPE_RANDOM_FOREST,
+        domain::model::ForecastModel::KNN { .. } => MODEL_TYPE_KNN,
+        domain::model::ForecastModel::Linear { .. } => MODEL_TYPE_LINEAR,
+        domain::model::ForecastModel::Ridge { .. } => MODEL_TYPE_RIDGE,
+        domain::model::ForecastModel::LASSO { .. } => MODEL_TYPE_LASSO,
+        domain::model::ForecastModel::ElasticNet { .. } => MODEL_TYPE_ELASTIC_NET,
+        domain::model::ForecastModel::Logistic { .. } => MODEL_TYPE_LOGISTIC,
+        domain::model::ForecastModel::SVR { .. } => MODEL_TYPE_SVR,
+        domain::model::ForecastModel::Quantile { .. } => MODEL_TYPE_QUANTILE,
+        domain::model::ForecastModel::GradientBoosting { .. } => MODEL_TYPE_GRADIENT_BOOSTING,
+        domain::model::ForecastModel::Ensemble { .. } => MODEL_TYPE_ENSEMBLE,
+    }
+}
+
+// DBに保存するモデルの内容をファイルに書き出すための形式（bincode で保存する）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForecastModelBlob {
+    pub pair: String,
+    pub model_no: i32,
+    pub model_type: u8,
+    pub model_data: Vec<u8>,
+    pub input_data_size: usize,
+    pub feature_params: FeatureParams,
+    pub feature_params_hash: String,
+    pub performance_mse: f64,
+    pub performance_rmse: f64,
+    pub memo: String,
+}
+
+impl ForecastModelBlob {
+    pub fn from_record(r: &ForecastModelRecord) -> Self {
+        ForecastModelBlob {
+            pair: r.pair.clone(),
+            model_no: r.model_no,
+            model_type: r.model_type,
+            model_data: r.model_data.clone(),
+            input_data_size: r.input_data_size,
+            feature_params: r.feature_params.clone(),
+            feature_params_hash: r.feature_params_hash.clone(),
+            performance_mse: r.performance_mse,
+            performance_rmse: r.performance_rmse,
+            memo: r.memo.clone(),
+        }
+    }
+
+    pub fn from_domain(m: &domain::model::ForecastModel) -> MyResult<Self> {
+        let feature_params = m.get_feature_params()?;
+        Ok(ForecastModelBlob {
+            pair: m.get_pair()?,
+            model_no: m.get_no()?,
+            model_type: model_type_of(m),
+            model_data: m.serialize_model_data()?,
+            input_data_size: m.get_input_data_size()?,
+            feature_params_hash: feature_params.to_hash()?,
+            feature_params,
+            performance_mse: m.get_performance_mse(),
+            performance_rmse: m.get_performance_rmse(),
+            memo: m.get_memo(),
+        })
+    }
+
+    pub fn serialize(&self) -> MyResult<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> MyResult<Self> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+
+    // バージョンを付けずに書き出した内容を読む
+    // どのバージョンの形式か分からないため新しい形式から順に読み、モデルを復元できた形式を使う
+    pub fn deserialize_unversioned(bytes: &[u8]) -> MyResult<Self> {
+        let current = ForecastModelBlob::deserialize(bytes);
+        if let Ok(blob) = &current {
+            if blob.to_domain().is_ok() {
+                return current;
+            }
+        }
+        let legacy = bincode::deserialize::<ForecastModelBlobV2>(bytes)
+            .map(|b| b.migrate())
+            .into_iter()
+            .chain(bincode::deserialize::<ForecastModelBlobV1>(bytes).map(|b| b.migrate()));
+        for blob in legacy {
+            if blob.to_domain().is_ok() {
+                return Ok(blob);
+            }
+        }
+        current
+    }
+
+    // 特徴量の設定が書き出した時点と異なる場合はエラーとする
+    pub fn to_domain(&self) -> MyResult<domain::model::ForecastModel> {
+        // 作成日時・更新日時はモデルの復元には使わない
+        let now = chrono::Utc::now().naive_utc();
+        let record = ForecastModelRecord {
+            pair: self.pair.clone(),
+            model_no: self.model_no,
+            model_type: self.model_type,
+            model_data: self.model_data.clone(),
+            input_data_size: self.input_data_size,
+            feature_params: self.feature_params.clone(),
+            feature_params_hash: self.feature_params_hash.clone(),
+            performance_mse: self.performance_mse,
+            performance_rmse: self.performance_rmse,
+            memo: self.memo.clone(),
+            created_at: now,
+            updated_at: now,
+        };
+        record.validate_feature_params()?;
+        record.to_domain()
+    }
+}
+
+// モデルを書き出すファイルの形式のバージョン（1件ずつ書き出す場合も同じ）
+// 2: 特徴量の設定に RSI・ストキャスティクスの期間を追加
+// 3: 特徴量の設定に尺度の変換を追加
+pub const FORECAST_MODEL_ARCHIVE_VERSION: u32 = 3;
+
+// 先頭の version を読む
+fn read_archive_version(bytes: &[u8]) -> MyResult<u32> {
+    Ok(bincode::deserialize(bytes)?)
+}
+
+// 先頭の version を読み、現在のバージョンでない場合はエラーとする
+fn check_archive_version(bytes: &[u8]) -> MyResult<()> {
+    let version = read_archive_version(bytes)?;
+    if version != FORECAST_MODEL_ARCHIVE_VERSION {
+        return Err(MyError::UnsupportedModelArchiveVersion { version });
+    }
+    Ok(())
+}
+
+// 以前のバージョンで書き出した形式（読み込み時に現在の形式へ変換する）
+// bincode は項目名を保存せず並び順で読むため、書き出した時点の項目のまま変更しない
+
+// バージョン1の特徴量の設定（RSI・ストキャスティクスの追加前）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct FeatureParamsV1 {
+    feature_size: usize,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    bb_period: usize,
+}
+
+impl FeatureParamsV1 {
+    // 追加した設定は使わない（追加前と同じ特徴量になる）
+    fn migrate(self) -> FeatureParams {
+        FeatureParams {
+            feature_size: self.feature_size,
+            fast_period: self.fast_period,
+            slow_period: self.slow_period,
+            signal_period: self.signal_period,
+            bb_period: self.bb_period,
+            ..FeatureParams::new_default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ForecastModelBlobV1 {
+    pair: String,
+    model_no: i32,
+    model_type: u8,
+    model_data: Vec<u8>,
+    input_data_size: usize,
+    feature_params: FeatureParamsV1,
+    feature_params_hash: String,
+    performance_mse: f64,
+    performance_rmse: f64,
+    memo: String,
+}
+
+impl ForecastModelBlobV1 {
+    // 使わない設定はハッシュに含めないため、feature_params_hash はそのまま使える
+    fn migrate(self) -> ForecastModelBlob {
+        ForecastModelBlob {
+            pair: self.pair,
+            model_no: self.model_no,
+            model_type: self.model_type,
+            model_data: self.model_data,
+            input_data_size: self.input_data_size,
+            feature_params: self.feature_params.migrate(),
+            feature_params_hash: self.feature_params_hash,
+            performance_mse: self.performance_mse,
+            performance_rmse: self.performance_rmse,
+            memo: self.memo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ForecastModelArchiveV1 {
+    version: u32,
+    pair: String,
+    exported_at: chrono::NaiveDateTime,
+    models: Vec<ForecastModelBlobV1>,
+}
+
+// バージョン2の特徴量の設定（尺度の変換の追加前）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct FeatureParamsV2 {
+    feature_size: usize,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    bb_period: usize,
+    rsi_period: usize,
+    stoch_period: usize,
+    stoch_d_period: usize,
+}
+
+impl FeatureParamsV2 {
+    // 尺度は変換しない（追加前と同じ特徴量になる）
+    fn migrate(self) -> FeatureParams {
+        FeatureParams {
+            feature_size: self.feature_size,
+            fast_period: self.fast_period,
+            slow_period: self.slow_period,
+            signal_period: self.signal_period,
+            bb_period: self.bb_period,
+            rsi_period: self.rsi_period,
+            stoch_period: self.stoch_period,
+            stoch_d_period: self.stoch_d_period,
+            scaler: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ForecastModelBlobV2 {
+    pair: String,
+    model_no: i32,
+    model_type: u8,
+    model_data: Vec<u8>,
+    input_data_size: usize,
+    feature_params: FeatureParamsV2,
+    feature_params_hash: String,
+    performance_mse: f64,
+    performance_rmse: f64,
+    memo: String,
+}
+
+impl ForecastModelBlobV2 {
+    // 使わない設定はハッシュに含めないため、feature_params_hash はそのまま使える
+    fn migrate(self) -> ForecastModelBlob {
+        ForecastModelBlob {
+            pair: self.pair,
+            model_no: self.model_no,
+            model_type: self.model_type,
+            model_data: self.model_data,
+            input_data_size: self.input_data_size,
+            feature_params: self.feature_params.migrate(),
+            feature_params_hash: self.feature_params_hash,
+            performance_mse: self.performance_mse,
+            performance_rmse: self.performance_rmse,
+            memo: self.memo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ForecastModelArchiveV2 {
+    version: u32,
+    pair: String,
+    exported_at: chrono::NaiveDateTime,
+    models: Vec<ForecastModelBlobV2>,
+}
+
+// モデルを1件ずつ書き出すための形式（環境間でモデルを移す）
+// 先頭の version で形式を判断できるよう、version は先頭のフィールドのままにする
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForecastModelFile {
+    pub version: u32,
+    pub exported_at: chrono::NaiveDateTime,
+    pub model: ForecastModelBlob,
+}
+
+impl ForecastModelFile {
+    pub fn new(model: ForecastModelBlob) -> Self {
+        ForecastModelFile {
+            version: FORECAST_MODEL_ARCHIVE_VERSION,
+            exported_at: chrono::Utc::now().naive_utc(),
+            model,
+        }
+    }
+
+    pub fn serialize(&self) -> MyResult<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    // 対応していないバージョンの場合は、内容を読む前にエラーとする
+    pub fn deserialize(bytes: &[u8]) -> MyResult<Self> {
+        check_archive_version(bytes)?;
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
+
+// 通貨ペアの全モデルをまとめて書き出すための形式（環境の再構築時に再学習せずに復元する）
+// 先頭の version で形式を判断できるよう、version は先頭のフィールドのままにする
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForecastModelArchive {
+    pub version: u32,
+    pub pair: String,
+    pub exported_at: chrono::NaiveDateTime,
+    pub models: Vec<ForecastModelBlob>,
+}
+
+impl ForecastModelArchive {
+    pub fn new(pair: &str, models: Vec<ForecastModelBlob>) -> Self {
+        ForecastModelArchive {
+            version: FORECAST_MODEL_ARCHIVE_VERSION,
+            pair: pair.to_string(),
+            exported_at: chrono::Utc::now().naive_utc(),
+            models,
+        }
+    }
+
+    pub fn serialize(&self) -> MyResult<Vec<u8>> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    // 以前のバージョンの場合は現在の形式に変換し、対応していないバージョンの場合は内容を読む前にエラーとする
+    pub fn deserialize(bytes: &[u8]) -> MyResult<Self> {
+        let v1 = |a: ForecastModelArchiveV1| ForecastModelArchive {
+            version: FORECAST_MODEL_ARCHIVE_VERSION,
+            pair: a.pair,
+            exported_at: a.exported_at,
+            models: a.models.into_iter().map(|m| m.migrate()).collect(),
+        };
+        let v2 = |a: ForecastModelArchiveV2| ForecastModelArchive {
+            version: FORECAST_MODEL_ARCHIVE_VERSION,
+            pair: a.pair,
+            exported_at: a.exported_at,
+            models: a.models.into_iter().map(|m| m.migrate()).collect(),
+        };
+        match read_archive_version(bytes)? {
+            1 => Ok(v1(bincode::deserialize(bytes)?)),
+            2 => Ok(v2(bincode::deserialize(bytes)?)),
+            FORECAST_MODEL_ARCHIVE_VERSION => Ok(bincode::deserialize(bytes)?),
+            version => Err(MyError::UnsupportedModelArchiveVersion { version }),
         }
     }
+
+    // 全モデルを復元する（1件でも復元できない場合はエラーとする）
+    pub fn to_domain(&self) -> MyResult<Vec<domain::model::ForecastModel>> {
+        self.models.iter().map(|m| m.to_domain()).collect()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -158,6 +547,10 @@ pub struct FeatureParamsValue {
     pub slow_period: Option<usize>,
     pub signal_period: Option<usize>,
     pub bb_period: Option<usize>,
+    pub rsi_period: Option<usize>,
+    pub stoch_period: Option<usize>,
+    pub stoch_d_period: Option<usize>,
+    pub scaler: Option<FeatureScaler>,
 }
 
 impl FeatureParamsValue {
@@ -179,7 +572,223 @@ impl FeatureParamsValue {
         if let Some(v) = self.bb_period {
             m.bb_period = v;
         }
+        if let Some(v) = self.rsi_period {
+            m.rsi_period = v;
+        }
+        if let Some(v) = self.stoch_period {
+            m.stoch_period = v;
+        }
+        if let Some(v) = self.stoch_d_period {
+            m.stoch_d_period = v;
+        }
+        m.scaler = self.scaler.clone();
 
         Ok(m)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{forecast_model, TEST_PAIR};
+
+    #[test]
+    fn test_for_forecast_model_blob() {
+        let m = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let bytes = ForecastModelBlob::from_domain(&m)
+            .unwrap()
+            .serialize()
+            .unwrap();
+
+        let mut blob = ForecastModelBlob::deserialize(&bytes).unwrap();
+        assert_eq!(blob.model_type, MODEL_TYPE_KNN);
+        let restored = blob.to_domain().unwrap();
+        assert_eq!(restored.get_pair().unwrap(), TEST_PAIR);
+        assert_eq!(restored.get_input_data_size().unwrap(), 30);
+        assert_eq!(restored.get_performance_mse(), m.get_performance_mse());
+
+        blob.feature_params.bb_period += 1;
+        assert!(blob.to_domain().is_err());
+    }
+
+    #[test]
+    fn test_for_feature_params_hash() {
+        // RSI・ストキャスティクスを使わない場合は、追加前に登録したモデルのハッシュと一致する
+        let mut p = FeatureParams::new_default();
+        assert_eq!(
+            p.to_hash().unwrap(),
+            "2b7eba975ca6cedfb477b460a36400571cbec33ff0e887eeebf133f5a4c3017d"
+        );
+
+        let before = p.to_hash().unwrap();
+        p.rsi_period = 6;
+        assert_ne!(p.to_hash().unwrap(), before);
+        // 尺度の変換を使わない場合は、変換の追加前に登録したモデルのハッシュと一致する
+        assert_eq!(
+            p.to_hash().unwrap(),
+            "48ca21cd2274666d48a852fd6e957682420376d1169a7799ab917e1f93fa1183"
+        );
+
+        let before = p.to_hash().unwrap();
+        p.scaler = Some(FeatureScaler {
+            method: "zscore".to_string(),
+            offsets: vec![100.0],
+            scales: vec![2.0],
+        });
+        assert_ne!(p.to_hash().unwrap(), before);
+    }
+
+    #[test]
+    fn test_for_forecast_model_file() {
+        let m = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let file = ForecastModelFile::new(ForecastModelBlob::from_domain(&m).unwrap());
+        let bytes = file.serialize().unwrap();
+
+        let file = ForecastModelFile::deserialize(&bytes).unwrap();
+        assert_eq!(file.version, FORECAST_MODEL_ARCHIVE_VERSION);
+        let restored = file.model.to_domain().unwrap();
+        assert_eq!(restored.get_no().unwrap(), 1);
+        assert_eq!(restored.get_performance_mse(), m.get_performance_mse());
+
+        // 対応していないバージョン
+        let mut file = file;
+        file.version = FORECAST_MODEL_ARCHIVE_VERSION + 1;
+        let bytes = file.serialize().unwrap();
+        assert!(matches!(
+            ForecastModelFile::deserialize(&bytes),
+            Err(MyError::UnsupportedModelArchiveVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_for_forecast_model_archive() {
+        let models: Vec<ForecastModelBlob> = [1, 2]
+            .iter()
+            .map(|no| {
+                ForecastModelBlob::from_domain(&forecast_model(TEST_PAIR, *no, 30).unwrap())
+                    .unwrap()
+            })
+            .collect();
+        let bytes = ForecastModelArchive::new(TEST_PAIR, models)
+            .serialize()
+            .unwrap();
+
+        let archive = ForecastModelArchive::deserialize(&bytes).unwrap();
+        assert_eq!(archive.version, FORECAST_MODEL_ARCHIVE_VERSION);
+        assert_eq!(archive.pair, TEST_PAIR);
+        let models = archive.to_domain().unwrap();
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[1].get_no().unwrap(), 2);
+
+        // 対応していないバージョン
+        let mut archive = archive;
+        archive.version = FORECAST_MODEL_ARCHIVE_VERSION + 1;
+        let bytes = archive.serialize().unwrap();
+        match ForecastModelArchive::deserialize(&bytes) {
+            Err(MyError::UnsupportedModelArchiveVersion { version }) => {
+                assert_eq!(version, FORECAST_MODEL_ARCHIVE_VERSION + 1)
+            }
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_for_forecast_model_archive_v1() {
+        // バージョン1で書き出したアーカイブ（RSI・ストキャスティクスの追加前の特徴量の設定）
+        let blob =
+            ForecastModelBlob::from_domain(&forecast_model(TEST_PAIR, 1, 30).unwrap()).unwrap();
+        let p = &blob.feature_params;
+        let v1 = ForecastModelArchiveV1 {
+            version: 1,
+            pair: TEST_PAIR.to_string(),
+            exported_at: chrono::Utc::now().naive_utc(),
+            models: vec![ForecastModelBlobV1 {
+                pair: blob.pair.clone(),
+                model_no: blob.model_no,
+                model_type: blob.model_type,
+                model_data: blob.model_data.clone(),
+                input_data_size: blob.input_data_size,
+                feature_params: FeatureParamsV1 {
+                    feature_size: p.feature_size,
+                    fast_period: p.fast_period,
+                    slow_period: p.slow_period,
+                    signal_period: p.signal_period,
+                    bb_period: p.bb_period,
+                },
+                feature_params_hash: blob.feature_params_hash.clone(),
+                performance_mse: blob.performance_mse,
+                performance_rmse: blob.performance_rmse,
+                memo: blob.memo.clone(),
+            }],
+        };
+        let bytes = bincode::serialize(&v1).unwrap();
+
+        // バージョンを付けずに書き出したモデル
+        let raw = bincode::serialize(&v1.models[0]).unwrap();
+        let b = ForecastModelBlob::deserialize_unversioned(&raw).unwrap();
+        assert_eq!(b.feature_params.bb_period, p.bb_period);
+        assert!(b.to_domain().is_ok());
+
+        let archive = ForecastModelArchive::deserialize(&bytes).unwrap();
+        assert_eq!(archive.version, FORECAST_MODEL_ARCHIVE_VERSION);
+        assert_eq!(archive.pair, TEST_PAIR);
+        assert_eq!(archive.models[0].feature_params.rsi_period, 0);
+        let models = archive.to_domain().unwrap();
+        assert_eq!(models[0].get_no().unwrap(), 1);
+        assert_eq!(models[0].get_input_data_size().unwrap(), 30);
+    }
+
+    #[test]
+    fn test_for_forecast_model_archive_v2() {
+        // バージョン2で書き出したアーカイブ（尺度の変換の追加前の特徴量の設定）
+        let mut blob =
+            ForecastModelBlob::from_domain(&forecast_model(TEST_PAIR, 1, 30).unwrap()).unwrap();
+        blob.feature_params.rsi_period = 6;
+        blob.feature_params_hash = blob.feature_params.to_hash().unwrap();
+        let p = &blob.feature_params;
+        let v2 = ForecastModelArchiveV2 {
+            version: 2,
+            pair: TEST_PAIR.to_string(),
+            exported_at: chrono::Utc::now().naive_utc(),
+            models: vec![ForecastModelBlobV2 {
+                pair: blob.pair.clone(),
+                model_no: blob.model_no,
+                model_type: blob.model_type,
+                model_data: blob.model_data.clone(),
+                input_data_size: blob.input_data_size,
+                feature_params: FeatureParamsV2 {
+                    feature_size: p.feature_size,
+                    fast_period: p.fast_period,
+                    slow_period: p.slow_period,
+                    signal_period: p.signal_period,
+                    bb_period: p.bb_period,
+                    rsi_period: p.rsi_period,
+                    stoch_period: p.stoch_period,
+                    stoch_d_period: p.stoch_d_period,
+                },
+                feature_params_hash: blob.feature_params_hash.clone(),
+                performance_mse: blob.performance_mse,
+                performance_rmse: blob.performance_rmse,
+                memo: blob.memo.clone(),
+            }],
+        };
+        let bytes = bincode::serialize(&v2).unwrap();
+
+        // バージョンを付けずに書き出したモデル
+        for raw in [
+            bincode::serialize(&v2.models[0]).unwrap(),
+            blob.serialize().unwrap(),
+        ] {
+            let b = ForecastModelBlob::deserialize_unversioned(&raw).unwrap();
+            assert_eq!(b.feature_params.rsi_period, 6);
+            assert!(b.to_domain().is_ok());
+        }
+
+        let archive = ForecastModelArchive::deserialize(&bytes).unwrap();
+        assert_eq!(archive.version, FORECAST_MODEL_ARCHIVE_VERSION);
+        assert_eq!(archive.models[0].feature_params.rsi_period, 6);
+        assert!(archive.models[0].feature_params.scaler.is_none());
+        let models = archive.to_domain().unwrap();
+        assert_eq!(models[0].get_feature_params().unwrap().rsi_period, 6);
+    }
+}
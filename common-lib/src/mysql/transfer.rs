@@ -0,0 +1,84 @@
+use std::{fs, path::Path};
+
+use crate::error::{MyError, MyResult};
+
+use super::{
+    client::{Client, DefaultClient},
+    model::{ForecastModelBlob, ForecastModelFile},
+};
+
+// 登録済みのモデルを、特徴量の設定と性能を含めてファイルに書き出す（ステージング・本番の間でモデルを移す）
+pub fn export_model(
+    mysql_cli: &DefaultClient,
+    pair: &str,
+    model_no: i32,
+    path: &Path,
+) -> MyResult<ForecastModelFile> {
+    let model =
+        mysql_cli.with_transaction(|tx| mysql_cli.select_forecast_model(tx, pair, model_no))?;
+    let model = model.ok_or_else(|| MyError::ForecastModelNotFound {
+        pair: pair.to_string(),
+        model_no,
+    })?;
+
+    let file = ForecastModelFile::new(ForecastModelBlob::from_domain(&model)?);
+    fs::write(path, file.serialize()?)?;
+    Ok(file)
+}
+
+// ファイルから読み込んだモデルを登録する（同じ通貨ペア・番号のモデルは上書きする）
+// pair, model_no を指定した場合はファイル内の値の代わりに使う
+pub fn import_model(
+    mysql_cli: &DefaultClient,
+    path: &Path,
+    pair: Option<&str>,
+    model_no: Option<i32>,
+) -> MyResult<ForecastModelFile> {
+    let mut file = read_model_file(&fs::read(path)?)?;
+    if let Some(p) = pair {
+        file.model.pair = p.to_string();
+    }
+    if let Some(n) = model_no {
+        file.model.model_no = n;
+    }
+
+    // 特徴量の設定が書き出した時点と異なる場合は登録しない
+    let model = file.model.to_domain()?;
+    mysql_cli.with_transaction(|tx| mysql_cli.upsert_forecast_model(tx, &model))?;
+    Ok(file)
+}
+
+// バージョンを付ける前に書き出したファイル（ForecastModelBlob のみ）も読み込む
+fn read_model_file(bytes: &[u8]) -> MyResult<ForecastModelFile> {
+    match ForecastModelFile::deserialize(bytes) {
+        Err(err @ MyError::UnsupportedModelArchiveVersion { .. }) => {
+            match ForecastModelBlob::deserialize_unversioned(bytes) {
+                Ok(blob) => Ok(ForecastModelFile::new(blob)),
+                Err(_) => Err(err),
+            }
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{forecast_model, TEST_PAIR};
+
+    #[test]
+    fn test_for_read_model_file() {
+        let m = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        let blob = ForecastModelBlob::from_domain(&m).unwrap();
+
+        let file =
+            read_model_file(&ForecastModelFile::new(blob.clone()).serialize().unwrap()).unwrap();
+        assert_eq!(file.model.model_no, 1);
+
+        // バージョンの無い形式
+        let file = read_model_file(&blob.serialize().unwrap()).unwrap();
+        assert_eq!(file.model.pair, TEST_PAIR);
+
+        assert!(read_model_file(&[0, 1]).is_err());
+    }
+}
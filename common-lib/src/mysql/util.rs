@@ -1,28 +1,30 @@
-use crate::error::MyResult;
+use crate::{
+    config::DbConfig,
+    error::{MyError, MyResult},
+};
 
-use super::client::DefaultClient;
+use super::client::{DefaultClient, PoolOptions};
 
-use serde::Deserialize;
+pub fn make_cli() -> MyResult<DefaultClient> {
+    make_cli_with(DbConfig::pool_options)
+}
 
-#[derive(Deserialize, Debug)]
-pub struct Config {
-    pub db_host: String,
-    pub db_port: u16,
-    pub db_name: String,
-    pub db_user_name: String,
-    pub db_password: String,
+// APIサーバー用（リクエスト処理中にDBの復旧を待たず、すぐにエラーを返す）
+pub fn make_server_cli() -> MyResult<DefaultClient> {
+    make_cli_with(DbConfig::server_pool_options)
 }
 
-pub fn make_cli() -> MyResult<DefaultClient> {
-    let config: Config;
-    match envy::from_env::<Config>() {
+fn make_cli_with(pool_options: fn(&DbConfig) -> PoolOptions) -> MyResult<DefaultClient> {
+    let config: DbConfig;
+    match crate::config::load::<DbConfig>() {
         Ok(c) => {
             config = c;
         }
         Err(err) => {
-            return Err(Box::new(err));
+            return Err(err);
         }
     }
+    config.validate()?;
 
     DefaultClient::new(
         &config.db_user_name,
@@ -30,5 +32,20 @@ pub fn make_cli() -> MyResult<DefaultClient> {
         &config.db_host,
         config.db_port,
         &config.db_name,
+        pool_options(&config),
     )
 }
+
+// DBへの接続に関するエラー（一時的に利用できない状態）か
+pub fn is_connection_error(err: &MyError) -> bool {
+    match err {
+        MyError::Database(mysql::Error::IoError(_)) => true,
+        MyError::Database(mysql::Error::DriverError(e)) => matches!(
+            e,
+            mysql::DriverError::CouldNotConnect(_) | mysql::DriverError::Timeout
+        ),
+        // 1040: Too many connections, 1053: Server shutdown in progress
+        MyError::Database(mysql::Error::MySqlError(e)) => matches!(e.code, 1040 | 1053),
+        _ => false,
+    }
+}
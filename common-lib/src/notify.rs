@@ -0,0 +1,133 @@
+use log::{error, info};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::error::MyResult;
+
+// 通知先のサービス
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyBackend {
+    Slack,
+    Discord,
+    // {"event": ..., "message": ...} をそのまま送信する
+    #[default]
+    Webhook,
+}
+
+// 通知するイベントの種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotifyEvent {
+    // 学習が完了した
+    TrainingCompleted,
+    // モデルを予想用に昇格した
+    ModelPromoted,
+    // チャレンジャーモデルの実績誤差が予想用モデルを上回り、自動で昇格した
+    ChallengerPromoted,
+    // レートのデータ品質が基準を下回った
+    DataQualityDegraded,
+    // 追加学習したモデルの精度が下がったため、更新を取り消した
+    ModelRefreshRolledBack,
+    // バッチのジョブが失敗した
+    BatchFailed,
+}
+
+impl NotifyEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotifyEvent::TrainingCompleted => "training_completed",
+            NotifyEvent::ModelPromoted => "model_promoted",
+            NotifyEvent::ChallengerPromoted => "challenger_promoted",
+            NotifyEvent::DataQualityDegraded => "data_quality_degraded",
+            NotifyEvent::ModelRefreshRolledBack => "model_refresh_rolled_back",
+            NotifyEvent::BatchFailed => "batch_failed",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Config {
+    #[serde(default)]
+    pub notify_backend: NotifyBackend,
+    // 通知先のURL、空の場合は通知しない
+    #[serde(default)]
+    pub notify_webhook_url: String,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_training_completed_enabled: bool,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_model_promoted_enabled: bool,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_challenger_promoted_enabled: bool,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_data_quality_degraded_enabled: bool,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_model_refresh_rolled_back_enabled: bool,
+    #[serde(default = "default_notify_enabled")]
+    pub notify_batch_failed_enabled: bool,
+}
+
+fn default_notify_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    config: Config,
+}
+
+impl Notifier {
+    pub fn new(config: Config) -> Self {
+        Notifier { config }
+    }
+
+    pub fn from_env() -> MyResult<Self> {
+        Ok(Notifier::new(crate::config::load::<Config>()?))
+    }
+
+    pub fn is_enabled(&self, event: NotifyEvent) -> bool {
+        if self.config.notify_webhook_url.is_empty() {
+            return false;
+        }
+        match event {
+            NotifyEvent::TrainingCompleted => self.config.notify_training_completed_enabled,
+            NotifyEvent::ModelPromoted => self.config.notify_model_promoted_enabled,
+            NotifyEvent::ChallengerPromoted => self.config.notify_challenger_promoted_enabled,
+            NotifyEvent::DataQualityDegraded => self.config.notify_data_quality_degraded_enabled,
+            NotifyEvent::ModelRefreshRolledBack => {
+                self.config.notify_model_refresh_rolled_back_enabled
+            }
+            NotifyEvent::BatchFailed => self.config.notify_batch_failed_enabled,
+        }
+    }
+
+    // ブロッキングで送信するため、非同期の処理からは spawn_blocking などで呼び出すこと
+    pub fn notify(&self, event: NotifyEvent, message: &str) -> MyResult<()> {
+        if !self.is_enabled(event) {
+            return Ok(());
+        }
+
+        let text = format!("[{}] {}", event.name(), message);
+        let body = match self.config.notify_backend {
+            NotifyBackend::Slack => json!({ "text": text }),
+            NotifyBackend::Discord => json!({ "content": text }),
+            NotifyBackend::Webhook => json!({ "event": event.name(), "message": message }),
+        };
+        reqwest::blocking::Client::new()
+            .post(&self.config.notify_webhook_url)
+            .json(&body)
+            .send()?
+            .error_for_status()?;
+
+        info!("notified, event: {}", event.name());
+        Ok(())
+    }
+}
+
+// 環境変数の設定で通知する
+// 通知に失敗しても呼び出し元の処理は続けられるよう、エラーはログに出力するだけにする
+pub fn notify_from_env(event: NotifyEvent, message: &str) {
+    let result = Notifier::from_env().and_then(|n| n.notify(event, message));
+    if let Err(err) = result {
+        error!("failed to notify, event: {}, error: {}", event.name(), err);
+    }
+}
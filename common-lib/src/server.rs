@@ -0,0 +1,11 @@
+pub mod admin;
+pub mod body_limit;
+pub mod error_response;
+pub mod health;
+pub mod layer;
+pub mod legacy_path;
+pub mod listener;
+pub mod metrics;
+pub mod openapi;
+pub mod rate_limit;
+pub mod trace_context;
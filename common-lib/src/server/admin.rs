@@ -0,0 +1,76 @@
+use sha2::{Digest, Sha256};
+
+// 管理用APIキーが一致するか（未設定・空の場合は常に不一致）
+// 比較にかかる時間からキーを推測されないよう、ハッシュ値を定数時間で比較する
+pub fn is_admin(admin_api_key: Option<&str>, api_key: Option<&str>) -> bool {
+    match (admin_api_key, api_key) {
+        (Some(admin_api_key), Some(api_key)) if !admin_api_key.is_empty() => {
+            let expected = Sha256::digest(admin_api_key.as_bytes());
+            let actual = Sha256::digest(api_key.as_bytes());
+            openssl::memcmp::eq(&expected, &actual)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_is_admin() {
+        struct Param {
+            title: String,
+            admin_api_key: Option<&'static str>,
+            api_key: Option<&'static str>,
+            expected: bool,
+        }
+        let params = vec![
+            Param {
+                title: "一致する場合は管理者".to_string(),
+                admin_api_key: Some("admin-key"),
+                api_key: Some("admin-key"),
+                expected: true,
+            },
+            Param {
+                title: "一致しない場合は管理者ではない".to_string(),
+                admin_api_key: Some("admin-key"),
+                api_key: Some("admin-kez"),
+                expected: false,
+            },
+            Param {
+                title: "長さが異なる場合は管理者ではない".to_string(),
+                admin_api_key: Some("admin-key"),
+                api_key: Some("admin-key-2"),
+                expected: false,
+            },
+            Param {
+                title: "APIキーが無い場合は管理者ではない".to_string(),
+                admin_api_key: Some("admin-key"),
+                api_key: None,
+                expected: false,
+            },
+            Param {
+                title: "管理用APIキーが未設定の場合は常に不一致".to_string(),
+                admin_api_key: None,
+                api_key: Some("admin-key"),
+                expected: false,
+            },
+            Param {
+                title: "管理用APIキーが空の場合は空のAPIキーとも一致しない".to_string(),
+                admin_api_key: Some(""),
+                api_key: Some(""),
+                expected: false,
+            },
+        ];
+
+        for p in params {
+            assert_eq!(
+                is_admin(p.admin_api_key, p.api_key),
+                p.expected,
+                "{}",
+                p.title
+            );
+        }
+    }
+}
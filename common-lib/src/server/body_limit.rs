@@ -0,0 +1,211 @@
+use std::{
+    io::Read,
+    task::{Context, Poll},
+};
+
+use flate2::read::GzDecoder;
+use futures::future::{self, BoxFuture};
+use hyper::{
+    body::HttpBody,
+    header::{CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
+    service::Service,
+    Body, Request, Response, StatusCode,
+};
+use log::warn;
+
+use super::layer::Layer;
+
+// リクエストボディのサイズ上限を設ける
+#[derive(Clone)]
+pub struct BodyLimitLayer {
+    max_bytes: u64,
+    // パスの末尾ごとの上限（max_bytes より優先する）
+    path_limits: Vec<(String, u64)>,
+}
+
+impl BodyLimitLayer {
+    pub fn new(max_bytes: u64) -> Self {
+        BodyLimitLayer {
+            max_bytes,
+            path_limits: vec![],
+        }
+    }
+
+    // 指定した末尾を持つパスのみ上限を変更する
+    pub fn with_path_limit(mut self, path_suffix: &str, max_bytes: u64) -> Self {
+        self.path_limits.push((path_suffix.to_string(), max_bytes));
+        self
+    }
+}
+
+impl<Target, S> Layer<Target, S> for BodyLimitLayer {
+    type State = Self;
+    type Service = BodyLimit<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        BodyLimit {
+            inner,
+            max_bytes: state.max_bytes,
+            path_limits: state.path_limits,
+        }
+    }
+}
+
+// 上限を超えるリクエストボディを 413 で拒否する
+// Content-Encoding: gzip の場合は展開してから後続へ渡し、上限は展開後のサイズにも適用する
+#[derive(Clone)]
+pub struct BodyLimit<T> {
+    inner: T,
+    max_bytes: u64,
+    path_limits: Vec<(String, u64)>,
+}
+
+impl<T> BodyLimit<T> {
+    fn max_bytes_for(&self, path: &str) -> u64 {
+        self.path_limits
+            .iter()
+            .find(|(suffix, _)| path.ends_with(suffix.as_str()))
+            .map(|(_, max_bytes)| *max_bytes)
+            .unwrap_or(self.max_bytes)
+    }
+}
+
+impl<T, C> Service<(Request<Body>, C)> for BodyLimit<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>> + Clone + Send + 'static,
+    T::Future: Send,
+    T::Error: Send,
+    C: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        let max_bytes = self.max_bytes_for(request.uri().path());
+
+        let encoding = request
+            .headers()
+            .get(CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim().to_lowercase());
+        let gzipped = match encoding.as_deref() {
+            None | Some("") | Some("identity") => false,
+            Some("gzip") | Some("x-gzip") => true,
+            Some(v) => {
+                warn!("unsupported content-encoding: {}", v);
+                return Box::pin(future::ready(Ok(error_response(
+                    StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                    &format!("unsupported content-encoding: {}", v),
+                ))));
+            }
+        };
+
+        let content_length = request
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        if let Some(len) = content_length {
+            if len > max_bytes {
+                warn!(
+                    "request body is too large, content-length: {}, limit: {}",
+                    len, max_bytes
+                );
+                return Box::pin(future::ready(Ok(payload_too_large(max_bytes))));
+            }
+        }
+
+        // poll_ready 済みのサービスを使うため、複製と入れ替えてから呼び出す
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            // Content-Length が無い（chunked）場合に備えて読み込み量も確認する
+            let (mut parts, mut body) = request.into_parts();
+            let mut buf: Vec<u8> = vec![];
+            while let Some(chunk) = body.data().await {
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(err) => {
+                        warn!("failed to read request body, error: {}", err);
+                        return Ok(error_response(
+                            StatusCode::BAD_REQUEST,
+                            &format!("failed to read request body, {}", err),
+                        ));
+                    }
+                };
+                if (buf.len() + chunk.len()) as u64 > max_bytes {
+                    warn!("request body is too large, limit: {}", max_bytes);
+                    return Ok(payload_too_large(max_bytes));
+                }
+                buf.extend_from_slice(&chunk);
+            }
+
+            if gzipped {
+                buf = match decompress(&buf, max_bytes) {
+                    Ok(Some(decoded)) => decoded,
+                    Ok(None) => {
+                        warn!(
+                            "decompressed request body is too large, limit: {}",
+                            max_bytes
+                        );
+                        return Ok(payload_too_large(max_bytes));
+                    }
+                    Err(err) => {
+                        warn!("failed to decompress request body, error: {}", err);
+                        return Ok(error_response(
+                            StatusCode::BAD_REQUEST,
+                            &format!("failed to decompress request body, {}", err),
+                        ));
+                    }
+                };
+                // 後続には展開済みのボディとして扱わせる
+                parts.headers.remove(CONTENT_ENCODING);
+                parts.headers.insert(CONTENT_LENGTH, buf.len().into());
+            }
+
+            inner
+                .call((Request::from_parts(parts, Body::from(buf)), context))
+                .await
+        })
+    }
+}
+
+// gzipを展開する（上限を超える場合はNone）
+fn decompress(buf: &[u8], max_bytes: u64) -> std::io::Result<Option<Vec<u8>>> {
+    let mut decoded: Vec<u8> = vec![];
+    GzDecoder::new(buf)
+        .take(max_bytes + 1)
+        .read_to_end(&mut decoded)?;
+    if decoded.len() as u64 > max_bytes {
+        return Ok(None);
+    }
+    Ok(Some(decoded))
+}
+
+fn payload_too_large(max_bytes: u64) -> Response<Body> {
+    error_response(
+        StatusCode::PAYLOAD_TOO_LARGE,
+        &format!("request body is too large, limit: {} bytes", max_bytes),
+    )
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(format!(
+            "{{\"message\":\"{}\"}}",
+            message.replace('\\', "\\\\").replace('"', "\\\"")
+        )))
+        .expect("Unable to create error response")
+}
@@ -0,0 +1,117 @@
+use hyper::StatusCode;
+
+use crate::{
+    error::{ErrorCategory, MyError},
+    error_report,
+};
+
+// DBに接続できない場合などに再試行を促すまでの秒数
+pub const RETRY_AFTER_SECONDS: i32 = 30;
+
+// APIのエラーレスポンス（ステータスとメッセージ）
+// 各サーバーで生成されたAPIのレスポンスに変換して返す
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorResponse {
+    pub status: StatusCode,
+    pub message: String,
+    pub retry_after: Option<i32>,
+}
+
+impl ErrorResponse {
+    pub fn new(status: StatusCode, message: String) -> Self {
+        ErrorResponse {
+            status,
+            message,
+            retry_after: None,
+        }
+    }
+
+    pub fn bad_request(memo: &str) -> Self {
+        ErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            format!("parameter is invalid, {}", memo),
+        )
+    }
+
+    pub fn not_found(memo: &str) -> Self {
+        ErrorResponse::new(StatusCode::NOT_FOUND, format!("not found, {}", memo))
+    }
+
+    pub fn conflict(memo: &str) -> Self {
+        ErrorResponse::new(StatusCode::CONFLICT, format!("conflict, {}", memo))
+    }
+
+    pub fn too_many_requests(memo: &str, retry_after: i32) -> Self {
+        ErrorResponse {
+            retry_after: Some(retry_after),
+            ..ErrorResponse::new(
+                StatusCode::TOO_MANY_REQUESTS,
+                format!("too many requests, {}", memo),
+            )
+        }
+    }
+
+    pub fn service_unavailable(memo: &str) -> Self {
+        ErrorResponse {
+            retry_after: Some(RETRY_AFTER_SECONDS),
+            ..ErrorResponse::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("service unavailable, {}", memo),
+            )
+        }
+    }
+
+    pub fn internal_server_error(memo: &str) -> Self {
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("internal server error, {}", memo),
+        )
+    }
+
+    // 処理中のエラーを分類に従ってレスポンスに変換する
+    // 想定外のエラー（500）はエラーの送信先にも送る
+    pub fn from_error(err: &MyError) -> Self {
+        ErrorResponse::from_error_with_tags(err, &[])
+    }
+
+    pub fn from_error_with_tags(err: &MyError, tags: &[(&str, &str)]) -> Self {
+        let memo = err.to_string();
+        match err.category() {
+            ErrorCategory::Transient => ErrorResponse::service_unavailable(&memo),
+            ErrorCategory::NotFound => ErrorResponse::not_found(&memo),
+            ErrorCategory::Validation => ErrorResponse::bad_request(&memo),
+            ErrorCategory::Internal => {
+                error_report::capture(err, tags);
+                ErrorResponse::internal_server_error(&memo)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_from_error() {
+        let err: MyError =
+            std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset").into();
+        let response = ErrorResponse::from_error(&err);
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.retry_after, Some(RETRY_AFTER_SECONDS));
+        assert!(response.message.starts_with("service unavailable"));
+
+        let err = MyError::ArrayIsEmpty {
+            name: "rates".to_string(),
+        };
+        let response = ErrorResponse::from_error(&err);
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(response.retry_after, None);
+        assert_eq!(response.message, "parameter is invalid, rates is empty");
+
+        let err = MyError::UnknownModelType { value: 99 };
+        let response = ErrorResponse::from_error(&err);
+        assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.message.starts_with("internal server error"));
+    }
+}
@@ -0,0 +1,120 @@
+use std::task::{Context, Poll};
+
+use futures::future::{self, BoxFuture};
+use hyper::{header::CONTENT_TYPE, service::Service, Body, Method, Request, Response, StatusCode};
+use log::warn;
+use serde::Serialize;
+
+use crate::mysql::client::{Client, DefaultClient};
+
+use super::layer::Layer;
+
+#[derive(Serialize, Debug)]
+struct HealthResponse {
+    status: String,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+// /healthz（稼働確認）と /readyz（DBに接続できるかの確認）を公開する
+#[derive(Clone)]
+pub struct HealthLayer {
+    mysql_cli: DefaultClient,
+    version: &'static str,
+}
+
+impl HealthLayer {
+    pub fn new(mysql_cli: DefaultClient, version: &'static str) -> Self {
+        HealthLayer { mysql_cli, version }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for HealthLayer {
+    type State = Self;
+    type Service = Health<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        Health {
+            inner,
+            mysql_cli: state.mysql_cli,
+            version: state.version,
+        }
+    }
+}
+
+// GET /healthz, /readyz は後続の処理（レート制限など）に渡さずに応答する
+// DBの起動待ちでプロセスが再起動されないよう、/healthz ではDBへの接続を確認しない
+#[derive(Clone)]
+pub struct Health<T> {
+    inner: T,
+    mysql_cli: DefaultClient,
+    version: &'static str,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for Health<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        if request.method() != Method::GET {
+            return Box::pin(self.inner.call((request, context)));
+        }
+
+        let version = self.version;
+        match request.uri().path() {
+            "/healthz" => Box::pin(future::ready(Ok(respond(StatusCode::OK, version, None)))),
+            "/readyz" => {
+                let mysql_cli = self.mysql_cli.clone();
+                Box::pin(async move {
+                    // DB処理はブロッキングするため、tokioのワーカーを占有しないようにして実行する
+                    let result = tokio::task::spawn_blocking(move || mysql_cli.ping()).await;
+                    let error = match result {
+                        Ok(Ok(())) => None,
+                        Ok(Err(err)) => Some(format!("{}", err)),
+                        Err(err) => Some(format!("{}", err)),
+                    };
+                    if let Some(err) = &error {
+                        warn!("not ready, error: {}", err);
+                        return Ok(respond(StatusCode::SERVICE_UNAVAILABLE, version, error));
+                    }
+                    Ok(respond(StatusCode::OK, version, None))
+                })
+            }
+            _ => Box::pin(self.inner.call((request, context))),
+        }
+    }
+}
+
+fn respond(status: StatusCode, version: &str, error: Option<String>) -> Response<Body> {
+    let body = HealthResponse {
+        status: if status.is_success() {
+            "ok".to_string()
+        } else {
+            "unavailable".to_string()
+        },
+        version: version.to_string(),
+        error,
+    };
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(
+            serde_json::to_string(&body).expect("impossible to fail to serialize"),
+        ))
+        .expect("Unable to create health response")
+}
@@ -0,0 +1,48 @@
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use hyper::service::Service;
+
+// 接続ごとに生成した後続のサービスを包む処理（ミドルウェアごとに実装する）
+pub trait Layer<Target, S> {
+    // 後続のサービスを生成する前に、接続の情報（接続元IPなど）から用意しておく値
+    type State: Send + 'static;
+    type Service;
+
+    fn state(&self, target: &Target) -> Self::State;
+    fn layer(state: Self::State, inner: S) -> Self::Service;
+}
+
+// 接続ごとに後続のサービスを生成し、layer で包んだサービスを返す
+pub struct MakeLayer<T, L> {
+    inner: T,
+    layer: L,
+}
+
+impl<T, L> MakeLayer<T, L> {
+    pub fn new(inner: T, layer: L) -> Self {
+        MakeLayer { inner, layer }
+    }
+}
+
+impl<T, L, Target> Service<Target> for MakeLayer<T, L>
+where
+    T: Service<Target>,
+    T::Future: Send + 'static,
+    L: Layer<Target, T::Response>,
+{
+    type Response = L::Service;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: Target) -> Self::Future {
+        let state = self.layer.state(&target);
+        let service = self.inner.call(target);
+
+        Box::pin(async move { Ok(L::layer(state, service.await?)) })
+    }
+}
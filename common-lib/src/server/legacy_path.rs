@@ -0,0 +1,110 @@
+use std::{
+    str::FromStr,
+    task::{Context, Poll},
+};
+
+use futures::future::BoxFuture;
+use hyper::{
+    header::HeaderValue,
+    http::uri::{PathAndQuery, Uri},
+    service::Service,
+    Request, Response,
+};
+use log::warn;
+
+use super::layer::Layer;
+
+// ベースパスの無い旧パスを、ベースパス付きのパスとして受け付ける
+#[derive(Clone)]
+pub struct LegacyPathLayer {
+    base_path: &'static str,
+    enabled: bool,
+}
+
+impl LegacyPathLayer {
+    pub fn new(base_path: &'static str, enabled: bool) -> Self {
+        LegacyPathLayer { base_path, enabled }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for LegacyPathLayer {
+    type State = Self;
+    type Service = LegacyPath<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        LegacyPath {
+            inner,
+            base_path: state.base_path,
+            enabled: state.enabled,
+        }
+    }
+}
+
+// 旧パスへのリクエストをベースパス付きに書き換え、非推奨であることをレスポンスヘッダで通知する
+#[derive(Clone)]
+pub struct LegacyPath<T> {
+    inner: T,
+    base_path: &'static str,
+    enabled: bool,
+}
+
+impl<T> LegacyPath<T> {
+    fn is_legacy(&self, path: &str) -> bool {
+        if !self.enabled || self.base_path.is_empty() {
+            return false;
+        }
+        match path.strip_prefix(self.base_path) {
+            Some(rest) => !(rest.is_empty() || rest.starts_with('/')),
+            None => true,
+        }
+    }
+}
+
+impl<T, B, C, ResBody> Service<(Request<B>, C)> for LegacyPath<T>
+where
+    T: Service<(Request<B>, C), Response = Response<ResBody>>,
+    T::Future: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (mut request, context): (Request<B>, C)) -> Self::Future {
+        let mut legacy = false;
+        if self.is_legacy(request.uri().path()) {
+            let original = request.uri().clone();
+            let path_and_query = original.path_and_query().map(|v| v.as_str()).unwrap_or("/");
+            let mut parts = original.clone().into_parts();
+            parts.path_and_query =
+                PathAndQuery::from_str(&format!("{}{}", self.base_path, path_and_query)).ok();
+            if let Ok(uri) = Uri::from_parts(parts) {
+                warn!(
+                    "deprecated path is requested, path: {}, use: {}",
+                    original.path(),
+                    uri.path()
+                );
+                *request.uri_mut() = uri;
+                legacy = true;
+            }
+        }
+
+        let response = self.inner.call((request, context));
+        Box::pin(async move {
+            let mut response = response.await?;
+            if legacy {
+                response
+                    .headers_mut()
+                    .insert("deprecation", HeaderValue::from_static("true"));
+            }
+            Ok(response)
+        })
+    }
+}
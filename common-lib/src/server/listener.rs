@@ -0,0 +1,119 @@
+use std::{error::Error, future::Future, net::SocketAddr, pin::Pin};
+
+use hyper::{body::HttpBody, server::conn::Http, service::Service, Body, Request, Response};
+use log::{info, warn};
+use openssl::ssl::{Ssl, SslAcceptor, SslFiletype, SslMethod};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+};
+use tokio_openssl::SslStream;
+
+use crate::{config::HttpConfig, error::MyResult};
+
+// 設定に従ってHTTP（証明書が指定されている場合はHTTPS）で待ち受ける
+// 接続ごとに接続元のアドレスでサービスを生成する
+pub async fn serve<M, S, B>(config: &HttpConfig, mut make_service: M) -> MyResult<()>
+where
+    M: Service<SocketAddr, Response = S>,
+    M::Future: Send + 'static,
+    M::Error: Send + 'static,
+    S: Service<Request<Body>, Response = Response<B>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    let addr: SocketAddr = config.get_address().parse()?;
+    let tls_acceptor = if config.is_tls_enabled() {
+        Some(make_tls_acceptor(config)?)
+    } else {
+        None
+    };
+
+    let tcp_listener = TcpListener::bind(&addr).await?;
+    info!("listening on {}, tls: {}", addr, config.is_tls_enabled());
+    loop {
+        let (tcp, remote_addr) = match tcp_listener.accept().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                warn!("failed to accept connection, error: {}", err);
+                continue;
+            }
+        };
+        let service = make_service.call(remote_addr);
+
+        let tls_acceptor = match &tls_acceptor {
+            Some(tls_acceptor) => tls_acceptor,
+            None => {
+                tokio::spawn(serve_connection(tcp, service, remote_addr));
+                continue;
+            }
+        };
+        let ssl = match Ssl::new(tls_acceptor.context()) {
+            Ok(ssl) => ssl,
+            Err(err) => {
+                warn!("failed to create ssl, error: {}", err);
+                continue;
+            }
+        };
+        tokio::spawn(async move {
+            let mut tls = match SslStream::new(ssl, tcp) {
+                Ok(tls) => tls,
+                Err(err) => {
+                    warn!("failed to create ssl stream, error: {}", err);
+                    return;
+                }
+            };
+            if let Err(err) = Pin::new(&mut tls).accept().await {
+                warn!(
+                    "failed to tls handshake, remote: {}, error: {}",
+                    remote_addr, err
+                );
+                return;
+            }
+            serve_connection(tls, service, remote_addr).await;
+        });
+    }
+}
+
+fn make_tls_acceptor(config: &HttpConfig) -> MyResult<SslAcceptor> {
+    let mut ssl = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
+    ssl.set_private_key_file(&config.tls_key_file, SslFiletype::PEM)?;
+    ssl.set_certificate_chain_file(&config.tls_cert_file)?;
+    ssl.check_private_key()?;
+    Ok(ssl.build())
+}
+
+// WebSocketなどのアップグレードを受け付けるため with_upgrades で処理する
+async fn serve_connection<I, F, E, S, B>(io: I, service: F, remote_addr: SocketAddr)
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    F: Future<Output = Result<S, E>> + Send + 'static,
+    E: Send + 'static,
+    S: Service<Request<Body>, Response = Response<B>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    let service = match service.await {
+        Ok(service) => service,
+        Err(_) => {
+            warn!("failed to make service, remote: {}", remote_addr);
+            return;
+        }
+    };
+    if let Err(err) = Http::new()
+        .serve_connection(io, service)
+        .with_upgrades()
+        .await
+    {
+        warn!(
+            "failed to serve connection, remote: {}, error: {}",
+            remote_addr, err
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use futures::future::{self, BoxFuture};
+use hyper::{service::Service, Body, Method, Request, Response};
+
+use crate::metrics;
+
+use super::layer::Layer;
+
+// リクエスト数と処理時間を記録し、/metrics を公開する
+#[derive(Clone)]
+pub struct MetricsLayer;
+
+impl MetricsLayer {
+    pub fn new() -> Self {
+        metrics::init();
+        MetricsLayer
+    }
+}
+
+impl Default for MetricsLayer {
+    fn default() -> Self {
+        MetricsLayer::new()
+    }
+}
+
+impl<Target, S> Layer<Target, S> for MetricsLayer {
+    type State = ();
+    type Service = Metrics<S>;
+
+    fn state(&self, _: &Target) -> Self::State {}
+
+    fn layer(_: Self::State, inner: S) -> Self::Service {
+        Metrics { inner }
+    }
+}
+
+// GET /metrics は後続の処理に渡さずにメトリクスを返す
+// ラベルの種類が増えすぎないよう、パスはラベルに含めない
+#[derive(Clone)]
+pub struct Metrics<T> {
+    inner: T,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for Metrics<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        if request.method() == Method::GET && request.uri().path() == "/metrics" {
+            return Box::pin(future::ready(Ok(metrics::response())));
+        }
+
+        let method = request.method().to_string();
+        let started = Instant::now();
+        let response = self.inner.call((request, context));
+        Box::pin(async move {
+            let response = response.await;
+            let status = match &response {
+                Ok(r) => r.status().as_u16().to_string(),
+                Err(_) => "error".to_string(),
+            };
+            metrics::HTTP_REQUESTS_TOTAL
+                .with_label_values(&[&method, &status])
+                .inc();
+            metrics::HTTP_REQUEST_DURATION_SECONDS
+                .with_label_values(&[&method])
+                .observe(started.elapsed().as_secs_f64());
+            response
+        })
+    }
+}
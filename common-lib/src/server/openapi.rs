@@ -0,0 +1,98 @@
+use std::task::{Context, Poll};
+
+use futures::future::{self, BoxFuture};
+use hyper::{
+    body::Bytes, header::CONTENT_TYPE, service::Service, Body, Method, Request, Response,
+    StatusCode,
+};
+
+use crate::error::MyResult;
+
+use super::layer::Layer;
+
+// APIの仕様を取得するパス（ベースパスに関わらず固定）
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+// ビルド時に埋め込んだOpenAPIの仕様（YAML）を、JSONとして公開する
+#[derive(Clone)]
+pub struct OpenApiLayer {
+    spec: Bytes,
+}
+
+impl OpenApiLayer {
+    // 仕様を読み込めない場合は起動時にエラーとする
+    pub fn new(spec_yaml: &str) -> MyResult<Self> {
+        Ok(OpenApiLayer {
+            spec: Bytes::from(to_json(spec_yaml)?),
+        })
+    }
+}
+
+impl<Target, S> Layer<Target, S> for OpenApiLayer {
+    type State = Bytes;
+    type Service = OpenApi<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.spec.clone()
+    }
+
+    fn layer(spec: Self::State, inner: S) -> Self::Service {
+        OpenApi { inner, spec }
+    }
+}
+
+// GET /openapi.json は後続の処理（レート制限など）に渡さずに応答する
+#[derive(Clone)]
+pub struct OpenApi<T> {
+    inner: T,
+    spec: Bytes,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for OpenApi<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        if request.method() != Method::GET || request.uri().path() != OPENAPI_PATH {
+            return Box::pin(self.inner.call((request, context)));
+        }
+
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(self.spec.clone()))
+            .expect("Unable to create openapi response");
+        Box::pin(future::ready(Ok(response)))
+    }
+}
+
+fn to_json(spec_yaml: &str) -> MyResult<String> {
+    let value: serde_json::Value = serde_yaml::from_str(spec_yaml)?;
+    Ok(serde_json::to_string(&value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_to_json() {
+        let json =
+            to_json("openapi: 3.0.0\ninfo:\n  title: ForecastServer\n  version: 1.0.0\n").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["openapi"], "3.0.0");
+        assert_eq!(value["info"]["title"], "ForecastServer");
+
+        assert!(to_json("openapi: [").is_err());
+    }
+}
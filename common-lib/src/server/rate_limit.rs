@@ -0,0 +1,289 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::future::{self, BoxFuture};
+use hyper::{
+    header::{CONTENT_TYPE, RETRY_AFTER},
+    server::conn::AddrStream,
+    service::Service,
+    Body, Request, Response, StatusCode,
+};
+use log::warn;
+use sha2::{Digest, Sha256};
+
+use super::layer::Layer;
+
+// 保持するクライアント数がこれを超えたら期限切れの記録を削除する
+const MAX_TRACKED_CLIENTS: usize = 10000;
+
+// クライアントごとのリクエスト数をトークンバケットで制限する
+// window あたり上限の回数まで続けて受け付け、以降は window / 上限 ごとに1回分ずつ回復する
+// 上限は動作中に変更できる（0の場合は制限しない）
+#[derive(Clone)]
+pub struct RateLimiter {
+    max_requests: Arc<AtomicU32>,
+    window: Duration,
+    // APIキーごとの上限（設定に無いAPIキーは接続元IPごとに max_requests で数える）
+    key_limits: Arc<RwLock<HashMap<String, u32>>>,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        RateLimiter {
+            max_requests: Arc::new(AtomicU32::new(max_requests)),
+            window,
+            key_limits: Arc::new(RwLock::new(HashMap::new())),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn set_max_requests(&self, max_requests: u32) {
+        self.max_requests.store(max_requests, Ordering::SeqCst);
+    }
+
+    pub fn set_key_limits(&self, key_limits: HashMap<String, u32>) {
+        *self.key_limits.write().unwrap() = key_limits;
+    }
+
+    // 上限を超えた場合は1回分回復するまでの待ち時間を返す
+    pub fn check(&self, key: &str) -> Result<(), Duration> {
+        self.check_with_limit(key, self.max_requests.load(Ordering::SeqCst))
+    }
+
+    // 設定済みのAPIキーはキーごとの上限で、それ以外は接続元IPごとに確認し、数えたクライアントと結果を返す
+    // 未登録のAPIキーを毎回変えて制限を逃れたり、バケットを増やし続けたりできないようにする
+    pub fn check_client(
+        &self,
+        api_key: Option<&str>,
+        remote_ip: IpAddr,
+    ) -> (String, Result<(), Duration>) {
+        if let Some(api_key) = api_key {
+            let limit = self.key_limits.read().unwrap().get(api_key).copied();
+            if let Some(limit) = limit {
+                let client = format!("key:{}", fingerprint(api_key));
+                let result = self.check_with_limit(&client, limit);
+                return (client, result);
+            }
+        }
+
+        let client = format!("ip:{}", remote_ip);
+        let result = self.check(&client);
+        (client, result)
+    }
+
+    fn check_with_limit(&self, key: &str, limit: u32) -> Result<(), Duration> {
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let capacity = limit as f64;
+        let per_second = capacity / self.window.as_secs_f64();
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > MAX_TRACKED_CLIENTS {
+            // window 以上使われていないバケットは満杯に戻っているため、削除しても結果は変わらない
+            let window = self.window;
+            buckets.retain(|_, b| now.duration_since(b.updated) < window);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_second).min(capacity);
+        bucket.updated = now;
+        if bucket.tokens < 1.0 {
+            return Err(Duration::from_secs_f64((1.0 - bucket.tokens) / per_second));
+        }
+        bucket.tokens -= 1.0;
+        Ok(())
+    }
+}
+
+// ログやバケットにAPIキーそのものを残さないよう、ハッシュ値の先頭だけを使う
+fn fingerprint(api_key: &str) -> String {
+    Sha256::digest(api_key.as_bytes())[..8]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// 接続元IPを取得できる接続情報
+pub trait RemoteIp {
+    fn remote_ip(&self) -> IpAddr;
+}
+
+impl RemoteIp for &AddrStream {
+    fn remote_ip(&self) -> IpAddr {
+        self.remote_addr().ip()
+    }
+}
+
+impl RemoteIp for SocketAddr {
+    fn remote_ip(&self) -> IpAddr {
+        self.ip()
+    }
+}
+
+// クライアントごとのリクエスト数を制限する
+// max_requests が 0 の場合は制限しない
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: RateLimiter,
+}
+
+impl RateLimitLayer {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        RateLimitLayer::with_limiter(RateLimiter::new(max_requests, window))
+    }
+
+    // 上限を後から変更する場合は、同じ RateLimiter を保持しておく
+    pub fn with_limiter(limiter: RateLimiter) -> Self {
+        RateLimitLayer { limiter }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for RateLimitLayer
+where
+    Target: RemoteIp,
+{
+    type State = (RateLimiter, IpAddr);
+    type Service = RateLimit<S>;
+
+    fn state(&self, target: &Target) -> Self::State {
+        (self.limiter.clone(), target.remote_ip())
+    }
+
+    fn layer((limiter, remote_ip): Self::State, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            limiter,
+            remote_ip,
+        }
+    }
+}
+
+// 上限を超えたリクエストを 429 で拒否する
+// 設定済みのAPIキー（X-API-Key）があればキーごと、無ければ接続元IPごとに数える
+#[derive(Clone)]
+pub struct RateLimit<T> {
+    inner: T,
+    limiter: RateLimiter,
+    remote_ip: IpAddr,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for RateLimit<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        let api_key = request
+            .headers()
+            .get("x-api-key")
+            .and_then(|v| v.to_str().ok());
+        let (client, result) = self.limiter.check_client(api_key, self.remote_ip);
+        if let Err(wait) = result {
+            warn!(
+                "too many requests, client: {}, path: {}",
+                client,
+                request.uri().path()
+            );
+            return Box::pin(future::ready(Ok(too_many_requests(wait))));
+        }
+
+        Box::pin(self.inner.call((request, context)))
+    }
+}
+
+fn too_many_requests(wait: Duration) -> Response<Body> {
+    // 端数は切り上げて、少なくとも1秒は待たせる
+    let retry_after = wait.as_secs() + if wait.subsec_nanos() > 0 { 1 } else { 0 };
+    Response::builder()
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .header(CONTENT_TYPE, "application/json")
+        .header(RETRY_AFTER, retry_after.max(1))
+        .body(Body::from("{\"message\":\"too many requests\"}"))
+        .expect("Unable to create Too Many Requests response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_rate_limiter() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        assert!(limiter.check("ip:127.0.0.1").is_ok());
+        assert!(limiter.check("ip:127.0.0.1").is_ok());
+        // 1回分の回復（60秒 / 2回）を待つ必要がある
+        let wait = limiter.check("ip:127.0.0.1").unwrap_err();
+        assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+        // 他のクライアントには影響しない
+        assert!(limiter.check("ip:127.0.0.2").is_ok());
+
+        // APIキーごとの上限（0の場合は制限しない）
+        let mut key_limits = HashMap::new();
+        key_limits.insert("feed".to_string(), 3);
+        key_limits.insert("admin".to_string(), 0);
+        limiter.set_key_limits(key_limits);
+        let ip: IpAddr = "127.0.0.3".parse().unwrap();
+        for _ in 0..3 {
+            assert!(limiter.check_client(Some("feed"), ip).1.is_ok());
+        }
+        let (client, result) = limiter.check_client(Some("feed"), ip);
+        assert_eq!(client, format!("key:{}", fingerprint("feed")));
+        assert!(!client.contains("feed"));
+        assert!(result.is_err());
+        for _ in 0..10 {
+            assert!(limiter.check_client(Some("admin"), ip).1.is_ok());
+        }
+        // キーごとの上限は接続元IPの上限とは別に数える
+        assert!(limiter.check_client(None, ip).1.is_ok());
+    }
+
+    #[test]
+    fn test_for_rate_limiter_unknown_api_key() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let mut key_limits = HashMap::new();
+        key_limits.insert("feed".to_string(), 100);
+        limiter.set_key_limits(key_limits);
+
+        // 未登録のAPIキーは、キーを変えても接続元IPごとの上限で制限する
+        let ip: IpAddr = "127.0.0.1".parse().unwrap();
+        let (client, result) = limiter.check_client(Some("random-1"), ip);
+        assert_eq!(client, "ip:127.0.0.1");
+        assert!(result.is_ok());
+        assert!(limiter.check_client(Some("random-2"), ip).1.is_ok());
+        assert!(limiter.check_client(Some("random-3"), ip).1.is_err());
+        assert!(limiter.check_client(None, ip).1.is_err());
+        assert_eq!(limiter.buckets.lock().unwrap().len(), 1);
+
+        // 登録済みのキーは接続元IPの上限に影響されない
+        assert!(limiter.check_client(Some("feed"), ip).1.is_ok());
+    }
+}
@@ -0,0 +1,158 @@
+use std::task::{Context, Poll};
+
+use hyper::{service::Service, Request};
+use swagger::{Has, XSpanIdString};
+use tracing::{info_span, instrument::Instrumented, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::{
+    logger::{self, WithSpanId},
+    telemetry,
+};
+
+use super::layer::Layer;
+
+// W3C Trace Context の traceparent ヘッダ
+// https://www.w3.org/TR/trace-context/#traceparent-header
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceParent {
+    pub version: String,
+    pub trace_id: String,
+    pub parent_id: String,
+    pub flags: String,
+}
+
+impl TraceParent {
+    pub fn parse(value: &str) -> Option<Self> {
+        let fields: Vec<&str> = value.trim().split('-').collect();
+        if fields.len() < 4 {
+            return None;
+        }
+        let (version, trace_id, parent_id, flags) = (fields[0], fields[1], fields[2], fields[3]);
+
+        let is_hex = |s: &str, len: usize| {
+            s.len() == len && s.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+        };
+        let is_zero = |s: &str| s.chars().all(|c| c == '0');
+        if !is_hex(version, 2) || version == "ff" || (version == "00" && fields.len() != 4) {
+            return None;
+        }
+        if !is_hex(trace_id, 32) || is_zero(trace_id) {
+            return None;
+        }
+        if !is_hex(parent_id, 16) || is_zero(parent_id) {
+            return None;
+        }
+        if !is_hex(flags, 2) {
+            return None;
+        }
+
+        Some(TraceParent {
+            version: version.to_string(),
+            trace_id: trace_id.to_string(),
+            parent_id: parent_id.to_string(),
+            flags: flags.to_string(),
+        })
+    }
+}
+
+// traceparent ヘッダを受け付ける
+#[derive(Clone)]
+pub struct TraceContextLayer;
+
+impl<Target, S> Layer<Target, S> for TraceContextLayer {
+    type State = ();
+    type Service = TraceContext<S>;
+
+    fn state(&self, _: &Target) -> Self::State {}
+
+    fn layer(_: Self::State, inner: S) -> Self::Service {
+        TraceContext { inner }
+    }
+}
+
+// X-Span-ID の指定が無く traceparent ヘッダがある場合は、trace-id を X-Span-ID としてコンテキストに設定する
+// （以降のログ出力や保存されるデータに trace-id が引き継がれる）
+// 後続の処理で出力したログには X-Span-ID を付与し、処理全体を traceparent の子スパンとして記録する
+#[derive(Clone)]
+pub struct TraceContext<T> {
+    inner: T,
+}
+
+impl<T, B, C> Service<(Request<B>, C)> for TraceContext<T>
+where
+    T: Service<(Request<B>, C)>,
+    C: Has<XSpanIdString>,
+{
+    type Response = T::Response;
+    type Error = T::Error;
+    type Future = WithSpanId<Instrumented<T::Future>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, mut context): (Request<B>, C)) -> Self::Future {
+        if !request.headers().contains_key(swagger::X_SPAN_ID) {
+            let trace_parent = request
+                .headers()
+                .get("traceparent")
+                .and_then(|v| v.to_str().ok())
+                .and_then(TraceParent::parse);
+            if let Some(trace_parent) = trace_parent {
+                *context.get_mut() = XSpanIdString(trace_parent.trace_id);
+            }
+        }
+
+        let span_id = Has::<XSpanIdString>::get(&context).0.clone();
+        let span = info_span!(
+            "http.request",
+            http.method = %request.method(),
+            http.target = %request.uri().path(),
+            span_id = %span_id,
+        );
+        span.set_parent(telemetry::parent_context(request.headers()));
+
+        logger::with_span_id(
+            span_id,
+            self.inner.call((request, context)).instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trace_parent() {
+        let v = TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01");
+        assert_eq!(
+            v,
+            Some(TraceParent {
+                version: "00".to_string(),
+                trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".to_string(),
+                parent_id: "00f067aa0ba902b7".to_string(),
+                flags: "01".to_string(),
+            })
+        );
+
+        assert_eq!(TraceParent::parse(""), None);
+        assert_eq!(
+            TraceParent::parse("00-00000000000000000000000000000000-00f067aa0ba902b7-01"),
+            None
+        );
+        assert_eq!(
+            TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01"),
+            None
+        );
+        assert_eq!(
+            TraceParent::parse("ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            None
+        );
+        assert_eq!(
+            TraceParent::parse("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01"),
+            None
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use log::{info, warn};
+use serde::Deserialize;
+use signal_hook::consts::SIGHUP;
+
+use crate::{
+    domain::pair::PairRegistry,
+    error::{MyError, MyResult},
+    feature::Feature,
+    mysql::client::{Client, DefaultClient},
+};
+
+// runtime_settings テーブルの設定名
+pub const SETTING_PAIRS: &str = "pairs";
+pub const SETTING_MAX_MODEL_MSE: &str = "max_model_mse";
+pub const SETTING_RATE_LIMIT_PER_MINUTE: &str = "rate_limit_per_minute";
+// 機能フラグは feature.<機能名> で設定する（例: feature.ensemble_forecast）
+pub const SETTING_FEATURE_PREFIX: &str = "feature.";
+
+// SIGHUP の受信を確認する間隔
+const WATCH_TICK: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // 実行時設定を再読み込みする間隔（秒）、0の場合はSIGHUPを受けたときのみ再読み込みする
+    #[serde(default = "default_settings_reload_interval_seconds")]
+    pub settings_reload_interval_seconds: u64,
+}
+
+fn default_settings_reload_interval_seconds() -> u64 {
+    60
+}
+
+// 再起動せずに変更できる設定
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RuntimeSettings {
+    // 受け付ける通貨ペア、空の場合は取り扱う通貨ペアすべて
+    pub pairs: Vec<String>,
+    // 取り扱う通貨ペア（currency_pairs テーブル）
+    pub currency_pairs: PairRegistry,
+    // 予想に使うモデルのMSEの上限、None の場合は制限しない
+    pub max_model_mse: Option<f64>,
+    // 1分あたりに受け付けるクライアントごとのリクエスト数、None の場合は起動時の設定のまま
+    pub rate_limit_per_minute: Option<u32>,
+    // 機能フラグ（設定されていない機能は環境変数の値に従う）
+    pub features: BTreeMap<Feature, bool>,
+}
+
+impl RuntimeSettings {
+    // 設定名と値の組から生成する（未知の設定名は無視する）
+    pub fn from_values(values: &[(String, String)]) -> MyResult<Self> {
+        let mut settings = RuntimeSettings::default();
+        for (name, value) in values {
+            let value = value.trim();
+            match name.as_str() {
+                SETTING_PAIRS => {
+                    settings.pairs = value
+                        .split(',')
+                        .map(|p| p.trim().to_string())
+                        .filter(|p| !p.is_empty())
+                        .collect();
+                }
+                SETTING_MAX_MODEL_MSE if !value.is_empty() => {
+                    settings.max_model_mse = Some(parse(name, value)?);
+                }
+                SETTING_RATE_LIMIT_PER_MINUTE if !value.is_empty() => {
+                    settings.rate_limit_per_minute = Some(parse(name, value)?);
+                }
+                SETTING_MAX_MODEL_MSE | SETTING_RATE_LIMIT_PER_MINUTE => {}
+                _ if name.starts_with(SETTING_FEATURE_PREFIX) => {
+                    let feature_name = &name[SETTING_FEATURE_PREFIX.len()..];
+                    match Feature::from_name(feature_name) {
+                        Some(feature) if !value.is_empty() => {
+                            settings.features.insert(feature, parse(name, value)?);
+                        }
+                        Some(_) => {}
+                        None => {
+                            warn!("unknown feature, name: {}", name);
+                        }
+                    }
+                }
+                _ => {
+                    warn!("unknown runtime setting, name: {}", name);
+                }
+            }
+        }
+        Ok(settings)
+    }
+
+    // 取り扱う通貨ペアか（受け付ける通貨ペアの設定は考慮しない）
+    pub fn is_pair_supported(&self, pair: &str) -> bool {
+        self.currency_pairs.is_supported_pair(pair)
+    }
+
+    pub fn is_pair_allowed(&self, pair: &str) -> bool {
+        self.is_pair_supported(pair)
+            && (self.pairs.is_empty() || self.pairs.iter().any(|p| p == pair))
+    }
+
+    // 処理対象とする通貨ペアの一覧
+    pub fn allowed_pairs(&self) -> Vec<String> {
+        self.currency_pairs
+            .enabled_pairs()
+            .into_iter()
+            .filter(|p| self.is_pair_allowed(p))
+            .collect()
+    }
+
+    pub fn is_model_allowed(&self, performance_mse: f64) -> bool {
+        match self.max_model_mse {
+            Some(max) => performance_mse <= max,
+            None => true,
+        }
+    }
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: &str) -> MyResult<T> {
+    value.parse::<T>().map_err(|_| MyError::ParseError {
+        param_name: name.to_string(),
+        value: value.to_string(),
+        memo: "runtime setting".to_string(),
+    })
+}
+
+// 複数のスレッドから参照する実行時設定
+#[derive(Clone, Debug, Default)]
+pub struct SharedSettings {
+    inner: Arc<RwLock<RuntimeSettings>>,
+}
+
+impl SharedSettings {
+    pub fn new() -> Self {
+        SharedSettings::default()
+    }
+
+    // 読み込み済みの設定から生成する（DBから読み込まない）
+    pub fn with(settings: RuntimeSettings) -> Self {
+        SharedSettings {
+            inner: Arc::new(RwLock::new(settings)),
+        }
+    }
+
+    pub fn get(&self) -> RuntimeSettings {
+        self.inner.read().unwrap().clone()
+    }
+
+    // DBから読み込み直す、変更があった場合は true を返す
+    pub fn reload(&self, mysql_cli: &DefaultClient) -> MyResult<bool> {
+        let (values, pairs) = mysql_cli.with_transaction(|tx| {
+            Ok((
+                mysql_cli.select_runtime_settings(tx)?,
+                mysql_cli.select_currency_pairs(tx)?,
+            ))
+        })?;
+        let mut settings = RuntimeSettings::from_values(&values)?;
+        settings.currency_pairs = PairRegistry::new(pairs);
+
+        let mut current = self.inner.write().unwrap();
+        if *current == settings {
+            return Ok(false);
+        }
+        info!("runtime settings reloaded, {:?}", settings);
+        *current = settings;
+        Ok(true)
+    }
+
+    // SIGHUP を受けたとき、または一定間隔で読み込み直すスレッドを開始する
+    // 読み込みに失敗した場合は直前の設定のまま動作を続ける
+    pub fn watch<F>(
+        &self,
+        mysql_cli: DefaultClient,
+        interval_seconds: u64,
+        on_change: F,
+    ) -> MyResult<()>
+    where
+        F: Fn(&RuntimeSettings) + Send + 'static,
+    {
+        let hangup = Arc::new(AtomicBool::new(false));
+        signal_hook::flag::register(SIGHUP, Arc::clone(&hangup))?;
+
+        let settings = self.clone();
+        let interval = Duration::from_secs(interval_seconds);
+        thread::spawn(move || {
+            let mut last_loaded = Instant::now();
+            loop {
+                thread::sleep(WATCH_TICK);
+                let requested = hangup.swap(false, Ordering::SeqCst);
+                if requested {
+                    info!("SIGHUP received, reload runtime settings");
+                } else if interval_seconds == 0 || last_loaded.elapsed() < interval {
+                    continue;
+                }
+
+                last_loaded = Instant::now();
+                match settings.reload(&mysql_cli) {
+                    Ok(true) => on_change(&settings.get()),
+                    Ok(false) => {}
+                    Err(err) => {
+                        warn!("failed to reload runtime settings, error: {}", err);
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+pub fn reload_interval_from_env() -> MyResult<u64> {
+    let config = crate::config::load::<Config>()?;
+    Ok(config.settings_reload_interval_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::currency_pair;
+
+    #[test]
+    fn test_for_from_values() {
+        let values = vec![
+            (SETTING_PAIRS.to_string(), "USDJPY, EURJPY".to_string()),
+            (SETTING_MAX_MODEL_MSE.to_string(), "0.25".to_string()),
+            (SETTING_RATE_LIMIT_PER_MINUTE.to_string(), "".to_string()),
+            ("unknown".to_string(), "1".to_string()),
+            ("feature.drift_detection".to_string(), "true".to_string()),
+            ("feature.unknown".to_string(), "true".to_string()),
+        ];
+        let settings = RuntimeSettings::from_values(&values).unwrap();
+        assert_eq!(
+            settings,
+            RuntimeSettings {
+                pairs: vec!["USDJPY".to_string(), "EURJPY".to_string()],
+                currency_pairs: PairRegistry::default(),
+                max_model_mse: Some(0.25),
+                rate_limit_per_minute: None,
+                features: BTreeMap::from([(Feature::DriftDetection, true)]),
+            }
+        );
+
+        let values = vec![(SETTING_RATE_LIMIT_PER_MINUTE.to_string(), "-1".to_string())];
+        assert!(RuntimeSettings::from_values(&values).is_err());
+    }
+
+    #[test]
+    fn test_for_is_allowed() {
+        let mut settings = RuntimeSettings {
+            currency_pairs: PairRegistry::new(vec![
+                currency_pair("USDJPY", true),
+                currency_pair("EURJPY", true),
+                currency_pair("GBPJPY", false),
+            ]),
+            ..Default::default()
+        };
+        assert!(settings.is_pair_allowed("USDJPY"));
+        assert!(!settings.is_pair_allowed("GBPJPY"));
+        assert!(!settings.is_pair_allowed("XXXYYY"));
+        assert_eq!(
+            settings.allowed_pairs(),
+            vec!["EURJPY".to_string(), "USDJPY".to_string()]
+        );
+        assert!(settings.is_model_allowed(100.0));
+
+        settings.pairs = vec!["EURJPY".to_string()];
+        settings.max_model_mse = Some(0.5);
+        assert!(settings.is_pair_supported("USDJPY"));
+        assert!(!settings.is_pair_allowed("USDJPY"));
+        assert_eq!(settings.allowed_pairs(), vec!["EURJPY".to_string()]);
+        assert!(settings.is_model_allowed(0.5));
+        assert!(!settings.is_model_allowed(0.6));
+    }
+}
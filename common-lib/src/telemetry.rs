@@ -0,0 +1,87 @@
+use hyper::HeaderMap;
+use log::info;
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    sdk::{propagation::TraceContextPropagator, trace, Resource},
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use serde::Deserialize;
+use tracing_subscriber::layer::SubscriberExt;
+
+use crate::error::MyResult;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // トレースの送信先（OTLP/HTTP、例: http://otel-collector:4318/v1/traces）、空の場合は送信しない
+    #[serde(default)]
+    pub otel_exporter_otlp_endpoint: String,
+}
+
+// 破棄時に未送信のスパンを送信してトレースを終了する
+pub struct TelemetryGuard {
+    enabled: bool,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if self.enabled {
+            global::shutdown_tracer_provider();
+        }
+    }
+}
+
+// トレースの送信を開始する
+// 送信先が設定されていない場合はスパンを記録しない
+pub fn init(service: &str) -> MyResult<TelemetryGuard> {
+    let config = crate::config::load::<Config>()?;
+    if config.otel_exporter_otlp_endpoint.is_empty() {
+        return Ok(TelemetryGuard { enabled: false });
+    }
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    // 同期のバッチでも使えるよう、送信は専用のスレッドで行う
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(&config.otel_exporter_otlp_endpoint),
+        )
+        .with_trace_config(
+            trace::config().with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                service.to_string(),
+            )])),
+        )
+        .install_batch(opentelemetry::runtime::TokioCurrentThread)?;
+
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    info!(
+        "telemetry started, service: {}, endpoint: {}",
+        service, config.otel_exporter_otlp_endpoint
+    );
+    Ok(TelemetryGuard { enabled: true })
+}
+
+// リクエストヘッダの traceparent から呼び出し元のトレースを取得する
+pub fn parent_context(headers: &HeaderMap) -> opentelemetry::Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)))
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl<'a> Extractor for HeaderExtractor<'a> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
@@ -0,0 +1,258 @@
+// 各crateのテストで共通して使うテストデータとDBへの投入処理
+// 他のcrateから使う場合は dev-dependencies で features = ["test-support"] を指定する
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use mysql::{params, prelude::Queryable};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use smartcore::{
+    linalg::naive::dense_matrix::DenseMatrix,
+    math::distance::Distances,
+    neighbors::knn_regressor::{KNNRegressor, KNNRegressorParameters},
+};
+
+use crate::{
+    domain::{
+        model::{FeatureParams, ForecastModel, InputData, RateForTraining},
+        pair::{CurrencyPair, PairRegistry},
+        service::convert_to_feature,
+    },
+    error::MyResult,
+    mysql::client::{Client, DefaultClient, PoolOptions},
+    settings::{RuntimeSettings, SharedSettings},
+};
+
+pub const TEST_PAIR: &str = "USDJPY";
+
+// 一定間隔のレートの系列を生成する
+#[derive(Debug, Clone)]
+pub struct RateSeries {
+    pair: String,
+    begin: NaiveDateTime,
+    interval: Duration,
+}
+
+impl RateSeries {
+    // 2022-01-01 00:00:00 から1分ごとの系列
+    pub fn new(pair: &str) -> Self {
+        RateSeries {
+            pair: pair.to_string(),
+            begin: NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            interval: Duration::minutes(1),
+        }
+    }
+
+    pub fn begin(mut self, begin: NaiveDateTime) -> Self {
+        self.begin = begin;
+        self
+    }
+
+    pub fn interval_minutes(mut self, minutes: i64) -> Self {
+        self.interval = Duration::minutes(minutes);
+        self
+    }
+
+    // start から step ずつ変化する系列
+    pub fn trending(&self, count: usize, start: f64, step: f64) -> Vec<RateForTraining> {
+        self.build((0..count).map(|i| start + step * i as f64))
+    }
+
+    // 値が変化しない系列
+    pub fn flat(&self, count: usize, value: f64) -> Vec<RateForTraining> {
+        self.build((0..count).map(|_| value))
+    }
+
+    // base を中心に ±amplitude の範囲で変動する系列（同じシードであれば同じ系列になる）
+    pub fn noisy(
+        &self,
+        count: usize,
+        base: f64,
+        amplitude: f64,
+        seed: u64,
+    ) -> Vec<RateForTraining> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let values: Vec<f64> = (0..count)
+            .map(|_| base + rng.gen_range(-amplitude..=amplitude))
+            .collect();
+        self.build(values.into_iter())
+    }
+
+    fn build(&self, values: impl Iterator<Item = f64>) -> Vec<RateForTraining> {
+        values
+            .enumerate()
+            .map(|(i, rate)| RateForTraining {
+                pair: self.pair.clone(),
+                recorded_at: self.begin + self.interval * i as i32,
+                rate,
+                created_at: self.begin,
+                updated_at: self.begin,
+            })
+            .collect()
+    }
+}
+
+// 系列の値のみを取り出す
+pub fn to_input_data(rates: &[RateForTraining]) -> InputData {
+    rates.iter().map(|r| r.rate).collect()
+}
+
+pub fn feature_params() -> FeatureParams {
+    FeatureParams::new_default()
+}
+
+pub fn currency_pair(pair: &str, enabled: bool) -> CurrencyPair {
+    CurrencyPair {
+        pair: pair.to_string(),
+        pip_size: 0.01,
+        enabled,
+    }
+}
+
+// 変動する系列で学習したモデル（KNN）
+pub fn forecast_model(pair: &str, no: i32, input_data_size: usize) -> MyResult<ForecastModel> {
+    let params = feature_params();
+    let rates = to_input_data(&RateSeries::new(pair).noisy(input_data_size + 50, 100.0, 1.0, 1));
+
+    let mut x = vec![];
+    let mut y = vec![];
+    for offset in 0..(rates.len() - input_data_size) {
+        let input = rates[offset..offset + input_data_size].to_vec();
+        x.push(convert_to_feature(&input, &params)?);
+        y.push(rates[offset + input_data_size]);
+    }
+
+    let matrix = DenseMatrix::from_2d_vec(&x);
+    let model = KNNRegressor::fit(
+        &matrix,
+        &y,
+        KNNRegressorParameters::default().with_distance(Distances::euclidian()),
+    )?;
+    let mut m = ForecastModel::KNN {
+        pair: pair.to_string(),
+        no,
+        model,
+        input_data_size,
+        feature_params: params,
+        performance_mse: 1.0,
+        performance_rmse: 1.0,
+        memo: "fixture".to_string(),
+    };
+    m.update_performance(&x, &y)?;
+    Ok(m)
+}
+
+// テスト用DBへの接続（接続先は DB_HOST などの通常の環境変数で指定する）
+pub fn test_db_client() -> MyResult<DefaultClient> {
+    crate::mysql::util::make_cli()
+}
+
+// 接続しないDBクライアント（DBを使わない処理のテストで、引数として渡すために使う）
+pub fn unconnected_db_client() -> DefaultClient {
+    let options = PoolOptions {
+        min_size: 0,
+        ..PoolOptions::default()
+    };
+    DefaultClient::new("test", "test", "127.0.0.1", 3306, "test", options)
+        .expect("failed to create db client")
+}
+
+// 指定した通貨ペアを取り扱う実行時設定
+pub fn shared_settings(pairs: &[&str]) -> SharedSettings {
+    SharedSettings::with(RuntimeSettings {
+        currency_pairs: PairRegistry::new(pairs.iter().map(|p| currency_pair(p, true)).collect()),
+        ..RuntimeSettings::default()
+    })
+}
+
+pub fn seed_currency_pairs(mysql_cli: &DefaultClient, pairs: &[CurrencyPair]) -> MyResult<()> {
+    mysql_cli.with_transaction(|tx| {
+        tx.exec_batch(
+            r"INSERT INTO currency_pairs (pair, pip_size, enabled)
+              VALUES (:pair, :pip_size, :enabled)
+              ON DUPLICATE KEY UPDATE pip_size = :pip_size, enabled = :enabled",
+            pairs.iter().map(|p| {
+                params! {
+                    "pair" => &p.pair,
+                    "pip_size" => p.pip_size,
+                    "enabled" => p.enabled,
+                }
+            }),
+        )?;
+        Ok(())
+    })
+}
+
+pub fn seed_rates(mysql_cli: &DefaultClient, rates: &[RateForTraining]) -> MyResult<u64> {
+    mysql_cli.with_transaction(|tx| mysql_cli.bulk_insert_rates_for_training(tx, rates))
+}
+
+pub fn seed_forecast_model(mysql_cli: &DefaultClient, model: &ForecastModel) -> MyResult<()> {
+    mysql_cli.with_transaction(|tx| mysql_cli.upsert_forecast_model(tx, model))
+}
+
+// 指定した通貨ペアのテストデータを削除する
+pub fn cleanup_pair(mysql_cli: &DefaultClient, pair: &str) -> MyResult<()> {
+    mysql_cli.with_transaction(|tx| {
+        // 予想用レートを参照する記録から削除する
+        for table in ["forecast_results", "forecast_errors", "forecast_explanations"] {
+            tx.exec_drop(
+                format!(
+                    "DELETE t FROM {} t INNER JOIN rates_for_forecast r ON t.rate_id = r.id WHERE r.pair = :pair",
+                    table
+                ),
+                params! { "pair" => pair },
+            )?;
+        }
+        for table in [
+            "rates_for_forecast",
+            "rates_for_training",
+            "forecast_models",
+            "rate_gaps",
+            "rate_anomalies",
+        ] {
+            tx.exec_drop(
+                format!("DELETE FROM {} WHERE pair = :pair", table),
+                params! { "pair" => pair },
+            )?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_rate_series() {
+        let series = RateSeries::new(TEST_PAIR).interval_minutes(5);
+
+        let rates = series.trending(3, 100.0, 0.5);
+        assert_eq!(to_input_data(&rates), vec![100.0, 100.5, 101.0]);
+        assert_eq!(
+            rates[2].recorded_at,
+            NaiveDate::from_ymd_opt(2022, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 10, 0)
+                .unwrap()
+        );
+
+        let rates = series.flat(3, 100.0);
+        assert_eq!(to_input_data(&rates), vec![100.0, 100.0, 100.0]);
+
+        let rates = series.noisy(100, 100.0, 1.0, 1);
+        assert!(rates.iter().all(|r| (99.0..=101.0).contains(&r.rate)));
+        assert_eq!(
+            to_input_data(&rates),
+            to_input_data(&series.noisy(100, 100.0, 1.0, 1))
+        );
+    }
+
+    #[test]
+    fn test_for_forecast_model() {
+        let m = forecast_model(TEST_PAIR, 1, 30).unwrap();
+        assert_eq!(m.get_input_data_size().unwrap(), 30);
+        assert!(m.get_performance_mse() >= 0.0);
+    }
+}
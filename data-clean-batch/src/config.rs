@@ -1,7 +1,80 @@
+use common_lib::{config::Validator, domain::anomaly::AnomalyRules, error::MyResult};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 pub struct Config {
     pub expire_date_count: i64,
     pub cron_schedule: String,
+
+    // 直前・直後のレートとの変化率の上限（これより大きく外れて戻ったレートを異常とする）
+    #[serde(default = "default_anomaly_max_jump_ratio")]
+    pub anomaly_max_jump_ratio: f64,
+    // 同じレートが連続してよい件数（0の場合は判定しない）
+    #[serde(default = "default_anomaly_max_stale_count")]
+    pub anomaly_max_stale_count: usize,
+    // 週末の取引停止時間帯（UTC）のレートを異常とする場合は true
+    #[serde(default = "default_anomaly_check_market_hours")]
+    pub anomaly_check_market_hours: bool,
+    // 週末の取引停止時間帯（UTC）、金曜の開始時から日曜の終了時まで
+    #[serde(default = "default_anomaly_weekend_begin_hour")]
+    pub anomaly_weekend_begin_hour: u32,
+    #[serde(default = "default_anomaly_weekend_end_hour")]
+    pub anomaly_weekend_end_hour: u32,
+}
+
+fn default_anomaly_max_jump_ratio() -> f64 {
+    0.01
+}
+
+fn default_anomaly_max_stale_count() -> usize {
+    30
+}
+
+fn default_anomaly_check_market_hours() -> bool {
+    true
+}
+
+fn default_anomaly_weekend_begin_hour() -> u32 {
+    22
+}
+
+fn default_anomaly_weekend_end_hour() -> u32 {
+    21
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("expire_date_count", self.expire_date_count);
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_positive("anomaly_max_jump_ratio", self.anomaly_max_jump_ratio);
+        v.check_range(
+            "anomaly_weekend_begin_hour",
+            self.anomaly_weekend_begin_hour,
+            0,
+            23,
+        );
+        v.check_range(
+            "anomaly_weekend_end_hour",
+            self.anomaly_weekend_end_hour,
+            0,
+            23,
+        );
+        v.finish()
+    }
+
+    pub fn anomaly_rules(&self) -> AnomalyRules {
+        AnomalyRules {
+            max_jump_ratio: self.anomaly_max_jump_ratio,
+            max_stale_count: self.anomaly_max_stale_count,
+            weekend_hours: if self.anomaly_check_market_hours {
+                Some((
+                    self.anomaly_weekend_begin_hour,
+                    self.anomaly_weekend_end_hour,
+                ))
+            } else {
+                None
+            },
+        }
+    }
 }
@@ -1,25 +1,55 @@
 extern crate common_lib;
 
-use chrono::{Duration, Utc};
+use chrono::Duration;
 use common_lib::{
     batch,
+    clock::{Clock, SystemClock},
+    domain::{anomaly, series},
     error::MyResult,
+    error_report, logger,
     mysql::{self, client::Client},
+    telemetry,
 };
 use config::Config;
 use log::{error, info};
 
 mod config;
 
+const JOB_NAME: &str = "data-clean-batch";
+
 fn init_logger() {
-    env_logger::init();
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
 }
 
 fn main() {
     init_logger();
 
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init(JOB_NAME) {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init(JOB_NAME) {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
     let config: config::Config;
-    match envy::from_env::<config::Config>() {
+    match common_lib::config::load::<config::Config>() {
         Ok(c) => {
             config = c;
         }
@@ -28,6 +58,10 @@ fn main() {
             return;
         }
     }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
 
     let mysql_cli: mysql::client::DefaultClient;
     match mysql::util::make_cli() {
@@ -40,21 +74,74 @@ fn main() {
         }
     }
 
-    if let Err(err) = batch::util::start_scheduler(&config.cron_schedule, || {
-        run(&config, &mysql_cli);
-    }) {
+    let retry_policy: batch::util::RetryPolicy;
+    match batch::util::RetryPolicy::from_env() {
+        Ok(p) => {
+            retry_policy = p;
+        }
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    }
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    // ワーカーモードでは、オーケストレーターからの要求を受けて実行する（再試行もオーケストレーターが行う）
+    let worker_mode: bool;
+    match batch::util::worker_mode_from_env() {
+        Ok(m) => {
+            worker_mode = m;
+        }
+        Err(err) => {
+            error!("failed to load worker mode, error: {}", err);
+            return;
+        }
+    }
+    if worker_mode {
+        if let Err(err) = batch::worker::start_worker(JOB_NAME, &mysql_cli, || {
+            health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    run(&config, &mysql_cli, &SystemClock)
+                })
+            })
+        }) {
+            error!("failed to start worker, error: {}", err);
+        }
+        return;
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            if let Err(err) = health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli, &SystemClock))
+                })
+            }) {
+                error!("failed to clean , error: {}", err);
+            }
+        })
+    {
         error!("failed to start scheduler, error: {}", err);
     }
 }
 
-fn run(config: &Config, mysql_cli: &mysql::client::DefaultClient) {
+fn run(
+    config: &Config,
+    mysql_cli: &mysql::client::DefaultClient,
+    clock: &dyn Clock,
+) -> MyResult<()> {
     info!(
         "start DataCleanBatch, expire_date:{}",
         config.expire_date_count
     );
 
-    let border = (Utc::now() - Duration::days(config.expire_date_count)).naive_utc();
-    match mysql_cli.with_transaction(|tx| -> MyResult<()> {
+    let border = (clock.now() - Duration::days(config.expire_date_count)).naive_utc();
+    mysql_cli.with_transaction(|tx| -> MyResult<()> {
         mysql_cli.delete_old_rates_for_training(tx, &border)?;
         info!(
             "successful cleaning table 'rate_for_training', border:{}",
@@ -67,14 +154,40 @@ fn run(config: &Config, mysql_cli: &mysql::client::DefaultClient) {
         mysql_cli.delete_forecast_errors_expired(tx)?;
         info!("successful cleaning table 'forecast_errors'");
 
+        mysql_cli.delete_forecast_explanations_expired(tx)?;
+        info!("successful cleaning table 'forecast_explanations'");
+
         mysql_cli.delete_rates_for_forecast_expired(tx)?;
         info!("successful cleaning table 'rates_for_forecast'");
 
-        Ok(())
-    }) {
-        Ok(_) => {}
-        Err(err) => {
-            error!("failed to clean , error: {}", err);
+        mysql_cli.delete_old_data_quality_reports(tx, &border)?;
+        info!(
+            "successful cleaning table 'data_quality_reports', border:{}",
+            border
+        );
+
+        // 削除後のレートを対象に、通貨ペアごとに欠損期間と異常値を記録し直す
+        let rules = config.anomaly_rules();
+        for pair in mysql_cli.select_currency_pairs(tx)? {
+            let rates = mysql_cli.select_rates_for_training(tx, &pair.pair, None, None)?;
+            let points = series::resample_minutely(&rates);
+            let gaps = series::detect_gaps(&pair.pair, &points, 1);
+            mysql_cli.replace_rate_gaps(tx, &pair.pair, &gaps)?;
+            info!(
+                "successful refreshing table 'rate_gaps', pair:{}, count:{}",
+                pair.pair,
+                gaps.len()
+            );
+
+            let anomalies = anomaly::detect_anomalies(&pair.pair, &rates, &rules);
+            mysql_cli.replace_rate_anomalies(tx, &pair.pair, &anomalies)?;
+            info!(
+                "successful refreshing table 'rate_anomalies', pair:{}, count:{}",
+                pair.pair,
+                anomalies.len()
+            );
         }
-    };
+
+        Ok(())
+    })
 }
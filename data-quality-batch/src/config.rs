@@ -0,0 +1,166 @@
+use common_lib::{config::Validator, domain::quality::DataQualityRules, error::MyResult};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // バッチ関連
+    pub cron_schedule: String,
+
+    // 計測する期間（現在日時から何時間前までのレートを対象にするか）
+    #[serde(default = "default_data_quality_window_hours")]
+    pub data_quality_window_hours: i64,
+    // 取引時間中の分のうち、レートがある分の割合の下限（0.0〜1.0）
+    #[serde(default = "default_data_quality_min_coverage")]
+    pub data_quality_min_coverage: f64,
+    // 欠損期間の件数の上限
+    #[serde(default = "default_data_quality_max_gap_count")]
+    pub data_quality_max_gap_count: i64,
+    // 同じ分に記録されたレート数の上限
+    #[serde(default = "default_data_quality_max_duplicate_count")]
+    pub data_quality_max_duplicate_count: i64,
+    // 最新のレートの記録日時からの経過秒数の上限
+    #[serde(default = "default_data_quality_max_lag_seconds")]
+    pub data_quality_max_lag_seconds: i64,
+
+    // 週末の取引停止時間帯（UTC）を計測から除く場合は true
+    #[serde(default = "default_data_quality_check_market_hours")]
+    pub data_quality_check_market_hours: bool,
+    // 週末の取引停止時間帯（UTC）、金曜の開始時から日曜の終了時まで
+    #[serde(default = "default_data_quality_weekend_begin_hour")]
+    pub data_quality_weekend_begin_hour: u32,
+    #[serde(default = "default_data_quality_weekend_end_hour")]
+    pub data_quality_weekend_end_hour: u32,
+}
+
+fn default_data_quality_window_hours() -> i64 {
+    24
+}
+
+fn default_data_quality_min_coverage() -> f64 {
+    0.95
+}
+
+fn default_data_quality_max_gap_count() -> i64 {
+    10
+}
+
+fn default_data_quality_max_duplicate_count() -> i64 {
+    60
+}
+
+fn default_data_quality_max_lag_seconds() -> i64 {
+    300
+}
+
+fn default_data_quality_check_market_hours() -> bool {
+    true
+}
+
+fn default_data_quality_weekend_begin_hour() -> u32 {
+    22
+}
+
+fn default_data_quality_weekend_end_hour() -> u32 {
+    21
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_positive("data_quality_window_hours", self.data_quality_window_hours);
+        v.check_range(
+            "data_quality_min_coverage",
+            self.data_quality_min_coverage,
+            0.0,
+            1.0,
+        );
+        v.check(
+            self.data_quality_max_gap_count >= 0,
+            format!(
+                "data_quality_max_gap_count must be 0 or more, value: {}",
+                self.data_quality_max_gap_count
+            ),
+        );
+        v.check(
+            self.data_quality_max_duplicate_count >= 0,
+            format!(
+                "data_quality_max_duplicate_count must be 0 or more, value: {}",
+                self.data_quality_max_duplicate_count
+            ),
+        );
+        v.check_positive(
+            "data_quality_max_lag_seconds",
+            self.data_quality_max_lag_seconds,
+        );
+        v.check_range(
+            "data_quality_weekend_begin_hour",
+            self.data_quality_weekend_begin_hour,
+            0,
+            23,
+        );
+        v.check_range(
+            "data_quality_weekend_end_hour",
+            self.data_quality_weekend_end_hour,
+            0,
+            23,
+        );
+        v.finish()
+    }
+
+    pub fn rules(&self) -> DataQualityRules {
+        DataQualityRules {
+            min_coverage: self.data_quality_min_coverage,
+            max_gap_count: self.data_quality_max_gap_count,
+            max_duplicate_count: self.data_quality_max_duplicate_count,
+            max_lag_seconds: self.data_quality_max_lag_seconds,
+            weekend_hours: if self.data_quality_check_market_hours {
+                Some((
+                    self.data_quality_weekend_begin_hour,
+                    self.data_quality_weekend_end_hour,
+                ))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn valid_config() -> Config {
+        Config {
+            cron_schedule: "0 0/10 * * * *".to_string(),
+            data_quality_window_hours: 24,
+            data_quality_min_coverage: 0.95,
+            data_quality_max_gap_count: 10,
+            data_quality_max_duplicate_count: 60,
+            data_quality_max_lag_seconds: 300,
+            data_quality_check_market_hours: true,
+            data_quality_weekend_begin_hour: 22,
+            data_quality_weekend_end_hour: 21,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(valid_config().validate().is_ok());
+
+        let c = Config {
+            data_quality_window_hours: 0,
+            data_quality_min_coverage: 1.5,
+            data_quality_max_gap_count: -1,
+            data_quality_weekend_end_hour: 24,
+            ..valid_config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+}
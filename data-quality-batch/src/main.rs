@@ -0,0 +1,147 @@
+extern crate common_lib;
+
+use chrono::{Duration, NaiveDateTime};
+use common_lib::{
+    batch,
+    clock::{Clock, SystemClock},
+    domain::quality::{DataQualityReport, DataQualityRules},
+    error::MyResult,
+    error_report, logger, metrics,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+    notify::{self, NotifyEvent},
+    telemetry,
+};
+use log::{error, info, warn};
+
+mod config;
+
+const JOB_NAME: &str = "data-quality-batch";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry = match telemetry::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    };
+
+    let _error_report = match error_report::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    };
+
+    let config = match common_lib::config::load::<config::Config>() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(cli) => cli,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    };
+
+    let retry_policy = match batch::util::RetryPolicy::from_env() {
+        Ok(p) => p,
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    };
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            if let Err(err) = health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli, &SystemClock))
+                })
+            }) {
+                error!("failed to measure data quality, error: {}", err);
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+// 取り扱う通貨ペアごとに直近のレートの品質を計測して記録する
+fn run(config: &config::Config, mysql_cli: &DefaultClient, clock: &dyn Clock) -> MyResult<()> {
+    let rules = config.rules();
+    let now = clock.now().naive_utc();
+    let begin = now - Duration::hours(config.data_quality_window_hours);
+
+    let pairs = mysql_cli.with_transaction(|tx| mysql_cli.select_currency_pairs(tx))?;
+    let mut result: MyResult<()> = Ok(());
+    for pair in pairs.iter().filter(|p| p.enabled) {
+        logger::set_field("pair", &pair.pair);
+        error_report::set_tag("pair", &pair.pair);
+        if let Err(err) = run_pair(mysql_cli, &rules, &pair.pair, begin, now) {
+            error!(
+                "failed to measure data quality, pair: {}, error: {}",
+                pair.pair, err
+            );
+            result = Err(err);
+        }
+    }
+    result
+}
+
+fn run_pair(
+    mysql_cli: &DefaultClient,
+    rules: &DataQualityRules,
+    pair: &str,
+    begin: NaiveDateTime,
+    now: NaiveDateTime,
+) -> MyResult<()> {
+    let (report, previous) = mysql_cli.with_transaction(|tx| {
+        let rates = mysql_cli.select_rates_for_training(tx, pair, Some(begin), Some(now))?;
+        let report = DataQualityReport::measure(pair, &rates, begin, now, now, rules);
+        let previous = mysql_cli.select_latest_data_quality_report(tx, pair)?;
+        mysql_cli.insert_data_quality_report(tx, &report)?;
+        Ok((report, previous))
+    })?;
+    metrics::observe_data_quality(&report);
+    info!(
+        "data quality measured, pair: {}, coverage: {:.3}, gaps: {}, duplicates: {}, lag_seconds: {:?}",
+        pair, report.coverage, report.gap_count, report.duplicate_count, report.lag_seconds
+    );
+
+    // 基準を下回り続けている間は繰り返し通知しない
+    if report.is_ok() || previous.map(|p| !p.is_ok()).unwrap_or(false) {
+        return Ok(());
+    }
+    let message = format!("pair: {}, {}", pair, report.problems.join(", "));
+    warn!("data quality degraded, {}", message);
+    notify::notify_from_env(NotifyEvent::DataQualityDegraded, &message);
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use common_lib::{
+    config::Validator, domain::model::forecast_type_from_offset_minutes, error::MyResult,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // 共通設定
+    pub forecast_offset_minutes: usize,
+    // 予測用モデル（チャンピオン）の番号
+    #[serde(default)]
+    pub forecast_model_no: i32,
+    // チャレンジャーのモデルNo（0の場合はA/Bテストを行わない）
+    #[serde(default)]
+    pub challenger_model_no: i32,
+
+    // バッチ関連
+    pub cron_schedule: String,
+
+    // 集計し直す期間（時間）、実績値の記録が遅れた評価も集計に含めるため直近の期間をまとめて集計する
+    #[serde(default = "default_evaluation_lookback_hours")]
+    pub evaluation_lookback_hours: i64,
+
+    // チャレンジャーとの比較に使う期間（時間）
+    #[serde(default = "default_challenger_window_hours")]
+    pub challenger_window_hours: i64,
+    // 比較に必要な予想の件数
+    #[serde(default = "default_challenger_min_samples")]
+    pub challenger_min_samples: usize,
+    // 昇格に必要なMSEの改善率
+    #[serde(default = "default_challenger_min_improvement")]
+    pub challenger_min_improvement: f64,
+    // 昇格に必要な検定統計量（1.96 の場合は片側約2.5%の有意水準）
+    #[serde(default = "default_challenger_z_threshold")]
+    pub challenger_z_threshold: f64,
+}
+
+fn default_evaluation_lookback_hours() -> i64 {
+    48
+}
+
+fn default_challenger_window_hours() -> i64 {
+    72
+}
+
+fn default_challenger_min_samples() -> usize {
+    100
+}
+
+fn default_challenger_min_improvement() -> f64 {
+    0.05
+}
+
+fn default_challenger_z_threshold() -> f64 {
+    1.96
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        if let Err(err) = forecast_type_from_offset_minutes(self.forecast_offset_minutes) {
+            v.check(
+                false,
+                format!("forecast_offset_minutes is invalid, {}", err),
+            );
+        }
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_positive("evaluation_lookback_hours", self.evaluation_lookback_hours);
+
+        v.check(
+            self.challenger_model_no >= 0,
+            format!(
+                "challenger_model_no must be 0 or more, value: {}",
+                self.challenger_model_no
+            ),
+        );
+        if self.challenger_model_no > 0 {
+            v.check_positive("forecast_model_no", self.forecast_model_no);
+            v.check(
+                self.forecast_model_no != self.challenger_model_no,
+                format!(
+                    "forecast_model_no and challenger_model_no must be different, value: {}",
+                    self.forecast_model_no
+                ),
+            );
+        }
+        v.check_positive("challenger_window_hours", self.challenger_window_hours);
+        // 分散を求めるために2件以上必要
+        v.check(
+            self.challenger_min_samples >= 2,
+            format!(
+                "challenger_min_samples must be 2 or more, value: {}",
+                self.challenger_min_samples
+            ),
+        );
+        v.check_range(
+            "challenger_min_improvement",
+            self.challenger_min_improvement,
+            0.0,
+            1.0,
+        );
+        v.check_positive("challenger_z_threshold", self.challenger_z_threshold);
+        v.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn valid_config() -> Config {
+        Config {
+            forecast_offset_minutes: 30,
+            forecast_model_no: 1,
+            challenger_model_no: 3,
+            cron_schedule: "0 0/10 * * * *".to_string(),
+            evaluation_lookback_hours: 48,
+            challenger_window_hours: 72,
+            challenger_min_samples: 100,
+            challenger_min_improvement: 0.05,
+            challenger_z_threshold: 1.96,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(valid_config().validate().is_ok());
+
+        // チャレンジャーを使わない場合は予測用モデルの番号は不要
+        let c = Config {
+            forecast_model_no: 0,
+            challenger_model_no: 0,
+            ..valid_config()
+        };
+        assert!(c.validate().is_ok());
+
+        let c = Config {
+            cron_schedule: "invalid".to_string(),
+            evaluation_lookback_hours: 0,
+            ..valid_config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 2),
+            _ => panic!("unexpected error: {}", err),
+        }
+
+        let c = Config {
+            challenger_model_no: 1,
+            challenger_min_samples: 1,
+            challenger_min_improvement: 1.5,
+            ..valid_config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 3),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+extern crate common_lib;
+
+use chrono::{Duration, NaiveDateTime};
+use common_lib::{
+    batch,
+    clock::{Clock, SystemClock},
+    domain::{
+        accuracy::AccuracyBucket,
+        challenger::{ChallengeCriteria, ChallengeResult},
+        model::forecast_type_from_offset_minutes,
+    },
+    error::MyResult,
+    error_report, logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+    notify::{self, NotifyEvent},
+    telemetry,
+};
+use log::{error, info, warn};
+
+mod config;
+
+const JOB_NAME: &str = "evaluation-batch";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry = match telemetry::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    };
+
+    let _error_report = match error_report::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    };
+
+    let config = match common_lib::config::load::<config::Config>() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(cli) => cli,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    };
+
+    let retry_policy = match batch::util::RetryPolicy::from_env() {
+        Ok(p) => p,
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    };
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            if let Err(err) = health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli, &SystemClock))
+                })
+            }) {
+                error!("failed to aggregate forecast accuracy, error: {}", err);
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+// 直近の期間の評価を、時間ごと・日ごとに集計し直す
+fn run(config: &config::Config, mysql_cli: &DefaultClient, clock: &dyn Clock) -> MyResult<()> {
+    let since = (clock.now() - Duration::hours(config.evaluation_lookback_hours)).naive_utc();
+    for bucket in [AccuracyBucket::Hour, AccuracyBucket::Day] {
+        // 集計期間の途中から集計すると期間の前半が欠けるため、期間の開始日時に揃える
+        let begin = bucket.truncate(since);
+        let affected = mysql_cli
+            .with_transaction(|tx| mysql_cli.upsert_forecast_accuracy_stats(tx, bucket, begin))?;
+        info!(
+            "forecast accuracy aggregated, bucket: {}, begin: {}, affected: {}",
+            bucket.as_str(),
+            begin,
+            affected
+        );
+    }
+
+    if config.challenger_model_no > 0 {
+        challenge(config, mysql_cli, clock)?;
+    }
+    Ok(())
+}
+
+// 通貨ペアごとにチャンピオンとチャレンジャーの実績誤差を比較し、チャレンジャーが有意に優れていれば昇格する
+fn challenge(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    clock: &dyn Clock,
+) -> MyResult<()> {
+    let forecast_type = forecast_type_from_offset_minutes(config.forecast_offset_minutes)?;
+    let criteria = ChallengeCriteria {
+        min_samples: config.challenger_min_samples,
+        min_improvement: config.challenger_min_improvement,
+        z_threshold: config.challenger_z_threshold,
+    };
+    let begin = (clock.now() - Duration::hours(config.challenger_window_hours)).naive_utc();
+
+    let pairs = mysql_cli.with_transaction(|tx| mysql_cli.select_currency_pairs(tx))?;
+    let mut result: MyResult<()> = Ok(());
+    for pair in pairs {
+        logger::set_field("pair", &pair.pair);
+        error_report::set_tag("pair", &pair.pair);
+        if let Err(err) = challenge_pair(
+            config,
+            mysql_cli,
+            &criteria,
+            forecast_type,
+            &pair.pair,
+            begin,
+        ) {
+            error!(
+                "failed to compare challenger, pair: {}, error: {}",
+                pair.pair, err
+            );
+            result = Err(err);
+        }
+    }
+    result
+}
+
+fn challenge_pair(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    criteria: &ChallengeCriteria,
+    forecast_type: i32,
+    pair: &str,
+    begin: NaiveDateTime,
+) -> MyResult<()> {
+    let errors = mysql_cli.with_transaction(|tx| {
+        mysql_cli.select_paired_forecast_errors(
+            tx,
+            pair,
+            forecast_type,
+            config.forecast_model_no,
+            config.challenger_model_no,
+            begin,
+        )
+    })?;
+    let result = match ChallengeResult::compare(&errors) {
+        Some(r) => r,
+        None => {
+            info!(
+                "challenger comparison skipped, pair: {}, samples: {}",
+                pair,
+                errors.len()
+            );
+            return Ok(());
+        }
+    };
+    info!(
+        "challenger compared, pair: {}, samples: {}, champion_mse: {:.6}, challenger_mse: {:.6}, improvement: {:.4}, z_score: {:.4}",
+        pair,
+        result.samples,
+        result.champion_mse,
+        result.challenger_mse,
+        result.improvement,
+        result.z_score
+    );
+    if !result.challenger_wins(criteria) {
+        return Ok(());
+    }
+
+    // 昇格するとチャンピオンの更新日時が変わるため、以降の比較は昇格後の予想のみで行われる
+    mysql_cli.with_transaction(|tx| {
+        mysql_cli.copy_forecast_model(
+            tx,
+            pair,
+            config.challenger_model_no,
+            config.forecast_model_no,
+        )?;
+        Ok(())
+    })?;
+    warn!(
+        "challenger promoted, pair: {}, from_no: {}, to_no: {}",
+        pair, config.challenger_model_no, config.forecast_model_no
+    );
+    notify::notify_from_env(
+        NotifyEvent::ChallengerPromoted,
+        &format!(
+            "pair: {}, from_no: {}, to_no: {}, samples: {}, champion_mse: {:.6}, challenger_mse: {:.6}, improvement: {:.4}, z_score: {:.4}",
+            pair,
+            config.challenger_model_no,
+            config.forecast_model_no,
+            result.samples,
+            result.champion_mse,
+            result.challenger_mse,
+            result.improvement,
+            result.z_score
+        ),
+    );
+    Ok(())
+}
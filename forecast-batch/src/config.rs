@@ -1,11 +1,53 @@
+use common_lib::{
+    config::Validator, domain::model::forecast_type_from_offset_minutes, error::MyResult,
+};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 pub struct Config {
     // 共通設定
     pub forecast_offset_minutes: usize,
-    pub currency_pair: String,
+    // チャレンジャーのモデルNo（0の場合はA/Bテストを行わない）
+    #[serde(default)]
+    pub challenger_model_no: i32,
+    // 予想値の推移を何ステップ分予想するか（1以下の場合は予想対象の時間の1点のみ）
+    #[serde(default)]
+    pub forecast_path_steps: usize,
+    // 予想結果の根拠（特徴量ごとの寄与）も登録するか
+    #[serde(default)]
+    pub forecast_explanation_enabled: bool,
 
     // バッチ関連
     pub cron_schedule: String,
+    // 登録から指定秒数が経っていない予想用レートは、登録直後の予想に任せて予想しない
+    #[serde(default)]
+    pub forecast_sweep_min_age_seconds: i64,
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check(
+            self.challenger_model_no >= 0,
+            format!(
+                "challenger_model_no must be 0 or more, value: {}",
+                self.challenger_model_no
+            ),
+        );
+        v.check(
+            self.forecast_sweep_min_age_seconds >= 0,
+            format!(
+                "forecast_sweep_min_age_seconds must be 0 or more, value: {}",
+                self.forecast_sweep_min_age_seconds
+            ),
+        );
+        if let Err(err) = forecast_type_from_offset_minutes(self.forecast_offset_minutes) {
+            v.check(
+                false,
+                format!("forecast_offset_minutes is invalid, {}", err),
+            );
+        }
+        v.finish()
+    }
 }
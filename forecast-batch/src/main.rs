@@ -2,29 +2,56 @@ extern crate common_lib;
 
 use common_lib::{
     batch,
-    domain::{
-        model::{ForecastError, ForecastResult},
-        service::convert_to_feature,
-    },
     error::MyResult,
+    error_report, feature,
+    forecast::{self, Forecaster},
+    logger,
     mysql::{
         self,
         client::{Client, DefaultClient},
     },
+    settings::{self, RuntimeSettings, SharedSettings},
+    telemetry,
 };
-use log::{error, info, warn};
+use log::{error, info};
 
 mod config;
 
+const JOB_NAME: &str = "forecast-batch";
+
 fn init_logger() {
-    env_logger::init();
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
 }
 
 fn main() {
     init_logger();
 
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init(JOB_NAME) {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init(JOB_NAME) {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
     let config: config::Config;
-    match envy::from_env::<config::Config>() {
+    match common_lib::config::load::<config::Config>() {
         Ok(c) => {
             config = c;
         }
@@ -33,6 +60,10 @@ fn main() {
             return;
         }
     }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
 
     let mysql_cli: DefaultClient;
     match mysql::util::make_cli() {
@@ -45,88 +76,155 @@ fn main() {
         }
     }
 
-    if let Err(err) = batch::util::start_scheduler(&config.cron_schedule, || {
-        info!("start forecast");
-        match run(&config, &mysql_cli) {
-            Ok(_) => {
-                info!("finished forecast");
-            }
-            Err(err) => {
-                error!("failed to forecast, error:{}", err);
+    let retry_policy: batch::util::RetryPolicy;
+    match batch::util::RetryPolicy::from_env() {
+        Ok(p) => {
+            retry_policy = p;
+        }
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    }
+
+    // 通貨ペアやモデルのMSEの上限は再起動せずに変更できるよう、SIGHUPまたは一定間隔で読み込み直す
+    let runtime_settings = SharedSettings::new();
+    if let Err(err) = runtime_settings.reload(&mysql_cli) {
+        error!("failed to load runtime settings, error: {}", err);
+        return;
+    }
+    match settings::reload_interval_from_env() {
+        Ok(interval) => {
+            if let Err(err) = runtime_settings.watch(mysql_cli.clone(), interval, |_| {}) {
+                error!("failed to watch runtime settings, error: {}", err);
+                return;
             }
         }
-    }) {
-        error!("failed to start scheduler, error: {}", err);
+        Err(err) => {
+            error!("failed to load settings config, error: {}", err);
+            return;
+        }
     }
-}
 
-fn run(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()> {
-    mysql_cli.with_transaction(|tx| -> MyResult<()> {
-        let models = mysql_cli.select_forecast_models(tx, &config.currency_pair)?;
-        let rates = mysql_cli.select_rates_for_forecast_unforecasted(tx, &config.currency_pair)?;
-        info!(
-            "model count: {}, rates count: {}",
-            models.len(),
-            rates.len()
-        );
-
-        let mut results: Vec<ForecastResult> = vec![];
-        let mut errors: Vec<ForecastError> = vec![];
-        for rate in &rates {
-            let rate_size = rate.histories.len();
-            for model in &models {
-                let model_no = model.get_no()?;
-                if let Some(e) = mysql_cli
-                    .select_forecast_errors_by_rate_id_and_model_no(tx, &rate.id, model_no)?
-                {
-                    warn!(
-                        "forecast skipped, error exists. id:{}, rate_id:{}, model_no:{}",
-                        e.id, &rate.id, model_no
-                    );
-                    continue;
-                }
+    let features: feature::FeatureFlags;
+    match feature::FeatureFlags::from_env(runtime_settings.clone()) {
+        Ok(f) => {
+            features = f;
+        }
+        Err(err) => {
+            error!("failed to load feature flags, error: {}", err);
+            return;
+        }
+    }
+    info!("feature flags: {}", features);
 
-                let input_data_size = model.get_input_data_size()?;
-                if input_data_size != rate_size {
-                    let record = ForecastError::new(
-                        rate.id.clone(),
-                        model.get_no()?,
-                        "input data size is not supported".to_string(),
-                        format!(
-                            "size(model): {}, size(input data): {}",
-                            input_data_size, rate_size
-                        ),
-                    )?;
-                    warn!("forecast skipped, {}", record);
-                    errors.push(record);
-
-                    continue;
-                }
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
 
-                let features = convert_to_feature(&rate.histories, &model.get_feature_params()?)?;
-
-                let result = ForecastResult::new(
-                    rate.id.to_string(),
-                    model.get_no()?,
-                    0,
-                    model.predict(&features)?,
-                    "after5min".to_string(),
-                )?;
-                info!(
-                    "forecast succeeded. pair: {}, model_no: {}, rate_id: {}, result: {}",
-                    model.get_pair()?,
-                    result.model_no,
-                    result.rate_id,
-                    result.result
-                );
-
-                results.push(result);
-            }
+    // ワーカーモードでは、オーケストレーターからの要求を受けて実行する（再試行もオーケストレーターが行う）
+    let worker_mode: bool;
+    match batch::util::worker_mode_from_env() {
+        Ok(m) => {
+            worker_mode = m;
+        }
+        Err(err) => {
+            error!("failed to load worker mode, error: {}", err);
+            return;
+        }
+    }
+    if worker_mode {
+        if let Err(err) = batch::worker::start_worker(JOB_NAME, &mysql_cli, || {
+            health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    run(&config, &mysql_cli, &runtime_settings)
+                })
+            })
+        }) {
+            error!("failed to start worker, error: {}", err);
         }
+        return;
+    }
 
-        mysql_cli.insert_forecast_results(tx, &results)?;
-        mysql_cli.insert_forecast_errors(tx, &errors)?;
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            info!("start forecast");
+            match health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli, &runtime_settings))
+                })
+            }) {
+                Ok(_) => {
+                    info!("finished forecast");
+                }
+                Err(err) => {
+                    error!("failed to forecast, error:{}", err);
+                }
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+fn run(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    runtime_settings: &SharedSettings,
+) -> MyResult<()> {
+    let forecaster = Forecaster::new(config.forecast_offset_minutes, config.challenger_model_no)?
+        .with_path_steps(config.forecast_path_steps)
+        .with_explanation(config.forecast_explanation_enabled);
+
+    // 取り扱う通貨ペアごとに予想する（1つの通貨ペアで失敗しても他の通貨ペアは予想する）
+    let settings = runtime_settings.get();
+    let pairs = settings.allowed_pairs();
+    if pairs.is_empty() {
+        info!("forecast skipped, no pair is allowed");
+        return Ok(());
+    }
+
+    let mut result: MyResult<()> = Ok(());
+    for pair in pairs {
+        logger::set_field("pair", &pair);
+        error_report::set_tag("pair", &pair);
+        if let Err(err) = run_pair(config, mysql_cli, &settings, &forecaster, &pair) {
+            error!("failed to forecast, pair: {}, error: {}", pair, err);
+            result = Err(err);
+        }
+    }
+    result
+}
 
-        Ok(())
-    })
+fn run_pair(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    settings: &RuntimeSettings,
+    forecaster: &Forecaster,
+    pair: &str,
+) -> MyResult<()> {
+    let outcome = mysql_cli.with_transaction(|tx| {
+        let outcome = forecaster.forecast_unforecasted(
+            mysql_cli,
+            tx,
+            settings,
+            pair,
+            config.forecast_sweep_min_age_seconds,
+        )?;
+
+        let evaluated = mysql_cli.insert_forecast_evaluations(
+            tx,
+            pair,
+            forecaster.forecast_type(),
+            config.forecast_offset_minutes,
+        )?;
+        info!("evaluated forecast results count: {}", evaluated);
+
+        Ok(outcome)
+    })?;
+
+    forecast::observe(pair, &outcome);
+    Ok(())
 }
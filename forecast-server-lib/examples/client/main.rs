@@ -5,7 +5,17 @@
 use futures::{future, Stream, stream};
 #[allow(unused_imports)]
 use forecast_server_lib::{Api, ApiNoContext, Client, ContextWrapperExt, models,
-                      ForecastAfter30minRateIdModelNoGetResponse,
+                      AdminModelsPairFromNoPromoteToNoPostResponse,
+                      AdminTrainingPostResponse,
+                      AdminTrainingRunIdGetResponse,
+                      ModelsModelNoPerformanceGetResponse,
+                      ModelsPairNoMetricsGetResponse,
+                      EvaluationsAccuracyGetResponse,
+                      ForecastErrorsGetResponse,
+                      ForecastHorizonRateIdModelNoGetResponse,
+                      ForecastHorizonRateIdModelNoExplanationGetResponse,
+                      ForecastNowPostResponse,
+                      ForecastsBatchPostResponse,
                       RatesPostResponse,
                      };
 use clap::{App, Arg};
@@ -28,7 +38,10 @@ fn main() {
         .arg(Arg::with_name("operation")
             .help("Sets the operation to run")
             .possible_values(&[
-                "ForecastAfter30minRateIdModelNoGet",
+                "AdminModelsPairFromNoPromoteToNoPost",
+                "AdminTrainingRunIdGet",
+                "ModelsPairNoMetricsGet",
+                "ForecastErrorsGet",
             ])
             .required(true)
             .index(1))
@@ -72,14 +85,110 @@ fn main() {
     let mut rt = tokio::runtime::Runtime::new().unwrap();
 
     match matches.value_of("operation") {
-        Some("ForecastAfter30minRateIdModelNoGet") => {
-            let result = rt.block_on(client.forecast_after30min_rate_id_model_no_get(
-                  "rate_id_example".to_string(),
+        Some("AdminModelsPairFromNoPromoteToNoPost") => {
+            let result = rt.block_on(client.admin_models_pair_from_no_promote_to_no_post(
+                  "pair_example".to_string(),
+                  56,
+                  56,
+                  Some("x_api_key_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        /* Disabled because there's no example.
+        Some("AdminTrainingPost") => {
+            let result = rt.block_on(client.admin_training_post(
+                  ???,
+                  Some("x_api_key_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        Some("AdminTrainingRunIdGet") => {
+            let result = rt.block_on(client.admin_training_run_id_get(
+                  "run_id_example".to_string(),
+                  Some("x_api_key_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        /* Disabled because there's no example.
+        Some("ModelsModelNoPerformanceGet") => {
+            let result = rt.block_on(client.models_model_no_performance_get(
+                  56,
+                  "pair_example".to_string(),
+                  ???,
+                  Some("from_example".to_string()),
+                  Some("to_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        Some("ModelsPairNoMetricsGet") => {
+            let result = rt.block_on(client.models_pair_no_metrics_get(
+                  "pair_example".to_string(),
                   56
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
         /* Disabled because there's no example.
+        Some("EvaluationsAccuracyGet") => {
+            let result = rt.block_on(client.evaluations_accuracy_get(
+                  Some("pair_example".to_string()),
+                  Some(56),
+                  ???,
+                  Some("bucket_example".to_string()),
+                  Some("from_example".to_string()),
+                  Some("to_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        Some("ForecastErrorsGet") => {
+            let result = rt.block_on(client.forecast_errors_get(
+                  "pair_example".to_string(),
+                  Some(56),
+                  Some(56)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        /* Disabled because there's no example.
+        Some("ForecastHorizonRateIdModelNoGet") => {
+            let result = rt.block_on(client.forecast_horizon_rate_id_model_no_get(
+                  ???,
+                  "rate_id_example".to_string(),
+                  56,
+                  Some("pair_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("ForecastHorizonRateIdModelNoExplanationGet") => {
+            let result = rt.block_on(client.forecast_horizon_rate_id_model_no_explanation_get(
+                  ???,
+                  "rate_id_example".to_string(),
+                  56,
+                  Some("pair_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("ForecastNowPost") => {
+            let result = rt.block_on(client.forecast_now_post(
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("ForecastsBatchPost") => {
+            let result = rt.block_on(client.forecasts_batch_post(
+                  Vec::new()
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
         Some("RatesPost") => {
             let result = rt.block_on(client.rates_post(
                   ???
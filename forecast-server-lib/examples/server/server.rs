@@ -93,7 +93,17 @@ impl<C> Server<C> {
 
 use forecast_server_lib::{
     Api,
-    ForecastAfter30minRateIdModelNoGetResponse,
+    AdminModelsPairFromNoPromoteToNoPostResponse,
+    AdminTrainingPostResponse,
+    AdminTrainingRunIdGetResponse,
+    ModelsModelNoPerformanceGetResponse,
+    ModelsPairNoMetricsGetResponse,
+    EvaluationsAccuracyGetResponse,
+    ForecastErrorsGetResponse,
+    ForecastHorizonRateIdModelNoGetResponse,
+    ForecastHorizonRateIdModelNoExplanationGetResponse,
+    ForecastNowPostResponse,
+    ForecastsBatchPostResponse,
     RatesPostResponse,
 };
 use forecast_server_lib::server::MakeService;
@@ -103,15 +113,147 @@ use swagger::ApiError;
 #[async_trait]
 impl<C> Api<C> for Server<C> where C: Has<XSpanIdString> + Send + Sync
 {
-    /// 30分後の予想を取得します
-    async fn forecast_after30min_rate_id_model_no_get(
+    /// 予測モデルを別のモデルNoへ昇格（コピー）します
+    async fn admin_models_pair_from_no_promote_to_no_post(
         &self,
+        pair: String,
+        from_no: i32,
+        to_no: i32,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminModelsPairFromNoPromoteToNoPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("admin_models_pair_from_no_promote_to_no_post(\"{}\", {}, {}, {:?}) - X-Span-ID: {:?}", pair, from_no, to_no, x_api_key, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 学習の実行を要求します
+    async fn admin_training_post(
+        &self,
+        training_request: models::TrainingRequest,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminTrainingPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("admin_training_post({:?}, {:?}) - X-Span-ID: {:?}", training_request, x_api_key, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 学習の実行状況を取得します
+    async fn admin_training_run_id_get(
+        &self,
+        run_id: String,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminTrainingRunIdGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("admin_training_run_id_get(\"{}\", {:?}) - X-Span-ID: {:?}", run_id, x_api_key, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 予測モデルの実績誤差の推移を取得します
+    async fn models_model_no_performance_get(
+        &self,
+        model_no: i32,
+        pair: String,
+        horizon: models::Horizon,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C) -> Result<ModelsModelNoPerformanceGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("models_model_no_performance_get({}, \"{}\", {:?}, {:?}, {:?}) - X-Span-ID: {:?}", model_no, pair, horizon, from, to, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 予測モデルの性能と学習日時を取得します
+    async fn models_pair_no_metrics_get(
+        &self,
+        pair: String,
+        no: i32,
+        context: &C) -> Result<ModelsPairNoMetricsGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("models_pair_no_metrics_get(\"{}\", {}) - X-Span-ID: {:?}", pair, no, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 予測精度の推移を集計期間ごとに取得します
+    async fn evaluations_accuracy_get(
+        &self,
+        pair: Option<String>,
+        model_no: Option<i32>,
+        horizon: Option<models::Horizon>,
+        bucket: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C) -> Result<EvaluationsAccuracyGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("evaluations_accuracy_get({:?}, {:?}, {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}", pair, model_no, horizon, bucket, from, to, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 予想に失敗した記録を取得します
+    async fn forecast_errors_get(
+        &self,
+        pair: String,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<ForecastErrorsGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("forecast_errors_get(\"{}\", {:?}, {:?}) - X-Span-ID: {:?}", pair, limit, offset, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 指定時間後の予想を取得します
+    async fn forecast_horizon_rate_id_model_no_get(
+        &self,
+        horizon: models::Horizon,
         rate_id: String,
         model_no: i32,
-        context: &C) -> Result<ForecastAfter30minRateIdModelNoGetResponse, ApiError>
+        pair: Option<String>,
+        context: &C) -> Result<ForecastHorizonRateIdModelNoGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("forecast_horizon_rate_id_model_no_get({:?}, \"{}\", {}, {:?}) - X-Span-ID: {:?}", horizon, rate_id, model_no, pair, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 指定時間後の予想の根拠（特徴量ごとの寄与）を取得します
+    async fn forecast_horizon_rate_id_model_no_explanation_get(
+        &self,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
+        context: &C) -> Result<ForecastHorizonRateIdModelNoExplanationGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("forecast_horizon_rate_id_model_no_explanation_get({:?}, \"{}\", {}, {:?}) - X-Span-ID: {:?}", horizon, rate_id, model_no, pair, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// レート履歴を登録せずにその場で予想します
+    async fn forecast_now_post(
+        &self,
+        forecast_now_request: models::ForecastNowRequest,
+        context: &C) -> Result<ForecastNowPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("forecast_now_post({:?}) - X-Span-ID: {:?}", forecast_now_request, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 複数のレート履歴をまとめて新規登録します
+    async fn forecasts_batch_post(
+        &self,
+        history: Vec<models::History>,
+        context: &C) -> Result<ForecastsBatchPostResponse, ApiError>
     {
         let context = context.clone();
-        info!("forecast_after30min_rate_id_model_no_get(\"{}\", {}) - X-Span-ID: {:?}", rate_id, model_no, context.get().0.clone());
+        info!("forecasts_batch_post({:?}) - X-Span-ID: {:?}", history, context.get().0.clone());
         Err(ApiError("Generic failure".into()))
     }
 
@@ -9,23 +9,325 @@ use serde::{Serialize, Deserialize};
 
 type ServiceError = Box<dyn Error + Send + Sync + 'static>;
 
-pub const BASE_PATH: &'static str = "";
+pub const BASE_PATH: &'static str = "/v1";
 pub const API_VERSION: &'static str = "1.0.0";
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
-pub enum ForecastAfter30minRateIdModelNoGetResponse {
+pub enum AdminModelsPairFromNoPromoteToNoPostResponse {
+    /// 昇格成功
+    Status200
+    (models::ModelInfo)
+    ,
+    /// 昇格失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 昇格失敗（認証エラー）
+    Status401
+    (models::Error)
+    ,
+    /// 昇格失敗（コピー元のモデルが見つからない）
+    Status404
+    (models::Error)
+    ,
+    /// 昇格失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 昇格失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum AdminTrainingPostResponse {
+    /// 受付成功
+    Status202
+    (models::TrainingRun)
+    ,
+    /// 受付失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 受付失敗（認証エラー）
+    Status401
+    (models::Error)
+    ,
+    /// 受付失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 受付失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum AdminTrainingRunIdGetResponse {
     /// 取得成功
     Status200
-    (models::ForecastAfter30minRateIdModelNoGet200Response)
+    (models::TrainingRun)
+    ,
+    /// 取得失敗（認証エラー）
+    Status401
+    (models::Error)
     ,
-    /// 取得失敗（レート情報もしくはモデルが見つからない）
+    /// 取得失敗（学習実行が見つからない）
     Status404
     (models::Error)
     ,
     /// 取得失敗（内部エラー）
     Status500
     (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ModelsModelNoPerformanceGetResponse {
+    /// 取得成功
+    Status200
+    (models::ModelPerformance)
+    ,
+    /// 取得失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 取得失敗（モデルが見つからない）
+    Status404
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ModelsPairNoMetricsGetResponse {
+    /// 取得成功
+    Status200
+    (models::ModelMetrics)
+    ,
+    /// 取得失敗（通貨ペアが非対応、もしくはモデルが見つからない）
+    Status404
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum EvaluationsAccuracyGetResponse {
+    /// 取得成功
+    Status200
+    (models::AccuracyReport)
+    ,
+    /// 取得失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ForecastErrorsGetResponse {
+    /// 取得成功
+    Status200
+    (models::ForecastErrorsGet200Response)
+    ,
+    /// 取得失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ForecastHorizonRateIdModelNoGetResponse {
+    /// 取得成功
+    Status200
+    (models::ForecastHorizonRateIdModelNoGet200Response)
+    ,
+    /// 取得失敗（レート情報もしくはモデルが見つからない、通貨ペアが一致しない）
+    Status404
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ForecastHorizonRateIdModelNoExplanationGetResponse {
+    /// 取得成功
+    Status200
+    (models::ForecastExplanation)
+    ,
+    /// 取得失敗（レート情報もしくは予想の根拠が見つからない、通貨ペアが一致しない）
+    Status404
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ForecastNowPostResponse {
+    /// 予想成功
+    Status200
+    (models::ForecastNowPost200Response)
+    ,
+    /// 予想失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 予想失敗（通貨ペアが非対応、もしくはモデルが見つからない）
+    Status404
+    (models::Error)
+    ,
+    /// 予想失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 予想失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ForecastsBatchPostResponse {
+    /// 登録成功
+    Status201
+    (models::ForecastsBatchPost201Response)
+    ,
+    /// 登録失敗（リクエストパラメータ不備、1件でも不備があれば何も登録しない）
+    Status400
+    (models::Error)
+    ,
+    /// 登録失敗（通貨ペアが非対応）
+    Status404
+    (models::Error)
+    ,
+    /// 登録失敗（同じレート履歴が登録済み）
+    Status409
+    (models::Error)
+    ,
+    /// 登録失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 登録失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -43,9 +345,23 @@ pub enum RatesPostResponse {
     Status404
     (models::Error)
     ,
+    /// 登録失敗（同じレート履歴が登録済み）
+    Status409
+    (models::Error)
+    ,
     /// 登録失敗（内部エラー）
     Status500
     (models::Error)
+    ,
+    /// 登録失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
 }
 
 /// API
@@ -55,12 +371,94 @@ pub trait Api<C: Send + Sync> {
         Poll::Ready(Ok(()))
     }
 
-    /// 30分後の予想を取得します
-    async fn forecast_after30min_rate_id_model_no_get(
+    /// 予測モデルを別のモデルNoへ昇格（コピー）します
+    async fn admin_models_pair_from_no_promote_to_no_post(
+        &self,
+        pair: String,
+        from_no: i32,
+        to_no: i32,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminModelsPairFromNoPromoteToNoPostResponse, ApiError>;
+
+    /// 学習の実行を要求します
+    async fn admin_training_post(
+        &self,
+        training_request: models::TrainingRequest,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminTrainingPostResponse, ApiError>;
+
+    /// 学習の実行状況を取得します
+    async fn admin_training_run_id_get(
+        &self,
+        run_id: String,
+        x_api_key: Option<String>,
+        context: &C) -> Result<AdminTrainingRunIdGetResponse, ApiError>;
+
+    /// 予測モデルの実績誤差の推移を取得します
+    async fn models_model_no_performance_get(
         &self,
+        model_no: i32,
+        pair: String,
+        horizon: models::Horizon,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C) -> Result<ModelsModelNoPerformanceGetResponse, ApiError>;
+
+    /// 予測モデルの性能と学習日時を取得します
+    async fn models_pair_no_metrics_get(
+        &self,
+        pair: String,
+        no: i32,
+        context: &C) -> Result<ModelsPairNoMetricsGetResponse, ApiError>;
+
+    /// 予測精度の推移を集計期間ごとに取得します
+    async fn evaluations_accuracy_get(
+        &self,
+        pair: Option<String>,
+        model_no: Option<i32>,
+        horizon: Option<models::Horizon>,
+        bucket: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C) -> Result<EvaluationsAccuracyGetResponse, ApiError>;
+
+    /// 予想に失敗した記録を取得します
+    async fn forecast_errors_get(
+        &self,
+        pair: String,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<ForecastErrorsGetResponse, ApiError>;
+
+    /// 指定時間後の予想を取得します
+    async fn forecast_horizon_rate_id_model_no_get(
+        &self,
+        horizon: models::Horizon,
         rate_id: String,
         model_no: i32,
-        context: &C) -> Result<ForecastAfter30minRateIdModelNoGetResponse, ApiError>;
+        pair: Option<String>,
+        context: &C) -> Result<ForecastHorizonRateIdModelNoGetResponse, ApiError>;
+
+    /// 指定時間後の予想の根拠（特徴量ごとの寄与）を取得します
+    async fn forecast_horizon_rate_id_model_no_explanation_get(
+        &self,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
+        context: &C) -> Result<ForecastHorizonRateIdModelNoExplanationGetResponse, ApiError>;
+
+    /// レート履歴を登録せずにその場で予想します
+    async fn forecast_now_post(
+        &self,
+        forecast_now_request: models::ForecastNowRequest,
+        context: &C) -> Result<ForecastNowPostResponse, ApiError>;
+
+    /// 複数のレート履歴をまとめて新規登録します
+    async fn forecasts_batch_post(
+        &self,
+        history: Vec<models::History>,
+        context: &C) -> Result<ForecastsBatchPostResponse, ApiError>;
 
     /// レート履歴を新規登録します
     async fn rates_post(
@@ -78,12 +476,94 @@ pub trait ApiNoContext<C: Send + Sync> {
 
     fn context(&self) -> &C;
 
-    /// 30分後の予想を取得します
-    async fn forecast_after30min_rate_id_model_no_get(
+    /// 予測モデルを別のモデルNoへ昇格（コピー）します
+    async fn admin_models_pair_from_no_promote_to_no_post(
+        &self,
+        pair: String,
+        from_no: i32,
+        to_no: i32,
+        x_api_key: Option<String>,
+        ) -> Result<AdminModelsPairFromNoPromoteToNoPostResponse, ApiError>;
+
+    /// 学習の実行を要求します
+    async fn admin_training_post(
+        &self,
+        training_request: models::TrainingRequest,
+        x_api_key: Option<String>,
+        ) -> Result<AdminTrainingPostResponse, ApiError>;
+
+    /// 学習の実行状況を取得します
+    async fn admin_training_run_id_get(
+        &self,
+        run_id: String,
+        x_api_key: Option<String>,
+        ) -> Result<AdminTrainingRunIdGetResponse, ApiError>;
+
+    /// 予測モデルの実績誤差の推移を取得します
+    async fn models_model_no_performance_get(
         &self,
+        model_no: i32,
+        pair: String,
+        horizon: models::Horizon,
+        from: Option<String>,
+        to: Option<String>,
+        ) -> Result<ModelsModelNoPerformanceGetResponse, ApiError>;
+
+    /// 予測モデルの性能と学習日時を取得します
+    async fn models_pair_no_metrics_get(
+        &self,
+        pair: String,
+        no: i32,
+        ) -> Result<ModelsPairNoMetricsGetResponse, ApiError>;
+
+    /// 予測精度の推移を集計期間ごとに取得します
+    async fn evaluations_accuracy_get(
+        &self,
+        pair: Option<String>,
+        model_no: Option<i32>,
+        horizon: Option<models::Horizon>,
+        bucket: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        ) -> Result<EvaluationsAccuracyGetResponse, ApiError>;
+
+    /// 予想に失敗した記録を取得します
+    async fn forecast_errors_get(
+        &self,
+        pair: String,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<ForecastErrorsGetResponse, ApiError>;
+
+    /// 指定時間後の予想を取得します
+    async fn forecast_horizon_rate_id_model_no_get(
+        &self,
+        horizon: models::Horizon,
         rate_id: String,
         model_no: i32,
-        ) -> Result<ForecastAfter30minRateIdModelNoGetResponse, ApiError>;
+        pair: Option<String>,
+        ) -> Result<ForecastHorizonRateIdModelNoGetResponse, ApiError>;
+
+    /// 指定時間後の予想の根拠（特徴量ごとの寄与）を取得します
+    async fn forecast_horizon_rate_id_model_no_explanation_get(
+        &self,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
+        ) -> Result<ForecastHorizonRateIdModelNoExplanationGetResponse, ApiError>;
+
+    /// レート履歴を登録せずにその場で予想します
+    async fn forecast_now_post(
+        &self,
+        forecast_now_request: models::ForecastNowRequest,
+        ) -> Result<ForecastNowPostResponse, ApiError>;
+
+    /// 複数のレート履歴をまとめて新規登録します
+    async fn forecasts_batch_post(
+        &self,
+        history: Vec<models::History>,
+        ) -> Result<ForecastsBatchPostResponse, ApiError>;
 
     /// レート履歴を新規登録します
     async fn rates_post(
@@ -116,15 +596,137 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         ContextWrapper::context(self)
     }
 
-    /// 30分後の予想を取得します
-    async fn forecast_after30min_rate_id_model_no_get(
+    /// 予測モデルを別のモデルNoへ昇格（コピー）します
+    async fn admin_models_pair_from_no_promote_to_no_post(
+        &self,
+        pair: String,
+        from_no: i32,
+        to_no: i32,
+        x_api_key: Option<String>,
+        ) -> Result<AdminModelsPairFromNoPromoteToNoPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().admin_models_pair_from_no_promote_to_no_post(pair, from_no, to_no, x_api_key, &context).await
+    }
+
+    /// 学習の実行を要求します
+    async fn admin_training_post(
+        &self,
+        training_request: models::TrainingRequest,
+        x_api_key: Option<String>,
+        ) -> Result<AdminTrainingPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().admin_training_post(training_request, x_api_key, &context).await
+    }
+
+    /// 学習の実行状況を取得します
+    async fn admin_training_run_id_get(
+        &self,
+        run_id: String,
+        x_api_key: Option<String>,
+        ) -> Result<AdminTrainingRunIdGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().admin_training_run_id_get(run_id, x_api_key, &context).await
+    }
+
+    /// 予測モデルの実績誤差の推移を取得します
+    async fn models_model_no_performance_get(
+        &self,
+        model_no: i32,
+        pair: String,
+        horizon: models::Horizon,
+        from: Option<String>,
+        to: Option<String>,
+        ) -> Result<ModelsModelNoPerformanceGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().models_model_no_performance_get(model_no, pair, horizon, from, to, &context).await
+    }
+
+    /// 予測モデルの性能と学習日時を取得します
+    async fn models_pair_no_metrics_get(
+        &self,
+        pair: String,
+        no: i32,
+        ) -> Result<ModelsPairNoMetricsGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().models_pair_no_metrics_get(pair, no, &context).await
+    }
+
+    /// 予測精度の推移を集計期間ごとに取得します
+    async fn evaluations_accuracy_get(
+        &self,
+        pair: Option<String>,
+        model_no: Option<i32>,
+        horizon: Option<models::Horizon>,
+        bucket: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        ) -> Result<EvaluationsAccuracyGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().evaluations_accuracy_get(pair, model_no, horizon, bucket, from, to, &context).await
+    }
+
+    /// 予想に失敗した記録を取得します
+    async fn forecast_errors_get(
+        &self,
+        pair: String,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<ForecastErrorsGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().forecast_errors_get(pair, limit, offset, &context).await
+    }
+
+    /// 指定時間後の予想を取得します
+    async fn forecast_horizon_rate_id_model_no_get(
         &self,
+        horizon: models::Horizon,
         rate_id: String,
         model_no: i32,
-        ) -> Result<ForecastAfter30minRateIdModelNoGetResponse, ApiError>
+        pair: Option<String>,
+        ) -> Result<ForecastHorizonRateIdModelNoGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().forecast_horizon_rate_id_model_no_get(horizon, rate_id, model_no, pair, &context).await
+    }
+
+    /// 指定時間後の予想の根拠（特徴量ごとの寄与）を取得します
+    async fn forecast_horizon_rate_id_model_no_explanation_get(
+        &self,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
+        ) -> Result<ForecastHorizonRateIdModelNoExplanationGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().forecast_horizon_rate_id_model_no_explanation_get(horizon, rate_id, model_no, pair, &context).await
+    }
+
+    /// レート履歴を登録せずにその場で予想します
+    async fn forecast_now_post(
+        &self,
+        forecast_now_request: models::ForecastNowRequest,
+        ) -> Result<ForecastNowPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().forecast_now_post(forecast_now_request, &context).await
+    }
+
+    /// 複数のレート履歴をまとめて新規登録します
+    async fn forecasts_batch_post(
+        &self,
+        history: Vec<models::History>,
+        ) -> Result<ForecastsBatchPostResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().forecast_after30min_rate_id_model_no_get(rate_id, model_no, &context).await
+        self.api().forecasts_batch_post(history, &context).await
     }
 
     /// レート履歴を新規登録します
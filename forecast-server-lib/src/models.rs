@@ -4,49 +4,2733 @@ use crate::models;
 #[cfg(any(feature = "client", feature = "server"))]
 use crate::header;
 
+/// 集計期間ごとの予測精度
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AccuracyPoint {
+    /// 集計期間の開始日時
+    #[serde(rename = "time")]
+    pub time: String,
+
+    /// 集計対象の予想件数
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    /// 平均絶対誤差
+    #[serde(rename = "mae")]
+    pub mae: f64,
+
+    /// 平均二乗誤差
+    #[serde(rename = "mse")]
+    pub mse: f64,
+
+    /// 二乗平均平方根誤差
+    #[serde(rename = "rmse")]
+    pub rmse: f64,
+
+    /// 予測値 - 実績値 の平均（正の場合は高めに予測している）
+    #[serde(rename = "bias")]
+    pub bias: f64,
+
+}
+
+impl AccuracyPoint {
+    pub fn new(time: String, count: i64, mae: f64, mse: f64, rmse: f64, bias: f64, ) -> AccuracyPoint {
+        AccuracyPoint {
+            time: time,
+            count: count,
+            mae: mae,
+            mse: mse,
+            rmse: rmse,
+            bias: bias,
+        }
+    }
+}
+
+/// Converts the AccuracyPoint value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for AccuracyPoint {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("time".to_string());
+        params.push(self.time.to_string());
+
+
+        params.push("count".to_string());
+        params.push(self.count.to_string());
+
+
+        params.push("mae".to_string());
+        params.push(self.mae.to_string());
+
+
+        params.push("mse".to_string());
+        params.push(self.mse.to_string());
+
+
+        params.push("rmse".to_string());
+        params.push(self.rmse.to_string());
+
+
+        params.push("bias".to_string());
+        params.push(self.bias.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AccuracyPoint value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AccuracyPoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub time: Vec<String>,
+            pub count: Vec<i64>,
+            pub mae: Vec<f64>,
+            pub mse: Vec<f64>,
+            pub rmse: Vec<f64>,
+            pub bias: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AccuracyPoint".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "time" => intermediate_rep.time.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mae" => intermediate_rep.mae.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mse" => intermediate_rep.mse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rmse" => intermediate_rep.rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "bias" => intermediate_rep.bias.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AccuracyPoint".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AccuracyPoint {
+            time: intermediate_rep.time.into_iter().next().ok_or("time missing in AccuracyPoint".to_string())?,
+            count: intermediate_rep.count.into_iter().next().ok_or("count missing in AccuracyPoint".to_string())?,
+            mae: intermediate_rep.mae.into_iter().next().ok_or("mae missing in AccuracyPoint".to_string())?,
+            mse: intermediate_rep.mse.into_iter().next().ok_or("mse missing in AccuracyPoint".to_string())?,
+            rmse: intermediate_rep.rmse.into_iter().next().ok_or("rmse missing in AccuracyPoint".to_string())?,
+            bias: intermediate_rep.bias.into_iter().next().ok_or("bias missing in AccuracyPoint".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AccuracyPoint> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<AccuracyPoint>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AccuracyPoint>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AccuracyPoint - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<AccuracyPoint> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AccuracyPoint as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AccuracyPoint - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予測精度の推移
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AccuracyReport {
+    /// 通貨ペア・モデル・予想対象の時間ごとの予測精度の推移
+    #[serde(rename = "series")]
+    pub series: Vec<models::AccuracySeries>,
+
+}
+
+impl AccuracyReport {
+    pub fn new(series: Vec<models::AccuracySeries>, ) -> AccuracyReport {
+        AccuracyReport {
+            series: series,
+        }
+    }
+}
+
+/// Converts the AccuracyReport value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for AccuracyReport {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping series in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AccuracyReport value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AccuracyReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub series: Vec<Vec<models::AccuracySeries>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AccuracyReport".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "series" => return std::result::Result::Err("Parsing a container in this style is not supported in AccuracyReport".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AccuracyReport".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AccuracyReport {
+            series: intermediate_rep.series.into_iter().next().ok_or("series missing in AccuracyReport".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AccuracyReport> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<AccuracyReport>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AccuracyReport>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AccuracyReport - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<AccuracyReport> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AccuracyReport as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AccuracyReport - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 通貨ペア・モデル・予想対象の時間ごとの予測精度の推移
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AccuracySeries {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    #[serde(rename = "horizon")]
+    pub horizon: models::Horizon,
+
+    /// 集計期間の単位（hour, day）
+    #[serde(rename = "bucket")]
+    pub bucket: String,
+
+    #[serde(rename = "summary")]
+    pub summary: models::AccuracySummary,
+
+    /// 集計期間ごとの予測精度
+    #[serde(rename = "points")]
+    pub points: Vec<models::AccuracyPoint>,
+
+}
+
+impl AccuracySeries {
+    pub fn new(pair: String, model_no: i32, horizon: models::Horizon, bucket: String, summary: models::AccuracySummary, points: Vec<models::AccuracyPoint>, ) -> AccuracySeries {
+        AccuracySeries {
+            pair: pair,
+            model_no: model_no,
+            horizon: horizon,
+            bucket: bucket,
+            summary: summary,
+            points: points,
+        }
+    }
+}
+
+/// Converts the AccuracySeries value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for AccuracySeries {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+        // Skipping horizon in query parameter serialization
+
+        params.push("bucket".to_string());
+        params.push(self.bucket.to_string());
+        // Skipping summary in query parameter serialization
+        // Skipping points in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AccuracySeries value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AccuracySeries {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub horizon: Vec<models::Horizon>,
+            pub bucket: Vec<String>,
+            pub summary: Vec<models::AccuracySummary>,
+            pub points: Vec<Vec<models::AccuracyPoint>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AccuracySeries".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "horizon" => intermediate_rep.horizon.push(<models::Horizon as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "bucket" => intermediate_rep.bucket.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "summary" => intermediate_rep.summary.push(<models::AccuracySummary as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "points" => return std::result::Result::Err("Parsing a container in this style is not supported in AccuracySeries".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AccuracySeries".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AccuracySeries {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in AccuracySeries".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in AccuracySeries".to_string())?,
+            horizon: intermediate_rep.horizon.into_iter().next().ok_or("horizon missing in AccuracySeries".to_string())?,
+            bucket: intermediate_rep.bucket.into_iter().next().ok_or("bucket missing in AccuracySeries".to_string())?,
+            summary: intermediate_rep.summary.into_iter().next().ok_or("summary missing in AccuracySeries".to_string())?,
+            points: intermediate_rep.points.into_iter().next().ok_or("points missing in AccuracySeries".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AccuracySeries> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<AccuracySeries>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AccuracySeries>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AccuracySeries - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<AccuracySeries> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AccuracySeries as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AccuracySeries - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 指定期間全体の予測精度
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AccuracySummary {
+    /// 集計対象の予想件数
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    /// 平均絶対誤差
+    #[serde(rename = "mae")]
+    pub mae: f64,
+
+    /// 平均二乗誤差
+    #[serde(rename = "mse")]
+    pub mse: f64,
+
+    /// 二乗平均平方根誤差
+    #[serde(rename = "rmse")]
+    pub rmse: f64,
+
+    /// 予測値 - 実績値 の平均（正の場合は高めに予測している）
+    #[serde(rename = "bias")]
+    pub bias: f64,
+
+    /// 集計期間ごとの平均絶対誤差の最小値
+    #[serde(rename = "best_mae")]
+    pub best_mae: f64,
+
+    /// 集計期間ごとの平均絶対誤差の最大値
+    #[serde(rename = "worst_mae")]
+    pub worst_mae: f64,
+
+}
+
+impl AccuracySummary {
+    pub fn new(count: i64, mae: f64, mse: f64, rmse: f64, bias: f64, best_mae: f64, worst_mae: f64, ) -> AccuracySummary {
+        AccuracySummary {
+            count: count,
+            mae: mae,
+            mse: mse,
+            rmse: rmse,
+            bias: bias,
+            best_mae: best_mae,
+            worst_mae: worst_mae,
+        }
+    }
+}
+
+/// Converts the AccuracySummary value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for AccuracySummary {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("count".to_string());
+        params.push(self.count.to_string());
+
+
+        params.push("mae".to_string());
+        params.push(self.mae.to_string());
+
+
+        params.push("mse".to_string());
+        params.push(self.mse.to_string());
+
+
+        params.push("rmse".to_string());
+        params.push(self.rmse.to_string());
+
+
+        params.push("bias".to_string());
+        params.push(self.bias.to_string());
+
+
+        params.push("best_mae".to_string());
+        params.push(self.best_mae.to_string());
+
+
+        params.push("worst_mae".to_string());
+        params.push(self.worst_mae.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AccuracySummary value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AccuracySummary {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub count: Vec<i64>,
+            pub mae: Vec<f64>,
+            pub mse: Vec<f64>,
+            pub rmse: Vec<f64>,
+            pub bias: Vec<f64>,
+            pub best_mae: Vec<f64>,
+            pub worst_mae: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AccuracySummary".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mae" => intermediate_rep.mae.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mse" => intermediate_rep.mse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rmse" => intermediate_rep.rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "bias" => intermediate_rep.bias.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "best_mae" => intermediate_rep.best_mae.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "worst_mae" => intermediate_rep.worst_mae.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AccuracySummary".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AccuracySummary {
+            count: intermediate_rep.count.into_iter().next().ok_or("count missing in AccuracySummary".to_string())?,
+            mae: intermediate_rep.mae.into_iter().next().ok_or("mae missing in AccuracySummary".to_string())?,
+            mse: intermediate_rep.mse.into_iter().next().ok_or("mse missing in AccuracySummary".to_string())?,
+            rmse: intermediate_rep.rmse.into_iter().next().ok_or("rmse missing in AccuracySummary".to_string())?,
+            bias: intermediate_rep.bias.into_iter().next().ok_or("bias missing in AccuracySummary".to_string())?,
+            best_mae: intermediate_rep.best_mae.into_iter().next().ok_or("best_mae missing in AccuracySummary".to_string())?,
+            worst_mae: intermediate_rep.worst_mae.into_iter().next().ok_or("worst_mae missing in AccuracySummary".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AccuracySummary> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<AccuracySummary>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AccuracySummary>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AccuracySummary - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<AccuracySummary> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AccuracySummary as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AccuracySummary - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
 /// エラー情報
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Error {
-    /// エラーメッセージ
-    #[serde(rename = "message")]
-    pub message: String,
+pub struct Error {
+    /// エラーメッセージ
+    #[serde(rename = "message")]
+    pub message: String,
+
+}
+
+impl Error {
+    pub fn new(message: String, ) -> Error {
+        Error {
+            message: message,
+        }
+    }
+}
+
+/// Converts the Error value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Error {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("message".to_string());
+        params.push(self.message.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Error value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Error {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Error".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Error".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Error {
+            message: intermediate_rep.message.into_iter().next().ok_or("message missing in Error".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Error> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Error>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Error>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Error - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Error> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Error as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Error - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 特徴量ごとの予想値への寄与
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FeatureContribution {
+    /// 特徴量の名前（t-0 が最新）
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// 特徴量の値
+    #[serde(rename = "value")]
+    pub value: f64,
+
+    /// 予想値への寄与
+    #[serde(rename = "contribution")]
+    pub contribution: f64,
+
+}
+
+impl FeatureContribution {
+    pub fn new(name: String, value: f64, contribution: f64, ) -> FeatureContribution {
+        FeatureContribution {
+            name: name,
+            value: value,
+            contribution: contribution,
+        }
+    }
+}
+
+/// Converts the FeatureContribution value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for FeatureContribution {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("name".to_string());
+        params.push(self.name.to_string());
+
+
+        params.push("value".to_string());
+        params.push(self.value.to_string());
+
+
+        params.push("contribution".to_string());
+        params.push(self.contribution.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FeatureContribution value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FeatureContribution {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub value: Vec<f64>,
+            pub contribution: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing FeatureContribution".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "value" => intermediate_rep.value.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "contribution" => intermediate_rep.contribution.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing FeatureContribution".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FeatureContribution {
+            name: intermediate_rep.name.into_iter().next().ok_or("name missing in FeatureContribution".to_string())?,
+            value: intermediate_rep.value.into_iter().next().ok_or("value missing in FeatureContribution".to_string())?,
+            contribution: intermediate_rep.contribution.into_iter().next().ok_or("contribution missing in FeatureContribution".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FeatureContribution> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<FeatureContribution>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<FeatureContribution>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for FeatureContribution - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<FeatureContribution> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <FeatureContribution as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into FeatureContribution - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予想失敗の記録
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastErrorInfo {
+    /// 予想失敗の記録ID
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// レート履歴ID
+    #[serde(rename = "rate_id")]
+    pub rate_id: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    /// エラー概要
+    #[serde(rename = "summary")]
+    pub summary: String,
+
+    /// エラー詳細
+    #[serde(rename = "detail")]
+    pub detail: String,
+
+}
+
+impl ForecastErrorInfo {
+    pub fn new(id: String, rate_id: String, model_no: i32, summary: String, detail: String, ) -> ForecastErrorInfo {
+        ForecastErrorInfo {
+            id: id,
+            rate_id: rate_id,
+            model_no: model_no,
+            summary: summary,
+            detail: detail,
+        }
+    }
+}
+
+/// Converts the ForecastErrorInfo value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastErrorInfo {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("id".to_string());
+        params.push(self.id.to_string());
+
+
+        params.push("rate_id".to_string());
+        params.push(self.rate_id.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+
+
+        params.push("summary".to_string());
+        params.push(self.summary.to_string());
+
+
+        params.push("detail".to_string());
+        params.push(self.detail.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastErrorInfo value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastErrorInfo {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub rate_id: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub summary: Vec<String>,
+            pub detail: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastErrorInfo".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "id" => intermediate_rep.id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rate_id" => intermediate_rep.rate_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "summary" => intermediate_rep.summary.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "detail" => intermediate_rep.detail.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastErrorInfo".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastErrorInfo {
+            id: intermediate_rep.id.into_iter().next().ok_or("id missing in ForecastErrorInfo".to_string())?,
+            rate_id: intermediate_rep.rate_id.into_iter().next().ok_or("rate_id missing in ForecastErrorInfo".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ForecastErrorInfo".to_string())?,
+            summary: intermediate_rep.summary.into_iter().next().ok_or("summary missing in ForecastErrorInfo".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next().ok_or("detail missing in ForecastErrorInfo".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastErrorInfo> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastErrorInfo>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastErrorInfo>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastErrorInfo - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastErrorInfo> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastErrorInfo as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastErrorInfo - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastErrorsGet200Response {
+    #[serde(rename = "errors")]
+    pub errors: Vec<models::ForecastErrorInfo>,
+
+}
+
+impl ForecastErrorsGet200Response {
+    pub fn new(errors: Vec<models::ForecastErrorInfo>, ) -> ForecastErrorsGet200Response {
+        ForecastErrorsGet200Response {
+            errors: errors,
+        }
+    }
+}
+
+/// Converts the ForecastErrorsGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastErrorsGet200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping errors in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastErrorsGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastErrorsGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub errors: Vec<Vec<models::ForecastErrorInfo>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastErrorsGet200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "errors" => return std::result::Result::Err("Parsing a container in this style is not supported in ForecastErrorsGet200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastErrorsGet200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastErrorsGet200Response {
+            errors: intermediate_rep.errors.into_iter().next().ok_or("errors missing in ForecastErrorsGet200Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastErrorsGet200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastErrorsGet200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastErrorsGet200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastErrorsGet200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastErrorsGet200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastErrorsGet200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastErrorsGet200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予想の根拠（base_value と寄与の合計がおおよそ予想値になる）
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastExplanation {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// レート履歴ID
+    #[serde(rename = "rate_id")]
+    pub rate_id: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    #[serde(rename = "horizon")]
+    pub horizon: models::Horizon,
+
+    /// 算出方法（linear: 係数×特徴量の値, occlusion: 特徴量を同じ種類の平均値に置き換えたときの予想値の変化）
+    #[serde(rename = "method")]
+    pub method: String,
+
+    /// 寄与の基準値
+    #[serde(rename = "base_value")]
+    pub base_value: f64,
+
+    /// 特徴量ごとの寄与
+    #[serde(rename = "contributions")]
+    pub contributions: Vec<models::FeatureContribution>,
+
+}
+
+impl ForecastExplanation {
+    pub fn new(pair: String, rate_id: String, model_no: i32, horizon: models::Horizon, method: String, base_value: f64, contributions: Vec<models::FeatureContribution>, ) -> ForecastExplanation {
+        ForecastExplanation {
+            pair: pair,
+            rate_id: rate_id,
+            model_no: model_no,
+            horizon: horizon,
+            method: method,
+            base_value: base_value,
+            contributions: contributions,
+        }
+    }
+}
+
+/// Converts the ForecastExplanation value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastExplanation {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("rate_id".to_string());
+        params.push(self.rate_id.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+        // Skipping horizon in query parameter serialization
+
+        params.push("method".to_string());
+        params.push(self.method.to_string());
+
+
+        params.push("base_value".to_string());
+        params.push(self.base_value.to_string());
+        // Skipping contributions in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastExplanation value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastExplanation {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub rate_id: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub horizon: Vec<models::Horizon>,
+            pub method: Vec<String>,
+            pub base_value: Vec<f64>,
+            pub contributions: Vec<Vec<models::FeatureContribution>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastExplanation".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rate_id" => intermediate_rep.rate_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "horizon" => intermediate_rep.horizon.push(<models::Horizon as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "method" => intermediate_rep.method.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "base_value" => intermediate_rep.base_value.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "contributions" => return std::result::Result::Err("Parsing a container in this style is not supported in ForecastExplanation".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastExplanation".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastExplanation {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ForecastExplanation".to_string())?,
+            rate_id: intermediate_rep.rate_id.into_iter().next().ok_or("rate_id missing in ForecastExplanation".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ForecastExplanation".to_string())?,
+            horizon: intermediate_rep.horizon.into_iter().next().ok_or("horizon missing in ForecastExplanation".to_string())?,
+            method: intermediate_rep.method.into_iter().next().ok_or("method missing in ForecastExplanation".to_string())?,
+            base_value: intermediate_rep.base_value.into_iter().next().ok_or("base_value missing in ForecastExplanation".to_string())?,
+            contributions: intermediate_rep.contributions.into_iter().next().ok_or("contributions missing in ForecastExplanation".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastExplanation> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastExplanation>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastExplanation>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastExplanation - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastExplanation> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastExplanation as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastExplanation - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastHorizonRateIdModelNoGet200Response {
+    #[serde(rename = "result")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub result: Option<models::ForecastResult>,
+
+}
+
+impl ForecastHorizonRateIdModelNoGet200Response {
+    pub fn new() -> ForecastHorizonRateIdModelNoGet200Response {
+        ForecastHorizonRateIdModelNoGet200Response {
+            result: None,
+        }
+    }
+}
+
+/// Converts the ForecastHorizonRateIdModelNoGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastHorizonRateIdModelNoGet200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping result in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastHorizonRateIdModelNoGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastHorizonRateIdModelNoGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub result: Vec<models::ForecastResult>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastHorizonRateIdModelNoGet200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "result" => intermediate_rep.result.push(<models::ForecastResult as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastHorizonRateIdModelNoGet200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastHorizonRateIdModelNoGet200Response {
+            result: intermediate_rep.result.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastHorizonRateIdModelNoGet200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastHorizonRateIdModelNoGet200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastHorizonRateIdModelNoGet200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastHorizonRateIdModelNoGet200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastHorizonRateIdModelNoGet200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastHorizonRateIdModelNoGet200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastHorizonRateIdModelNoGet200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastNowPost200Response {
+    #[serde(rename = "result")]
+    pub result: models::ForecastResult,
+
+}
+
+impl ForecastNowPost200Response {
+    pub fn new(result: models::ForecastResult, ) -> ForecastNowPost200Response {
+        ForecastNowPost200Response {
+            result: result,
+        }
+    }
+}
+
+/// Converts the ForecastNowPost200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastNowPost200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping result in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastNowPost200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastNowPost200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub result: Vec<models::ForecastResult>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastNowPost200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "result" => intermediate_rep.result.push(<models::ForecastResult as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastNowPost200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastNowPost200Response {
+            result: intermediate_rep.result.into_iter().next().ok_or("result missing in ForecastNowPost200Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastNowPost200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastNowPost200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastNowPost200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastNowPost200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastNowPost200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastNowPost200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastNowPost200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// その場で予想するレート履歴
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastNowRequest {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// レートの履歴（先頭が過去）
+    #[serde(rename = "rate_histories")]
+    pub rate_histories: Vec<f64>,
+
+    /// 予想に使うモデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+}
+
+impl ForecastNowRequest {
+    pub fn new(pair: String, rate_histories: Vec<f64>, model_no: i32, ) -> ForecastNowRequest {
+        ForecastNowRequest {
+            pair: pair,
+            rate_histories: rate_histories,
+            model_no: model_no,
+        }
+    }
+}
+
+/// Converts the ForecastNowRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastNowRequest {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+        params.push("rate_histories".to_string());
+        params.push(self.rate_histories.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").to_string());
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastNowRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastNowRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub rate_histories: Vec<Vec<f64>>,
+            pub model_no: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastNowRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rate_histories" => return std::result::Result::Err("Parsing a container in this style is not supported in ForecastNowRequest".to_string()),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastNowRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastNowRequest {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ForecastNowRequest".to_string())?,
+            rate_histories: intermediate_rep.rate_histories.into_iter().next().ok_or("rate_histories missing in ForecastNowRequest".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ForecastNowRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastNowRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastNowRequest>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastNowRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastNowRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastNowRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastNowRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastNowRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予想値の分位点
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastQuantiles {
+    /// 10%分位点
+    #[serde(rename = "p10")]
+    pub p10: f64,
+
+    /// 50%分位点（中央値）
+    #[serde(rename = "p50")]
+    pub p50: f64,
+
+    /// 90%分位点
+    #[serde(rename = "p90")]
+    pub p90: f64,
+
+}
+
+impl ForecastQuantiles {
+    pub fn new(p10: f64, p50: f64, p90: f64, ) -> ForecastQuantiles {
+        ForecastQuantiles {
+            p10: p10,
+            p50: p50,
+            p90: p90,
+        }
+    }
+}
+
+/// Converts the ForecastQuantiles value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastQuantiles {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("p10".to_string());
+        params.push(self.p10.to_string());
+
+
+        params.push("p50".to_string());
+        params.push(self.p50.to_string());
+
+
+        params.push("p90".to_string());
+        params.push(self.p90.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastQuantiles value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastQuantiles {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub p10: Vec<f64>,
+            pub p50: Vec<f64>,
+            pub p90: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastQuantiles".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "p10" => intermediate_rep.p10.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "p50" => intermediate_rep.p50.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "p90" => intermediate_rep.p90.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastQuantiles".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastQuantiles {
+            p10: intermediate_rep.p10.into_iter().next().ok_or("p10 missing in ForecastQuantiles".to_string())?,
+            p50: intermediate_rep.p50.into_iter().next().ok_or("p50 missing in ForecastQuantiles".to_string())?,
+            p90: intermediate_rep.p90.into_iter().next().ok_or("p90 missing in ForecastQuantiles".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastQuantiles> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastQuantiles>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastQuantiles>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastQuantiles - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastQuantiles> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastQuantiles as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastQuantiles - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予測結果
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastResult {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// 予測が完了したか？（予想に失敗した場合は false のまま error を返す）
+    #[serde(rename = "complete")]
+    pub complete: bool,
+
+    /// レートの値
+    #[serde(rename = "rate")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub rate: Option<f64>,
+
+    /// 予測モデルのRMSE
+    #[serde(rename = "rmse")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub rmse: Option<f64>,
+
+    /// 予想値の推移（予想対象の時間ごと、1件目は rate と同じ。複数ステップで予想した場合のみ）
+    #[serde(rename = "path")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub path: Option<Vec<f64>>,
+
+    #[serde(rename = "quantiles")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub quantiles: Option<models::ForecastQuantiles>,
+
+    #[serde(rename = "error")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub error: Option<models::ForecastErrorInfo>,
+
+}
+
+impl ForecastResult {
+    pub fn new(pair: String, complete: bool, ) -> ForecastResult {
+        ForecastResult {
+            pair: pair,
+            complete: complete,
+            rate: None,
+            rmse: None,
+            path: None,
+            quantiles: None,
+            error: None,
+        }
+    }
+}
+
+/// Converts the ForecastResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastResult {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("complete".to_string());
+        params.push(self.complete.to_string());
+
+
+        if let Some(ref rate) = self.rate {
+            params.push("rate".to_string());
+            params.push(rate.to_string());
+        }
+
+
+        if let Some(ref rmse) = self.rmse {
+            params.push("rmse".to_string());
+            params.push(rmse.to_string());
+        }
+
+
+        if let Some(ref path) = self.path {
+            params.push("path".to_string());
+            params.push(path.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").to_string());
+        }
+
+        // Skipping quantiles in query parameter serialization
+
+        // Skipping error in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub complete: Vec<bool>,
+            pub rate: Vec<f64>,
+            pub rmse: Vec<f64>,
+            pub path: Vec<Vec<f64>>,
+            pub quantiles: Vec<models::ForecastQuantiles>,
+            pub error: Vec<models::ForecastErrorInfo>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "complete" => intermediate_rep.complete.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rate" => intermediate_rep.rate.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rmse" => intermediate_rep.rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "path" => return std::result::Result::Err("Parsing a container in this style is not supported in ForecastResult".to_string()),
+                    "quantiles" => intermediate_rep.quantiles.push(<models::ForecastQuantiles as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "error" => intermediate_rep.error.push(<models::ForecastErrorInfo as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastResult {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ForecastResult".to_string())?,
+            complete: intermediate_rep.complete.into_iter().next().ok_or("complete missing in ForecastResult".to_string())?,
+            rate: intermediate_rep.rate.into_iter().next(),
+            rmse: intermediate_rep.rmse.into_iter().next(),
+            path: intermediate_rep.path.into_iter().next(),
+            quantiles: intermediate_rep.quantiles.into_iter().next(),
+            error: intermediate_rep.error.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastResult> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastResult>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForecastsBatchPost201Response {
+    /// レート履歴ID（リクエストのレート履歴と同じ順）
+    #[serde(rename = "rate_ids")]
+    pub rate_ids: Vec<String>,
+
+}
+
+impl ForecastsBatchPost201Response {
+    pub fn new(rate_ids: Vec<String>, ) -> ForecastsBatchPost201Response {
+        ForecastsBatchPost201Response {
+            rate_ids: rate_ids,
+        }
+    }
+}
+
+/// Converts the ForecastsBatchPost201Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForecastsBatchPost201Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        params.push("rate_ids".to_string());
+        params.push(self.rate_ids.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForecastsBatchPost201Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForecastsBatchPost201Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub rate_ids: Vec<Vec<String>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ForecastsBatchPost201Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "rate_ids" => return std::result::Result::Err("Parsing a container in this style is not supported in ForecastsBatchPost201Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastsBatchPost201Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForecastsBatchPost201Response {
+            rate_ids: intermediate_rep.rate_ids.into_iter().next().ok_or("rate_ids missing in ForecastsBatchPost201Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForecastsBatchPost201Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForecastsBatchPost201Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ForecastsBatchPost201Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ForecastsBatchPost201Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastsBatchPost201Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ForecastsBatchPost201Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ForecastsBatchPost201Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// レート履歴
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct History {
+    /// 通貨ペア
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    #[serde(rename = "rate_histories")]
+    pub rate_histories: Vec<f64>,
+
+    /// 有効期限（現在日時から何分後にするかを指定。未指定の場合はサーバーの設定値を使用）
+    #[serde(rename = "expire_minutes")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub expire_minutes: Option<i64>,
+
+}
+
+impl History {
+    pub fn new(pair: String, rate_histories: Vec<f64>, ) -> History {
+        History {
+            pair: pair,
+            rate_histories: rate_histories,
+            expire_minutes: None,
+        }
+    }
+}
+
+/// Converts the History value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for History {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("rate_histories".to_string());
+        params.push(self.rate_histories.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").to_string());
+
+
+        if let Some(ref expire_minutes) = self.expire_minutes {
+            params.push("expire_minutes".to_string());
+            params.push(expire_minutes.to_string());
+        }
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a History value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for History {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub rate_histories: Vec<Vec<f64>>,
+            pub expire_minutes: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing History".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rate_histories" => return std::result::Result::Err("Parsing a container in this style is not supported in History".to_string()),
+                    "expire_minutes" => intermediate_rep.expire_minutes.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing History".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(History {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in History".to_string())?,
+            rate_histories: intermediate_rep.rate_histories.into_iter().next().ok_or("rate_histories missing in History".to_string())?,
+            expire_minutes: intermediate_rep.expire_minutes.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<History> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<History>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<History>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for History - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<History> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <History as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into History - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予想対象の時間
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Horizon {
+    #[serde(rename = "after5min")]
+    AFTER5MIN,
+    #[serde(rename = "after30min")]
+    AFTER30MIN,
+}
+
+impl std::fmt::Display for Horizon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Horizon::AFTER5MIN => write!(f, "{}", "after5min"),
+            Horizon::AFTER30MIN => write!(f, "{}", "after30min"),
+        }
+    }
+}
+
+impl std::str::FromStr for Horizon {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "after5min" => std::result::Result::Ok(Horizon::AFTER5MIN),
+            "after30min" => std::result::Result::Ok(Horizon::AFTER30MIN),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+/// 予測モデルの情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ModelInfo {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    /// 予測モデルのMSE
+    #[serde(rename = "performance_mse")]
+    pub performance_mse: f64,
+
+    /// 予測モデルのRMSE
+    #[serde(rename = "performance_rmse")]
+    pub performance_rmse: f64,
 
 }
 
-impl Error {
-    pub fn new(message: String, ) -> Error {
-        Error {
-            message: message,
+impl ModelInfo {
+    pub fn new(pair: String, model_no: i32, performance_mse: f64, performance_rmse: f64, ) -> ModelInfo {
+        ModelInfo {
+            pair: pair,
+            model_no: model_no,
+            performance_mse: performance_mse,
+            performance_rmse: performance_rmse,
+        }
+    }
+}
+
+/// Converts the ModelInfo value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ModelInfo {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+
+
+        params.push("performance_mse".to_string());
+        params.push(self.performance_mse.to_string());
+
+
+        params.push("performance_rmse".to_string());
+        params.push(self.performance_rmse.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ModelInfo value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ModelInfo {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub performance_mse: Vec<f64>,
+            pub performance_rmse: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ModelInfo".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "performance_mse" => intermediate_rep.performance_mse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "performance_rmse" => intermediate_rep.performance_rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ModelInfo".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ModelInfo {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ModelInfo".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ModelInfo".to_string())?,
+            performance_mse: intermediate_rep.performance_mse.into_iter().next().ok_or("performance_mse missing in ModelInfo".to_string())?,
+            performance_rmse: intermediate_rep.performance_rmse.into_iter().next().ok_or("performance_rmse missing in ModelInfo".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ModelInfo> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ModelInfo>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ModelInfo>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ModelInfo - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ModelInfo> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ModelInfo as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ModelInfo - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予測モデルの性能と学習日時
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ModelMetrics {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    /// メモ（モデルの種類）
+    #[serde(rename = "memo")]
+    pub memo: String,
+
+    /// 入力するレート履歴の件数
+    #[serde(rename = "input_data_size")]
+    pub input_data_size: i32,
+
+    /// テストデータでのMSE
+    #[serde(rename = "performance_mse")]
+    pub performance_mse: f64,
+
+    /// テストデータでのRMSE
+    #[serde(rename = "performance_rmse")]
+    pub performance_rmse: f64,
+
+    /// 作成日時（yyyy-mm-dd hh:mm:ss）
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+
+    /// 最後に学習した日時（yyyy-mm-dd hh:mm:ss）
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+
+}
+
+impl ModelMetrics {
+    pub fn new(pair: String, model_no: i32, memo: String, input_data_size: i32, performance_mse: f64, performance_rmse: f64, created_at: String, updated_at: String, ) -> ModelMetrics {
+        ModelMetrics {
+            pair: pair,
+            model_no: model_no,
+            memo: memo,
+            input_data_size: input_data_size,
+            performance_mse: performance_mse,
+            performance_rmse: performance_rmse,
+            created_at: created_at,
+            updated_at: updated_at,
+        }
+    }
+}
+
+/// Converts the ModelMetrics value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ModelMetrics {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+
+
+        params.push("memo".to_string());
+        params.push(self.memo.to_string());
+
+
+        params.push("input_data_size".to_string());
+        params.push(self.input_data_size.to_string());
+
+
+        params.push("performance_mse".to_string());
+        params.push(self.performance_mse.to_string());
+
+
+        params.push("performance_rmse".to_string());
+        params.push(self.performance_rmse.to_string());
+
+
+        params.push("created_at".to_string());
+        params.push(self.created_at.to_string());
+
+
+        params.push("updated_at".to_string());
+        params.push(self.updated_at.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ModelMetrics value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ModelMetrics {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub pair: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub memo: Vec<String>,
+            pub input_data_size: Vec<i32>,
+            pub performance_mse: Vec<f64>,
+            pub performance_rmse: Vec<f64>,
+            pub created_at: Vec<String>,
+            pub updated_at: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ModelMetrics".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "memo" => intermediate_rep.memo.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "input_data_size" => intermediate_rep.input_data_size.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "performance_mse" => intermediate_rep.performance_mse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "performance_rmse" => intermediate_rep.performance_rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "created_at" => intermediate_rep.created_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "updated_at" => intermediate_rep.updated_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ModelMetrics".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ModelMetrics {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ModelMetrics".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ModelMetrics".to_string())?,
+            memo: intermediate_rep.memo.into_iter().next().ok_or("memo missing in ModelMetrics".to_string())?,
+            input_data_size: intermediate_rep.input_data_size.into_iter().next().ok_or("input_data_size missing in ModelMetrics".to_string())?,
+            performance_mse: intermediate_rep.performance_mse.into_iter().next().ok_or("performance_mse missing in ModelMetrics".to_string())?,
+            performance_rmse: intermediate_rep.performance_rmse.into_iter().next().ok_or("performance_rmse missing in ModelMetrics".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or("created_at missing in ModelMetrics".to_string())?,
+            updated_at: intermediate_rep.updated_at.into_iter().next().ok_or("updated_at missing in ModelMetrics".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ModelMetrics> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ModelMetrics>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ModelMetrics>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ModelMetrics - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ModelMetrics> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ModelMetrics as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ModelMetrics - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 予測モデルの実績誤差の推移
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ModelPerformance {
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    /// モデルNo
+    #[serde(rename = "model_no")]
+    pub model_no: i32,
+
+    #[serde(rename = "horizon")]
+    pub horizon: models::Horizon,
+
+    /// 1時間ごとの実績誤差
+    #[serde(rename = "points")]
+    pub points: Vec<models::ModelPerformancePoint>,
+
+}
+
+impl ModelPerformance {
+    pub fn new(pair: String, model_no: i32, horizon: models::Horizon, points: Vec<models::ModelPerformancePoint>, ) -> ModelPerformance {
+        ModelPerformance {
+            pair: pair,
+            model_no: model_no,
+            horizon: horizon,
+            points: points,
         }
     }
 }
 
-/// Converts the Error value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ModelPerformance value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Error {
+impl std::string::ToString for ModelPerformance {
     fn to_string(&self) -> String {
         let mut params: Vec<String> = vec![];
 
-        params.push("message".to_string());
-        params.push(self.message.to_string());
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+
+
+        params.push("model_no".to_string());
+        params.push(self.model_no.to_string());
+        // Skipping horizon in query parameter serialization
+        // Skipping points in query parameter serialization
 
         params.join(",").to_string()
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Error value
+/// Converts Query Parameters representation (style=form, explode=false) to a ModelPerformance value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Error {
+impl std::str::FromStr for ModelPerformance {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         #[derive(Default)]
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
-            pub message: Vec<String>,
+            pub pair: Vec<String>,
+            pub model_no: Vec<i32>,
+            pub horizon: Vec<models::Horizon>,
+            pub points: Vec<Vec<models::ModelPerformancePoint>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -58,13 +2742,16 @@ impl std::str::FromStr for Error {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Error".to_string())
+                None => return std::result::Result::Err("Missing value while parsing ModelPerformance".to_string())
             };
 
             if let Some(key) = key_result {
                 match key {
-                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Error".to_string())
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "model_no" => intermediate_rep.model_no.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "horizon" => intermediate_rep.horizon.push(<models::Horizon as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "points" => return std::result::Result::Err("Parsing a container in this style is not supported in ModelPerformance".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ModelPerformance".to_string())
                 }
             }
 
@@ -73,40 +2760,43 @@ impl std::str::FromStr for Error {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Error {
-            message: intermediate_rep.message.into_iter().next().ok_or("message missing in Error".to_string())?,
+        std::result::Result::Ok(ModelPerformance {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in ModelPerformance".to_string())?,
+            model_no: intermediate_rep.model_no.into_iter().next().ok_or("model_no missing in ModelPerformance".to_string())?,
+            horizon: intermediate_rep.horizon.into_iter().next().ok_or("horizon missing in ModelPerformance".to_string())?,
+            points: intermediate_rep.points.into_iter().next().ok_or("points missing in ModelPerformance".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Error> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ModelPerformance> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Error>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ModelPerformance>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Error>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<ModelPerformance>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Error - value: {} is invalid {}",
+                 format!("Invalid header value for ModelPerformance - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Error> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ModelPerformance> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Error as std::str::FromStr>::from_str(value) {
+                    match <ModelPerformance as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Error - {}",
+                            format!("Unable to convert header value '{}' into ModelPerformance - {}",
                                 value, err))
                     }
              },
@@ -118,47 +2808,89 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 }
 
 
-/// 成功時の情報
+/// 一定期間の実績誤差
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ForecastAfter30minRateIdModelNoGet200Response {
-    #[serde(rename = "result")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub result: Option<models::ForecastResult>,
+pub struct ModelPerformancePoint {
+    /// 集計期間の開始日時
+    #[serde(rename = "time")]
+    pub time: String,
+
+    /// 集計対象の予想件数
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    /// 平均絶対誤差
+    #[serde(rename = "mae")]
+    pub mae: f64,
+
+    /// 平均二乗誤差
+    #[serde(rename = "mse")]
+    pub mse: f64,
+
+    /// 二乗平均平方根誤差
+    #[serde(rename = "rmse")]
+    pub rmse: f64,
 
 }
 
-impl ForecastAfter30minRateIdModelNoGet200Response {
-    pub fn new() -> ForecastAfter30minRateIdModelNoGet200Response {
-        ForecastAfter30minRateIdModelNoGet200Response {
-            result: None,
+impl ModelPerformancePoint {
+    pub fn new(time: String, count: i64, mae: f64, mse: f64, rmse: f64, ) -> ModelPerformancePoint {
+        ModelPerformancePoint {
+            time: time,
+            count: count,
+            mae: mae,
+            mse: mse,
+            rmse: rmse,
         }
     }
 }
 
-/// Converts the ForecastAfter30minRateIdModelNoGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ModelPerformancePoint value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ForecastAfter30minRateIdModelNoGet200Response {
+impl std::string::ToString for ModelPerformancePoint {
     fn to_string(&self) -> String {
         let mut params: Vec<String> = vec![];
-        // Skipping result in query parameter serialization
+
+        params.push("time".to_string());
+        params.push(self.time.to_string());
+
+
+        params.push("count".to_string());
+        params.push(self.count.to_string());
+
+
+        params.push("mae".to_string());
+        params.push(self.mae.to_string());
+
+
+        params.push("mse".to_string());
+        params.push(self.mse.to_string());
+
+
+        params.push("rmse".to_string());
+        params.push(self.rmse.to_string());
 
         params.join(",").to_string()
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ForecastAfter30minRateIdModelNoGet200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a ModelPerformancePoint value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ForecastAfter30minRateIdModelNoGet200Response {
+impl std::str::FromStr for ModelPerformancePoint {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         #[derive(Default)]
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
-            pub result: Vec<models::ForecastResult>,
+            pub time: Vec<String>,
+            pub count: Vec<i64>,
+            pub mae: Vec<f64>,
+            pub mse: Vec<f64>,
+            pub rmse: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -170,13 +2902,17 @@ impl std::str::FromStr for ForecastAfter30minRateIdModelNoGet200Response {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing ForecastAfter30minRateIdModelNoGet200Response".to_string())
+                None => return std::result::Result::Err("Missing value while parsing ModelPerformancePoint".to_string())
             };
 
             if let Some(key) = key_result {
                 match key {
-                    "result" => intermediate_rep.result.push(<models::ForecastResult as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastAfter30minRateIdModelNoGet200Response".to_string())
+                    "time" => intermediate_rep.time.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mae" => intermediate_rep.mae.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "mse" => intermediate_rep.mse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "rmse" => intermediate_rep.rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ModelPerformancePoint".to_string())
                 }
             }
 
@@ -185,40 +2921,44 @@ impl std::str::FromStr for ForecastAfter30minRateIdModelNoGet200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ForecastAfter30minRateIdModelNoGet200Response {
-            result: intermediate_rep.result.into_iter().next(),
+        std::result::Result::Ok(ModelPerformancePoint {
+            time: intermediate_rep.time.into_iter().next().ok_or("time missing in ModelPerformancePoint".to_string())?,
+            count: intermediate_rep.count.into_iter().next().ok_or("count missing in ModelPerformancePoint".to_string())?,
+            mae: intermediate_rep.mae.into_iter().next().ok_or("mae missing in ModelPerformancePoint".to_string())?,
+            mse: intermediate_rep.mse.into_iter().next().ok_or("mse missing in ModelPerformancePoint".to_string())?,
+            rmse: intermediate_rep.rmse.into_iter().next().ok_or("rmse missing in ModelPerformancePoint".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ForecastAfter30minRateIdModelNoGet200Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ModelPerformancePoint> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ForecastAfter30minRateIdModelNoGet200Response>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ModelPerformancePoint>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<ForecastAfter30minRateIdModelNoGet200Response>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<ModelPerformancePoint>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for ForecastAfter30minRateIdModelNoGet200Response - value: {} is invalid {}",
+                 format!("Invalid header value for ModelPerformancePoint - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastAfter30minRateIdModelNoGet200Response> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ModelPerformancePoint> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <ForecastAfter30minRateIdModelNoGet200Response as std::str::FromStr>::from_str(value) {
+                    match <ModelPerformancePoint as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into ForecastAfter30minRateIdModelNoGet200Response - {}",
+                            format!("Unable to convert header value '{}' into ModelPerformancePoint - {}",
                                 value, err))
                     }
              },
@@ -230,75 +2970,59 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 }
 
 
-/// 予測結果
+/// 成功時の情報
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ForecastResult {
-    /// 予測が完了したか？
-    #[serde(rename = "complete")]
-    pub complete: bool,
-
-    /// レートの値
-    #[serde(rename = "rate")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub rate: Option<f64>,
+pub struct RatesPost201Response {
+    /// レート履歴ID
+    #[serde(rename = "rateId")]
+    pub rate_id: String,
 
-    /// 予測モデルのRMSE
-    #[serde(rename = "rmse")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub rmse: Option<f64>,
+    /// 有効期限
+    #[serde(rename = "expire")]
+    pub expire: String,
 
 }
 
-impl ForecastResult {
-    pub fn new(complete: bool, ) -> ForecastResult {
-        ForecastResult {
-            complete: complete,
-            rate: None,
-            rmse: None,
+impl RatesPost201Response {
+    pub fn new(rate_id: String, expire: String, ) -> RatesPost201Response {
+        RatesPost201Response {
+            rate_id: rate_id,
+            expire: expire,
         }
     }
 }
 
-/// Converts the ForecastResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RatesPost201Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ForecastResult {
+impl std::string::ToString for RatesPost201Response {
     fn to_string(&self) -> String {
         let mut params: Vec<String> = vec![];
 
-        params.push("complete".to_string());
-        params.push(self.complete.to_string());
-
-
-        if let Some(ref rate) = self.rate {
-            params.push("rate".to_string());
-            params.push(rate.to_string());
-        }
+        params.push("rateId".to_string());
+        params.push(self.rate_id.to_string());
 
 
-        if let Some(ref rmse) = self.rmse {
-            params.push("rmse".to_string());
-            params.push(rmse.to_string());
-        }
+        params.push("expire".to_string());
+        params.push(self.expire.to_string());
 
         params.join(",").to_string()
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ForecastResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a RatesPost201Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ForecastResult {
+impl std::str::FromStr for RatesPost201Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         #[derive(Default)]
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
-            pub complete: Vec<bool>,
-            pub rate: Vec<f64>,
-            pub rmse: Vec<f64>,
+            pub rate_id: Vec<String>,
+            pub expire: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -310,15 +3034,14 @@ impl std::str::FromStr for ForecastResult {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing ForecastResult".to_string())
+                None => return std::result::Result::Err("Missing value while parsing RatesPost201Response".to_string())
             };
 
             if let Some(key) = key_result {
                 match key {
-                    "complete" => intermediate_rep.complete.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    "rate" => intermediate_rep.rate.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    "rmse" => intermediate_rep.rmse.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ForecastResult".to_string())
+                    "rateId" => intermediate_rep.rate_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "expire" => intermediate_rep.expire.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RatesPost201Response".to_string())
                 }
             }
 
@@ -327,42 +3050,41 @@ impl std::str::FromStr for ForecastResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ForecastResult {
-            complete: intermediate_rep.complete.into_iter().next().ok_or("complete missing in ForecastResult".to_string())?,
-            rate: intermediate_rep.rate.into_iter().next(),
-            rmse: intermediate_rep.rmse.into_iter().next(),
+        std::result::Result::Ok(RatesPost201Response {
+            rate_id: intermediate_rep.rate_id.into_iter().next().ok_or("rateId missing in RatesPost201Response".to_string())?,
+            expire: intermediate_rep.expire.into_iter().next().ok_or("expire missing in RatesPost201Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ForecastResult> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RatesPost201Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ForecastResult>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RatesPost201Response>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<ForecastResult>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<RatesPost201Response>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for ForecastResult - value: {} is invalid {}",
+                 format!("Invalid header value for RatesPost201Response - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ForecastResult> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RatesPost201Response> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <ForecastResult as std::str::FromStr>::from_str(value) {
+                    match <RatesPost201Response as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into ForecastResult - {}",
+                            format!("Unable to convert header value '{}' into RatesPost201Response - {}",
                                 value, err))
                     }
              },
@@ -374,33 +3096,34 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 }
 
 
-/// レート履歴
+/// 学習要求
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct History {
+pub struct TrainingRequest {
     /// 通貨ペア
-    // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "pair")]
     pub pair: String,
 
-    #[serde(rename = "rate_histories")]
-    pub rate_histories: Vec<f64>,
+    /// メモ
+    #[serde(rename = "memo")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub memo: Option<String>,
 
 }
 
-impl History {
-    pub fn new(pair: String, rate_histories: Vec<f64>, ) -> History {
-        History {
+impl TrainingRequest {
+    pub fn new(pair: String, ) -> TrainingRequest {
+        TrainingRequest {
             pair: pair,
-            rate_histories: rate_histories,
+            memo: None,
         }
     }
 }
 
-/// Converts the History value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TrainingRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for History {
+impl std::string::ToString for TrainingRequest {
     fn to_string(&self) -> String {
         let mut params: Vec<String> = vec![];
 
@@ -408,17 +3131,19 @@ impl std::string::ToString for History {
         params.push(self.pair.to_string());
 
 
-        params.push("rate_histories".to_string());
-        params.push(self.rate_histories.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",").to_string());
+        if let Some(ref memo) = self.memo {
+            params.push("memo".to_string());
+            params.push(memo.to_string());
+        }
 
         params.join(",").to_string()
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a History value
+/// Converts Query Parameters representation (style=form, explode=false) to a TrainingRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for History {
+impl std::str::FromStr for TrainingRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -426,7 +3151,7 @@ impl std::str::FromStr for History {
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
             pub pair: Vec<String>,
-            pub rate_histories: Vec<Vec<f64>>,
+            pub memo: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -438,14 +3163,14 @@ impl std::str::FromStr for History {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing History".to_string())
+                None => return std::result::Result::Err("Missing value while parsing TrainingRequest".to_string())
             };
 
             if let Some(key) = key_result {
                 match key {
                     "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    "rate_histories" => return std::result::Result::Err("Parsing a container in this style is not supported in History".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing History".to_string())
+                    "memo" => intermediate_rep.memo.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TrainingRequest".to_string())
                 }
             }
 
@@ -454,41 +3179,41 @@ impl std::str::FromStr for History {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(History {
-            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in History".to_string())?,
-            rate_histories: intermediate_rep.rate_histories.into_iter().next().ok_or("rate_histories missing in History".to_string())?,
+        std::result::Result::Ok(TrainingRequest {
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in TrainingRequest".to_string())?,
+            memo: intermediate_rep.memo.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<History> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<TrainingRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<History>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TrainingRequest>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<History>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<TrainingRequest>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for History - value: {} is invalid {}",
+                 format!("Invalid header value for TrainingRequest - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<History> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TrainingRequest> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <History as std::str::FromStr>::from_str(value) {
+                    match <TrainingRequest as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into History - {}",
+                            format!("Unable to convert header value '{}' into TrainingRequest - {}",
                                 value, err))
                     }
              },
@@ -500,59 +3225,126 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 }
 
 
-/// 成功時の情報
+/// 学習実行
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RatesPost201Response {
-    /// レート履歴ID
-    #[serde(rename = "rateId")]
-    pub rate_id: String,
+pub struct TrainingRun {
+    /// 学習実行ID
+    #[serde(rename = "id")]
+    pub id: String,
 
-    /// 有効期限
-    #[serde(rename = "expire")]
-    pub expire: String,
+    /// 通貨ペア
+    #[serde(rename = "pair")]
+    pub pair: String,
+
+    #[serde(rename = "status")]
+    pub status: models::TrainingRunStatus,
+
+    /// メモ
+    #[serde(rename = "memo")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub memo: Option<String>,
+
+    /// 実行結果
+    #[serde(rename = "result")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub result: Option<String>,
+
+    /// 要求日時
+    #[serde(rename = "requested_at")]
+    pub requested_at: String,
+
+    /// 開始日時
+    #[serde(rename = "started_at")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub started_at: Option<String>,
+
+    /// 終了日時
+    #[serde(rename = "finished_at")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub finished_at: Option<String>,
 
 }
 
-impl RatesPost201Response {
-    pub fn new(rate_id: String, expire: String, ) -> RatesPost201Response {
-        RatesPost201Response {
-            rate_id: rate_id,
-            expire: expire,
+impl TrainingRun {
+    pub fn new(id: String, pair: String, status: models::TrainingRunStatus, requested_at: String, ) -> TrainingRun {
+        TrainingRun {
+            id: id,
+            pair: pair,
+            status: status,
+            memo: None,
+            result: None,
+            requested_at: requested_at,
+            started_at: None,
+            finished_at: None,
         }
     }
 }
 
-/// Converts the RatesPost201Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TrainingRun value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RatesPost201Response {
+impl std::string::ToString for TrainingRun {
     fn to_string(&self) -> String {
         let mut params: Vec<String> = vec![];
 
-        params.push("rateId".to_string());
-        params.push(self.rate_id.to_string());
+        params.push("id".to_string());
+        params.push(self.id.to_string());
 
 
-        params.push("expire".to_string());
-        params.push(self.expire.to_string());
+        params.push("pair".to_string());
+        params.push(self.pair.to_string());
+        // Skipping status in query parameter serialization
+
+        if let Some(ref memo) = self.memo {
+            params.push("memo".to_string());
+            params.push(memo.to_string());
+        }
+
+
+        if let Some(ref result) = self.result {
+            params.push("result".to_string());
+            params.push(result.to_string());
+        }
+
+
+        params.push("requested_at".to_string());
+        params.push(self.requested_at.to_string());
+
+
+        if let Some(ref started_at) = self.started_at {
+            params.push("started_at".to_string());
+            params.push(started_at.to_string());
+        }
+
+
+        if let Some(ref finished_at) = self.finished_at {
+            params.push("finished_at".to_string());
+            params.push(finished_at.to_string());
+        }
 
         params.join(",").to_string()
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RatesPost201Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a TrainingRun value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RatesPost201Response {
+impl std::str::FromStr for TrainingRun {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         #[derive(Default)]
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
-            pub rate_id: Vec<String>,
-            pub expire: Vec<String>,
+            pub id: Vec<String>,
+            pub pair: Vec<String>,
+            pub status: Vec<models::TrainingRunStatus>,
+            pub memo: Vec<String>,
+            pub result: Vec<String>,
+            pub requested_at: Vec<String>,
+            pub started_at: Vec<String>,
+            pub finished_at: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -564,14 +3356,20 @@ impl std::str::FromStr for RatesPost201Response {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing RatesPost201Response".to_string())
+                None => return std::result::Result::Err("Missing value while parsing TrainingRun".to_string())
             };
 
             if let Some(key) = key_result {
                 match key {
-                    "rateId" => intermediate_rep.rate_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    "expire" => intermediate_rep.expire.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing RatesPost201Response".to_string())
+                    "id" => intermediate_rep.id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "pair" => intermediate_rep.pair.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "status" => intermediate_rep.status.push(<models::TrainingRunStatus as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "memo" => intermediate_rep.memo.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "result" => intermediate_rep.result.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "requested_at" => intermediate_rep.requested_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "started_at" => intermediate_rep.started_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "finished_at" => intermediate_rep.finished_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TrainingRun".to_string())
                 }
             }
 
@@ -580,41 +3378,47 @@ impl std::str::FromStr for RatesPost201Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RatesPost201Response {
-            rate_id: intermediate_rep.rate_id.into_iter().next().ok_or("rateId missing in RatesPost201Response".to_string())?,
-            expire: intermediate_rep.expire.into_iter().next().ok_or("expire missing in RatesPost201Response".to_string())?,
+        std::result::Result::Ok(TrainingRun {
+            id: intermediate_rep.id.into_iter().next().ok_or("id missing in TrainingRun".to_string())?,
+            pair: intermediate_rep.pair.into_iter().next().ok_or("pair missing in TrainingRun".to_string())?,
+            status: intermediate_rep.status.into_iter().next().ok_or("status missing in TrainingRun".to_string())?,
+            memo: intermediate_rep.memo.into_iter().next(),
+            result: intermediate_rep.result.into_iter().next(),
+            requested_at: intermediate_rep.requested_at.into_iter().next().ok_or("requested_at missing in TrainingRun".to_string())?,
+            started_at: intermediate_rep.started_at.into_iter().next(),
+            finished_at: intermediate_rep.finished_at.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RatesPost201Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<TrainingRun> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RatesPost201Response>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TrainingRun>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<RatesPost201Response>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<TrainingRun>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for RatesPost201Response - value: {} is invalid {}",
+                 format!("Invalid header value for TrainingRun - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RatesPost201Response> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TrainingRun> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <RatesPost201Response as std::str::FromStr>::from_str(value) {
+                    match <TrainingRun as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into RatesPost201Response - {}",
+                            format!("Unable to convert header value '{}' into TrainingRun - {}",
                                 value, err))
                     }
              },
@@ -625,3 +3429,46 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+
+/// 学習実行の状態
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TrainingRunStatus {
+    #[serde(rename = "requested")]
+    REQUESTED,
+    #[serde(rename = "running")]
+    RUNNING,
+    #[serde(rename = "succeeded")]
+    SUCCEEDED,
+    #[serde(rename = "failed")]
+    FAILED,
+}
+
+impl std::fmt::Display for TrainingRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TrainingRunStatus::REQUESTED => write!(f, "{}", "requested"),
+            TrainingRunStatus::RUNNING => write!(f, "{}", "running"),
+            TrainingRunStatus::SUCCEEDED => write!(f, "{}", "succeeded"),
+            TrainingRunStatus::FAILED => write!(f, "{}", "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for TrainingRunStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "requested" => std::result::Result::Ok(TrainingRunStatus::REQUESTED),
+            "running" => std::result::Result::Ok(TrainingRunStatus::RUNNING),
+            "succeeded" => std::result::Result::Ok(TrainingRunStatus::SUCCEEDED),
+            "failed" => std::result::Result::Ok(TrainingRunStatus::FAILED),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
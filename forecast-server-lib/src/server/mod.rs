@@ -22,7 +22,17 @@ pub use crate::context;
 type ServiceFuture = BoxFuture<'static, Result<Response<Body>, crate::ServiceError>>;
 
 use crate::{Api,
-     ForecastAfter30minRateIdModelNoGetResponse,
+     AdminModelsPairFromNoPromoteToNoPostResponse,
+     AdminTrainingPostResponse,
+     AdminTrainingRunIdGetResponse,
+     ModelsModelNoPerformanceGetResponse,
+     ModelsPairNoMetricsGetResponse,
+     EvaluationsAccuracyGetResponse,
+     ForecastErrorsGetResponse,
+     ForecastHorizonRateIdModelNoGetResponse,
+     ForecastHorizonRateIdModelNoExplanationGetResponse,
+     ForecastNowPostResponse,
+     ForecastsBatchPostResponse,
      RatesPostResponse
 };
 
@@ -31,18 +41,63 @@ mod paths {
 
     lazy_static! {
         pub static ref GLOBAL_REGEX_SET: regex::RegexSet = regex::RegexSet::new(vec![
-            r"^/forecast/after30min/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)$",
-            r"^/rates$"
+            r"^/v1/admin/models/(?P<pair>[^/?#]*)/(?P<fromNo>[^/?#]*)/promote/(?P<toNo>[^/?#]*)$",
+            r"^/v1/admin/training$",
+            r"^/v1/admin/training/(?P<runId>[^/?#]*)$",
+            r"^/v1/evaluations/accuracy$",
+            r"^/v1/forecast/errors$",
+            r"^/v1/forecast/now$",
+            r"^/v1/forecast/(?P<horizon>[^/?#]*)/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)$",
+            r"^/v1/forecast/(?P<horizon>[^/?#]*)/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)/explanation$",
+            r"^/v1/forecasts/batch$",
+            r"^/v1/models/(?P<modelNo>[^/?#]*)/performance$",
+            r"^/v1/models/(?P<pair>[^/?#]*)/(?P<no>[^/?#]*)/metrics$",
+            r"^/v1/rates$"
         ])
         .expect("Unable to create global regex set");
     }
-    pub(crate) static ID_FORECAST_AFTER30MIN_RATEID_MODELNO: usize = 0;
+    pub(crate) static ID_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO: usize = 0;
     lazy_static! {
-        pub static ref REGEX_FORECAST_AFTER30MIN_RATEID_MODELNO: regex::Regex =
-            regex::Regex::new(r"^/forecast/after30min/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)$")
-                .expect("Unable to create regex for FORECAST_AFTER30MIN_RATEID_MODELNO");
+        pub static ref REGEX_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO: regex::Regex =
+            regex::Regex::new(r"^/v1/admin/models/(?P<pair>[^/?#]*)/(?P<fromNo>[^/?#]*)/promote/(?P<toNo>[^/?#]*)$")
+                .expect("Unable to create regex for V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO");
     }
-    pub(crate) static ID_RATES: usize = 1;
+    pub(crate) static ID_V1_ADMIN_TRAINING: usize = 1;
+    pub(crate) static ID_V1_ADMIN_TRAINING_RUNID: usize = 2;
+    lazy_static! {
+        pub static ref REGEX_V1_ADMIN_TRAINING_RUNID: regex::Regex =
+            regex::Regex::new(r"^/v1/admin/training/(?P<runId>[^/?#]*)$")
+                .expect("Unable to create regex for V1_ADMIN_TRAINING_RUNID");
+    }
+    pub(crate) static ID_V1_EVALUATIONS_ACCURACY: usize = 3;
+    pub(crate) static ID_V1_FORECAST_ERRORS: usize = 4;
+    pub(crate) static ID_V1_FORECAST_NOW: usize = 5;
+    pub(crate) static ID_V1_FORECAST_HORIZON_RATEID_MODELNO: usize = 6;
+    lazy_static! {
+        pub static ref REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO: regex::Regex =
+            regex::Regex::new(r"^/v1/forecast/(?P<horizon>[^/?#]*)/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)$")
+                .expect("Unable to create regex for V1_FORECAST_HORIZON_RATEID_MODELNO");
+    }
+    pub(crate) static ID_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION: usize = 7;
+    lazy_static! {
+        pub static ref REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION: regex::Regex =
+            regex::Regex::new(r"^/v1/forecast/(?P<horizon>[^/?#]*)/(?P<rateId>[^/?#]*)/(?P<modelNo>[^/?#]*)/explanation$")
+                .expect("Unable to create regex for V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION");
+    }
+    pub(crate) static ID_V1_FORECASTS_BATCH: usize = 8;
+    pub(crate) static ID_V1_MODELS_MODELNO_PERFORMANCE: usize = 9;
+    lazy_static! {
+        pub static ref REGEX_V1_MODELS_MODELNO_PERFORMANCE: regex::Regex =
+            regex::Regex::new(r"^/v1/models/(?P<modelNo>[^/?#]*)/performance$")
+                .expect("Unable to create regex for V1_MODELS_MODELNO_PERFORMANCE");
+    }
+    pub(crate) static ID_V1_MODELS_PAIR_NO_METRICS: usize = 10;
+    lazy_static! {
+        pub static ref REGEX_V1_MODELS_PAIR_NO_METRICS: regex::Regex =
+            regex::Regex::new(r"^/v1/models/(?P<pair>[^/?#]*)/(?P<no>[^/?#]*)/metrics$")
+                .expect("Unable to create regex for V1_MODELS_PAIR_NO_METRICS");
+    }
+    pub(crate) static ID_V1_RATES: usize = 11;
 }
 
 pub struct MakeService<T, C> where
@@ -147,48 +202,84 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
         match &method {
 
-            // ForecastAfter30minRateIdModelNoGet - GET /forecast/after30min/{rateId}/{modelNo}
-            &hyper::Method::GET if path.matched(paths::ID_FORECAST_AFTER30MIN_RATEID_MODELNO) => {
+            // AdminModelsPairFromNoPromoteToNoPost - POST /admin/models/{pair}/{fromNo}/promote/{toNo}
+            &hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO) => {
                 // Path parameters
                 let path: &str = &uri.path().to_string();
                 let path_params =
-                    paths::REGEX_FORECAST_AFTER30MIN_RATEID_MODELNO
+                    paths::REGEX_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO
                     .captures(&path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE FORECAST_AFTER30MIN_RATEID_MODELNO in set but failed match against \"{}\"", path, paths::REGEX_FORECAST_AFTER30MIN_RATEID_MODELNO.as_str())
+                        panic!("Path {} matched RE V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO.as_str())
                     );
 
-                let param_rate_id = match percent_encoding::percent_decode(path_params["rateId"].as_bytes()).decode_utf8() {
-                    Ok(param_rate_id) => match param_rate_id.parse::<String>() {
-                        Ok(param_rate_id) => param_rate_id,
+                let param_pair = match percent_encoding::percent_decode(path_params["pair"].as_bytes()).decode_utf8() {
+                    Ok(param_pair) => match param_pair.parse::<String>() {
+                        Ok(param_pair) => param_pair,
                         Err(e) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter rateId: {}", e)))
+                                        .body(Body::from(format!("Couldn't parse path parameter pair: {}", e)))
                                         .expect("Unable to create Bad Request response for invalid path parameter")),
                     },
                     Err(_) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["rateId"])))
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["pair"])))
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                let param_model_no = match percent_encoding::percent_decode(path_params["modelNo"].as_bytes()).decode_utf8() {
-                    Ok(param_model_no) => match param_model_no.parse::<i32>() {
-                        Ok(param_model_no) => param_model_no,
+                let param_from_no = match percent_encoding::percent_decode(path_params["fromNo"].as_bytes()).decode_utf8() {
+                    Ok(param_from_no) => match param_from_no.parse::<i32>() {
+                        Ok(param_from_no) => param_from_no,
                         Err(e) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter modelNo: {}", e)))
+                                        .body(Body::from(format!("Couldn't parse path parameter fromNo: {}", e)))
                                         .expect("Unable to create Bad Request response for invalid path parameter")),
                     },
                     Err(_) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["modelNo"])))
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["fromNo"])))
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                                let result = api_impl.forecast_after30min_rate_id_model_no_get(
-                                            param_rate_id,
-                                            param_model_no,
+                let param_to_no = match percent_encoding::percent_decode(path_params["toNo"].as_bytes()).decode_utf8() {
+                    Ok(param_to_no) => match param_to_no.parse::<i32>() {
+                        Ok(param_to_no) => param_to_no,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter toNo: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["toNo"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Header parameters
+                let param_x_api_key = headers.get(HeaderName::from_static("x-api-key"));
+
+                let param_x_api_key = match param_x_api_key {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Invalid header X-Api-Key - {}", err)))
+                                        .expect("Unable to create Bad Request response for invalid header X-Api-Key"));
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                                let result = api_impl.admin_models_pair_from_no_promote_to_no_post(
+                                            param_pair,
+                                            param_from_no,
+                                            param_to_no,
+                                            param_x_api_key,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -199,36 +290,88 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                ForecastAfter30minRateIdModelNoGetResponse::Status200
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status200
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for FORECAST_AFTER30MIN_RATE_ID_MODEL_NO_GET_STATUS200"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status401
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS401"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                ForecastAfter30minRateIdModelNoGetResponse::Status404
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status404
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for FORECAST_AFTER30MIN_RATE_ID_MODEL_NO_GET_STATUS404"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS404"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                ForecastAfter30minRateIdModelNoGetResponse::Status500
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status500
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for FORECAST_AFTER30MIN_RATE_ID_MODEL_NO_GET_STATUS500"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminModelsPairFromNoPromoteToNoPostResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_MODELS_PAIR_FROM_NO_PROMOTE_TO_NO_POST_STATUS503"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
@@ -244,8 +387,28 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // RatesPost - POST /rates
-            &hyper::Method::POST if path.matched(paths::ID_RATES) => {
+            // AdminTrainingPost - POST /admin/training
+            &hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_TRAINING) => {
+                // Header parameters
+                let param_x_api_key = headers.get(HeaderName::from_static("x-api-key"));
+
+                let param_x_api_key = match param_x_api_key {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Invalid header X-Api-Key - {}", err)))
+                                        .expect("Unable to create Bad Request response for invalid header X-Api-Key"));
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
                 // Body parameters (note that non-required body parameters will ignore garbage
                 // values, rather than causing a 400 response). Produce warning header and logs for
                 // any unused fields.
@@ -253,31 +416,32 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                 match result {
                             Ok(body) => {
                                 let mut unused_elements = Vec::new();
-                                let param_history: Option<models::History> = if !body.is_empty() {
+                                let param_training_request: Option<models::TrainingRequest> = if !body.is_empty() {
                                     let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
                                     match serde_ignored::deserialize(deserializer, |path| {
                                             warn!("Ignoring unknown field in body: {}", path);
                                             unused_elements.push(path.to_string());
                                     }) {
-                                        Ok(param_history) => param_history,
+                                        Ok(param_training_request) => param_training_request,
                                         Err(e) => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from(format!("Couldn't parse body parameter History - doesn't match schema: {}", e)))
-                                                        .expect("Unable to create Bad Request response for invalid body parameter History due to schema")),
+                                                        .body(Body::from(format!("Couldn't parse body parameter TrainingRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter TrainingRequest due to schema")),
                                     }
                                 } else {
                                     None
                                 };
-                                let param_history = match param_history {
-                                    Some(param_history) => param_history,
+                                let param_training_request = match param_training_request {
+                                    Some(param_training_request) => param_training_request,
                                     None => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from("Missing required body parameter History"))
-                                                        .expect("Unable to create Bad Request response for missing body parameter History")),
+                                                        .body(Body::from("Missing required body parameter TrainingRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter TrainingRequest")),
                                 };
 
-                                let result = api_impl.rates_post(
-                                            param_history,
+                                let result = api_impl.admin_training_post(
+                                            param_training_request,
+                                            param_x_api_key,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -295,47 +459,1688 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                RatesPostResponse::Status201
+                                                AdminTrainingPostResponse::Status202
                                                     (body)
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(202).expect("Unable to turn 202 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS201"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_POST_STATUS202"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                RatesPostResponse::Status400
+                                                AdminTrainingPostResponse::Status400
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS400"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_POST_STATUS400"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                RatesPostResponse::Status404
+                                                AdminTrainingPostResponse::Status401
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_POST_STATUS401"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminTrainingPostResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_POST_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminTrainingPostResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_POST_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter TrainingRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter TrainingRequest")),
+                        }
+            },
+
+            // AdminTrainingRunIdGet - GET /admin/training/{runId}
+            &hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_TRAINING_RUNID) => {
+                // Path parameters
+                let path: &str = &uri.path().to_string();
+                let path_params =
+                    paths::REGEX_V1_ADMIN_TRAINING_RUNID
+                    .captures(&path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_TRAINING_RUNID in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_TRAINING_RUNID.as_str())
+                    );
+
+                let param_run_id = match percent_encoding::percent_decode(path_params["runId"].as_bytes()).decode_utf8() {
+                    Ok(param_run_id) => match param_run_id.parse::<String>() {
+                        Ok(param_run_id) => param_run_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter runId: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["runId"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Header parameters
+                let param_x_api_key = headers.get(HeaderName::from_static("x-api-key"));
+
+                let param_x_api_key = match param_x_api_key {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Invalid header X-Api-Key - {}", err)))
+                                        .expect("Unable to create Bad Request response for invalid header X-Api-Key"));
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                                let result = api_impl.admin_training_run_id_get(
+                                            param_run_id,
+                                            param_x_api_key,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                AdminTrainingRunIdGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_RUN_ID_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminTrainingRunIdGetResponse::Status401
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_RUN_ID_GET_STATUS401"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminTrainingRunIdGetResponse::Status404
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS404"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_RUN_ID_GET_STATUS404"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                RatesPostResponse::Status500
+                                                AdminTrainingRunIdGetResponse::Status500
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS500"));
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_RUN_ID_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                AdminTrainingRunIdGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ADMIN_TRAINING_RUN_ID_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ModelsModelNoPerformanceGet - GET /models/{modelNo}/performance
+            &hyper::Method::GET if path.matched(paths::ID_V1_MODELS_MODELNO_PERFORMANCE) => {
+                // Path parameters
+                let path: &str = &uri.path().to_string();
+                let path_params =
+                    paths::REGEX_V1_MODELS_MODELNO_PERFORMANCE
+                    .captures(&path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_MODELS_MODELNO_PERFORMANCE in set but failed match against \"{}\"", path, paths::REGEX_V1_MODELS_MODELNO_PERFORMANCE.as_str())
+                    );
+
+                let param_model_no = match percent_encoding::percent_decode(path_params["modelNo"].as_bytes()).decode_utf8() {
+                    Ok(param_model_no) => match param_model_no.parse::<i32>() {
+                        Ok(param_model_no) => param_model_no,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter modelNo: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["modelNo"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_pair = query_params.iter().filter(|e| e.0 == "pair").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_pair = match param_pair {
+                    Some(param_pair) => {
+                        let param_pair =
+                            <String as std::str::FromStr>::from_str
+                                (&param_pair);
+                        match param_pair {
+                            Ok(param_pair) => Some(param_pair),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pair - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pair")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_pair = match param_pair {
+                    Some(param_pair) => param_pair,
+                    None => return Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Missing required query parameter pair"))
+                        .expect("Unable to create Bad Request response for missing query parameter pair")),
+                };
+                let param_horizon = query_params.iter().filter(|e| e.0 == "horizon").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_horizon = match param_horizon {
+                    Some(param_horizon) => {
+                        let param_horizon =
+                            <models::Horizon as std::str::FromStr>::from_str
+                                (&param_horizon);
+                        match param_horizon {
+                            Ok(param_horizon) => Some(param_horizon),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter horizon - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter horizon")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_horizon = match param_horizon {
+                    Some(param_horizon) => param_horizon,
+                    None => return Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Missing required query parameter horizon"))
+                        .expect("Unable to create Bad Request response for missing query parameter horizon")),
+                };
+                let param_from = query_params.iter().filter(|e| e.0 == "from").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_from = match param_from {
+                    Some(param_from) => {
+                        let param_from =
+                            <String as std::str::FromStr>::from_str
+                                (&param_from);
+                        match param_from {
+                            Ok(param_from) => Some(param_from),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter from - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter from")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_to = query_params.iter().filter(|e| e.0 == "to").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_to = match param_to {
+                    Some(param_to) => {
+                        let param_to =
+                            <String as std::str::FromStr>::from_str
+                                (&param_to);
+                        match param_to {
+                            Ok(param_to) => Some(param_to),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter to - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter to")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.models_model_no_performance_get(
+                                            param_model_no,
+                                            param_pair,
+                                            param_horizon,
+                                            param_from,
+                                            param_to,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ModelsModelNoPerformanceGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_MODEL_NO_PERFORMANCE_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsModelNoPerformanceGetResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_MODEL_NO_PERFORMANCE_GET_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsModelNoPerformanceGetResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_MODEL_NO_PERFORMANCE_GET_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsModelNoPerformanceGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_MODEL_NO_PERFORMANCE_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsModelNoPerformanceGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_MODEL_NO_PERFORMANCE_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ModelsPairNoMetricsGet - GET /models/{pair}/{no}/metrics
+            &hyper::Method::GET if path.matched(paths::ID_V1_MODELS_PAIR_NO_METRICS) => {
+                // Path parameters
+                let path: &str = &uri.path().to_string();
+                let path_params =
+                    paths::REGEX_V1_MODELS_PAIR_NO_METRICS
+                    .captures(&path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_MODELS_PAIR_NO_METRICS in set but failed match against \"{}\"", path, paths::REGEX_V1_MODELS_PAIR_NO_METRICS.as_str())
+                    );
+
+                let param_pair = match percent_encoding::percent_decode(path_params["pair"].as_bytes()).decode_utf8() {
+                    Ok(param_pair) => match param_pair.parse::<String>() {
+                        Ok(param_pair) => param_pair,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter pair: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["pair"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                let param_no = match percent_encoding::percent_decode(path_params["no"].as_bytes()).decode_utf8() {
+                    Ok(param_no) => match param_no.parse::<i32>() {
+                        Ok(param_no) => param_no,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter no: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["no"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                                let result = api_impl.models_pair_no_metrics_get(
+                                            param_pair,
+                                            param_no,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ModelsPairNoMetricsGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_PAIR_NO_METRICS_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsPairNoMetricsGetResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_PAIR_NO_METRICS_GET_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsPairNoMetricsGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_PAIR_NO_METRICS_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ModelsPairNoMetricsGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for MODELS_PAIR_NO_METRICS_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // EvaluationsAccuracyGet - GET /evaluations/accuracy
+            &hyper::Method::GET if path.matched(paths::ID_V1_EVALUATIONS_ACCURACY) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_pair = query_params.iter().filter(|e| e.0 == "pair").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_pair = match param_pair {
+                    Some(param_pair) => {
+                        let param_pair =
+                            <String as std::str::FromStr>::from_str
+                                (&param_pair);
+                        match param_pair {
+                            Ok(param_pair) => Some(param_pair),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pair - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pair")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_model_no = query_params.iter().filter(|e| e.0 == "modelNo").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_model_no = match param_model_no {
+                    Some(param_model_no) => {
+                        let param_model_no =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_model_no);
+                        match param_model_no {
+                            Ok(param_model_no) => Some(param_model_no),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter modelNo - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter modelNo")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_horizon = query_params.iter().filter(|e| e.0 == "horizon").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_horizon = match param_horizon {
+                    Some(param_horizon) => {
+                        let param_horizon =
+                            <models::Horizon as std::str::FromStr>::from_str
+                                (&param_horizon);
+                        match param_horizon {
+                            Ok(param_horizon) => Some(param_horizon),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter horizon - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter horizon")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_bucket = query_params.iter().filter(|e| e.0 == "bucket").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_bucket = match param_bucket {
+                    Some(param_bucket) => {
+                        let param_bucket =
+                            <String as std::str::FromStr>::from_str
+                                (&param_bucket);
+                        match param_bucket {
+                            Ok(param_bucket) => Some(param_bucket),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter bucket - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter bucket")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_from = query_params.iter().filter(|e| e.0 == "from").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_from = match param_from {
+                    Some(param_from) => {
+                        let param_from =
+                            <String as std::str::FromStr>::from_str
+                                (&param_from);
+                        match param_from {
+                            Ok(param_from) => Some(param_from),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter from - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter from")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_to = query_params.iter().filter(|e| e.0 == "to").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_to = match param_to {
+                    Some(param_to) => {
+                        let param_to =
+                            <String as std::str::FromStr>::from_str
+                                (&param_to);
+                        match param_to {
+                            Ok(param_to) => Some(param_to),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter to - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter to")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.evaluations_accuracy_get(
+                                            param_pair,
+                                            param_model_no,
+                                            param_horizon,
+                                            param_bucket,
+                                            param_from,
+                                            param_to,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                EvaluationsAccuracyGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for EVALUATIONS_ACCURACY_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                EvaluationsAccuracyGetResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for EVALUATIONS_ACCURACY_GET_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                EvaluationsAccuracyGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for EVALUATIONS_ACCURACY_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                EvaluationsAccuracyGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for EVALUATIONS_ACCURACY_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ForecastErrorsGet - GET /forecast/errors
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_ERRORS) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_pair = query_params.iter().filter(|e| e.0 == "pair").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_pair = match param_pair {
+                    Some(param_pair) => {
+                        let param_pair =
+                            <String as std::str::FromStr>::from_str
+                                (&param_pair);
+                        match param_pair {
+                            Ok(param_pair) => Some(param_pair),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pair - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pair")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_pair = match param_pair {
+                    Some(param_pair) => param_pair,
+                    None => return Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Missing required query parameter pair"))
+                        .expect("Unable to create Bad Request response for missing query parameter pair")),
+                };
+                let param_limit = query_params.iter().filter(|e| e.0 == "limit").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_limit = match param_limit {
+                    Some(param_limit) => {
+                        let param_limit =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_limit);
+                        match param_limit {
+                            Ok(param_limit) => Some(param_limit),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_offset = query_params.iter().filter(|e| e.0 == "offset").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_offset = match param_offset {
+                    Some(param_offset) => {
+                        let param_offset =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_offset);
+                        match param_offset {
+                            Ok(param_offset) => Some(param_offset),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.forecast_errors_get(
+                                            param_pair,
+                                            param_limit,
+                                            param_offset,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ForecastErrorsGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_ERRORS_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastErrorsGetResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_ERRORS_GET_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastErrorsGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_ERRORS_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastErrorsGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_ERRORS_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ForecastHorizonRateIdModelNoGet - GET /forecast/{horizon}/{rateId}/{modelNo}
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO) => {
+                // Path parameters
+                let path: &str = &uri.path().to_string();
+                let path_params =
+                    paths::REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO
+                    .captures(&path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_FORECAST_HORIZON_RATEID_MODELNO in set but failed match against \"{}\"", path, paths::REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO.as_str())
+                    );
+
+                let param_horizon = match percent_encoding::percent_decode(path_params["horizon"].as_bytes()).decode_utf8() {
+                    Ok(param_horizon) => match param_horizon.parse::<models::Horizon>() {
+                        Ok(param_horizon) => param_horizon,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter horizon: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["horizon"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                let param_rate_id = match percent_encoding::percent_decode(path_params["rateId"].as_bytes()).decode_utf8() {
+                    Ok(param_rate_id) => match param_rate_id.parse::<String>() {
+                        Ok(param_rate_id) => param_rate_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter rateId: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["rateId"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                let param_model_no = match percent_encoding::percent_decode(path_params["modelNo"].as_bytes()).decode_utf8() {
+                    Ok(param_model_no) => match param_model_no.parse::<i32>() {
+                        Ok(param_model_no) => param_model_no,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter modelNo: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["modelNo"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_pair = query_params.iter().filter(|e| e.0 == "pair").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_pair = match param_pair {
+                    Some(param_pair) => {
+                        let param_pair =
+                            <String as std::str::FromStr>::from_str
+                                (&param_pair);
+                        match param_pair {
+                            Ok(param_pair) => Some(param_pair),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pair - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pair")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.forecast_horizon_rate_id_model_no_get(
+                                            param_horizon,
+                                            param_rate_id,
+                                            param_model_no,
+                                            param_pair,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ForecastHorizonRateIdModelNoGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoGetResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_GET_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ForecastHorizonRateIdModelNoExplanationGet - GET /forecast/{horizon}/{rateId}/{modelNo}/explanation
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION) => {
+                // Path parameters
+                let path: &str = &uri.path().to_string();
+                let path_params =
+                    paths::REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION
+                    .captures(&path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION in set but failed match against \"{}\"", path, paths::REGEX_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION.as_str())
+                    );
+
+                let param_horizon = match percent_encoding::percent_decode(path_params["horizon"].as_bytes()).decode_utf8() {
+                    Ok(param_horizon) => match param_horizon.parse::<models::Horizon>() {
+                        Ok(param_horizon) => param_horizon,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter horizon: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["horizon"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                let param_rate_id = match percent_encoding::percent_decode(path_params["rateId"].as_bytes()).decode_utf8() {
+                    Ok(param_rate_id) => match param_rate_id.parse::<String>() {
+                        Ok(param_rate_id) => param_rate_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter rateId: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["rateId"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                let param_model_no = match percent_encoding::percent_decode(path_params["modelNo"].as_bytes()).decode_utf8() {
+                    Ok(param_model_no) => match param_model_no.parse::<i32>() {
+                        Ok(param_model_no) => param_model_no,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter modelNo: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["modelNo"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_pair = query_params.iter().filter(|e| e.0 == "pair").map(|e| e.1.to_owned())
+                    .nth(0);
+                let param_pair = match param_pair {
+                    Some(param_pair) => {
+                        let param_pair =
+                            <String as std::str::FromStr>::from_str
+                                (&param_pair);
+                        match param_pair {
+                            Ok(param_pair) => Some(param_pair),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pair - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pair")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.forecast_horizon_rate_id_model_no_explanation_get(
+                                            param_horizon,
+                                            param_rate_id,
+                                            param_model_no,
+                                            param_pair,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ForecastHorizonRateIdModelNoExplanationGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_EXPLANATION_GET_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoExplanationGetResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_EXPLANATION_GET_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoExplanationGetResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_EXPLANATION_GET_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastHorizonRateIdModelNoExplanationGetResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_HORIZON_RATE_ID_MODEL_NO_EXPLANATION_GET_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ForecastNowPost - POST /forecast/now
+            &hyper::Method::POST if path.matched(paths::ID_V1_FORECAST_NOW) => {
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_forecast_now_request: Option<models::ForecastNowRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_forecast_now_request) => param_forecast_now_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter ForecastNowRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter ForecastNowRequest due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_forecast_now_request = match param_forecast_now_request {
+                                    Some(param_forecast_now_request) => param_forecast_now_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter ForecastNowRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter ForecastNowRequest")),
+                                };
+
+                                let result = api_impl.forecast_now_post(
+                                            param_forecast_now_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ForecastNowPostResponse::Status200
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_NOW_POST_STATUS200"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastNowPostResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_NOW_POST_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastNowPostResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_NOW_POST_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastNowPostResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_NOW_POST_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastNowPostResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECAST_NOW_POST_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter ForecastNowRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter ForecastNowRequest")),
+                        }
+            },
+
+            // ForecastsBatchPost - POST /forecasts/batch
+            &hyper::Method::POST if path.matched(paths::ID_V1_FORECASTS_BATCH) => {
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_history: Option<Vec<models::History>> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_history) => param_history,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter History - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter History due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_history = match param_history {
+                                    Some(param_history) => param_history,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter History"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter History")),
+                                };
+
+                                let result = api_impl.forecasts_batch_post(
+                                            param_history,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ForecastsBatchPostResponse::Status201
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS201"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastsBatchPostResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastsBatchPostResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastsBatchPostResponse::Status409
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS409"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastsBatchPostResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ForecastsBatchPostResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for FORECASTS_BATCH_POST_STATUS503"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter History: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter History")),
+                        }
+            },
+
+            // RatesPost - POST /rates
+            &hyper::Method::POST if path.matched(paths::ID_V1_RATES) => {
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_history: Option<models::History> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_history) => param_history,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter History - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter History due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_history = match param_history {
+                                    Some(param_history) => param_history,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter History"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter History")),
+                                };
+
+                                let result = api_impl.rates_post(
+                                            param_history,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().to_string().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                RatesPostResponse::Status201
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS201"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                RatesPostResponse::Status400
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS400"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                RatesPostResponse::Status404
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS404"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                RatesPostResponse::Status409
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS409"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                RatesPostResponse::Status500
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 500 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS500"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                RatesPostResponse::Status503
+                                                    {
+                                                        body,
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    if let Some(retry_after) = retry_after {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("retry-after"),
+                                                        retry_after
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for RATES_POST_STATUS503"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
@@ -357,8 +2162,18 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                         }
             },
 
-            _ if path.matched(paths::ID_FORECAST_AFTER30MIN_RATEID_MODELNO) => method_not_allowed(),
-            _ if path.matched(paths::ID_RATES) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_ADMIN_TRAINING) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_ADMIN_TRAINING_RUNID) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_EVALUATIONS_ACCURACY) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_FORECAST_ERRORS) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_FORECAST_NOW) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_FORECASTS_BATCH) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_MODELS_MODELNO_PERFORMANCE) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_MODELS_PAIR_NO_METRICS) => method_not_allowed(),
+            _ if path.matched(paths::ID_V1_RATES) => method_not_allowed(),
             _ => Ok(Response::builder().status(StatusCode::NOT_FOUND)
                     .body(Body::empty())
                     .expect("Unable to create Not Found response"))
@@ -372,10 +2187,30 @@ impl<T> RequestParser<T> for ApiRequestParser {
     fn parse_operation_id(request: &Request<T>) -> Option<&'static str> {
         let path = paths::GLOBAL_REGEX_SET.matches(request.uri().path());
         match request.method() {
-            // ForecastAfter30minRateIdModelNoGet - GET /forecast/after30min/{rateId}/{modelNo}
-            &hyper::Method::GET if path.matched(paths::ID_FORECAST_AFTER30MIN_RATEID_MODELNO) => Some("ForecastAfter30minRateIdModelNoGet"),
+            // AdminModelsPairFromNoPromoteToNoPost - POST /admin/models/{pair}/{fromNo}/promote/{toNo}
+            &hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_MODELS_PAIR_FROMNO_PROMOTE_TONO) => Some("AdminModelsPairFromNoPromoteToNoPost"),
+            // AdminTrainingPost - POST /admin/training
+            &hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_TRAINING) => Some("AdminTrainingPost"),
+            // AdminTrainingRunIdGet - GET /admin/training/{runId}
+            &hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_TRAINING_RUNID) => Some("AdminTrainingRunIdGet"),
+            // ModelsModelNoPerformanceGet - GET /models/{modelNo}/performance
+            &hyper::Method::GET if path.matched(paths::ID_V1_MODELS_MODELNO_PERFORMANCE) => Some("ModelsModelNoPerformanceGet"),
+            // ModelsPairNoMetricsGet - GET /models/{pair}/{no}/metrics
+            &hyper::Method::GET if path.matched(paths::ID_V1_MODELS_PAIR_NO_METRICS) => Some("ModelsPairNoMetricsGet"),
+            // EvaluationsAccuracyGet - GET /evaluations/accuracy
+            &hyper::Method::GET if path.matched(paths::ID_V1_EVALUATIONS_ACCURACY) => Some("EvaluationsAccuracyGet"),
+            // ForecastErrorsGet - GET /forecast/errors
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_ERRORS) => Some("ForecastErrorsGet"),
+            // ForecastHorizonRateIdModelNoGet - GET /forecast/{horizon}/{rateId}/{modelNo}
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO) => Some("ForecastHorizonRateIdModelNoGet"),
+            // ForecastHorizonRateIdModelNoExplanationGet - GET /forecast/{horizon}/{rateId}/{modelNo}/explanation
+            &hyper::Method::GET if path.matched(paths::ID_V1_FORECAST_HORIZON_RATEID_MODELNO_EXPLANATION) => Some("ForecastHorizonRateIdModelNoExplanationGet"),
+            // ForecastNowPost - POST /forecast/now
+            &hyper::Method::POST if path.matched(paths::ID_V1_FORECAST_NOW) => Some("ForecastNowPost"),
+            // ForecastsBatchPost - POST /forecasts/batch
+            &hyper::Method::POST if path.matched(paths::ID_V1_FORECASTS_BATCH) => Some("ForecastsBatchPost"),
             // RatesPost - POST /rates
-            &hyper::Method::POST if path.matched(paths::ID_RATES) => Some("RatesPost"),
+            &hyper::Method::POST if path.matched(paths::ID_V1_RATES) => Some("RatesPost"),
             _ => None,
         }
     }
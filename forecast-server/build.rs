@@ -0,0 +1,7 @@
+// gRPC のコードを proto から生成する
+// ビルド環境に protoc が無くてもビルドできるよう、同梱の protoc を使う
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::compile_protos("proto/forecast.proto")?;
+    Ok(())
+}
@@ -1,15 +1,81 @@
+use common_lib::{config::Validator, error::MyResult};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 pub struct Config {
-    pub server_host: String,
-    pub server_port: i32,
     pub rate_expire_hour: i64,
+    // レート履歴の有効期限としてリクエストで指定可能な最大値（分）
+    #[serde(default = "default_rate_expire_max_minutes")]
+    pub rate_expire_max_minutes: i64,
+    // 同じレート履歴の重複登録を拒否する期間（分）、0の場合は拒否しない
+    #[serde(default)]
+    pub rate_duplicate_window_minutes: i64,
+    // まとめて登録できるレート履歴の最大件数
+    #[serde(default = "default_rate_batch_max_size")]
+    pub rate_batch_max_size: usize,
+    // 管理用APIのキー（未設定または空文字の場合は管理用APIを利用不可）
+    #[serde(default)]
+    pub admin_api_key: Option<String>,
+    // バージョン無しの旧パスを受け付けるか（非推奨、既存のクライアントのため未設定の場合は受け付ける）
+    #[serde(default = "default_legacy_path_enabled")]
+    pub legacy_path_enabled: bool,
+    // 1分あたりに受け付けるクライアントごとのリクエスト数、0の場合は制限しない
+    // （runtime_settings の rate_limit_per_minute が設定されている場合はそちらを優先する）
+    #[serde(default)]
+    pub rate_limit_per_minute: u32,
+
+    // gRPC API のポート、0の場合は公開しない
+    #[serde(default)]
+    pub grpc_port: u16,
+    // gRPC の StreamForecasts で予想の完了を確認する間隔（ミリ秒）と、打ち切るまでの秒数
+    #[serde(default = "default_grpc_stream_poll_interval_ms")]
+    pub grpc_stream_poll_interval_ms: u64,
+    #[serde(default = "default_grpc_stream_timeout_seconds")]
+    pub grpc_stream_timeout_seconds: u64,
+}
+
+fn default_rate_expire_max_minutes() -> i64 {
+    720
+}
+
+fn default_legacy_path_enabled() -> bool {
+    true
+}
+
+fn default_rate_batch_max_size() -> usize {
+    100
+}
+
+fn default_grpc_stream_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_grpc_stream_timeout_seconds() -> u64 {
+    300
 }
 
 impl Config {
-    pub fn get_address(&self) -> String {
-        format!("{}:{}", self.server_host, self.server_port)
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("rate_expire_hour", self.rate_expire_hour);
+        v.check_positive("rate_expire_max_minutes", self.rate_expire_max_minutes);
+        v.check(
+            self.rate_duplicate_window_minutes >= 0,
+            format!(
+                "rate_duplicate_window_minutes must not be negative, value: {}",
+                self.rate_duplicate_window_minutes
+            ),
+        );
+        v.check_positive("rate_batch_max_size", self.rate_batch_max_size);
+        v.check_positive(
+            "grpc_stream_poll_interval_ms",
+            self.grpc_stream_poll_interval_ms,
+        );
+        v.check_positive(
+            "grpc_stream_timeout_seconds",
+            self.grpc_stream_timeout_seconds,
+        );
+        v.finish()
     }
 }
 
@@ -18,12 +84,44 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_for_get_address() {
-        let config = Config {
-            server_host: "127.0.0.1".to_string(),
-            server_port: 8888,
+    fn test_for_validate() {
+        let mut config = Config {
             rate_expire_hour: 12,
+            rate_expire_max_minutes: 720,
+            rate_duplicate_window_minutes: 5,
+            rate_batch_max_size: 100,
+            admin_api_key: None,
+            legacy_path_enabled: false,
+            rate_limit_per_minute: 600,
+            grpc_port: 0,
+            grpc_stream_poll_interval_ms: 1000,
+            grpc_stream_timeout_seconds: 300,
         };
-        assert_eq!(config.get_address(), "127.0.0.1:8888".to_string());
+        assert!(config.validate().is_ok());
+
+        config.rate_expire_max_minutes = 0;
+        config.rate_expire_hour = 0;
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("rate_expire_max_minutes"));
+        assert!(err.contains("rate_expire_hour"));
+    }
+
+    #[test]
+    fn test_for_default() {
+        // 既存の設定に無い項目は、追加前と同じ動作になる値を使う
+        let config: Config = common_lib::config::load_from(&[("RATE_EXPIRE_HOUR", "12")]).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.admin_api_key, None);
+        assert_eq!(config.rate_expire_max_minutes, 720);
+        assert_eq!(config.rate_duplicate_window_minutes, 0);
+        assert_eq!(config.rate_limit_per_minute, 0);
+        assert!(config.legacy_path_enabled);
+
+        let config: Config = common_lib::config::load_from(&[
+            ("RATE_EXPIRE_HOUR", "12"),
+            ("LEGACY_PATH_ENABLED", "false"),
+        ])
+        .unwrap();
+        assert!(!config.legacy_path_enabled);
     }
 }
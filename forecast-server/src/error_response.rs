@@ -0,0 +1,68 @@
+use common_lib::server::error_response::ErrorResponse;
+use forecast_server_lib::{
+    models, AdminModelsPairFromNoPromoteToNoPostResponse, AdminTrainingPostResponse,
+    AdminTrainingRunIdGetResponse, EvaluationsAccuracyGetResponse, ForecastErrorsGetResponse,
+    ForecastHorizonRateIdModelNoExplanationGetResponse, ForecastHorizonRateIdModelNoGetResponse,
+    ForecastNowPostResponse, ForecastsBatchPostResponse, ModelsModelNoPerformanceGetResponse,
+    ModelsPairNoMetricsGetResponse, RatesPostResponse,
+};
+
+// 共通のエラーレスポンスを各APIのレスポンスに変換する
+// （ErrorResponse もレスポンスの型もこのクレートの外で定義されているため、From は実装できない）
+pub trait IntoResponse<R> {
+    fn into_response(self) -> R;
+}
+
+// APIに定義されていないステータスは500として返す
+macro_rules! impl_into_response {
+    ($response:ident { $($status:literal => $variant:ident),* }) => {
+        impl IntoResponse<$response> for ErrorResponse {
+            fn into_response(self) -> $response {
+                let retry_after = self.retry_after;
+                let body = models::Error { message: self.message };
+                match self.status.as_u16() {
+                    503 => $response::Status503 { body, retry_after },
+                    $($status => $response::$variant(body),)*
+                    _ => $response::Status500(body),
+                }
+            }
+        }
+    };
+}
+
+impl_into_response!(AdminModelsPairFromNoPromoteToNoPostResponse {
+    400 => Status400,
+    401 => Status401,
+    404 => Status404
+});
+impl_into_response!(AdminTrainingPostResponse {
+    400 => Status400,
+    401 => Status401
+});
+impl_into_response!(AdminTrainingRunIdGetResponse {
+    401 => Status401,
+    404 => Status404
+});
+impl_into_response!(EvaluationsAccuracyGetResponse { 400 => Status400 });
+impl_into_response!(ForecastErrorsGetResponse { 400 => Status400 });
+impl_into_response!(ForecastHorizonRateIdModelNoGetResponse { 404 => Status404 });
+impl_into_response!(ForecastHorizonRateIdModelNoExplanationGetResponse { 404 => Status404 });
+impl_into_response!(ForecastNowPostResponse {
+    400 => Status400,
+    404 => Status404
+});
+impl_into_response!(ForecastsBatchPostResponse {
+    400 => Status400,
+    404 => Status404,
+    409 => Status409
+});
+impl_into_response!(ModelsModelNoPerformanceGetResponse {
+    400 => Status400,
+    404 => Status404
+});
+impl_into_response!(ModelsPairNoMetricsGetResponse { 404 => Status404 });
+impl_into_response!(RatesPostResponse {
+    400 => Status400,
+    404 => Status404,
+    409 => Status409
+});
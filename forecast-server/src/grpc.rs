@@ -0,0 +1,429 @@
+use std::{net::SocketAddr, pin::Pin, time};
+
+use forecast_server_lib::{
+    models, Api, ForecastHorizonRateIdModelNoGetResponse, RatesPostResponse,
+};
+use log::{error, info, warn};
+use swagger::{Has, XSpanIdString};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+use tonic::{Request, Response, Status};
+
+use crate::server::Server;
+
+pub mod proto {
+    tonic::include_proto!("binoption.forecast.v1");
+}
+
+use proto::forecast_service_server::{ForecastService, ForecastServiceServer};
+
+// x-span-id メタデータが無い場合は REST API と同様に生成する
+const X_SPAN_ID: &str = "x-span-id";
+
+// REST API の処理（Server の Api 実装）に渡すコンテキスト
+#[derive(Clone, Debug)]
+struct GrpcContext {
+    x_span_id: XSpanIdString,
+}
+
+impl GrpcContext {
+    fn from_request<T>(request: &Request<T>) -> Self {
+        let x_span_id = request
+            .metadata()
+            .get(X_SPAN_ID)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| XSpanIdString(v.to_string()))
+            .unwrap_or_default();
+        GrpcContext { x_span_id }
+    }
+}
+
+impl Has<XSpanIdString> for GrpcContext {
+    fn get(&self) -> &XSpanIdString {
+        &self.x_span_id
+    }
+
+    fn get_mut(&mut self) -> &mut XSpanIdString {
+        &mut self.x_span_id
+    }
+
+    fn set(&mut self, value: XSpanIdString) {
+        self.x_span_id = value;
+    }
+}
+
+#[derive(Clone)]
+pub struct GrpcServer {
+    server: Server,
+    // StreamForecasts で予想の完了を確認する間隔と、打ち切るまでの時間
+    stream_poll_interval: time::Duration,
+    stream_timeout: time::Duration,
+}
+
+impl GrpcServer {
+    pub fn new(
+        server: Server,
+        stream_poll_interval: time::Duration,
+        stream_timeout: time::Duration,
+    ) -> Self {
+        GrpcServer {
+            server,
+            stream_poll_interval,
+            stream_timeout,
+        }
+    }
+
+    async fn forecast_result(
+        &self,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
+        context: &GrpcContext,
+    ) -> Result<proto::ForecastResult, Status> {
+        let response = Api::<GrpcContext>::forecast_horizon_rate_id_model_no_get(
+            &self.server,
+            horizon,
+            rate_id,
+            model_no,
+            pair,
+            context,
+        )
+        .await
+        .map_err(|err| Status::internal(err.to_string()))?;
+
+        match response {
+            ForecastHorizonRateIdModelNoGetResponse::Status200(body) => match body.result {
+                // 予想に失敗した場合は従来どおりエラーとして返す（ストリームも終了する）
+                Some(models::ForecastResult { error: Some(e), .. }) => {
+                    Err(Status::internal(format!(
+                        "{}, {}, rate_id: {}, model_no: {}",
+                        e.summary, e.detail, e.rate_id, e.model_no
+                    )))
+                }
+                Some(r) => Ok(proto::ForecastResult {
+                    model_no,
+                    pair: r.pair,
+                    complete: r.complete,
+                    rate: r.rate,
+                    rmse: r.rmse,
+                    path: r.path.unwrap_or_default(),
+                    quantiles: r.quantiles.map(|q| proto::ForecastQuantiles {
+                        p10: q.p10,
+                        p50: q.p50,
+                        p90: q.p90,
+                    }),
+                }),
+                None => Err(Status::internal("forecast result is empty")),
+            },
+            ForecastHorizonRateIdModelNoGetResponse::Status404(e) => {
+                Err(Status::not_found(e.message))
+            }
+            ForecastHorizonRateIdModelNoGetResponse::Status500(e) => {
+                Err(Status::internal(e.message))
+            }
+            ForecastHorizonRateIdModelNoGetResponse::Status503 { body, .. } => {
+                Err(Status::unavailable(body.message))
+            }
+        }
+    }
+}
+
+fn to_horizon(value: i32) -> Result<models::Horizon, Status> {
+    match proto::Horizon::from_i32(value) {
+        Some(proto::Horizon::After30min) => Ok(models::Horizon::AFTER30MIN),
+        Some(proto::Horizon::After5min) => Ok(models::Horizon::AFTER5MIN),
+        None => Err(Status::invalid_argument(format!(
+            "parameter is invalid, unknown horizon: {}",
+            value
+        ))),
+    }
+}
+
+#[tonic::async_trait]
+impl ForecastService for GrpcServer {
+    async fn register_rates(
+        &self,
+        request: Request<proto::RegisterRatesRequest>,
+    ) -> Result<Response<proto::RegisterRatesResponse>, Status> {
+        let context = GrpcContext::from_request(&request);
+        let request = request.into_inner();
+        let mut history = models::History::new(request.pair, request.rate_histories);
+        history.expire_minutes = request.expire_minutes;
+
+        let response = Api::<GrpcContext>::rates_post(&self.server, history, &context)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        match response {
+            RatesPostResponse::Status201(body) => Ok(Response::new(proto::RegisterRatesResponse {
+                rate_id: body.rate_id,
+                expire: body.expire,
+            })),
+            RatesPostResponse::Status400(e) => Err(Status::invalid_argument(e.message)),
+            RatesPostResponse::Status404(e) => Err(Status::not_found(e.message)),
+            RatesPostResponse::Status409(e) => Err(Status::already_exists(e.message)),
+            RatesPostResponse::Status500(e) => Err(Status::internal(e.message)),
+            RatesPostResponse::Status503 { body, .. } => Err(Status::unavailable(body.message)),
+        }
+    }
+
+    async fn get_forecast(
+        &self,
+        request: Request<proto::GetForecastRequest>,
+    ) -> Result<Response<proto::GetForecastResponse>, Status> {
+        let context = GrpcContext::from_request(&request);
+        let request = request.into_inner();
+        let horizon = to_horizon(request.horizon)?;
+
+        let result = self
+            .forecast_result(
+                horizon,
+                request.rate_id,
+                request.model_no,
+                request.pair,
+                &context,
+            )
+            .await?;
+        Ok(Response::new(proto::GetForecastResponse {
+            result: Some(result),
+        }))
+    }
+
+    type StreamForecastsStream =
+        Pin<Box<dyn Stream<Item = Result<proto::ForecastResult, Status>> + Send + 'static>>;
+
+    async fn stream_forecasts(
+        &self,
+        request: Request<proto::StreamForecastsRequest>,
+    ) -> Result<Response<Self::StreamForecastsStream>, Status> {
+        let context = GrpcContext::from_request(&request);
+        let request = request.into_inner();
+        let horizon = to_horizon(request.horizon)?;
+        if request.model_nos.is_empty() {
+            return Err(Status::invalid_argument(
+                "parameter is invalid, model_nos is empty.",
+            ));
+        }
+
+        let (sender, receiver) = mpsc::channel(request.model_nos.len());
+        let grpc = self.clone();
+        tokio::spawn(async move {
+            let deadline = tokio::time::Instant::now() + grpc.stream_timeout;
+            let mut pending = request.model_nos.clone();
+            pending.sort_unstable();
+            pending.dedup();
+            while !pending.is_empty() {
+                let mut waiting = vec![];
+                for model_no in pending {
+                    let result = grpc
+                        .forecast_result(
+                            horizon,
+                            request.rate_id.clone(),
+                            model_no,
+                            request.pair.clone(),
+                            &context,
+                        )
+                        .await;
+                    match result {
+                        Ok(r) if !r.complete => waiting.push(model_no),
+                        // 予想済みの結果とエラーはそのまま返す（クライアントが切断した場合は終了する）
+                        r => {
+                            let failed = r.is_err();
+                            if sender.send(r).await.is_err() || failed {
+                                return;
+                            }
+                        }
+                    }
+                }
+                pending = waiting;
+                if pending.is_empty() {
+                    break;
+                }
+
+                if tokio::time::Instant::now() + grpc.stream_poll_interval > deadline {
+                    warn!(
+                        "stream forecasts timed out, rate_id: {}, pending: {:?}, X-Span-ID: {:?}",
+                        request.rate_id, pending, context.x_span_id
+                    );
+                    let _ = sender
+                        .send(Err(Status::deadline_exceeded(format!(
+                            "forecast is not completed, model_nos: {:?}",
+                            pending
+                        ))))
+                        .await;
+                    return;
+                }
+                tokio::time::sleep(grpc.stream_poll_interval).await;
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(receiver))))
+    }
+}
+
+pub async fn serve(grpc: GrpcServer, addr: SocketAddr) {
+    info!("start gRPC server {}", addr);
+    if let Err(err) = tonic::transport::Server::builder()
+        .add_service(ForecastServiceServer::new(grpc))
+        .serve(addr)
+        .await
+    {
+        error!("failed to serve gRPC, error: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::{
+        clock::SystemClock,
+        config,
+        test_support::{shared_settings, unconnected_db_client, TEST_PAIR},
+    };
+    use tokio::net::TcpListener;
+    use tokio_stream::{wrappers::TcpListenerStream, StreamExt};
+    use tonic::{transport::Channel, Code};
+
+    use super::*;
+    use proto::forecast_service_client::ForecastServiceClient;
+
+    // DBに接続できない状態のサーバーを起動し、接続したクライアントを返す
+    async fn client() -> ForecastServiceClient<Channel> {
+        let config: crate::config::Config =
+            config::load_from(&[("RATE_EXPIRE_HOUR", "12")]).unwrap();
+        let server = Server::new(
+            unconnected_db_client(),
+            &config,
+            shared_settings(&[TEST_PAIR]),
+            SystemClock::shared(),
+        );
+        let grpc = GrpcServer::new(
+            server,
+            time::Duration::from_millis(10),
+            time::Duration::from_secs(1),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(ForecastServiceServer::new(grpc))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        ForecastServiceClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_register_rates() {
+        let mut client = client().await;
+
+        struct Param {
+            title: String,
+            request: proto::RegisterRatesRequest,
+            expected: Code,
+        }
+        let params = vec![
+            Param {
+                title: "レート履歴が空の場合は INVALID_ARGUMENT".to_string(),
+                request: proto::RegisterRatesRequest {
+                    pair: TEST_PAIR.to_string(),
+                    rate_histories: vec![],
+                    expire_minutes: None,
+                },
+                expected: Code::InvalidArgument,
+            },
+            Param {
+                title: "有効期限が範囲外の場合は INVALID_ARGUMENT".to_string(),
+                request: proto::RegisterRatesRequest {
+                    pair: TEST_PAIR.to_string(),
+                    rate_histories: vec![100.0, 100.1],
+                    expire_minutes: Some(0),
+                },
+                expected: Code::InvalidArgument,
+            },
+            Param {
+                title: "取り扱っていない通貨ペアの場合は NOT_FOUND".to_string(),
+                request: proto::RegisterRatesRequest {
+                    pair: "EURJPY".to_string(),
+                    rate_histories: vec![100.0, 100.1],
+                    expire_minutes: None,
+                },
+                expected: Code::NotFound,
+            },
+            Param {
+                title: "DBに接続できない場合は UNAVAILABLE".to_string(),
+                request: proto::RegisterRatesRequest {
+                    pair: TEST_PAIR.to_string(),
+                    rate_histories: vec![100.0, 100.1],
+                    expire_minutes: Some(30),
+                },
+                expected: Code::Unavailable,
+            },
+        ];
+
+        for p in params {
+            let status = client.register_rates(p.request).await.unwrap_err();
+            assert_eq!(status.code(), p.expected, "{}, {:?}", p.title, status);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_get_forecast() {
+        let mut client = client().await;
+
+        let status = client
+            .get_forecast(proto::GetForecastRequest {
+                rate_id: "rate-id".to_string(),
+                model_no: 1,
+                horizon: 99,
+                pair: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument, "{:?}", status);
+
+        let status = client
+            .get_forecast(proto::GetForecastRequest {
+                rate_id: "rate-id".to_string(),
+                model_no: 1,
+                horizon: proto::Horizon::After5min as i32,
+                pair: Some(TEST_PAIR.to_string()),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable, "{:?}", status);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_stream_forecasts() {
+        let mut client = client().await;
+
+        let status = client
+            .stream_forecasts(proto::StreamForecastsRequest {
+                rate_id: "rate-id".to_string(),
+                model_nos: vec![],
+                horizon: proto::Horizon::After30min as i32,
+                pair: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument, "{:?}", status);
+
+        // エラーを1件返してストリームを終了する
+        let mut stream = client
+            .stream_forecasts(proto::StreamForecastsRequest {
+                rate_id: "rate-id".to_string(),
+                model_nos: vec![2, 1, 2],
+                horizon: proto::Horizon::After30min as i32,
+                pair: None,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        let status = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable, "{:?}", status);
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -1,22 +1,49 @@
 extern crate common_lib;
 extern crate forecast_server_lib;
 
-use common_lib::mysql;
+use common_lib::{
+    config::HttpConfig, error_report, feature, forecast, logger, mysql, settings, telemetry,
+};
 use log::{error, info};
 
 mod config;
+mod error_response;
+mod grpc;
 mod server;
 
 fn init_logger() {
-    env_logger::init();
+    logger::init("forecast-server");
 }
 
 #[tokio::main]
 async fn main() {
     init_logger();
 
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init("forecast-server") {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init("forecast-server") {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
     let config: config::Config;
-    match envy::from_env::<config::Config>() {
+    match common_lib::config::load::<config::Config>() {
         Ok(c) => {
             config = c;
         }
@@ -25,9 +52,28 @@ async fn main() {
             return;
         }
     }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let http_config: HttpConfig;
+    match common_lib::config::load::<HttpConfig>() {
+        Ok(c) => {
+            http_config = c;
+        }
+        Err(err) => {
+            error!("failed to load http config, error: {}", err);
+            return;
+        }
+    }
+    if let Err(err) = http_config.validate() {
+        error!("invalid http config, error: {}", err);
+        return;
+    }
 
     let mysql_cli: mysql::client::DefaultClient;
-    match mysql::util::make_cli() {
+    match mysql::util::make_server_cli() {
         Ok(cli) => {
             mysql_cli = cli;
         }
@@ -37,7 +83,54 @@ async fn main() {
         }
     }
 
-    let addr = config.get_address();
-    info!("start ForecastServer {}", addr);
-    server::run(&addr, mysql_cli, &config).await;
+    let settings_reload_interval_seconds: u64;
+    match settings::reload_interval_from_env() {
+        Ok(v) => {
+            settings_reload_interval_seconds = v;
+        }
+        Err(err) => {
+            error!("failed to load settings config, error: {}", err);
+            return;
+        }
+    }
+    let runtime_settings = settings::SharedSettings::new();
+    if let Err(err) = runtime_settings.reload(&mysql_cli) {
+        error!("failed to load runtime settings, error: {}", err);
+        return;
+    }
+
+    let features: feature::FeatureFlags;
+    match feature::FeatureFlags::from_env(runtime_settings.clone()) {
+        Ok(f) => {
+            features = f;
+        }
+        Err(err) => {
+            error!("failed to load feature flags, error: {}", err);
+            return;
+        }
+    }
+    info!("feature flags: {}", features);
+
+    let forecaster: Option<forecast::Forecaster>;
+    match forecast::on_ingest_from_env() {
+        Ok(f) => {
+            forecaster = f;
+        }
+        Err(err) => {
+            error!("failed to load forecast config, error: {}", err);
+            return;
+        }
+    }
+    info!("forecast on ingest: {}", forecaster.is_some());
+
+    info!("start ForecastServer {}", http_config.get_address());
+    server::run(
+        &http_config,
+        mysql_cli,
+        &config,
+        runtime_settings,
+        settings_reload_interval_seconds,
+        forecaster,
+    )
+    .await;
 }
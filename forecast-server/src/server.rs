@@ -1,50 +1,368 @@
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use std::{collections::HashMap, time};
+
+use chrono::{Duration, NaiveDateTime};
 use common_lib::{
-    domain::model::{ForecastError, ForecastModel, ForecastResult, RateForForecast},
-    mysql::{self, client::Client},
+    clock::{SharedClock, SystemClock},
+    config::HttpConfig,
+    domain::{
+        accuracy::{AccuracyBucket, AccuracySummary, ForecastAccuracyStat},
+        model::{
+            ForecastError, ForecastModel, ForecastResult, ModelPerformance, RateForForecast,
+            TrainingRun, FORECAST_TYPE_AFTER_30MIN, FORECAST_TYPE_AFTER_5MIN,
+            TRAINING_RUN_STATUS_FAILED, TRAINING_RUN_STATUS_REQUESTED, TRAINING_RUN_STATUS_RUNNING,
+            TRAINING_RUN_STATUS_SUCCEEDED,
+        },
+        service::convert_to_feature,
+    },
+    error::MyResult,
+    forecast::{self, Forecaster},
+    mysql::{
+        self,
+        client::{Client, Transaction},
+    },
+    notify::{self, NotifyEvent},
+    server::{
+        admin,
+        body_limit::BodyLimitLayer,
+        error_response::ErrorResponse,
+        health::HealthLayer,
+        layer::MakeLayer,
+        legacy_path::LegacyPathLayer,
+        listener,
+        metrics::MetricsLayer,
+        openapi::OpenApiLayer,
+        rate_limit::{RateLimitLayer, RateLimiter},
+        trace_context::TraceContextLayer,
+    },
+    settings::{RuntimeSettings, SharedSettings},
 };
 use forecast_server_lib::{
-    models::{self, RatesPost201Response},
+    models::{self, ForecastsBatchPost201Response, RatesPost201Response},
     server::MakeService,
-    Api, ForecastAfter30minRateIdModelNoGetResponse, RatesPostResponse,
+    AdminModelsPairFromNoPromoteToNoPostResponse, AdminTrainingPostResponse,
+    AdminTrainingRunIdGetResponse, Api, EvaluationsAccuracyGetResponse, ForecastErrorsGetResponse,
+    ForecastHorizonRateIdModelNoExplanationGetResponse, ForecastHorizonRateIdModelNoGetResponse,
+    ForecastNowPostResponse, ForecastsBatchPostResponse, ModelsModelNoPerformanceGetResponse,
+    ModelsPairNoMetricsGetResponse, RatesPostResponse,
 };
-use log::{info, warn};
+use log::{error, info, warn};
 use swagger::{auth::MakeAllowAllAuthenticator, ApiError, EmptyContext, Has, XSpanIdString};
 
-use crate::config;
+use crate::{config, error_response::IntoResponse, grpc};
+
+// GET /openapi.json で公開するAPIの仕様（生成元の仕様をビルド時に埋め込む）
+const OPENAPI_SPEC: &str = include_str!("../../forecast-server-lib/api/openapi.yaml");
+
+// 予想エラー一覧の取得件数（デフォルト/最大）
+const FORECAST_ERRORS_LIMIT_DEFAULT: i32 = 100;
+const FORECAST_ERRORS_LIMIT_MAX: i32 = 1000;
+
+// 予測精度の推移の集計開始日時を省略した場合に遡る日数
+const ACCURACY_DEFAULT_DAYS: i64 = 7;
+
+pub async fn run(
+    http_config: &HttpConfig,
+    mysql_cli: mysql::client::DefaultClient,
+    config: &config::Config,
+    settings: SharedSettings,
+    settings_reload_interval_seconds: u64,
+    forecaster: Option<Forecaster>,
+) {
+    let default_rate_limit = config.rate_limit_per_minute;
+    let limiter = RateLimiter::new(
+        settings
+            .get()
+            .rate_limit_per_minute
+            .unwrap_or(default_rate_limit),
+        time::Duration::from_secs(60),
+    );
+    {
+        let limiter = limiter.clone();
+        if let Err(err) = settings.watch(
+            mysql_cli.clone(),
+            settings_reload_interval_seconds,
+            move |s| {
+                limiter.set_max_requests(s.rate_limit_per_minute.unwrap_or(default_rate_limit))
+            },
+        ) {
+            error!("failed to watch runtime settings, error: {}", err);
+            return;
+        }
+    }
 
-pub async fn run(addr: &str, mysql_cli: mysql::client::DefaultClient, config: &config::Config) {
-    let addr = addr.parse().expect("Failed to parse bind address");
+    let server = Server::new(mysql_cli.clone(), config, settings, SystemClock::shared())
+        .with_forecaster(forecaster);
 
-    let server = Server::new(mysql_cli, config);
+    // 内部向けのgRPC APIはREST APIと同じ処理で応答する
+    if config.grpc_port > 0 {
+        match format!("{}:{}", http_config.server_host, config.grpc_port).parse() {
+            Ok(addr) => {
+                let grpc = grpc::GrpcServer::new(
+                    server.clone(),
+                    time::Duration::from_millis(config.grpc_stream_poll_interval_ms),
+                    time::Duration::from_secs(config.grpc_stream_timeout_seconds),
+                );
+                tokio::spawn(grpc::serve(grpc, addr));
+            }
+            Err(err) => {
+                error!("invalid gRPC address, error: {}", err);
+                return;
+            }
+        }
+    }
 
     let service = MakeService::new(server);
 
+    let service = MakeLayer::new(
+        service,
+        LegacyPathLayer::new(forecast_server_lib::BASE_PATH, config.legacy_path_enabled),
+    );
+
+    let service = MakeLayer::new(service, BodyLimitLayer::new(http_config.max_body_bytes));
+
+    let service = MakeLayer::new(service, TraceContextLayer);
+
+    let service = MakeLayer::new(service, RateLimitLayer::with_limiter(limiter));
+
+    let service = MakeLayer::new(
+        service,
+        HealthLayer::new(mysql_cli, env!("CARGO_PKG_VERSION")),
+    );
+
+    let service = match OpenApiLayer::new(OPENAPI_SPEC) {
+        Ok(layer) => MakeLayer::new(service, layer),
+        Err(err) => {
+            error!("failed to load openapi spec, error: {}", err);
+            return;
+        }
+    };
+
+    let service = MakeLayer::new(service, MetricsLayer::new());
+
     let service = MakeAllowAllAuthenticator::new(service, "cosmo");
 
     let service =
         forecast_server_lib::server::context::MakeAddContext::<_, EmptyContext>::new(service);
 
-    hyper::server::Server::bind(&addr)
-        .serve(service)
-        .await
-        .unwrap()
+    if let Err(err) = listener::serve(http_config, service).await {
+        error!("failed to serve, error: {}", err);
+    }
 }
 
 #[derive(Clone)]
 pub struct Server {
     mysql_cli: mysql::client::DefaultClient,
     rate_expire_hour: i64,
+    rate_expire_max_minutes: i64,
+    rate_duplicate_window_minutes: i64,
+    rate_batch_max_size: usize,
+    admin_api_key: Option<String>,
+    settings: SharedSettings,
+    clock: SharedClock,
+    // 登録直後に予想する場合のみ設定する
+    forecaster: Option<Forecaster>,
 }
 
 impl Server {
-    pub fn new(mysql_cli: mysql::client::DefaultClient, config: &config::Config) -> Self {
+    pub fn new(
+        mysql_cli: mysql::client::DefaultClient,
+        config: &config::Config,
+        settings: SharedSettings,
+        clock: SharedClock,
+    ) -> Self {
         Server {
             mysql_cli: mysql_cli,
             rate_expire_hour: config.rate_expire_hour,
+            rate_expire_max_minutes: config.rate_expire_max_minutes,
+            rate_duplicate_window_minutes: config.rate_duplicate_window_minutes,
+            rate_batch_max_size: config.rate_batch_max_size,
+            admin_api_key: config.admin_api_key.clone(),
+            settings,
+            clock,
+            forecaster: None,
+        }
+    }
+
+    pub fn with_forecaster(mut self, forecaster: Option<Forecaster>) -> Self {
+        self.forecaster = forecaster;
+        self
+    }
+
+    // 登録した予想用レートを、応答を待たせないようバックグラウンドで予想する
+    // 失敗しても forecast-batch が後から予想するため、ログの出力のみ行う
+    fn forecast_in_background(&self, rate_id: &str, pair: &str) {
+        let forecaster = match &self.forecaster {
+            Some(f) => f.clone(),
+            None => return,
+        };
+        let mysql_cli = self.mysql_cli.clone();
+        let settings = self.settings.clone();
+        let rate_id = rate_id.to_string();
+        let pair = pair.to_string();
+        tokio::task::spawn_blocking(move || {
+            match forecaster.forecast_rate(&mysql_cli, &settings.get(), &rate_id) {
+                Ok(outcome) => forecast::observe(&pair, &outcome),
+                Err(err) => {
+                    warn!(
+                        "failed to forecast on ingest, rate_id: {}, error: {}",
+                        rate_id, err
+                    );
+                }
+            }
+        });
+    }
+
+    // DB処理はブロッキングするため、tokioのワーカーを占有しないようにして実行する
+    fn with_transaction<F, T>(&self, f: F) -> MyResult<T>
+    where
+        F: FnMut(&mut Transaction) -> MyResult<T>,
+    {
+        tokio::task::block_in_place(|| self.mysql_cli.with_transaction(f))
+    }
+
+    // レート履歴の有効期限（指定された分数が範囲外の場合は不備の内容を返す）
+    fn expire_of(&self, history: &models::History) -> Result<NaiveDateTime, String> {
+        let duration = match history.expire_minutes {
+            Some(minutes) => {
+                if minutes <= 0 || minutes > self.rate_expire_max_minutes {
+                    return Err(format!(
+                        "expire_minutes must be between 1 and {}. expire_minutes: {}",
+                        self.rate_expire_max_minutes, minutes
+                    ));
+                }
+                Duration::minutes(minutes)
+            }
+            None => Duration::hours(self.rate_expire_hour),
+        };
+        Ok((self.clock.now() + duration).naive_utc())
+    }
+
+    // 予測モデルが受け付ける入力サイズ
+    // MSEが上限を超えるモデルは予想に使わないため対象外とする
+    fn supported_sizes(
+        &self,
+        tx: &mut Transaction,
+        settings: &RuntimeSettings,
+        pair: &str,
+    ) -> MyResult<Vec<usize>> {
+        let mut sizes = self
+            .mysql_cli
+            .select_forecast_models(tx, pair)?
+            .iter()
+            .filter(|m| settings.is_model_allowed(m.get_performance_mse()))
+            .map(|m| m.get_input_data_size())
+            .collect::<MyResult<Vec<usize>>>()?;
+        sizes.sort();
+        sizes.dedup();
+        Ok(sizes)
+    }
+}
+
+// 予想用レートを登録せずに予想する（予想値の推移は予想しない）
+fn forecast_now(model: &ForecastModel, histories: &Vec<f64>) -> MyResult<models::ForecastResult> {
+    let features = convert_to_feature(histories, &model.get_feature_params()?)?;
+    let rate = model.predict(&features)?;
+    let quantiles = model.predict_quantiles(&features)?;
+    Ok(models::ForecastResult {
+        pair: model.get_pair()?,
+        complete: true,
+        rate: Some(rate),
+        rmse: Some(model.get_performance_rmse()),
+        path: None,
+        quantiles: quantiles.map(|q| models::ForecastQuantiles::new(q.p10, q.p50, q.p90)),
+        error: None,
+    })
+}
+
+fn to_forecast_type(horizon: &models::Horizon) -> i32 {
+    match horizon {
+        models::Horizon::AFTER5MIN => FORECAST_TYPE_AFTER_5MIN,
+        models::Horizon::AFTER30MIN => FORECAST_TYPE_AFTER_30MIN,
+    }
+}
+
+fn to_horizon(forecast_type: i32) -> Option<models::Horizon> {
+    match forecast_type {
+        FORECAST_TYPE_AFTER_5MIN => Some(models::Horizon::AFTER5MIN),
+        FORECAST_TYPE_AFTER_30MIN => Some(models::Horizon::AFTER30MIN),
+        _ => None,
+    }
+}
+
+// 通貨ペア・モデル・予測種別ごとにまとめる（集計結果はこの順に並んでいること）
+fn to_accuracy_series(
+    stats: &[ForecastAccuracyStat],
+    bucket: AccuracyBucket,
+) -> Vec<models::AccuracySeries> {
+    let mut groups: Vec<&[ForecastAccuracyStat]> = vec![];
+    let mut start = 0;
+    for i in 1..=stats.len() {
+        if i == stats.len()
+            || stats[i].pair != stats[start].pair
+            || stats[i].model_no != stats[start].model_no
+            || stats[i].forecast_type != stats[start].forecast_type
+        {
+            groups.push(&stats[start..i]);
+            start = i;
         }
     }
+
+    groups
+        .into_iter()
+        .filter_map(|group| {
+            let first = &group[0];
+            let horizon = to_horizon(first.forecast_type)?;
+            let summary = AccuracySummary::summarize(group)?;
+            Some(models::AccuracySeries {
+                pair: first.pair.clone(),
+                model_no: first.model_no,
+                horizon,
+                bucket: bucket.as_str().to_string(),
+                summary: models::AccuracySummary {
+                    count: summary.count,
+                    mae: summary.mae,
+                    mse: summary.mse,
+                    rmse: summary.rmse,
+                    bias: summary.bias,
+                    best_mae: summary.best_mae,
+                    worst_mae: summary.worst_mae,
+                },
+                points: group
+                    .iter()
+                    .map(|p| models::AccuracyPoint {
+                        time: p.bucket_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                        count: p.count,
+                        mae: p.mae,
+                        mse: p.mse,
+                        rmse: p.rmse,
+                        bias: p.bias,
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn to_training_run_model(run: &TrainingRun) -> models::TrainingRun {
+    let status = match run.status {
+        TRAINING_RUN_STATUS_RUNNING => models::TrainingRunStatus::RUNNING,
+        TRAINING_RUN_STATUS_SUCCEEDED => models::TrainingRunStatus::SUCCEEDED,
+        TRAINING_RUN_STATUS_FAILED => models::TrainingRunStatus::FAILED,
+        _ => models::TrainingRunStatus::REQUESTED,
+    };
+    let format = |t: &NaiveDateTime| t.format("%Y-%m-%d %H:%M:%S").to_string();
+
+    models::TrainingRun {
+        id: run.id.clone(),
+        pair: run.pair.clone(),
+        status,
+        memo: run.memo.clone(),
+        result: run.result.clone(),
+        requested_at: format(&run.created_at),
+        started_at: run.started_at.as_ref().map(format),
+        finished_at: run.finished_at.as_ref().map(format),
+    }
 }
 
 #[async_trait]
@@ -52,33 +370,332 @@ impl<C> Api<C> for Server
 where
     C: Has<XSpanIdString> + Send + Sync,
 {
-    /// 30分後の予想を取得します
-    async fn forecast_after30min_rate_id_model_no_get(
+    /// 予測モデルを別のモデルNoへ昇格（コピー）します
+    async fn admin_models_pair_from_no_promote_to_no_post(
+        &self,
+        pair: String,
+        from_no: i32,
+        to_no: i32,
+        x_api_key: Option<String>,
+        context: &C,
+    ) -> Result<AdminModelsPairFromNoPromoteToNoPostResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "admin_models_pair_from_no_promote_to_no_post(\"{}\", {}, {}) - X-Span-ID: {:?}",
+            pair,
+            from_no,
+            to_no,
+            context.get().0.clone()
+        );
+
+        if !admin::is_admin(self.admin_api_key.as_deref(), x_api_key.as_deref()) {
+            return Ok(AdminModelsPairFromNoPromoteToNoPostResponse::Status401(
+                models::Error {
+                    message: "unauthorized".to_string(),
+                },
+            ));
+        }
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            let error = models::Error {
+                message: format!("unsupported pair, pair: {}", pair),
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(AdminModelsPairFromNoPromoteToNoPostResponse::Status404(
+                error,
+            ));
+        }
+
+        if from_no == to_no {
+            return Ok(AdminModelsPairFromNoPromoteToNoPostResponse::Status400(
+                models::Error {
+                    message: format!(
+                        "parameter is invalid, fromNo and toNo are same. model_no: {}",
+                        from_no
+                    ),
+                },
+            ));
+        }
+
+        let mut model: Option<ForecastModel> = None;
+        match self.with_transaction(|tx| {
+            if self
+                .mysql_cli
+                .select_forecast_model(tx, &pair, from_no)?
+                .is_none()
+            {
+                return Ok(());
+            }
+
+            self.mysql_cli
+                .copy_forecast_model(tx, &pair, from_no, to_no)?;
+            model = self.mysql_cli.select_forecast_model(tx, &pair, to_no)?;
+            Ok(())
+        }) {
+            Ok(_) => {
+                if let Some(m) = model {
+                    info!(
+                        "model promoted, pair: {}, from_no: {}, to_no: {}, X-Span-ID: {:?}",
+                        pair,
+                        from_no,
+                        to_no,
+                        context.get().0.clone()
+                    );
+                    let message = format!(
+                        "pair: {}, from_no: {}, to_no: {}, mse: {}, rmse: {}",
+                        pair,
+                        from_no,
+                        to_no,
+                        m.get_performance_mse(),
+                        m.get_performance_rmse()
+                    );
+                    tokio::task::spawn_blocking(move || {
+                        notify::notify_from_env(NotifyEvent::ModelPromoted, &message)
+                    });
+                    Ok(AdminModelsPairFromNoPromoteToNoPostResponse::Status200(
+                        models::ModelInfo {
+                            pair: pair.clone(),
+                            model_no: to_no,
+                            performance_mse: m.get_performance_mse(),
+                            performance_rmse: m.get_performance_rmse(),
+                        },
+                    ))
+                } else {
+                    Ok(AdminModelsPairFromNoPromoteToNoPostResponse::Status404(
+                        models::Error {
+                            message: format!(
+                                "model is not found, pair: {}, model_no: {}",
+                                pair, from_no
+                            ),
+                        },
+                    ))
+                }
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 学習の実行を要求します
+    async fn admin_training_post(
+        &self,
+        training_request: models::TrainingRequest,
+        x_api_key: Option<String>,
+        context: &C,
+    ) -> Result<AdminTrainingPostResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "admin_training_post({:?}) - X-Span-ID: {:?}",
+            training_request,
+            context.get().0.clone()
+        );
+
+        if !admin::is_admin(self.admin_api_key.as_deref(), x_api_key.as_deref()) {
+            return Ok(AdminTrainingPostResponse::Status401(models::Error {
+                message: "unauthorized".to_string(),
+            }));
+        }
+
+        if training_request.pair.is_empty() {
+            return Ok(AdminTrainingPostResponse::Status400(models::Error {
+                message: "parameter is invalid, pair is empty.".to_string(),
+            }));
+        }
+        if !self
+            .settings
+            .get()
+            .is_pair_supported(&training_request.pair)
+        {
+            return Ok(AdminTrainingPostResponse::Status400(models::Error {
+                message: format!(
+                    "parameter is invalid, unsupported pair. pair: {}",
+                    training_request.pair
+                ),
+            }));
+        }
+
+        let mut run: Option<TrainingRun> = None;
+        match self.with_transaction(|tx| {
+            let record = TrainingRun::new(
+                training_request.pair.clone(),
+                TRAINING_RUN_STATUS_REQUESTED,
+                training_request
+                    .memo
+                    .clone()
+                    .unwrap_or_else(|| "requested by forecast-server".to_string()),
+            )?;
+
+            let id = self.mysql_cli.insert_training_run(tx, &record)?;
+            run = self.mysql_cli.select_training_run_by_id(tx, &id)?;
+            Ok(())
+        }) {
+            Ok(_) => Ok(AdminTrainingPostResponse::Status202(to_training_run_model(
+                &run.unwrap(),
+            ))),
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 学習の実行状況を取得します
+    async fn admin_training_run_id_get(
+        &self,
+        run_id: String,
+        x_api_key: Option<String>,
+        context: &C,
+    ) -> Result<AdminTrainingRunIdGetResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "admin_training_run_id_get(\"{}\") - X-Span-ID: {:?}",
+            run_id,
+            context.get().0.clone()
+        );
+
+        if !admin::is_admin(self.admin_api_key.as_deref(), x_api_key.as_deref()) {
+            return Ok(AdminTrainingRunIdGetResponse::Status401(models::Error {
+                message: "unauthorized".to_string(),
+            }));
+        }
+
+        let mut run: Option<TrainingRun> = None;
+        match self.with_transaction(|tx| {
+            run = self.mysql_cli.select_training_run_by_id(tx, &run_id)?;
+            Ok(())
+        }) {
+            Ok(_) => {
+                if let Some(run) = run {
+                    Ok(AdminTrainingRunIdGetResponse::Status200(
+                        to_training_run_model(&run),
+                    ))
+                } else {
+                    Ok(AdminTrainingRunIdGetResponse::Status404(models::Error {
+                        message: format!("training run is not found, run_id: {}", run_id),
+                    }))
+                }
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 予想に失敗した記録を取得します
+    async fn forecast_errors_get(
+        &self,
+        pair: String,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C,
+    ) -> Result<ForecastErrorsGetResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "forecast_errors_get(\"{}\", {:?}, {:?}) - X-Span-ID: {:?}",
+            pair,
+            limit,
+            offset,
+            context.get().0.clone()
+        );
+
+        let limit = limit.unwrap_or(FORECAST_ERRORS_LIMIT_DEFAULT);
+        let offset = offset.unwrap_or(0);
+        if !(1..=FORECAST_ERRORS_LIMIT_MAX).contains(&limit) || offset < 0 {
+            let error = models::Error {
+                message: format!(
+                    "limit must be between 1 and {}, and offset must not be negative, limit: {}, offset: {}",
+                    FORECAST_ERRORS_LIMIT_MAX, limit, offset
+                ),
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(ForecastErrorsGetResponse::Status400(error));
+        }
+
+        let mut records: Vec<ForecastError> = vec![];
+        match self.with_transaction(|tx| {
+            records =
+                self.mysql_cli
+                    .select_forecast_errors(tx, &pair, limit as u32, offset as u32)?;
+            Ok(())
+        }) {
+            Ok(_) => Ok(ForecastErrorsGetResponse::Status200(
+                models::ForecastErrorsGet200Response {
+                    errors: records
+                        .iter()
+                        .map(|e| models::ForecastErrorInfo {
+                            id: e.id.clone(),
+                            rate_id: e.rate_id.clone(),
+                            model_no: e.model_no,
+                            summary: e.summary.clone(),
+                            detail: e.detail.clone(),
+                        })
+                        .collect(),
+                },
+            )),
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 指定時間後の予想を取得します
+    async fn forecast_horizon_rate_id_model_no_get(
         &self,
+        horizon: models::Horizon,
         rate_id: String,
         model_no: i32,
+        pair: Option<String>,
         context: &C,
-    ) -> Result<ForecastAfter30minRateIdModelNoGetResponse, ApiError> {
+    ) -> Result<ForecastHorizonRateIdModelNoGetResponse, ApiError> {
         let context = context.clone();
         info!(
-            "forecast_after30min_rate_id_model_no_get(\"{}\", {}) - X-Span-ID: {:?}",
+            "forecast_horizon_rate_id_model_no_get({:?}, \"{}\", {}, {:?}) - X-Span-ID: {:?}",
+            horizon,
             rate_id,
             model_no,
+            pair,
             context.get().0.clone()
         );
 
+        let forecast_type = to_forecast_type(&horizon);
+
         let mut rate: Option<RateForForecast> = None;
         let mut model: Option<ForecastModel> = None;
         let mut forecast: Option<ForecastResult> = None;
         let mut error: Option<ForecastError> = None;
-        match self.mysql_cli.with_transaction(|tx| {
-            error = self
-                .mysql_cli
-                .select_forecast_errors_by_rate_id_and_model_no(tx, &rate_id, model_no)?;
-            if error.is_some() {
-                return Ok(());
-            }
-
+        match self.with_transaction(|tx| {
             rate = self
                 .mysql_cli
                 .select_rates_for_forecast_by_id(tx, &rate_id)?;
@@ -86,26 +703,43 @@ where
                 return Ok(());
             }
 
-            let pair = rate.clone().unwrap().pair;
+            let rate_pair = rate.clone().unwrap().pair;
+            // 他の通貨ペアのレート履歴は参照させない
+            if pair.is_some() && pair.as_ref() != Some(&rate_pair) {
+                return Ok(());
+            }
 
-            model = self.mysql_cli.select_forecast_model(tx, &pair, model_no)?;
+            model = self
+                .mysql_cli
+                .select_forecast_model(tx, &rate_pair, model_no)?;
             if model.is_none() {
                 return Ok(());
             }
 
+            // 予想の失敗は予測時間ごとに記録しているため、他の予測時間の失敗は返さない
+            error = self
+                .mysql_cli
+                .select_forecast_errors_by_rate_id_and_model_no(
+                    tx,
+                    &rate_id,
+                    model_no,
+                    forecast_type,
+                )?;
+            if error.is_some() {
+                return Ok(());
+            }
+
             forecast = self
                 .mysql_cli
-                .select_forecast_results_by_rate_id_and_model_no(tx, &rate_id, model_no)?;
+                .select_forecast_results_by_rate_id_and_model_no(
+                    tx,
+                    &rate_id,
+                    model_no,
+                    forecast_type,
+                )?;
             Ok(())
         }) {
             Ok(_) => {
-                if let Some(e) = error {
-                    let e = models::Error {
-                        message: format!("internal server error, {}", e),
-                    };
-                    warn!("error: {:?}, X-Span-ID: {:?}", e, context.get().0.clone());
-                    return Ok(ForecastAfter30minRateIdModelNoGetResponse::Status500(e));
-                }
                 if rate.is_none() {
                     let error = models::Error {
                         message: format!("rate is not found, rate_id: {}", rate_id),
@@ -116,7 +750,24 @@ where
                         context.get().0.clone()
                     );
 
-                    return Ok(ForecastAfter30minRateIdModelNoGetResponse::Status404(error));
+                    return Ok(ForecastHorizonRateIdModelNoGetResponse::Status404(error));
+                }
+
+                let rate_pair = rate.unwrap().pair;
+                if let Some(pair) = pair.filter(|p| p != &rate_pair) {
+                    let error = models::Error {
+                        message: format!(
+                            "pair is not matched, rate_id: {}, pair: {}",
+                            rate_id, pair
+                        ),
+                    };
+                    warn!(
+                        "error: {:?}, X-Span-ID: {:?}",
+                        error,
+                        context.get().0.clone()
+                    );
+
+                    return Ok(ForecastHorizonRateIdModelNoGetResponse::Status404(error));
                 }
 
                 if model.is_none() {
@@ -129,20 +780,44 @@ where
                         context.get().0.clone()
                     );
 
-                    return Ok(ForecastAfter30minRateIdModelNoGetResponse::Status404(error));
+                    return Ok(ForecastHorizonRateIdModelNoGetResponse::Status404(error));
                 }
 
                 let result = if let Some(forecast) = forecast {
                     models::ForecastResult {
+                        pair: rate_pair,
                         complete: true,
                         rate: Some(forecast.result),
                         rmse: Some(model.unwrap().get_performance_rmse()),
+                        path: forecast.path,
+                        quantiles: forecast
+                            .quantiles
+                            .map(|q| models::ForecastQuantiles::new(q.p10, q.p50, q.p90)),
+                        error: None,
                     }
                 } else {
+                    // 予想に失敗した場合は完了することがないため、失敗の内容を返して待つのをやめさせる
+                    if let Some(e) = &error {
+                        warn!(
+                            "forecast failed: {}, X-Span-ID: {:?}",
+                            e,
+                            context.get().0.clone()
+                        );
+                    }
                     models::ForecastResult {
+                        pair: rate_pair,
                         complete: false,
                         rate: None,
                         rmse: Some(model.unwrap().get_performance_rmse()),
+                        path: None,
+                        quantiles: None,
+                        error: error.map(|e| models::ForecastErrorInfo {
+                            id: e.id,
+                            rate_id: e.rate_id,
+                            model_no: e.model_no,
+                            summary: e.summary,
+                            detail: e.detail,
+                        }),
                     }
                 };
                 info!(
@@ -151,65 +826,918 @@ where
                     context.get().0.clone()
                 );
 
-                Ok(ForecastAfter30minRateIdModelNoGetResponse::Status200(
-                    models::ForecastAfter30minRateIdModelNoGet200Response {
+                Ok(ForecastHorizonRateIdModelNoGetResponse::Status200(
+                    models::ForecastHorizonRateIdModelNoGet200Response {
                         result: Some(result),
                     },
                 ))
             }
             Err(err) => {
-                let error = models::Error {
-                    message: format!("internal server error, {}", err),
-                };
+                let error = ErrorResponse::from_error_with_tags(
+                    &err,
+                    &[("model_no", &model_no.to_string()), ("rate_id", &rate_id)],
+                );
                 warn!(
                     "error: {:?}, X-Span-ID: {:?}",
                     error,
                     context.get().0.clone()
                 );
-                Ok(ForecastAfter30minRateIdModelNoGetResponse::Status500(error))
+                Ok(error.into_response())
             }
         }
     }
 
-    /// レート履歴を新規登録します
-    async fn rates_post(
+    /// 指定時間後の予想の根拠（特徴量ごとの寄与）を取得します
+    async fn forecast_horizon_rate_id_model_no_explanation_get(
         &self,
-        history: models::History,
+        horizon: models::Horizon,
+        rate_id: String,
+        model_no: i32,
+        pair: Option<String>,
         context: &C,
-    ) -> Result<RatesPostResponse, ApiError> {
+    ) -> Result<ForecastHorizonRateIdModelNoExplanationGetResponse, ApiError> {
         let context = context.clone();
         info!(
-            "rates_post({:?}) - X-Span-ID: {:?}",
-            history,
+            "forecast_horizon_rate_id_model_no_explanation_get({:?}, \"{}\", {}, {:?}) - X-Span-ID: {:?}",
+            horizon,
+            rate_id,
+            model_no,
+            pair,
             context.get().0.clone()
         );
 
-        if history.rate_histories.is_empty() {
-            return Ok(RatesPostResponse::Status400(models::Error {
-                message: "parameter is invalid, rate_histories is empty.".to_string(),
-            }));
-        }
+        let forecast_type = to_forecast_type(&horizon);
+        let not_found = |message: String| {
+            let error = models::Error { message };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            Ok(ForecastHorizonRateIdModelNoExplanationGetResponse::Status404(error))
+        };
 
-        let expire = (Utc::now() + Duration::hours(self.rate_expire_hour)).naive_utc();
-        let mut id: Option<String> = None;
-        match self.mysql_cli.with_transaction(|tx| {
-            let rate = RateForForecast::new(
-                history.pair.clone(),
-                history.rate_histories.clone(),
-                expire.clone(),
-                "inserted by forecast-server".to_string(),
-            )?;
+        let (rate, explanation) = match self.with_transaction(|tx| {
+            let rate = self
+                .mysql_cli
+                .select_rates_for_forecast_by_id(tx, &rate_id)?;
+            // 他の通貨ペアのレート履歴の予想は参照させない
+            let rate = rate.filter(|r| pair.is_none() || pair.as_ref() == Some(&r.pair));
+            if rate.is_none() {
+                return Ok((None, None));
+            }
 
-            id = Some(self.mysql_cli.insert_rates_for_forecast(tx, &rate)?);
-            Ok(())
+            let explanation = self.mysql_cli.select_forecast_explanation(
+                tx,
+                &rate_id,
+                model_no,
+                forecast_type,
+            )?;
+            Ok((rate, explanation))
         }) {
-            Ok(_) => Ok(RatesPostResponse::Status201(RatesPost201Response {
-                rate_id: id.unwrap(),
-                expire: expire.format("%Y-%m-%d %H:%M:%S").to_string(),
-            })),
-            Err(err) => Ok(RatesPostResponse::Status500(models::Error {
-                message: format!("internal server error, {}", err),
-            })),
-        }
+            Ok(v) => v,
+            Err(err) => {
+                let error = ErrorResponse::from_error_with_tags(
+                    &err,
+                    &[("model_no", &model_no.to_string()), ("rate_id", &rate_id)],
+                );
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                return Ok(error.into_response());
+            }
+        };
+
+        let rate = match rate {
+            Some(r) => r,
+            None => {
+                return not_found(format!(
+                    "rate is not found, rate_id: {}, pair: {:?}",
+                    rate_id, pair
+                ))
+            }
+        };
+        let explanation = match explanation {
+            Some(e) => e.explanation,
+            None => {
+                return not_found(format!(
+                    "explanation is not found, rate_id: {}, model_no: {}",
+                    rate_id, model_no
+                ))
+            }
+        };
+
+        let result = models::ForecastExplanation {
+            pair: rate.pair,
+            rate_id,
+            model_no,
+            horizon,
+            method: explanation.method,
+            base_value: explanation.base_value,
+            contributions: explanation
+                .contributions
+                .into_iter()
+                .map(|c| models::FeatureContribution::new(c.name, c.value, c.contribution))
+                .collect(),
+        };
+        info!(
+            "result: {:?}, X-Span-ID: {:?}",
+            result,
+            context.get().0.clone()
+        );
+        Ok(ForecastHorizonRateIdModelNoExplanationGetResponse::Status200(result))
+    }
+
+    /// 予測モデルの実績誤差の推移を取得します
+    async fn models_model_no_performance_get(
+        &self,
+        model_no: i32,
+        pair: String,
+        horizon: models::Horizon,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C,
+    ) -> Result<ModelsModelNoPerformanceGetResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "models_model_no_performance_get({}, \"{}\", {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
+            model_no,
+            pair,
+            horizon,
+            from,
+            to,
+            context.get().0.clone()
+        );
+
+        let parse = |value: &Option<String>| match value {
+            Some(v) => NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S")
+                .map(Some)
+                .map_err(|err| format!("invalid datetime format, value: {}, {}", v, err)),
+            None => Ok(None),
+        };
+        let range = match (parse(&from), parse(&to)) {
+            (Ok(begin), Ok(end)) => match (begin, end) {
+                (Some(b), Some(e)) if b > e => {
+                    Err(format!("from must not be after to, from: {}, to: {}", b, e))
+                }
+                _ => Ok((begin, end)),
+            },
+            (Err(err), _) | (_, Err(err)) => Err(err),
+        };
+        let (begin, end) = match range {
+            Ok(range) => range,
+            Err(message) => {
+                let error = models::Error { message };
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                return Ok(ModelsModelNoPerformanceGetResponse::Status400(error));
+            }
+        };
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            let error = models::Error {
+                message: format!("unsupported pair, pair: {}", pair),
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(ModelsModelNoPerformanceGetResponse::Status404(error));
+        }
+
+        let forecast_type = to_forecast_type(&horizon);
+
+        let mut model: Option<ForecastModel> = None;
+        let mut performances: Vec<ModelPerformance> = vec![];
+        match self.with_transaction(|tx| {
+            model = self.mysql_cli.select_forecast_model(tx, &pair, model_no)?;
+            if model.is_none() {
+                return Ok(());
+            }
+
+            performances = self.mysql_cli.select_model_performance(
+                tx,
+                &pair,
+                model_no,
+                forecast_type,
+                begin,
+                end,
+            )?;
+            Ok(())
+        }) {
+            Ok(_) => {
+                if model.is_none() {
+                    let error = models::Error {
+                        message: format!(
+                            "model is not found, pair: {}, model_no: {}",
+                            pair, model_no
+                        ),
+                    };
+                    warn!(
+                        "error: {:?}, X-Span-ID: {:?}",
+                        error,
+                        context.get().0.clone()
+                    );
+                    return Ok(ModelsModelNoPerformanceGetResponse::Status404(error));
+                }
+
+                Ok(ModelsModelNoPerformanceGetResponse::Status200(
+                    models::ModelPerformance {
+                        pair,
+                        model_no,
+                        horizon,
+                        points: performances
+                            .iter()
+                            .map(|p| models::ModelPerformancePoint {
+                                time: p.time.format("%Y-%m-%d %H:%M:%S").to_string(),
+                                count: p.count,
+                                mae: p.mae,
+                                mse: p.mse,
+                                rmse: p.rmse,
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 予測モデルの性能と学習日時を取得します
+    async fn models_pair_no_metrics_get(
+        &self,
+        pair: String,
+        no: i32,
+        context: &C,
+    ) -> Result<ModelsPairNoMetricsGetResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "models_pair_no_metrics_get(\"{}\", {}) - X-Span-ID: {:?}",
+            pair,
+            no,
+            context.get().0.clone()
+        );
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            let error = models::Error {
+                message: format!("unsupported pair, pair: {}", pair),
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(ModelsPairNoMetricsGetResponse::Status404(error));
+        }
+
+        // 性能と学習日時のみ返すため、モデル本体は復元しない
+        match self.with_transaction(|tx| self.mysql_cli.select_forecast_model_record(tx, &pair, no))
+        {
+            Ok(Some(record)) => {
+                let result = models::ModelMetrics {
+                    pair: record.pair,
+                    model_no: record.model_no,
+                    memo: record.memo,
+                    input_data_size: record.input_data_size as i32,
+                    performance_mse: record.performance_mse,
+                    performance_rmse: record.performance_rmse,
+                    created_at: record.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    updated_at: record.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                };
+                info!(
+                    "result: {:?}, X-Span-ID: {:?}",
+                    result,
+                    context.get().0.clone()
+                );
+                Ok(ModelsPairNoMetricsGetResponse::Status200(result))
+            }
+            Ok(None) => {
+                let error = models::Error {
+                    message: format!("model is not found, pair: {}, model_no: {}", pair, no),
+                };
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(ModelsPairNoMetricsGetResponse::Status404(error))
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 予測精度の推移を集計期間ごとに取得します
+    async fn evaluations_accuracy_get(
+        &self,
+        pair: Option<String>,
+        model_no: Option<i32>,
+        horizon: Option<models::Horizon>,
+        bucket: Option<String>,
+        from: Option<String>,
+        to: Option<String>,
+        context: &C,
+    ) -> Result<EvaluationsAccuracyGetResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "evaluations_accuracy_get({:?}, {:?}, {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
+            pair,
+            model_no,
+            horizon,
+            bucket,
+            from,
+            to,
+            context.get().0.clone()
+        );
+
+        let parse = |value: &Option<String>| match value {
+            Some(v) => NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S")
+                .map(Some)
+                .map_err(|err| format!("invalid datetime format, value: {}, {}", v, err)),
+            None => Ok(None),
+        };
+        let params = match (
+            AccuracyBucket::parse(bucket.as_deref().unwrap_or("hour")),
+            parse(&from),
+            parse(&to),
+        ) {
+            (Err(err), _, _) => Err(err.to_string()),
+            (_, Err(err), _) | (_, _, Err(err)) => Err(err),
+            (Ok(bucket), Ok(begin), Ok(end)) => {
+                let begin = begin.unwrap_or_else(|| {
+                    (self.clock.now() - Duration::days(ACCURACY_DEFAULT_DAYS)).naive_utc()
+                });
+                match end {
+                    Some(e) if begin > e => Err(format!(
+                        "from must not be after to, from: {}, to: {}",
+                        begin, e
+                    )),
+                    _ => Ok((bucket, begin, end)),
+                }
+            }
+        };
+        let (bucket, begin, end) = match params {
+            Ok(params) => params,
+            Err(message) => {
+                let error = models::Error { message };
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                return Ok(EvaluationsAccuracyGetResponse::Status400(error));
+            }
+        };
+
+        let forecast_type = horizon.as_ref().map(to_forecast_type);
+        match self.with_transaction(|tx| {
+            self.mysql_cli.select_forecast_accuracy_stats(
+                tx,
+                pair.as_deref(),
+                model_no,
+                forecast_type,
+                bucket,
+                Some(begin),
+                end,
+            )
+        }) {
+            Ok(stats) => Ok(EvaluationsAccuracyGetResponse::Status200(
+                models::AccuracyReport {
+                    series: to_accuracy_series(&stats, bucket),
+                },
+            )),
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// レート履歴を登録せずにその場で予想します
+    async fn forecast_now_post(
+        &self,
+        request: models::ForecastNowRequest,
+        context: &C,
+    ) -> Result<ForecastNowPostResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "forecast_now_post({:?}) - X-Span-ID: {:?}",
+            request,
+            context.get().0.clone()
+        );
+
+        if request.rate_histories.is_empty() {
+            return Ok(ForecastNowPostResponse::Status400(models::Error {
+                message: "parameter is invalid, rate_histories is empty.".to_string(),
+            }));
+        }
+
+        let settings = self.settings.get();
+        if !settings.is_pair_supported(&request.pair) {
+            return Ok(ForecastNowPostResponse::Status404(models::Error {
+                message: format!("unsupported pair, pair: {}", request.pair),
+            }));
+        }
+        if !settings.is_pair_allowed(&request.pair) {
+            return Ok(ForecastNowPostResponse::Status400(models::Error {
+                message: format!(
+                    "parameter is invalid, pair is not allowed. pair: {}",
+                    request.pair
+                ),
+            }));
+        }
+
+        let mut model: Option<ForecastModel> = None;
+        if let Err(err) = self.with_transaction(|tx| {
+            model = self
+                .mysql_cli
+                .select_forecast_model(tx, &request.pair, request.model_no)?;
+            Ok(())
+        }) {
+            let error = ErrorResponse::from_error_with_tags(
+                &err,
+                &[("model_no", &request.model_no.to_string())],
+            );
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(error.into_response());
+        }
+
+        // MSEが上限を超えるモデルは予想に使わない
+        let model = match model.filter(|m| settings.is_model_allowed(m.get_performance_mse())) {
+            Some(m) => m,
+            None => {
+                let error = models::Error {
+                    message: format!(
+                        "model is not found, pair: {}, model_no: {}",
+                        request.pair, request.model_no
+                    ),
+                };
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                return Ok(ForecastNowPostResponse::Status404(error));
+            }
+        };
+
+        // 予想は計算量が多いため、tokioのワーカーを占有しないようにして実行する
+        match tokio::task::block_in_place(|| {
+            let input_data_size = model.get_input_data_size()?;
+            if input_data_size != request.rate_histories.len() {
+                return Ok(None);
+            }
+            forecast_now(&model, &request.rate_histories).map(Some)
+        }) {
+            Ok(Some(result)) => {
+                info!(
+                    "result: {:?}, X-Span-ID: {:?}",
+                    result,
+                    context.get().0.clone()
+                );
+                Ok(ForecastNowPostResponse::Status200(
+                    models::ForecastNowPost200Response { result },
+                ))
+            }
+            Ok(None) => {
+                let error = models::Error {
+                    message: format!(
+                        "parameter is invalid, size of rate_histories is not supported. size: {}, model_no: {}",
+                        request.rate_histories.len(),
+                        request.model_no
+                    ),
+                };
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(ForecastNowPostResponse::Status400(error))
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error_with_tags(
+                    &err,
+                    &[("model_no", &request.model_no.to_string())],
+                );
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// 複数のレート履歴をまとめて新規登録します
+    async fn forecasts_batch_post(
+        &self,
+        histories: Vec<models::History>,
+        context: &C,
+    ) -> Result<ForecastsBatchPostResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "forecasts_batch_post(count: {}) - X-Span-ID: {:?}",
+            histories.len(),
+            context.get().0.clone()
+        );
+
+        if histories.is_empty() || histories.len() > self.rate_batch_max_size {
+            return Ok(ForecastsBatchPostResponse::Status400(models::Error {
+                message: format!(
+                    "parameter is invalid, size of histories must be between 1 and {}. size: {}",
+                    self.rate_batch_max_size,
+                    histories.len()
+                ),
+            }));
+        }
+
+        // 1件でも不備があれば何も登録しない
+        let settings = self.settings.get();
+        let mut expires: Vec<NaiveDateTime> = vec![];
+        for (i, history) in histories.iter().enumerate() {
+            if history.rate_histories.is_empty() {
+                return Ok(ForecastsBatchPostResponse::Status400(models::Error {
+                    message: format!(
+                        "parameter is invalid, histories[{}].rate_histories is empty.",
+                        i
+                    ),
+                }));
+            }
+            if !settings.is_pair_supported(&history.pair) {
+                return Ok(ForecastsBatchPostResponse::Status404(models::Error {
+                    message: format!("unsupported pair, histories[{}].pair: {}", i, history.pair),
+                }));
+            }
+            if !settings.is_pair_allowed(&history.pair) {
+                return Ok(ForecastsBatchPostResponse::Status400(models::Error {
+                    message: format!(
+                        "parameter is invalid, pair is not allowed. histories[{}].pair: {}",
+                        i, history.pair
+                    ),
+                }));
+            }
+            match self.expire_of(history) {
+                Ok(expire) => expires.push(expire),
+                Err(message) => {
+                    return Ok(ForecastsBatchPostResponse::Status400(models::Error {
+                        message: format!("parameter is invalid, histories[{}].{}", i, message),
+                    }));
+                }
+            }
+        }
+
+        let mut ids: Vec<String> = vec![];
+        let mut rejected: Option<ForecastsBatchPostResponse> = None;
+        match self.with_transaction(|tx| {
+            let mut supported_sizes: HashMap<String, Vec<usize>> = HashMap::new();
+            let mut rates: Vec<RateForForecast> = vec![];
+            for (i, (history, expire)) in histories.iter().zip(&expires).enumerate() {
+                if !supported_sizes.contains_key(&history.pair) {
+                    let sizes = self.supported_sizes(tx, &settings, &history.pair)?;
+                    supported_sizes.insert(history.pair.clone(), sizes);
+                }
+                let sizes = &supported_sizes[&history.pair];
+                if !sizes.is_empty() && !sizes.contains(&history.rate_histories.len()) {
+                    rejected = Some(ForecastsBatchPostResponse::Status400(models::Error {
+                        message: format!(
+                            "parameter is invalid, size of histories[{}].rate_histories is not supported. size: {}, supported: {:?}",
+                            i,
+                            history.rate_histories.len(),
+                            sizes
+                        ),
+                    }));
+                    return Ok(());
+                }
+
+                let rate = RateForForecast::new(
+                    history.pair.clone(),
+                    history.rate_histories.clone(),
+                    *expire,
+                    "inserted by forecast-server".to_string(),
+                    Some(context.get().0.clone()),
+                )?;
+
+                // 直近に同じレート履歴が登録済み、もしくはリクエスト内で重複していれば登録しない
+                if self.rate_duplicate_window_minutes > 0 {
+                    let duplicated_id = self.mysql_cli.select_rates_for_forecast_duplicated(
+                        tx,
+                        &rate,
+                        self.rate_duplicate_window_minutes,
+                    )?;
+                    let duplicated_in_request = rates
+                        .iter()
+                        .any(|r| r.pair == rate.pair && r.histories_hash() == rate.histories_hash());
+                    if duplicated_id.is_some() || duplicated_in_request {
+                        rejected = Some(ForecastsBatchPostResponse::Status409(models::Error {
+                            message: format!(
+                                "same rate histories are already registered, histories[{}], rate_id: {}",
+                                i,
+                                duplicated_id.unwrap_or_else(|| "-".to_string())
+                            ),
+                        }));
+                        return Ok(());
+                    }
+                }
+                rates.push(rate);
+            }
+
+            ids = self.mysql_cli.insert_rates_for_forecast_batch(tx, &rates)?;
+            Ok(())
+        }) {
+            Ok(_) => {
+                if let Some(response) = rejected {
+                    warn!(
+                        "error: {:?}, X-Span-ID: {:?}",
+                        response,
+                        context.get().0.clone()
+                    );
+                    return Ok(response);
+                }
+                for (id, history) in ids.iter().zip(&histories) {
+                    self.forecast_in_background(id, &history.pair);
+                }
+                Ok(ForecastsBatchPostResponse::Status201(
+                    ForecastsBatchPost201Response { rate_ids: ids },
+                ))
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+
+    /// レート履歴を新規登録します
+    async fn rates_post(
+        &self,
+        history: models::History,
+        context: &C,
+    ) -> Result<RatesPostResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "rates_post({:?}) - X-Span-ID: {:?}",
+            history,
+            context.get().0.clone()
+        );
+
+        if history.rate_histories.is_empty() {
+            return Ok(RatesPostResponse::Status400(models::Error {
+                message: "parameter is invalid, rate_histories is empty.".to_string(),
+            }));
+        }
+
+        let settings = self.settings.get();
+        if !settings.is_pair_supported(&history.pair) {
+            return Ok(RatesPostResponse::Status404(models::Error {
+                message: format!("unsupported pair, pair: {}", history.pair),
+            }));
+        }
+        if !settings.is_pair_allowed(&history.pair) {
+            return Ok(RatesPostResponse::Status400(models::Error {
+                message: format!(
+                    "parameter is invalid, pair is not allowed. pair: {}",
+                    history.pair
+                ),
+            }));
+        }
+
+        let expire = match self.expire_of(&history) {
+            Ok(expire) => expire,
+            Err(message) => {
+                return Ok(RatesPostResponse::Status400(models::Error {
+                    message: format!("parameter is invalid, {}", message),
+                }));
+            }
+        };
+        let mut id: Option<String> = None;
+        let mut supported_sizes: Vec<usize> = vec![];
+        let mut duplicated_id: Option<String> = None;
+        match self.with_transaction(|tx| {
+            // 予測モデルが受け付けない入力サイズであれば登録しない
+            supported_sizes = self.supported_sizes(tx, &settings, &history.pair)?;
+            if !supported_sizes.is_empty()
+                && !supported_sizes.contains(&history.rate_histories.len())
+            {
+                return Ok(());
+            }
+
+            let rate = RateForForecast::new(
+                history.pair.clone(),
+                history.rate_histories.clone(),
+                expire.clone(),
+                "inserted by forecast-server".to_string(),
+                Some(context.get().0.clone()),
+            )?;
+
+            // 直近に同じレート履歴が登録済みであれば登録しない
+            if self.rate_duplicate_window_minutes > 0 {
+                duplicated_id = self.mysql_cli.select_rates_for_forecast_duplicated(
+                    tx,
+                    &rate,
+                    self.rate_duplicate_window_minutes,
+                )?;
+                if duplicated_id.is_some() {
+                    return Ok(());
+                }
+            }
+
+            id = Some(self.mysql_cli.insert_rates_for_forecast(tx, &rate)?);
+            Ok(())
+        }) {
+            Ok(_) => {
+                if let Some(duplicated_id) = duplicated_id {
+                    let error = models::Error {
+                        message: format!(
+                            "same rate histories are already registered, rate_id: {}",
+                            duplicated_id
+                        ),
+                    };
+                    warn!(
+                        "error: {:?}, X-Span-ID: {:?}",
+                        error,
+                        context.get().0.clone()
+                    );
+                    return Ok(RatesPostResponse::Status409(error));
+                }
+                if let Some(id) = id {
+                    self.forecast_in_background(&id, &history.pair);
+                    Ok(RatesPostResponse::Status201(RatesPost201Response {
+                        rate_id: id,
+                        expire: expire.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    }))
+                } else {
+                    let error = models::Error {
+                        message: format!(
+                            "parameter is invalid, size of rate_histories is not supported. size: {}, supported: {:?}",
+                            history.rate_histories.len(),
+                            supported_sizes
+                        ),
+                    };
+                    warn!(
+                        "error: {:?}, X-Span-ID: {:?}",
+                        error,
+                        context.get().0.clone()
+                    );
+                    Ok(RatesPostResponse::Status400(error))
+                }
+            }
+            Err(err) => {
+                let error = ErrorResponse::from_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    error,
+                    context.get().0.clone()
+                );
+                Ok(error.into_response())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::{
+        config::load_from,
+        test_support::{
+            cleanup_pair, currency_pair, forecast_model, seed_currency_pairs, seed_forecast_model,
+            shared_settings, test_db_client, TEST_PAIR,
+        },
+    };
+    use swagger::{ContextBuilder, Push};
+
+    use super::*;
+
+    fn context() -> ContextBuilder<XSpanIdString, EmptyContext> {
+        EmptyContext.push(XSpanIdString::default())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "テスト用DB（DB_HOST などで指定）が必要"]
+    async fn test_for_forecast_horizon_rate_id_model_no_get_with_both_horizons() {
+        let mysql_cli = test_db_client().unwrap();
+        cleanup_pair(&mysql_cli, TEST_PAIR).unwrap();
+        seed_currency_pairs(&mysql_cli, &[currency_pair(TEST_PAIR, true)]).unwrap();
+        seed_forecast_model(&mysql_cli, &forecast_model(TEST_PAIR, 1, 3).unwrap()).unwrap();
+
+        // 5分後の予想は成功し、30分後の予想は失敗した状態にする
+        let rate_id = mysql_cli
+            .with_transaction(|tx| {
+                let rate = RateForForecast::new(
+                    TEST_PAIR.to_string(),
+                    vec![100.0, 100.1, 100.2],
+                    (chrono::Utc::now() + Duration::hours(1)).naive_utc(),
+                    "test".to_string(),
+                    None,
+                )?;
+                let rate_id = mysql_cli.insert_rates_for_forecast(tx, &rate)?;
+                mysql_cli.insert_forecast_results(
+                    tx,
+                    &vec![ForecastResult::new(
+                        rate_id.clone(),
+                        1,
+                        FORECAST_TYPE_AFTER_5MIN,
+                        100.3,
+                        "after5min".to_string(),
+                    )?],
+                )?;
+                mysql_cli.insert_forecast_errors(
+                    tx,
+                    &vec![ForecastError::new(
+                        rate_id.clone(),
+                        1,
+                        FORECAST_TYPE_AFTER_30MIN,
+                        "forecast failed".to_string(),
+                        "detail".to_string(),
+                    )?],
+                )?;
+                Ok(rate_id)
+            })
+            .unwrap();
+
+        let config: config::Config = load_from(&[("RATE_EXPIRE_HOUR", "12")]).unwrap();
+        let server = Server::new(
+            mysql_cli.clone(),
+            &config,
+            shared_settings(&[TEST_PAIR]),
+            SystemClock::shared(),
+        );
+
+        // 他の予測時間の失敗は返さない
+        match server
+            .forecast_horizon_rate_id_model_no_get(
+                models::Horizon::AFTER5MIN,
+                rate_id.clone(),
+                1,
+                None,
+                &context(),
+            )
+            .await
+            .unwrap()
+        {
+            ForecastHorizonRateIdModelNoGetResponse::Status200(body) => {
+                let result = body.result.unwrap();
+                assert!(result.complete);
+                assert_eq!(result.rate, Some(100.3));
+                assert!(result.error.is_none());
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        match server
+            .forecast_horizon_rate_id_model_no_get(
+                models::Horizon::AFTER30MIN,
+                rate_id.clone(),
+                1,
+                None,
+                &context(),
+            )
+            .await
+            .unwrap()
+        {
+            ForecastHorizonRateIdModelNoGetResponse::Status200(body) => {
+                let result = body.result.unwrap();
+                assert!(!result.complete);
+                assert_eq!(result.error.unwrap().summary, "forecast failed");
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        cleanup_pair(&mysql_cli, TEST_PAIR).unwrap();
     }
 }
@@ -0,0 +1,167 @@
+use common_lib::{
+    config::Validator,
+    domain::{model::forecast_type_from_offset_minutes, quantile::QuantileRegressionParameters},
+    error::MyResult,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // 共通設定
+    pub forecast_input_size: usize,
+    pub forecast_offset_minutes: usize,
+    // 追加学習する予測用モデルの番号
+    pub forecast_model_no: i32,
+
+    // バッチ関連
+    pub cron_schedule: String,
+
+    // 追加学習に使う期間（現在日時から何分前までのレートを使うか、評価用の期間を含む）
+    #[serde(default = "default_refresh_window_minutes")]
+    pub refresh_window_minutes: i64,
+    // 追加学習の前後で性能を比較する評価用の期間（現在日時から何分前までか）
+    #[serde(default = "default_refresh_holdout_minutes")]
+    pub refresh_holdout_minutes: i64,
+    // 評価データの必要数（これより少ない場合は追加学習しない）
+    #[serde(default = "default_refresh_holdout_required_count")]
+    pub refresh_holdout_required_count: usize,
+    // 追加学習前からのMSEの悪化を許容する割合（超えた場合は追加学習前のモデルのままにする）
+    #[serde(default = "default_refresh_max_degradation")]
+    pub refresh_max_degradation: f64,
+    // 追加学習のエポック数と学習率
+    #[serde(default = "default_refresh_epochs")]
+    pub refresh_epochs: usize,
+    #[serde(default = "default_refresh_learning_rate")]
+    pub refresh_learning_rate: f64,
+    // 直前のレートで埋める欠損の最大分数（これより長い欠損をまたぐデータは使わない）
+    #[serde(default = "default_refresh_fill_gap_minutes")]
+    pub refresh_fill_gap_minutes: usize,
+    // data-clean-batch が異常と判定したレートを使わない場合は true
+    #[serde(default = "default_refresh_exclude_anomalies")]
+    pub refresh_exclude_anomalies: bool,
+}
+
+fn default_refresh_window_minutes() -> i64 {
+    90
+}
+
+fn default_refresh_holdout_minutes() -> i64 {
+    30
+}
+
+fn default_refresh_holdout_required_count() -> usize {
+    10
+}
+
+fn default_refresh_max_degradation() -> f64 {
+    0.05
+}
+
+fn default_refresh_epochs() -> usize {
+    20
+}
+
+fn default_refresh_learning_rate() -> f64 {
+    0.05
+}
+
+fn default_refresh_fill_gap_minutes() -> usize {
+    5
+}
+
+fn default_refresh_exclude_anomalies() -> bool {
+    true
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("forecast_input_size", self.forecast_input_size);
+        if let Err(err) = forecast_type_from_offset_minutes(self.forecast_offset_minutes) {
+            v.check(
+                false,
+                format!("forecast_offset_minutes is invalid, {}", err),
+            );
+        }
+        v.check_positive("forecast_model_no", self.forecast_model_no);
+        v.check_cron("cron_schedule", &self.cron_schedule);
+
+        v.check_positive("refresh_holdout_minutes", self.refresh_holdout_minutes);
+        v.check(
+            self.refresh_window_minutes > self.refresh_holdout_minutes,
+            format!(
+                "refresh_window_minutes must be greater than refresh_holdout_minutes, window: {}, holdout: {}",
+                self.refresh_window_minutes, self.refresh_holdout_minutes
+            ),
+        );
+        v.check_positive(
+            "refresh_holdout_required_count",
+            self.refresh_holdout_required_count,
+        );
+        v.check(
+            self.refresh_max_degradation >= 0.0,
+            format!(
+                "refresh_max_degradation must be 0.0 or more, value: {}",
+                self.refresh_max_degradation
+            ),
+        );
+        v.check_positive("refresh_epochs", self.refresh_epochs);
+        v.check(
+            self.refresh_learning_rate > 0.0,
+            format!(
+                "refresh_learning_rate must be greater than 0.0, value: {}",
+                self.refresh_learning_rate
+            ),
+        );
+        v.finish()
+    }
+
+    pub fn update_params(&self) -> QuantileRegressionParameters {
+        QuantileRegressionParameters {
+            learning_rate: self.refresh_learning_rate,
+            epochs: self.refresh_epochs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn valid_config() -> Config {
+        Config {
+            forecast_input_size: 50,
+            forecast_offset_minutes: 30,
+            forecast_model_no: 1,
+            cron_schedule: "0 5 * * * *".to_string(),
+            refresh_window_minutes: 90,
+            refresh_holdout_minutes: 30,
+            refresh_holdout_required_count: 10,
+            refresh_max_degradation: 0.05,
+            refresh_epochs: 20,
+            refresh_learning_rate: 0.05,
+            refresh_fill_gap_minutes: 5,
+            refresh_exclude_anomalies: true,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(valid_config().validate().is_ok());
+
+        let c = Config {
+            refresh_window_minutes: 30,
+            refresh_max_degradation: -0.1,
+            refresh_epochs: 0,
+            refresh_learning_rate: 0.0,
+            ..valid_config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+}
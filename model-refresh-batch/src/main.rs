@@ -0,0 +1,225 @@
+extern crate common_lib;
+
+use chrono::{Duration, NaiveDateTime};
+use common_lib::{
+    batch,
+    clock::{Clock, SystemClock},
+    domain::{
+        anomaly,
+        refresh::{self, RefreshDataset},
+        series,
+        service::convert_to_features,
+    },
+    error::MyResult,
+    error_report, logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+    notify::{self, NotifyEvent},
+    telemetry,
+};
+use log::{debug, error, info, warn};
+
+mod config;
+
+const JOB_NAME: &str = "model-refresh-batch";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry = match telemetry::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    };
+
+    let _error_report = match error_report::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    };
+
+    let config = match common_lib::config::load::<config::Config>() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(cli) => cli,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    };
+
+    let retry_policy = match batch::util::RetryPolicy::from_env() {
+        Ok(p) => p,
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    };
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            if let Err(err) = health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli, &SystemClock))
+                })
+            }) {
+                error!("failed to refresh models, error: {}", err);
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+// 取り扱う通貨ペアごとに、予測用モデルを直近のレートで追加学習する
+fn run(config: &config::Config, mysql_cli: &DefaultClient, clock: &dyn Clock) -> MyResult<()> {
+    let now = clock.now().naive_utc();
+
+    let pairs = mysql_cli.with_transaction(|tx| mysql_cli.select_currency_pairs(tx))?;
+    let mut result: MyResult<()> = Ok(());
+    for pair in pairs.iter().filter(|p| p.enabled) {
+        logger::set_field("pair", &pair.pair);
+        error_report::set_tag("pair", &pair.pair);
+        if let Err(err) = run_pair(config, mysql_cli, &pair.pair, now) {
+            error!(
+                "failed to refresh model, pair: {}, error: {}",
+                pair.pair, err
+            );
+            result = Err(err);
+        }
+    }
+    result
+}
+
+fn run_pair(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    pair: &str,
+    now: NaiveDateTime,
+) -> MyResult<()> {
+    let begin = now - Duration::minutes(config.refresh_window_minutes);
+    let holdout_begin = now - Duration::minutes(config.refresh_holdout_minutes);
+
+    let (model, rates, anomalies) = mysql_cli.with_transaction(|tx| {
+        let model = mysql_cli.select_forecast_model(tx, pair, config.forecast_model_no)?;
+        let rates = mysql_cli.select_rates_for_training(tx, pair, Some(begin), Some(now))?;
+        let anomalies = if config.refresh_exclude_anomalies {
+            mysql_cli.select_rate_anomalies(tx, pair, Some(begin), Some(now))?
+        } else {
+            vec![]
+        };
+        Ok((model, rates, anomalies))
+    })?;
+
+    // 全体の学習（training-batch）で作られたモデルのうち、追加学習できるものだけを対象にする
+    let mut model = match model {
+        Some(m) if m.supports_incremental_update() => m,
+        Some(m) => {
+            info!(
+                "refresh skipped, model does not support incremental update, model: {}",
+                m
+            );
+            return Ok(());
+        }
+        None => {
+            info!(
+                "refresh skipped, model not found, pair: {}, no: {}",
+                pair, config.forecast_model_no
+            );
+            return Ok(());
+        }
+    };
+    let input_data_size = model.get_input_data_size()?;
+    if input_data_size != config.forecast_input_size {
+        warn!(
+            "refresh skipped, input data size is not match. model: {}, config: {}",
+            input_data_size, config.forecast_input_size
+        );
+        return Ok(());
+    }
+
+    debug!(
+        "fetched rates count: {}, anomalies count: {}",
+        rates.len(),
+        anomalies.len()
+    );
+    // 異常なレートは欠損として扱う
+    let rates = anomaly::exclude_anomalies(rates, &anomalies);
+    let mut points = series::resample_minutely(&rates);
+    series::forward_fill(&mut points, config.refresh_fill_gap_minutes);
+    let dataset = RefreshDataset::make(
+        &points,
+        config.forecast_input_size,
+        config.forecast_offset_minutes,
+        holdout_begin,
+    );
+    if dataset.train_x.is_empty() || dataset.holdout_x.len() < config.refresh_holdout_required_count
+    {
+        info!(
+            "refresh skipped, data is too little, pair: {}, train: {}, holdout: {}",
+            pair,
+            dataset.train_x.len(),
+            dataset.holdout_x.len()
+        );
+        return Ok(());
+    }
+
+    let feature_params = model.get_feature_params()?;
+    let train_x = convert_to_features(&dataset.train_x, &feature_params)?;
+    let holdout_x = convert_to_features(&dataset.holdout_x, &feature_params)?;
+
+    let before_mse = model.evaluate_mse(&holdout_x, &dataset.holdout_y)?;
+    model.update_incrementally(&train_x, &dataset.train_y, &config.update_params())?;
+    let after_mse = model.evaluate_mse(&holdout_x, &dataset.holdout_y)?;
+    info!(
+        "model refreshed, pair: {}, train: {}, holdout: {}, before_mse: {:.6}, after_mse: {:.6}",
+        pair,
+        dataset.train_x.len(),
+        dataset.holdout_x.len(),
+        before_mse,
+        after_mse
+    );
+
+    // 悪化した場合は保存しないことで、追加学習前のモデルに戻す
+    if refresh::is_degraded(before_mse, after_mse, config.refresh_max_degradation) {
+        let message = format!(
+            "pair: {}, no: {}, before_mse: {:.6}, after_mse: {:.6}",
+            pair, config.forecast_model_no, before_mse, after_mse
+        );
+        warn!("model refresh rolled back, {}", message);
+        notify::notify_from_env(NotifyEvent::ModelRefreshRolledBack, &message);
+        return Ok(());
+    }
+
+    model.update_performance(&holdout_x, &dataset.holdout_y)?;
+    mysql_cli.with_transaction(|tx| mysql_cli.upsert_forecast_model(tx, &model))?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use common_lib::{config::Validator, error::MyResult};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // ジョブ実行要求の状態を更新する（タイムアウト・再試行・実行可能への変更）スケジュール
+    pub cron_schedule: String,
+
+    // 各バッチの実行要求を登録するスケジュール（空の場合は登録しない）
+    #[serde(default)]
+    pub orchestrator_training_cron_schedule: String,
+    #[serde(default)]
+    pub orchestrator_forecast_cron_schedule: String,
+    #[serde(default)]
+    pub orchestrator_data_clean_cron_schedule: String,
+
+    // 1つの要求の最大試行回数（1の場合は再試行しない）
+    #[serde(default = "default_orchestrator_max_attempts")]
+    pub orchestrator_max_attempts: i32,
+    // 再試行までの待ち時間（秒）、再試行のたびに2倍にする
+    #[serde(default = "default_orchestrator_retry_backoff_seconds")]
+    pub orchestrator_retry_backoff_seconds: i64,
+    // 実行中のまま終わらない要求を失敗として扱うまでの時間（秒）
+    #[serde(default = "default_orchestrator_job_timeout_seconds")]
+    pub orchestrator_job_timeout_seconds: i64,
+    // /jobs で返す要求の件数
+    #[serde(default = "default_orchestrator_status_limit")]
+    pub orchestrator_status_limit: u32,
+}
+
+fn default_orchestrator_max_attempts() -> i32 {
+    3
+}
+
+fn default_orchestrator_retry_backoff_seconds() -> i64 {
+    60
+}
+
+fn default_orchestrator_job_timeout_seconds() -> i64 {
+    3600
+}
+
+fn default_orchestrator_status_limit() -> u32 {
+    100
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_not_empty("cron_schedule", &self.cron_schedule);
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_cron(
+            "orchestrator_training_cron_schedule",
+            &self.orchestrator_training_cron_schedule,
+        );
+        v.check_cron(
+            "orchestrator_forecast_cron_schedule",
+            &self.orchestrator_forecast_cron_schedule,
+        );
+        v.check_cron(
+            "orchestrator_data_clean_cron_schedule",
+            &self.orchestrator_data_clean_cron_schedule,
+        );
+        v.check_positive("orchestrator_max_attempts", self.orchestrator_max_attempts);
+        v.check(
+            self.orchestrator_retry_backoff_seconds >= 0,
+            format!(
+                "orchestrator_retry_backoff_seconds must not be negative, value: {}",
+                self.orchestrator_retry_backoff_seconds
+            ),
+        );
+        v.check_positive(
+            "orchestrator_job_timeout_seconds",
+            self.orchestrator_job_timeout_seconds,
+        );
+        v.check_positive("orchestrator_status_limit", self.orchestrator_status_limit);
+        v.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            cron_schedule: "0/10 * * * * *".to_string(),
+            orchestrator_training_cron_schedule: "0 0 * * * *".to_string(),
+            orchestrator_forecast_cron_schedule: "0 * * * * *".to_string(),
+            orchestrator_data_clean_cron_schedule: "".to_string(),
+            orchestrator_max_attempts: 3,
+            orchestrator_retry_backoff_seconds: 60,
+            orchestrator_job_timeout_seconds: 3600,
+            orchestrator_status_limit: 100,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(config().validate().is_ok());
+
+        let c = Config {
+            cron_schedule: "".to_string(),
+            orchestrator_forecast_cron_schedule: "invalid".to_string(),
+            orchestrator_max_attempts: 0,
+            orchestrator_retry_backoff_seconds: -1,
+            ..config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::NaiveDateTime;
+use common_lib::domain::model::{
+    JobRequest, JOB_REQUEST_STATUS_PENDING, JOB_REQUEST_STATUS_READY, JOB_REQUEST_STATUS_RUNNING,
+};
+
+// ワーカーモードの各バッチのジョブ名
+pub const JOB_NAME_TRAINING: &str = "training-batch";
+pub const JOB_NAME_FORECAST: &str = "forecast-batch";
+pub const JOB_NAME_DATA_CLEAN: &str = "data-clean-batch";
+
+// 同時に実行しないジョブの組み合わせ（学習中に学習用のレートを削除しない）
+const CONFLICTS: &[(&str, &str)] = &[(JOB_NAME_TRAINING, JOB_NAME_DATA_CLEAN)];
+
+fn is_conflicted(a: &str, b: &str) -> bool {
+    a == b
+        || CONFLICTS
+            .iter()
+            .any(|(x, y)| (*x == a && *y == b) || (*x == b && *y == a))
+}
+
+fn is_active(request: &JobRequest) -> bool {
+    request.status == JOB_REQUEST_STATUS_READY || request.status == JOB_REQUEST_STATUS_RUNNING
+}
+
+// 実行可能にする待機中の要求のIDを返す
+// 同じジョブや同時に実行しないジョブが実行可能・実行中の場合は待たせる（作成日時の古い要求から判定する）
+pub fn select_dispatchable(requests: &[JobRequest], now: NaiveDateTime) -> Vec<String> {
+    let mut active: Vec<&str> = requests
+        .iter()
+        .filter(|r| is_active(r))
+        .map(|r| r.job_name.as_str())
+        .collect();
+
+    let mut ids = vec![];
+    for request in requests
+        .iter()
+        .filter(|r| r.status == JOB_REQUEST_STATUS_PENDING && r.available_at <= now)
+    {
+        if active.iter().any(|a| is_conflicted(a, &request.job_name)) {
+            continue;
+        }
+        active.push(&request.job_name);
+        ids.push(request.id.clone());
+    }
+    ids
+}
+
+// 同じジョブの未実行の要求が残っている場合は新たに登録しない（実行中の場合は次の要求を登録する）
+pub fn should_enqueue(requests: &[JobRequest], job_name: &str) -> bool {
+    !requests.iter().any(|r| {
+        r.job_name == job_name
+            && (r.status == JOB_REQUEST_STATUS_PENDING || r.status == JOB_REQUEST_STATUS_READY)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use super::*;
+
+    fn request(id: &str, job_name: &str, status: u8, available_at: NaiveDateTime) -> JobRequest {
+        JobRequest {
+            id: id.to_string(),
+            job_name: job_name.to_string(),
+            status,
+            attempts: 0,
+            max_attempts: 3,
+            owner: None,
+            message: None,
+            available_at,
+            started_at: None,
+            finished_at: None,
+            created_at: available_at,
+            updated_at: available_at,
+        }
+    }
+
+    #[test]
+    fn test_for_select_dispatchable() {
+        let now = NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let later = now + Duration::minutes(1);
+
+        // 学習の実行中は削除を待たせ、予想は実行可能にする
+        let requests = vec![
+            request("1", JOB_NAME_TRAINING, JOB_REQUEST_STATUS_RUNNING, now),
+            request("2", JOB_NAME_DATA_CLEAN, JOB_REQUEST_STATUS_PENDING, now),
+            request("3", JOB_NAME_FORECAST, JOB_REQUEST_STATUS_PENDING, now),
+            request("4", JOB_NAME_FORECAST, JOB_REQUEST_STATUS_PENDING, now),
+        ];
+        assert_eq!(select_dispatchable(&requests, now), vec!["3".to_string()]);
+
+        // 同時に実行しないジョブは古い要求だけを実行可能にし、再試行の待ち時間中の要求は待たせる
+        let requests = vec![
+            request("1", JOB_NAME_TRAINING, JOB_REQUEST_STATUS_PENDING, later),
+            request("2", JOB_NAME_DATA_CLEAN, JOB_REQUEST_STATUS_PENDING, now),
+            request("3", JOB_NAME_TRAINING, JOB_REQUEST_STATUS_PENDING, now),
+        ];
+        assert_eq!(select_dispatchable(&requests, now), vec!["2".to_string()]);
+    }
+
+    #[test]
+    fn test_for_should_enqueue() {
+        let now = NaiveDate::from_ymd_opt(2022, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let requests = vec![
+            request("1", JOB_NAME_TRAINING, JOB_REQUEST_STATUS_RUNNING, now),
+            request("2", JOB_NAME_FORECAST, JOB_REQUEST_STATUS_READY, now),
+        ];
+        assert!(should_enqueue(&requests, JOB_NAME_TRAINING));
+        assert!(!should_enqueue(&requests, JOB_NAME_FORECAST));
+        assert!(should_enqueue(&requests, JOB_NAME_DATA_CLEAN));
+    }
+}
@@ -0,0 +1,170 @@
+extern crate common_lib;
+
+use common_lib::{
+    batch,
+    clock::{Clock, SystemClock},
+    error::MyResult,
+    error_report, logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+    telemetry,
+};
+use log::{error, info};
+
+mod config;
+mod dispatch;
+
+const JOB_NAME: &str = "orchestrator";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry = match telemetry::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    };
+
+    let _error_report = match error_report::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    };
+
+    let config = match common_lib::config::load::<config::Config>() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(cli) => cli,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    };
+
+    // ジョブ実行要求の状態は /jobs で確認する
+    let status_cli = mysql_cli.clone();
+    let status_limit = config.orchestrator_status_limit;
+    let health = batch::health::HealthReporter::new(JOB_NAME).with_route("/jobs", move || {
+        let requests = status_cli
+            .with_transaction(|tx| status_cli.select_job_requests(tx, None, status_limit, 0))?;
+        Ok(serde_json::to_string(&requests)?)
+    });
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    let dispatch_job = || {
+        if let Err(err) = health.track(|| dispatch(&config, &mysql_cli, &SystemClock)) {
+            error!("failed to dispatch job requests, error: {}", err);
+        }
+    };
+    let enqueue_training = || enqueue_or_log(&config, &mysql_cli, dispatch::JOB_NAME_TRAINING);
+    let enqueue_forecast = || enqueue_or_log(&config, &mysql_cli, dispatch::JOB_NAME_FORECAST);
+    let enqueue_data_clean = || enqueue_or_log(&config, &mysql_cli, dispatch::JOB_NAME_DATA_CLEAN);
+
+    // スケジュールが空のジョブは登録しない（空のスケジュールは1回だけ実行されるため除く）
+    let jobs: Vec<(&str, &(dyn Fn() + Sync))> = [
+        (
+            config.cron_schedule.as_str(),
+            &dispatch_job as &(dyn Fn() + Sync),
+        ),
+        (
+            config.orchestrator_training_cron_schedule.as_str(),
+            &enqueue_training,
+        ),
+        (
+            config.orchestrator_forecast_cron_schedule.as_str(),
+            &enqueue_forecast,
+        ),
+        (
+            config.orchestrator_data_clean_cron_schedule.as_str(),
+            &enqueue_data_clean,
+        ),
+    ]
+    .into_iter()
+    .filter(|(cron_schedule, _)| !cron_schedule.is_empty())
+    .collect();
+
+    if let Err(err) = batch::util::start_multi_scheduler_with_lock(JOB_NAME, &mysql_cli, &jobs) {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+// 実行中のまま終わらない要求を失敗にし、失敗した要求を再試行に回してから、実行できる要求を実行可能にする
+fn dispatch(config: &config::Config, mysql_cli: &DefaultClient, clock: &dyn Clock) -> MyResult<()> {
+    let (timed_out, requeued, dispatched) = mysql_cli.with_transaction(|tx| {
+        let timed_out =
+            mysql_cli.fail_timed_out_job_requests(tx, config.orchestrator_job_timeout_seconds)?;
+        let requeued =
+            mysql_cli.requeue_failed_job_requests(tx, config.orchestrator_retry_backoff_seconds)?;
+
+        let requests = mysql_cli.select_active_job_requests(tx)?;
+        let mut dispatched = vec![];
+        for id in dispatch::select_dispatchable(&requests, clock.now().naive_utc()) {
+            if mysql_cli.update_job_request_ready(tx, &id)? {
+                dispatched.push(id);
+            }
+        }
+        Ok((timed_out, requeued, dispatched))
+    })?;
+
+    if timed_out > 0 || requeued > 0 || !dispatched.is_empty() {
+        info!(
+            "job requests updated, timed_out: {}, requeued: {}, dispatched: {:?}",
+            timed_out, requeued, dispatched
+        );
+    }
+    Ok(())
+}
+
+fn enqueue(config: &config::Config, mysql_cli: &DefaultClient, job_name: &str) -> MyResult<()> {
+    let id = mysql_cli.with_transaction(|tx| {
+        let requests = mysql_cli.select_active_job_requests(tx)?;
+        if !dispatch::should_enqueue(&requests, job_name) {
+            return Ok(None);
+        }
+        Ok(Some(mysql_cli.insert_job_request(
+            tx,
+            job_name,
+            config.orchestrator_max_attempts,
+        )?))
+    })?;
+
+    match id {
+        Some(id) => info!("job requested, job: {}, id: {}", job_name, id),
+        None => info!(
+            "skip job request, previous request is not started, job: {}",
+            job_name
+        ),
+    }
+    Ok(())
+}
+
+fn enqueue_or_log(config: &config::Config, mysql_cli: &DefaultClient, job_name: &str) {
+    if let Err(err) = enqueue(config, mysql_cli, job_name) {
+        error!("failed to request job, job: {}, error: {}", job_name, err);
+    }
+}
@@ -0,0 +1,105 @@
+use common_lib::{
+    config::Validator, domain::model::forecast_type_from_offset_minutes, error::MyResult,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    // 共通設定
+    pub forecast_offset_minutes: usize,
+
+    // バッチ関連
+    pub cron_schedule: String,
+
+    // 的中した場合の払戻率（掛金に対する利益の割合、例: 0.85）
+    pub paper_trading_payout_ratio: f64,
+    // 取引する予想変化幅の下限（予想レートと現在レートの差がこれ未満の場合は取引しない）
+    #[serde(default)]
+    pub paper_trading_trade_threshold: f64,
+    // 1回の取引の掛金
+    #[serde(default = "default_paper_trading_stake")]
+    pub paper_trading_stake: f64,
+    // 1回の実行で通貨ペアごとに処理する予想の最大件数
+    #[serde(default = "default_paper_trading_batch_size")]
+    pub paper_trading_batch_size: u32,
+    // /report で集計する日数
+    #[serde(default = "default_paper_trading_report_days")]
+    pub paper_trading_report_days: i64,
+}
+
+fn default_paper_trading_stake() -> f64 {
+    1.0
+}
+
+fn default_paper_trading_batch_size() -> u32 {
+    1000
+}
+
+fn default_paper_trading_report_days() -> i64 {
+    7
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        if let Err(err) = forecast_type_from_offset_minutes(self.forecast_offset_minutes) {
+            v.check(
+                false,
+                format!("forecast_offset_minutes is invalid, {}", err),
+            );
+        }
+        v.check_positive(
+            "paper_trading_payout_ratio",
+            self.paper_trading_payout_ratio,
+        );
+        v.check(
+            self.paper_trading_trade_threshold >= 0.0,
+            format!(
+                "paper_trading_trade_threshold must not be negative, value: {}",
+                self.paper_trading_trade_threshold
+            ),
+        );
+        v.check_positive("paper_trading_stake", self.paper_trading_stake);
+        v.check_positive("paper_trading_batch_size", self.paper_trading_batch_size);
+        v.check_positive("paper_trading_report_days", self.paper_trading_report_days);
+        v.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            forecast_offset_minutes: 30,
+            cron_schedule: "0 * * * * *".to_string(),
+            paper_trading_payout_ratio: 0.85,
+            paper_trading_trade_threshold: 0.0,
+            paper_trading_stake: 1.0,
+            paper_trading_batch_size: 1000,
+            paper_trading_report_days: 7,
+        }
+    }
+
+    #[test]
+    fn test_for_validate() {
+        assert!(config().validate().is_ok());
+
+        let c = Config {
+            forecast_offset_minutes: 0,
+            paper_trading_payout_ratio: 0.0,
+            paper_trading_trade_threshold: -0.1,
+            paper_trading_stake: 0.0,
+            ..config()
+        };
+        let err = c.validate().unwrap_err();
+        match err {
+            MyError::InvalidConfig { problems } => assert_eq!(problems.len(), 4),
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+}
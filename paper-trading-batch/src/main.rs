@@ -0,0 +1,169 @@
+extern crate common_lib;
+
+use chrono::Duration;
+use common_lib::{
+    batch,
+    clock::{Clock, SystemClock},
+    domain::{
+        model::forecast_type_from_offset_minutes,
+        trade::{PaperTrade, PayoutModel, TRADE_DIRECTION_NONE},
+    },
+    error::MyResult,
+    error_report, logger,
+    mysql::{
+        self,
+        client::{Client, DefaultClient},
+    },
+    telemetry,
+};
+use log::{error, info};
+
+mod config;
+
+const JOB_NAME: &str = "paper-trading-batch";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry = match telemetry::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    };
+
+    let _error_report = match error_report::init(JOB_NAME) {
+        Ok(g) => g,
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    };
+
+    let config = match common_lib::config::load::<config::Config>() {
+        Ok(c) => c,
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli = match mysql::util::make_cli() {
+        Ok(cli) => cli,
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    };
+
+    let retry_policy = match batch::util::RetryPolicy::from_env() {
+        Ok(p) => p,
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    };
+
+    // モデルごとの仮想取引の損益は /report で確認する
+    let report_cli = mysql_cli.clone();
+    let report_clock = SystemClock::shared();
+    let report_days = config.paper_trading_report_days;
+    let health = batch::health::HealthReporter::new(JOB_NAME).with_route("/report", move || {
+        report(&report_cli, report_clock.as_ref(), report_days)
+    });
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            if let Err(err) = health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || run(&config, &mysql_cli))
+                })
+            }) {
+                error!("failed to paper trade, error: {}", err);
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+// 直近 report_days 日間の損益をモデルごとに集計したJSONを返す
+fn report(mysql_cli: &DefaultClient, clock: &dyn Clock, report_days: i64) -> MyResult<String> {
+    let begin = (clock.now() - Duration::days(report_days)).naive_utc();
+    let summaries =
+        mysql_cli.with_transaction(|tx| mysql_cli.select_paper_trade_summaries(tx, Some(begin)))?;
+    Ok(serde_json::to_string(&summaries)?)
+}
+
+fn run(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()> {
+    let forecast_type = forecast_type_from_offset_minutes(config.forecast_offset_minutes)?;
+    let payout = PayoutModel {
+        payout_ratio: config.paper_trading_payout_ratio,
+        trade_threshold: config.paper_trading_trade_threshold,
+    };
+
+    // 取り扱いを止めた通貨ペアも、判定待ちの予想が残っていれば取引を記録する
+    let pairs = mysql_cli.with_transaction(|tx| mysql_cli.select_currency_pairs(tx))?;
+    let mut result: MyResult<()> = Ok(());
+    for pair in pairs {
+        logger::set_field("pair", &pair.pair);
+        error_report::set_tag("pair", &pair.pair);
+        if let Err(err) = run_pair(config, mysql_cli, &payout, forecast_type, &pair.pair) {
+            error!("failed to paper trade, pair: {}, error: {}", pair.pair, err);
+            result = Err(err);
+        }
+    }
+    result
+}
+
+fn run_pair(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    payout: &PayoutModel,
+    forecast_type: i32,
+    pair: &str,
+) -> MyResult<()> {
+    let trades = mysql_cli.with_transaction(|tx| {
+        let trades: Vec<PaperTrade> = mysql_cli
+            .select_paper_trade_candidates(
+                tx,
+                pair,
+                forecast_type,
+                config.forecast_offset_minutes,
+                config.paper_trading_batch_size,
+            )?
+            .into_iter()
+            .map(|c| PaperTrade::settle(c, payout, config.paper_trading_stake))
+            .collect();
+        mysql_cli.insert_paper_trades(tx, &trades)?;
+        Ok(trades)
+    })?;
+
+    let traded: Vec<&PaperTrade> = trades
+        .iter()
+        .filter(|t| t.direction != TRADE_DIRECTION_NONE)
+        .collect();
+    info!(
+        "paper trade finished, pair: {}, forecasts: {}, trades: {}, profit: {:.4}",
+        pair,
+        trades.len(),
+        traded.len(),
+        traded.iter().map(|t| t.profit).sum::<f64>()
+    );
+    Ok(())
+}
@@ -5,6 +5,10 @@
 use futures::{future, Stream, stream};
 #[allow(unused_imports)]
 use rate_gateway_lib::{Api, ApiNoContext, Client, ContextWrapperExt, models,
+                      RatesPairCsvPostResponse,
+                      RatesPairDeleteResponse,
+                      RatesPairGapsGetResponse,
+                      RatesPairGetResponse,
                       RatesPairPostResponse,
                      };
 use clap::{App, Arg};
@@ -27,6 +31,10 @@ fn main() {
         .arg(Arg::with_name("operation")
             .help("Sets the operation to run")
             .possible_values(&[
+                "RatesPairCsvPost",
+                "RatesPairDelete",
+                "RatesPairGapsGet",
+                "RatesPairGet",
                 "RatesPairPost",
             ])
             .required(true)
@@ -71,10 +79,47 @@ fn main() {
     let mut rt = tokio::runtime::Runtime::new().unwrap();
 
     match matches.value_of("operation") {
+        Some("RatesPairCsvPost") => {
+            let result = rt.block_on(client.rates_pair_csv_post(
+                  "pair_example".to_string(),
+                  swagger::ByteArray(Vec::from("BYTE_ARRAY_DATA_HERE"))
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("RatesPairDelete") => {
+            let result = rt.block_on(client.rates_pair_delete(
+                  "pair_example".to_string(),
+                  "from_example".to_string(),
+                  "to_example".to_string(),
+                  Some("x_api_key_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("RatesPairGapsGet") => {
+            let result = rt.block_on(client.rates_pair_gaps_get(
+                  "pair_example".to_string(),
+                  Some("from_example".to_string()),
+                  Some("to_example".to_string()),
+                  Some(56),
+                  Some(56)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("RatesPairGet") => {
+            let result = rt.block_on(client.rates_pair_get(
+                  "pair_example".to_string(),
+                  Some("from_example".to_string()),
+                  Some("to_example".to_string()),
+                  Some(56),
+                  Some(56)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
         Some("RatesPairPost") => {
             let result = rt.block_on(client.rates_pair_post(
                   "pair_example".to_string(),
-                  &Vec::new()
+                  &Vec::new(),
+                  Some(true)
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
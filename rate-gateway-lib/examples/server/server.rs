@@ -93,6 +93,10 @@ impl<C> Server<C> {
 
 use rate_gateway_lib::{
     Api,
+    RatesPairCsvPostResponse,
+    RatesPairDeleteResponse,
+    RatesPairGapsGetResponse,
+    RatesPairGetResponse,
     RatesPairPostResponse,
 };
 use rate_gateway_lib::server::MakeService;
@@ -102,15 +106,72 @@ use swagger::ApiError;
 #[async_trait]
 impl<C> Api<C> for Server<C> where C: Has<XSpanIdString> + Send + Sync
 {
+    /// CSV形式のレートを一括登録します
+    async fn rates_pair_csv_post(
+        &self,
+        pair: String,
+        body: swagger::ByteArray,
+        context: &C) -> Result<RatesPairCsvPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("rates_pair_csv_post(\"{}\", {:?}) - X-Span-ID: {:?}", pair, body, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 指定期間のレートを削除します
+    async fn rates_pair_delete(
+        &self,
+        pair: String,
+        from: String,
+        to: String,
+        x_api_key: Option<String>,
+        context: &C) -> Result<RatesPairDeleteResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("rates_pair_delete(\"{}\", \"{}\", \"{}\", {:?}) - X-Span-ID: {:?}", pair, from, to, x_api_key, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 学習用レートの欠損期間を取得します
+    async fn rates_pair_gaps_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<RatesPairGapsGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("rates_pair_gaps_get(\"{}\", {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}", pair, from, to, limit, offset, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// 登録済みのレートを取得します
+    async fn rates_pair_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<RatesPairGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("rates_pair_get(\"{}\", {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}", pair, from, to, limit, offset, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
     /// レートを新規登録します
     async fn rates_pair_post(
         &self,
         pair: String,
         rate: &Vec<models::Rate>,
+        partial: Option<bool>,
         context: &C) -> Result<RatesPairPostResponse, ApiError>
     {
         let context = context.clone();
-        info!("rates_pair_post(\"{}\", {:?}) - X-Span-ID: {:?}", pair, rate, context.get().0.clone());
+        info!("rates_pair_post(\"{}\", {:?}, {:?}) - X-Span-ID: {:?}", pair, rate, partial, context.get().0.clone());
         Err(ApiError("Generic failure".into()))
     }
 
@@ -14,7 +14,7 @@ pub const API_VERSION: &'static str = "1.0.0";
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
-pub enum RatesPairPostResponse {
+pub enum RatesPairCsvPostResponse {
     /// 登録成功
     Status201
     (models::PostSuccess)
@@ -27,9 +27,153 @@ pub enum RatesPairPostResponse {
     Status404
     (models::Error)
     ,
+    /// 登録失敗（展開後のCSVまたはレートの件数が上限を超えている）
+    Status413
+    (models::Error)
+    ,
+    /// 登録失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 登録失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum RatesPairDeleteResponse {
+    /// 削除成功
+    Status200
+    (models::RatesPairDelete200Response)
+    ,
+    /// 削除失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 削除失敗（認証エラー）
+    Status401
+    (models::Error)
+    ,
+    /// 削除失敗（通貨ペアが非対応）
+    Status404
+    (models::Error)
+    ,
+    /// 削除失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 削除失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum RatesPairGapsGetResponse {
+    /// 取得成功
+    Status200
+    (models::RatesPairGapsGet200Response)
+    ,
+    /// 取得失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum RatesPairGetResponse {
+    /// 取得成功
+    Status200
+    (models::RatesPairGet200Response)
+    ,
+    /// 取得失敗（リクエストパラメータ不備）
+    Status400
+    (models::Error)
+    ,
+    /// 取得失敗（内部エラー）
+    Status500
+    (models::Error)
+    ,
+    /// 取得失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum RatesPairPostResponse {
+    /// 登録成功
+    Status201
+    (models::PostSuccess)
+    ,
+    /// 受付成功（書き込みキューに追加済み、登録は非同期に行う）
+    Status202
+    (models::PostAccepted)
+    ,
+    /// 登録失敗（リクエストパラメータ不備）
+    Status400
+    (models::ValidationError)
+    ,
+    /// 登録失敗（通貨ペアが非対応）
+    Status404
+    (models::Error)
+    ,
+    /// 登録失敗（レート数が上限を超えている）
+    Status413
+    {
+        body: models::Error,
+        x_max_rates:
+        Option<
+        i64
+        >
+    }
+    ,
     /// 登録失敗（内部エラー）
     Status500
     (models::Error)
+    ,
+    /// 登録失敗（データベースに接続できない）
+    Status503
+    {
+        body: models::Error,
+        retry_after:
+        Option<
+        i32
+        >
+    }
 }
 
 /// API
@@ -39,11 +183,48 @@ pub trait Api<C: Send + Sync> {
         Poll::Ready(Ok(()))
     }
 
+    /// CSV形式のレートを一括登録します
+    async fn rates_pair_csv_post(
+        &self,
+        pair: String,
+        body: swagger::ByteArray,
+        context: &C) -> Result<RatesPairCsvPostResponse, ApiError>;
+
+    /// 指定期間のレートを削除します
+    async fn rates_pair_delete(
+        &self,
+        pair: String,
+        from: String,
+        to: String,
+        x_api_key: Option<String>,
+        context: &C) -> Result<RatesPairDeleteResponse, ApiError>;
+
+    /// 学習用レートの欠損期間を取得します
+    async fn rates_pair_gaps_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<RatesPairGapsGetResponse, ApiError>;
+
+    /// 登録済みのレートを取得します
+    async fn rates_pair_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C) -> Result<RatesPairGetResponse, ApiError>;
+
     /// レートを新規登録します
     async fn rates_pair_post(
         &self,
         pair: String,
         rate: &Vec<models::Rate>,
+        partial: Option<bool>,
         context: &C) -> Result<RatesPairPostResponse, ApiError>;
 
 }
@@ -56,11 +237,48 @@ pub trait ApiNoContext<C: Send + Sync> {
 
     fn context(&self) -> &C;
 
+    /// CSV形式のレートを一括登録します
+    async fn rates_pair_csv_post(
+        &self,
+        pair: String,
+        body: swagger::ByteArray,
+        ) -> Result<RatesPairCsvPostResponse, ApiError>;
+
+    /// 指定期間のレートを削除します
+    async fn rates_pair_delete(
+        &self,
+        pair: String,
+        from: String,
+        to: String,
+        x_api_key: Option<String>,
+        ) -> Result<RatesPairDeleteResponse, ApiError>;
+
+    /// 学習用レートの欠損期間を取得します
+    async fn rates_pair_gaps_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<RatesPairGapsGetResponse, ApiError>;
+
+    /// 登録済みのレートを取得します
+    async fn rates_pair_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<RatesPairGetResponse, ApiError>;
+
     /// レートを新規登録します
     async fn rates_pair_post(
         &self,
         pair: String,
         rate: &Vec<models::Rate>,
+        partial: Option<bool>,
         ) -> Result<RatesPairPostResponse, ApiError>;
 
 }
@@ -88,15 +306,68 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         ContextWrapper::context(self)
     }
 
+    /// CSV形式のレートを一括登録します
+    async fn rates_pair_csv_post(
+        &self,
+        pair: String,
+        body: swagger::ByteArray,
+        ) -> Result<RatesPairCsvPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().rates_pair_csv_post(pair, body, &context).await
+    }
+
+    /// 指定期間のレートを削除します
+    async fn rates_pair_delete(
+        &self,
+        pair: String,
+        from: String,
+        to: String,
+        x_api_key: Option<String>,
+        ) -> Result<RatesPairDeleteResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().rates_pair_delete(pair, from, to, x_api_key, &context).await
+    }
+
+    /// 学習用レートの欠損期間を取得します
+    async fn rates_pair_gaps_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<RatesPairGapsGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().rates_pair_gaps_get(pair, from, to, limit, offset, &context).await
+    }
+
+    /// 登録済みのレートを取得します
+    async fn rates_pair_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        ) -> Result<RatesPairGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().rates_pair_get(pair, from, to, limit, offset, &context).await
+    }
+
     /// レートを新規登録します
     async fn rates_pair_post(
         &self,
         pair: String,
         rate: &Vec<models::Rate>,
+        partial: Option<bool>,
         ) -> Result<RatesPairPostResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().rates_pair_post(pair, rate, &context).await
+        self.api().rates_pair_post(pair, rate, partial, &context).await
     }
 
 }
@@ -118,20 +118,160 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 }
 
 
+/// Post受付時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostAccepted {
+    /// 受け付けた件数
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    /// 不正なため登録しなかったレートごとのエラー（partial=trueの場合のみ）
+    #[serde(rename = "errors")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub errors: Option<Vec<models::RecordError>>,
+
+}
+
+impl PostAccepted {
+    pub fn new(count: i64, ) -> PostAccepted {
+        PostAccepted {
+            count: count,
+            errors: None,
+        }
+    }
+}
+
+/// Converts the PostAccepted value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for PostAccepted {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("count".to_string());
+        params.push(self.count.to_string());
+        // Skipping errors in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostAccepted value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostAccepted {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub count: Vec<i64>,
+            pub errors: Vec<Vec<models::RecordError>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostAccepted".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "errors" => return std::result::Result::Err("Parsing a container in this style is not supported in PostAccepted".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostAccepted".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostAccepted {
+            count: intermediate_rep.count.into_iter().next().ok_or("count missing in PostAccepted".to_string())?,
+            errors: intermediate_rep.errors.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostAccepted> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostAccepted>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostAccepted>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostAccepted - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<PostAccepted> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostAccepted as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostAccepted - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
 /// Post成功時の情報
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct PostSuccess {
-    /// 登録数
+    /// 受け付けた件数（新規登録数と重複による無視数の合計）
     #[serde(rename = "count")]
     pub count: i64,
 
+    /// 新規に登録した件数
+    #[serde(rename = "inserted")]
+    pub inserted: i64,
+
+    /// 同じ日時のレートが登録済みのため無視した件数
+    #[serde(rename = "skipped")]
+    pub skipped: i64,
+
+    /// 不正なため登録しなかったレートごとのエラー（partial=trueの場合のみ）
+    #[serde(rename = "errors")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub errors: Option<Vec<models::RecordError>>,
+
 }
 
 impl PostSuccess {
-    pub fn new(count: i64, ) -> PostSuccess {
+    pub fn new(count: i64, inserted: i64, skipped: i64, ) -> PostSuccess {
         PostSuccess {
             count: count,
+            inserted: inserted,
+            skipped: skipped,
+            errors: None,
         }
     }
 }
@@ -146,6 +286,15 @@ impl std::string::ToString for PostSuccess {
         params.push("count".to_string());
         params.push(self.count.to_string());
 
+
+        params.push("inserted".to_string());
+        params.push(self.inserted.to_string());
+
+
+        params.push("skipped".to_string());
+        params.push(self.skipped.to_string());
+        // Skipping errors in query parameter serialization
+
         params.join(",").to_string()
     }
 }
@@ -161,6 +310,9 @@ impl std::str::FromStr for PostSuccess {
         // An intermediate representation of the struct to use for parsing.
         struct IntermediateRep {
             pub count: Vec<i64>,
+            pub inserted: Vec<i64>,
+            pub skipped: Vec<i64>,
+            pub errors: Vec<Vec<models::RecordError>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -178,6 +330,9 @@ impl std::str::FromStr for PostSuccess {
             if let Some(key) = key_result {
                 match key {
                     "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "inserted" => intermediate_rep.inserted.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "skipped" => intermediate_rep.skipped.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "errors" => return std::result::Result::Err("Parsing a container in this style is not supported in PostSuccess".to_string()),
                     _ => return std::result::Result::Err("Unexpected key while parsing PostSuccess".to_string())
                 }
             }
@@ -189,6 +344,9 @@ impl std::str::FromStr for PostSuccess {
         // Use the intermediate representation to return the struct
         std::result::Result::Ok(PostSuccess {
             count: intermediate_rep.count.into_iter().next().ok_or("count missing in PostSuccess".to_string())?,
+            inserted: intermediate_rep.inserted.into_iter().next().ok_or("inserted missing in PostSuccess".to_string())?,
+            skipped: intermediate_rep.skipped.into_iter().next().ok_or("skipped missing in PostSuccess".to_string())?,
+            errors: intermediate_rep.errors.into_iter().next(),
         })
     }
 }
@@ -357,3 +515,741 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+
+/// レートの欠損期間
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RateGap {
+    /// 欠損直前のレートの記録日時（分単位）
+    #[serde(rename = "begin")]
+    pub begin: String,
+
+    /// 欠損直後のレートの記録日時（分単位）
+    #[serde(rename = "end")]
+    pub end: String,
+
+    /// 欠損している分数
+    #[serde(rename = "missing_minutes")]
+    pub missing_minutes: i64,
+
+}
+
+impl RateGap {
+    pub fn new(begin: String, end: String, missing_minutes: i64, ) -> RateGap {
+        RateGap {
+            begin: begin,
+            end: end,
+            missing_minutes: missing_minutes,
+        }
+    }
+}
+
+/// Converts the RateGap value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for RateGap {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("begin".to_string());
+        params.push(self.begin.to_string());
+
+
+        params.push("end".to_string());
+        params.push(self.end.to_string());
+
+
+        params.push("missing_minutes".to_string());
+        params.push(self.missing_minutes.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RateGap value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RateGap {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub begin: Vec<String>,
+            pub end: Vec<String>,
+            pub missing_minutes: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RateGap".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "begin" => intermediate_rep.begin.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "end" => intermediate_rep.end.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "missing_minutes" => intermediate_rep.missing_minutes.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RateGap".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RateGap {
+            begin: intermediate_rep.begin.into_iter().next().ok_or("begin missing in RateGap".to_string())?,
+            end: intermediate_rep.end.into_iter().next().ok_or("end missing in RateGap".to_string())?,
+            missing_minutes: intermediate_rep.missing_minutes.into_iter().next().ok_or("missing_minutes missing in RateGap".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RateGap> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<RateGap>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RateGap>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RateGap - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RateGap> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RateGap as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RateGap - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RatesPairDelete200Response {
+    /// 削除した件数
+    #[serde(rename = "deleted")]
+    pub deleted: i64,
+
+}
+
+impl RatesPairDelete200Response {
+    pub fn new(deleted: i64, ) -> RatesPairDelete200Response {
+        RatesPairDelete200Response {
+            deleted: deleted,
+        }
+    }
+}
+
+/// Converts the RatesPairDelete200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for RatesPairDelete200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("deleted".to_string());
+        params.push(self.deleted.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RatesPairDelete200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RatesPairDelete200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub deleted: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RatesPairDelete200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "deleted" => intermediate_rep.deleted.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RatesPairDelete200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RatesPairDelete200Response {
+            deleted: intermediate_rep.deleted.into_iter().next().ok_or("deleted missing in RatesPairDelete200Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RatesPairDelete200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<RatesPairDelete200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RatesPairDelete200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RatesPairDelete200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RatesPairDelete200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RatesPairDelete200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RatesPairDelete200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RatesPairGapsGet200Response {
+    #[serde(rename = "gaps")]
+    pub gaps: Vec<models::RateGap>,
+
+}
+
+impl RatesPairGapsGet200Response {
+    pub fn new(gaps: Vec<models::RateGap>, ) -> RatesPairGapsGet200Response {
+        RatesPairGapsGet200Response {
+            gaps: gaps,
+        }
+    }
+}
+
+/// Converts the RatesPairGapsGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for RatesPairGapsGet200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping gaps in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RatesPairGapsGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RatesPairGapsGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub gaps: Vec<Vec<models::RateGap>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RatesPairGapsGet200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "gaps" => return std::result::Result::Err("Parsing a container in this style is not supported in RatesPairGapsGet200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RatesPairGapsGet200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RatesPairGapsGet200Response {
+            gaps: intermediate_rep.gaps.into_iter().next().ok_or("gaps missing in RatesPairGapsGet200Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RatesPairGapsGet200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<RatesPairGapsGet200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RatesPairGapsGet200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RatesPairGapsGet200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RatesPairGapsGet200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RatesPairGapsGet200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RatesPairGapsGet200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 成功時の情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RatesPairGet200Response {
+    #[serde(rename = "rates")]
+    pub rates: Vec<models::Rate>,
+
+}
+
+impl RatesPairGet200Response {
+    pub fn new(rates: Vec<models::Rate>, ) -> RatesPairGet200Response {
+        RatesPairGet200Response {
+            rates: rates,
+        }
+    }
+}
+
+/// Converts the RatesPairGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for RatesPairGet200Response {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+        // Skipping rates in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RatesPairGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RatesPairGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub rates: Vec<Vec<models::Rate>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RatesPairGet200Response".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "rates" => return std::result::Result::Err("Parsing a container in this style is not supported in RatesPairGet200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RatesPairGet200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RatesPairGet200Response {
+            rates: intermediate_rep.rates.into_iter().next().ok_or("rates missing in RatesPairGet200Response".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RatesPairGet200Response> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<RatesPairGet200Response>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RatesPairGet200Response>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RatesPairGet200Response - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RatesPairGet200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RatesPairGet200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RatesPairGet200Response - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// レートごとのエラー情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RecordError {
+    /// リクエスト内の位置（0始まり）
+    #[serde(rename = "index")]
+    pub index: i32,
+
+    /// レートの日時
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub time: Option<String>,
+
+    /// エラーメッセージ
+    #[serde(rename = "message")]
+    pub message: String,
+
+}
+
+impl RecordError {
+    pub fn new(index: i32, message: String, ) -> RecordError {
+        RecordError {
+            index: index,
+            time: None,
+            message: message,
+        }
+    }
+}
+
+/// Converts the RecordError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for RecordError {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("index".to_string());
+        params.push(self.index.to_string());
+
+
+        if let Some(ref time) = self.time {
+            params.push("time".to_string());
+            params.push(time.to_string());
+        }
+
+
+        params.push("message".to_string());
+        params.push(self.message.to_string());
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RecordError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub index: Vec<i32>,
+            pub time: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RecordError".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "index" => intermediate_rep.index.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "time" => intermediate_rep.time.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RecordError".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RecordError {
+            index: intermediate_rep.index.into_iter().next().ok_or("index missing in RecordError".to_string())?,
+            time: intermediate_rep.time.into_iter().next(),
+            message: intermediate_rep.message.into_iter().next().ok_or("message missing in RecordError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RecordError> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordError>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RecordError>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RecordError - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<RecordError> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RecordError as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RecordError - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// 入力チェックのエラー情報
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ValidationError {
+    /// エラーメッセージ
+    #[serde(rename = "message")]
+    pub message: String,
+
+    /// レートごとのエラー
+    #[serde(rename = "errors")]
+    pub errors: Vec<models::RecordError>,
+
+}
+
+impl ValidationError {
+    pub fn new(message: String, errors: Vec<models::RecordError>, ) -> ValidationError {
+        ValidationError {
+            message: message,
+            errors: errors,
+        }
+    }
+}
+
+/// Converts the ValidationError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ValidationError {
+    fn to_string(&self) -> String {
+        let mut params: Vec<String> = vec![];
+
+        params.push("message".to_string());
+        params.push(self.message.to_string());
+        // Skipping errors in query parameter serialization
+
+        params.join(",").to_string()
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ValidationError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ValidationError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        #[derive(Default)]
+        // An intermediate representation of the struct to use for parsing.
+        struct IntermediateRep {
+            pub message: Vec<String>,
+            pub errors: Vec<Vec<models::RecordError>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',').into_iter();
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ValidationError".to_string())
+            };
+
+            if let Some(key) = key_result {
+                match key {
+                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| format!("{}", x))?),
+                    "errors" => return std::result::Result::Err("Parsing a container in this style is not supported in ValidationError".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ValidationError".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ValidationError {
+            message: intermediate_rep.message.into_iter().next().ok_or("message missing in ValidationError".to_string())?,
+            errors: intermediate_rep.errors.into_iter().next().ok_or("errors missing in ValidationError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ValidationError> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ValidationError>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ValidationError>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ValidationError - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ValidationError> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ValidationError as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ValidationError - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
@@ -1,27 +1,351 @@
+use std::collections::HashMap;
+
+use common_lib::{
+    config::Validator,
+    error::{MyError, MyResult},
+};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 pub struct Config {
-    pub server_host: String,
-    pub server_port: i32,
+    // CSV一括登録時のリクエストボディの最大サイズ（バイト）
+    #[serde(default = "default_max_csv_body_bytes")]
+    pub max_csv_body_bytes: u64,
+    // 1分あたりに受け付けるクライアントごとのリクエスト数、0の場合は制限しない
+    #[serde(default)]
+    pub rate_limit_per_minute: u32,
+    // APIキーごとの1分あたりのリクエスト数（例: feed-key=1200,test-key=60）、指定の無いキーは接続元IPごとに rate_limit_per_minute
+    #[serde(default)]
+    pub rate_limit_api_keys: String,
+    // 1リクエストで登録を受け付けるレート数の上限
+    #[serde(default = "default_max_rates_per_request")]
+    pub max_rates_per_request: usize,
+    // 登録を受け付けるレートの保持期間（日）、これより古い日時は拒否する
+    #[serde(default = "default_rate_retention_days")]
+    pub rate_retention_days: i64,
+    // 未来の日時として許容する時計のずれ（秒）
+    #[serde(default = "default_rate_future_tolerance_seconds")]
+    pub rate_future_tolerance_seconds: i64,
+    // 書き込みキューのレートを登録する間隔（ミリ秒）
+    #[serde(default = "default_write_flush_interval_ms")]
+    pub write_flush_interval_ms: u64,
+    // 書き込みキューに保持できるレート数（超えた場合は503を返す）
+    #[serde(default = "default_write_queue_capacity")]
+    pub write_queue_capacity: usize,
+    // 書き込みキューから1回に登録するレート数（この件数たまった場合は間隔を待たずに登録する）
+    #[serde(default = "default_write_batch_size")]
+    pub write_batch_size: usize,
+    // レートの登録（POST）を書き込みキュー経由で非同期に行うか
+    #[serde(default)]
+    pub write_queue_enabled: bool,
+    // 書き込みキューから捨てたレートを書き出すファイル（空文字の場合は書き出さない）
+    #[serde(default)]
+    pub write_dead_letter_file: String,
+    // レートの登録（POST・書き込みキュー）後に直近のレートを予想用レートとして登録するか
+    #[serde(default)]
+    pub forecast_forward_enabled: bool,
+    // 予想用レートとして登録する直近のレート数（予想モデルの入力データ数）
+    #[serde(default = "default_forecast_input_size")]
+    pub forecast_input_size: usize,
+    // 予想用レートの有効期間（分）
+    #[serde(default = "default_forecast_expire_minutes")]
+    pub forecast_expire_minutes: i64,
+    // 管理用APIのキー（未設定または空文字の場合は管理用APIを利用不可）
+    #[serde(default)]
+    pub admin_api_key: Option<String>,
+    // MQTTブローカーのホスト（空文字の場合はMQTTで受信しない）
+    #[serde(default)]
+    pub mqtt_host: String,
+    #[serde(default = "default_mqtt_port")]
+    pub mqtt_port: u16,
+    // 購読するトピックと通貨ペアの対応（例: rates/usdjpy=USDJPY,ticks/+/eurjpy=EURJPY）
+    #[serde(default)]
+    pub mqtt_topics: String,
+}
+
+fn default_max_csv_body_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_max_rates_per_request() -> usize {
+    10000
+}
+
+// 過去データの一括登録を拒否しないよう、既定値は長めにしておく
+fn default_rate_retention_days() -> i64 {
+    3650
+}
+
+fn default_rate_future_tolerance_seconds() -> i64 {
+    60
+}
+
+fn default_write_flush_interval_ms() -> u64 {
+    1000
+}
+
+fn default_write_queue_capacity() -> usize {
+    100000
+}
+
+fn default_write_batch_size() -> usize {
+    1000
+}
+
+fn default_forecast_input_size() -> usize {
+    50
+}
+
+fn default_forecast_expire_minutes() -> i64 {
+    60
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
 }
 
 impl Config {
-    pub fn get_address(&self) -> String {
-        format!("{}:{}", self.server_host, self.server_port)
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("max_csv_body_bytes", self.max_csv_body_bytes);
+        v.check_positive("max_rates_per_request", self.max_rates_per_request);
+        v.check_positive("rate_retention_days", self.rate_retention_days);
+        v.check(
+            self.rate_future_tolerance_seconds >= 0,
+            format!(
+                "rate_future_tolerance_seconds must not be negative, value: {}",
+                self.rate_future_tolerance_seconds
+            ),
+        );
+        if self.write_queue_enabled {
+            v.check_positive("write_flush_interval_ms", self.write_flush_interval_ms);
+            v.check_positive("write_batch_size", self.write_batch_size);
+            v.check(
+                self.write_batch_size <= self.write_queue_capacity,
+                format!(
+                    "write_batch_size must be less than or equal to write_queue_capacity, value: {}",
+                    self.write_batch_size
+                ),
+            );
+        }
+        if self.forecast_forward_enabled {
+            v.check_positive("forecast_input_size", self.forecast_input_size);
+            v.check_positive("forecast_expire_minutes", self.forecast_expire_minutes);
+        }
+        if !self.mqtt_host.is_empty() {
+            v.check_port("mqtt_port", self.mqtt_port as i64);
+        }
+        if let Err(err) = self.get_mqtt_topics() {
+            v.check(false, format!("mqtt_topics is invalid, {}", err));
+        }
+        if let Err(err) = self.get_rate_limit_api_keys() {
+            v.check(false, format!("rate_limit_api_keys is invalid, {}", err));
+        }
+        v.finish()
+    }
+
+    pub fn get_rate_limit_api_keys(&self) -> MyResult<HashMap<String, u32>> {
+        let mut limits = HashMap::new();
+        for item in self.rate_limit_api_keys.split(',') {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let limit = item
+                .split_once('=')
+                .filter(|(key, _)| !key.trim().is_empty())
+                .and_then(|(key, limit)| Some((key.trim(), limit.trim().parse::<u32>().ok()?)));
+            match limit {
+                Some((key, limit)) => {
+                    limits.insert(key.to_string(), limit);
+                }
+                None => {
+                    return Err(MyError::ParseError {
+                        param_name: "rate_limit_api_keys".to_string(),
+                        value: item.to_string(),
+                        memo: "format must be api_key=limit".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(limits)
+    }
+
+    pub fn get_mqtt_topics(&self) -> MyResult<Vec<(String, String)>> {
+        let mut topics = vec![];
+        for item in self.mqtt_topics.split(',') {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            match item.split_once('=') {
+                Some((topic, pair)) if !topic.trim().is_empty() && !pair.trim().is_empty() => {
+                    topics.push((topic.trim().to_string(), pair.trim().to_string()));
+                }
+                _ => {
+                    return Err(MyError::ParseError {
+                        param_name: "mqtt_topics".to_string(),
+                        value: item.to_string(),
+                        memo: "format must be topic=pair".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(topics)
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use super::*;
 
+    // 他のモジュールのテストでも使う設定
+    pub fn config() -> Config {
+        Config {
+            max_csv_body_bytes: 67108864,
+            rate_limit_per_minute: 0,
+            rate_limit_api_keys: "".to_string(),
+            max_rates_per_request: 10000,
+            rate_retention_days: 30,
+            rate_future_tolerance_seconds: 60,
+            write_flush_interval_ms: 1000,
+            write_queue_capacity: 100000,
+            write_batch_size: 1000,
+            write_queue_enabled: false,
+            write_dead_letter_file: "".to_string(),
+            forecast_forward_enabled: false,
+            forecast_input_size: 50,
+            forecast_expire_minutes: 60,
+            admin_api_key: None,
+            mqtt_host: "".to_string(),
+            mqtt_port: 1883,
+            mqtt_topics: "".to_string(),
+        }
+    }
+
     #[test]
-    fn test_for_get_address() {
-        let config = Config {
-            server_host: "127.0.0.1".to_string(),
-            server_port: 8888,
+    fn test_for_default() {
+        // 既存の設定に無い項目は、追加前と同じ動作になる値を使う
+        let config: Config = common_lib::config::load_from(&[]).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.max_csv_body_bytes, 67108864);
+        assert_eq!(config.max_rates_per_request, 10000);
+        assert_eq!(config.rate_retention_days, 3650);
+        assert_eq!(config.rate_future_tolerance_seconds, 60);
+        assert!(!config.write_queue_enabled);
+        assert_eq!(config.write_flush_interval_ms, 1000);
+        assert_eq!(config.write_queue_capacity, 100000);
+        assert_eq!(config.write_batch_size, 1000);
+        assert_eq!(config.write_dead_letter_file, "");
+        assert!(!config.forecast_forward_enabled);
+        assert_eq!(config.forecast_input_size, 50);
+        assert_eq!(config.forecast_expire_minutes, 60);
+        assert_eq!(config.admin_api_key, None);
+        assert_eq!(config.mqtt_host, "");
+        assert_eq!(config.mqtt_port, 1883);
+        assert!(config.get_mqtt_topics().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_for_get_mqtt_topics() {
+        let mut config = Config {
+            max_csv_body_bytes: 67108864,
+            rate_limit_per_minute: 600,
+            rate_limit_api_keys: "".to_string(),
+            max_rates_per_request: 10000,
+            rate_retention_days: 30,
+            rate_future_tolerance_seconds: 60,
+            write_flush_interval_ms: 1000,
+            write_queue_capacity: 100000,
+            write_batch_size: 1000,
+            write_queue_enabled: false,
+            write_dead_letter_file: "".to_string(),
+            forecast_forward_enabled: false,
+            forecast_input_size: 50,
+            forecast_expire_minutes: 60,
+            admin_api_key: None,
+            mqtt_host: "localhost".to_string(),
+            mqtt_port: 1883,
+            mqtt_topics: "rates/usdjpy=USDJPY, ticks/+/eurjpy=EURJPY".to_string(),
         };
-        assert_eq!(config.get_address(), "127.0.0.1:8888".to_string());
+        assert_eq!(
+            config.get_mqtt_topics().unwrap(),
+            vec![
+                ("rates/usdjpy".to_string(), "USDJPY".to_string()),
+                ("ticks/+/eurjpy".to_string(), "EURJPY".to_string()),
+            ]
+        );
+
+        config.mqtt_topics = "".to_string();
+        assert!(config.get_mqtt_topics().unwrap().is_empty());
+
+        config.mqtt_topics = "rates/usdjpy".to_string();
+        assert!(config.get_mqtt_topics().is_err());
+    }
+
+    #[test]
+    fn test_for_get_rate_limit_api_keys() {
+        let mut config = Config {
+            max_csv_body_bytes: 67108864,
+            rate_limit_per_minute: 600,
+            rate_limit_api_keys: "feed-key=1200, test-key=0".to_string(),
+            max_rates_per_request: 10000,
+            rate_retention_days: 30,
+            rate_future_tolerance_seconds: 60,
+            write_flush_interval_ms: 1000,
+            write_queue_capacity: 100000,
+            write_batch_size: 1000,
+            write_queue_enabled: false,
+            write_dead_letter_file: "".to_string(),
+            forecast_forward_enabled: false,
+            forecast_input_size: 50,
+            forecast_expire_minutes: 60,
+            admin_api_key: None,
+            mqtt_host: "".to_string(),
+            mqtt_port: 1883,
+            mqtt_topics: "".to_string(),
+        };
+        let limits = config.get_rate_limit_api_keys().unwrap();
+        assert_eq!(limits.len(), 2);
+        assert_eq!(limits["feed-key"], 1200);
+        assert_eq!(limits["test-key"], 0);
+
+        config.rate_limit_api_keys = "feed-key=many".to_string();
+        assert!(config.get_rate_limit_api_keys().is_err());
+        config.rate_limit_api_keys = "=60".to_string();
+        assert!(config.get_rate_limit_api_keys().is_err());
+    }
+
+    #[test]
+    fn test_for_validate() {
+        let mut config = Config {
+            max_csv_body_bytes: 67108864,
+            rate_limit_per_minute: 600,
+            rate_limit_api_keys: "".to_string(),
+            max_rates_per_request: 10000,
+            rate_retention_days: 30,
+            rate_future_tolerance_seconds: 60,
+            write_flush_interval_ms: 1000,
+            write_queue_capacity: 100000,
+            write_batch_size: 1000,
+            write_queue_enabled: true,
+            write_dead_letter_file: "".to_string(),
+            forecast_forward_enabled: false,
+            forecast_input_size: 0,
+            forecast_expire_minutes: 60,
+            admin_api_key: None,
+            mqtt_host: "".to_string(),
+            mqtt_port: 1883,
+            mqtt_topics: "".to_string(),
+        };
+        assert!(config.validate().is_ok());
+
+        config.max_csv_body_bytes = 0;
+        config.write_batch_size = 200000;
+        config.mqtt_topics = "rates/usdjpy".to_string();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("max_csv_body_bytes"));
+        assert!(err.contains("write_batch_size"));
+        assert!(err.contains("mqtt_topics"));
     }
 }
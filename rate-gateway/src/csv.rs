@@ -0,0 +1,458 @@
+use std::task::{Context, Poll};
+
+use chrono::Utc;
+use common_lib::{
+    domain::model::RateForTraining,
+    error::{MyError, MyResult},
+    server::{error_response::ErrorResponse, layer::Layer},
+    settings::SharedSettings,
+};
+use futures::future::BoxFuture;
+use hyper::{service::Service, Body, Method, Request, Response, StatusCode};
+use log::{info, warn};
+use rate_gateway_lib::models;
+
+use crate::{
+    config,
+    error_response::{error_response, json_response, IntoResponse},
+    lines::LineReader,
+    validation,
+    writer::{self, SharedRateWriter},
+};
+
+// CSV形式のレート登録を受け付ける
+#[derive(Clone)]
+pub struct CsvLayer {
+    ingest: Ingest,
+}
+
+impl CsvLayer {
+    pub fn new(
+        rate_writer: SharedRateWriter,
+        config: &config::Config,
+        settings: SharedSettings,
+    ) -> Self {
+        CsvLayer {
+            ingest: Ingest {
+                rate_writer,
+                settings,
+                max_body_bytes: config.max_csv_body_bytes,
+                max_rates: config.max_rates_per_request,
+                retention_days: config.rate_retention_days,
+                future_tolerance_seconds: config.rate_future_tolerance_seconds,
+            },
+        }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for CsvLayer {
+    type State = Self;
+    type Service = Csv<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        Csv {
+            inner,
+            ingest: state.ingest,
+        }
+    }
+}
+
+// POST /rates/{pair}/csv を処理し、それ以外は後続へ渡す
+// 生成されたAPIはボディ全体を読み込んでから呼び出されるため、ここで1行ずつ読み込む
+#[derive(Clone)]
+pub struct Csv<T> {
+    inner: T,
+    ingest: Ingest,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for Csv<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        match csv_pair(request.uri().path()) {
+            Some(pair) if request.method() == Method::POST => {
+                let ingest = self.ingest.clone();
+                Box::pin(async move { Ok(ingest.handle(pair, request).await) })
+            }
+            _ => Box::pin(self.inner.call((request, context))),
+        }
+    }
+}
+
+fn csv_pair(path: &str) -> Option<String> {
+    let pair = path.strip_prefix("/rates/")?.strip_suffix("/csv")?;
+    if pair.is_empty() || pair.contains('/') {
+        return None;
+    }
+    Some(pair.to_string())
+}
+
+#[derive(Clone)]
+struct Ingest {
+    rate_writer: SharedRateWriter,
+    settings: SharedSettings,
+    max_body_bytes: u64,
+    max_rates: usize,
+    retention_days: i64,
+    future_tolerance_seconds: i64,
+}
+
+impl Ingest {
+    // JSONでの登録と同じく、件数の上限と日時を確認してから1つのトランザクションで登録する
+    // 書き込みキューを使う場合も、CSVは登録結果（201）を返すため直接登録する
+    async fn handle(&self, pair: String, request: Request<Body>) -> Response<Body> {
+        info!("rates_pair_csv_post(\"{}\")", pair);
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            return error_response(
+                StatusCode::NOT_FOUND,
+                format!("unsupported pair, pair: {}", pair),
+            );
+        }
+
+        let (parts, body) = request.into_parts();
+        let mut reader = match LineReader::new(&parts, body, self.max_body_bytes as usize) {
+            Ok(reader) => reader
+                .with_max_total_bytes(self.max_body_bytes)
+                .with_gzip_detection(),
+            Err(err) => return rejected_response(err.into()),
+        };
+
+        let mut rates: Vec<RateForTraining> = vec![];
+        // レートごとの行番号（1始まり）
+        let mut line_nos: Vec<usize> = vec![];
+        let mut line_no: usize = 0;
+        while let Some(lines) = reader.next_lines().await {
+            let lines = match lines {
+                Ok(lines) => lines,
+                Err(err) => return rejected_response(err.into()),
+            };
+            for line in lines {
+                line_no += 1;
+                let line = String::from_utf8_lossy(&line);
+                match parse_csv_line(&pair, line_no, &line) {
+                    Ok(Some(rate)) => {
+                        rates.push(rate);
+                        line_nos.push(line_no);
+                    }
+                    Ok(None) => {}
+                    Err(err) => return rejected_response(ErrorResponse::from_error(&err)),
+                }
+
+                // 巨大なリクエストでトランザクションを長時間保持しないよう件数を制限する
+                if rates.len() > self.max_rates {
+                    return rejected_response(ErrorResponse::new(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        format!("too many rates, max: {}, split the request", self.max_rates),
+                    ));
+                }
+            }
+        }
+
+        let errors = validation::validate_rate_times(
+            &rates,
+            Utc::now().naive_utc(),
+            self.retention_days,
+            self.future_tolerance_seconds,
+        );
+        if let Some(error) = errors.into_iter().next() {
+            return rejected_response(ErrorResponse::bad_request(&format!(
+                "line: {}, time: {}, {}",
+                line_nos[error.index as usize],
+                error.time.unwrap_or_default(),
+                error.message
+            )));
+        }
+
+        match writer::write_and_forecast(&self.rate_writer, &rates) {
+            Ok(inserted) => {
+                let count = rates.len() as i64;
+                let inserted = inserted as i64;
+                info!(
+                    "inserted csv rates, pair: {}, count: {}, inserted: {}",
+                    pair, count, inserted
+                );
+                json_response(
+                    StatusCode::CREATED,
+                    &models::PostSuccess {
+                        count,
+                        inserted,
+                        skipped: count - inserted,
+                        errors: None,
+                    },
+                )
+            }
+            // コミットの失敗もここで返る（登録されていないため成功扱いにしない）
+            Err(err) => {
+                warn!("failed to insert csv rates, error: {}", err);
+                ErrorResponse::from_error(&err).into_response()
+            }
+        }
+    }
+}
+
+// 「日時,値」形式の1行をレートに変換する（空行やヘッダ行はNone）
+fn parse_csv_line(pair: &str, line_no: usize, line: &str) -> MyResult<Option<RateForTraining>> {
+    let line = line.trim();
+    if line.is_empty() || (line_no == 1 && line.to_lowercase().starts_with("time")) {
+        return Ok(None);
+    }
+
+    let parse_error = |memo: String| -> MyError {
+        MyError::ParseError {
+            param_name: format!("line {}", line_no),
+            value: line.to_string(),
+            memo,
+        }
+    };
+
+    let columns: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+    if columns.len() != 2 {
+        return Err(parse_error(format!(
+            "expected 2 columns (time,value), but got {}",
+            columns.len()
+        )));
+    }
+    let value: f64 = columns[1]
+        .parse()
+        .map_err(|err| parse_error(format!("{}", err)))?;
+    let rate = RateForTraining::new(pair, columns[0], value)
+        .map_err(|err| parse_error(format!("{}", err)))?;
+
+    Ok(Some(rate))
+}
+
+fn rejected_response(error: ErrorResponse) -> Response<Body> {
+    warn!("rejected csv rates, error: {:?}", error);
+    error.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use common_lib::test_support::{shared_settings, TEST_PAIR};
+    use futures::future;
+    use hyper::header::CONTENT_ENCODING;
+
+    use super::*;
+    use crate::{lines::tests::gzip, writer::mock::MockRateWriter};
+
+    // CSV以外のリクエストを受ける後続のサービス
+    #[derive(Clone)]
+    struct Inner;
+
+    impl Service<(Request<Body>, ())> for Inner {
+        type Response = Response<Body>;
+        type Error = hyper::Error;
+        type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: (Request<Body>, ())) -> Self::Future {
+            future::ready(Ok(error_response(
+                StatusCode::IM_A_TEAPOT,
+                "inner".to_string(),
+            )))
+        }
+    }
+
+    fn service(writer: Arc<MockRateWriter>, config: config::Config) -> Csv<Inner> {
+        let layer = CsvLayer::new(writer, &config, shared_settings(&[TEST_PAIR]));
+        <CsvLayer as Layer<(), Inner>>::layer(layer, Inner)
+    }
+
+    fn line(minutes_ago: i64, value: f64) -> String {
+        let time = Utc::now().naive_utc() - chrono::Duration::minutes(minutes_ago);
+        format!("{},{}\n", time.format("%Y-%m-%d %H:%M:%S"), value)
+    }
+
+    // 行の途中で分割したチャンクを順に送るボディ
+    fn request(body: Vec<u8>, chunk_size: usize) -> Request<Body> {
+        let chunks: Vec<Result<Vec<u8>, std::io::Error>> =
+            body.chunks(chunk_size).map(|c| Ok(c.to_vec())).collect();
+        Request::builder()
+            .method(Method::POST)
+            .uri(format!("/rates/{}/csv", TEST_PAIR))
+            .body(Body::wrap_stream(futures::stream::iter(chunks)))
+            .unwrap()
+    }
+
+    async fn call(
+        service: &mut Csv<Inner>,
+        request: Request<Body>,
+    ) -> (StatusCode, serde_json::Value) {
+        let response = service.call((request, ())).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_valid_csv() {
+        let csv = format!(
+            "time,value\n{}{}\n{}",
+            line(3, 100.0),
+            line(2, 100.1),
+            line(1, 100.2)
+        );
+
+        // 圧縮の有無（Content-Encoding の有無）にかかわらず登録できる
+        let bodies = vec![
+            (csv.as_bytes().to_vec(), None),
+            (gzip(csv.as_bytes()), None),
+            (gzip(csv.as_bytes()), Some("gzip")),
+        ];
+        for (body, encoding) in bodies {
+            let writer = Arc::new(MockRateWriter::default());
+            let mut service = service(writer.clone(), config::tests::config());
+            let mut request = request(body, 7);
+            if let Some(encoding) = encoding {
+                request
+                    .headers_mut()
+                    .insert(CONTENT_ENCODING, encoding.parse().unwrap());
+            }
+            let (status, body) = call(&mut service, request).await;
+
+            assert_eq!(status, StatusCode::CREATED, "{}", body);
+            assert_eq!(body["count"], 3);
+            assert_eq!(body["inserted"], 3);
+            assert_eq!(body["skipped"], 0);
+            assert_eq!(writer.persisted().len(), 3);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_forecast_forwarding() {
+        let writer = Arc::new(MockRateWriter::forwarding());
+        let mut service = service(writer.clone(), config::tests::config());
+
+        let csv = format!("{}{}", line(2, 100.0), line(1, 100.1));
+        let (status, body) = call(&mut service, request(csv.into_bytes(), 100)).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+
+        // 予想は応答後にバックグラウンドで行う
+        for _ in 0..100 {
+            if !writer.forecasted().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(
+            writer.forecasted(),
+            vec![(TEST_PAIR.to_string(), format!("forecast-{}", TEST_PAIR))]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_invalid_csv() {
+        struct Param {
+            title: String,
+            csv: String,
+            status: StatusCode,
+            message: String,
+        }
+
+        let params = vec![
+            Param {
+                title: "列数が不正".to_string(),
+                csv: format!("{}2022-01-01 00:00:00\n", line(2, 100.0)),
+                status: StatusCode::BAD_REQUEST,
+                message: "line 2".to_string(),
+            },
+            Param {
+                title: "未来の日時".to_string(),
+                csv: format!("{}{}", line(2, 100.0), line(-10, 100.1)),
+                status: StatusCode::BAD_REQUEST,
+                message: "line: 2".to_string(),
+            },
+            Param {
+                title: "保持期間より古い日時".to_string(),
+                csv: "2000-01-01 00:00:00,100.0\n".to_string(),
+                status: StatusCode::BAD_REQUEST,
+                message: "line: 1".to_string(),
+            },
+            Param {
+                title: "直前のレートより前の日時".to_string(),
+                csv: format!("{}{}", line(1, 100.0), line(2, 100.1)),
+                status: StatusCode::BAD_REQUEST,
+                message: "line: 2".to_string(),
+            },
+            Param {
+                title: "件数が上限を超える".to_string(),
+                csv: format!("{}{}{}", line(3, 100.0), line(2, 100.1), line(1, 100.2)),
+                status: StatusCode::PAYLOAD_TOO_LARGE,
+                message: "too many rates, max: 2, split the request".to_string(),
+            },
+        ];
+
+        for p in params {
+            let writer = Arc::new(MockRateWriter::default());
+            let config = config::Config {
+                max_rates_per_request: 2,
+                ..config::tests::config()
+            };
+            let mut service = service(writer.clone(), config);
+            let (status, body) = call(&mut service, request(p.csv.into_bytes(), 100)).await;
+
+            assert_eq!(status, p.status, "{}, {}", p.title, body);
+            let message = body["message"].as_str().unwrap();
+            assert!(message.contains(&p.message), "{}, {}", p.title, message);
+            // 1件でも不正な場合は何も登録しない
+            assert!(writer.persisted().is_empty(), "{}", p.title);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_oversized_gzip() {
+        // 圧縮後は小さくても、展開後に上限を超える場合は読み込みを打ち切る
+        let csv = "2022-01-01 00:00:00,100.0\n".repeat(100_000);
+        let body = gzip(csv.as_bytes());
+        assert!(body.len() < csv.len() / 10);
+
+        let writer = Arc::new(MockRateWriter::default());
+        let config = config::Config {
+            max_csv_body_bytes: 1024,
+            ..config::tests::config()
+        };
+        let mut service = service(writer.clone(), config);
+        let (status, body) = call(&mut service, request(body, 1024)).await;
+
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE, "{}", body);
+        assert_eq!(
+            body["message"],
+            "request body is too large, limit: 1024 bytes, split the request"
+        );
+        assert!(writer.persisted().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_not_csv() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), config::tests::config());
+
+        let mut request = request(line(1, 100.0).into_bytes(), 100);
+        *request.uri_mut() = format!("/rates/{}", TEST_PAIR).parse().unwrap();
+        let (status, _) = call(&mut service, request).await;
+
+        assert_eq!(status, StatusCode::IM_A_TEAPOT);
+        assert!(writer.persisted().is_empty());
+    }
+}
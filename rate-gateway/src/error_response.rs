@@ -0,0 +1,97 @@
+use common_lib::server::error_response::ErrorResponse;
+use hyper::{
+    header::{CONTENT_TYPE, RETRY_AFTER},
+    Body, Response, StatusCode,
+};
+use rate_gateway_lib::{
+    models, RatesPairCsvPostResponse, RatesPairDeleteResponse, RatesPairGapsGetResponse,
+    RatesPairGetResponse, RatesPairPostResponse,
+};
+use serde::Serialize;
+
+// 共通のエラーレスポンスを各APIのレスポンスに変換する
+// （ErrorResponse もレスポンスの型もこのクレートの外で定義されているため、From は実装できない）
+pub trait IntoResponse<R> {
+    fn into_response(self) -> R;
+}
+
+// APIに定義されていないステータスは500として返す
+macro_rules! impl_into_response {
+    ($response:ident { $($status:literal => $variant:ident),* }) => {
+        impl IntoResponse<$response> for ErrorResponse {
+            fn into_response(self) -> $response {
+                let retry_after = self.retry_after;
+                let body = models::Error { message: self.message };
+                match self.status.as_u16() {
+                    503 => $response::Status503 { body, retry_after },
+                    $($status => $response::$variant(body),)*
+                    _ => $response::Status500(body),
+                }
+            }
+        }
+    };
+}
+
+impl_into_response!(RatesPairCsvPostResponse {
+    400 => Status400,
+    404 => Status404
+});
+impl_into_response!(RatesPairDeleteResponse {
+    400 => Status400,
+    401 => Status401,
+    404 => Status404
+});
+impl_into_response!(RatesPairGapsGetResponse { 400 => Status400 });
+impl_into_response!(RatesPairGetResponse { 400 => Status400 });
+
+// 400は項目ごとのエラーを返す形式のため、エラーの一覧を空にして返す
+impl IntoResponse<RatesPairPostResponse> for ErrorResponse {
+    fn into_response(self) -> RatesPairPostResponse {
+        let retry_after = self.retry_after;
+        match self.status.as_u16() {
+            400 => RatesPairPostResponse::Status400(models::ValidationError {
+                message: self.message,
+                errors: vec![],
+            }),
+            404 => RatesPairPostResponse::Status404(models::Error {
+                message: self.message,
+            }),
+            503 => RatesPairPostResponse::Status503 {
+                body: models::Error {
+                    message: self.message,
+                },
+                retry_after,
+            },
+            _ => RatesPairPostResponse::Status500(models::Error {
+                message: self.message,
+            }),
+        }
+    }
+}
+
+// 生成されたAPIを通さずに処理するリクエスト（NDJSON・CSV）のレスポンス
+impl IntoResponse<Response<Body>> for ErrorResponse {
+    fn into_response(self) -> Response<Body> {
+        let mut response = error_response(self.status, self.message);
+        if let Some(retry_after) = self.retry_after {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, retry_after.into());
+        }
+        response
+    }
+}
+
+pub fn error_response(status: StatusCode, message: String) -> Response<Body> {
+    json_response(status, &models::Error { message })
+}
+
+pub fn json_response<T: Serialize>(status: StatusCode, body: &T) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(
+            serde_json::to_string(body).expect("impossible to fail to serialize"),
+        ))
+        .expect("Unable to create response")
+}
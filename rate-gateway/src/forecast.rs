@@ -0,0 +1,103 @@
+use chrono::Duration;
+use common_lib::{
+    clock::Clock,
+    domain::model::RateForForecast,
+    error::MyResult,
+    forecast::{self, Forecaster},
+    mysql::client::{Client, DefaultClient, Transaction},
+    settings::SharedSettings,
+};
+use log::{info, warn};
+
+// 学習用レートの登録後に直近のレートを予想用レートとして登録する
+#[derive(Clone)]
+pub struct ForecastForwarder {
+    input_size: usize,
+    expire_minutes: i64,
+    // 登録直後に予想する場合のみ設定する
+    forecaster: Option<(Forecaster, SharedSettings)>,
+}
+
+impl ForecastForwarder {
+    // 無効な場合や input_size が 0 の場合は登録しない
+    pub fn new(enabled: bool, input_size: usize, expire_minutes: i64) -> Option<Self> {
+        if !enabled || input_size == 0 {
+            return None;
+        }
+        Some(ForecastForwarder {
+            input_size,
+            expire_minutes,
+            forecaster: None,
+        })
+    }
+
+    pub fn with_forecaster(
+        mut self,
+        forecaster: Option<Forecaster>,
+        settings: SharedSettings,
+    ) -> Self {
+        self.forecaster = forecaster.map(|f| (f, settings));
+        self
+    }
+
+    // 直近 input_size 件のレートを予想用レートとして登録し、登録したIDを返す
+    // レートが足りない場合や同じ履歴が登録済みの場合は登録しない
+    pub fn forward(
+        &self,
+        mysql_cli: &DefaultClient,
+        tx: &mut Transaction,
+        pair: &str,
+        clock: &dyn Clock,
+    ) -> MyResult<Option<String>> {
+        let rates = mysql_cli.select_latest_rates_for_training(tx, pair, self.input_size as u32)?;
+        if rates.len() < self.input_size {
+            return Ok(None);
+        }
+
+        let latest = rates[rates.len() - 1].recorded_at;
+        let histories = rates.iter().map(|r| r.rate).collect();
+        let expire = (clock.now() + Duration::minutes(self.expire_minutes)).naive_utc();
+        let rate = RateForForecast::new(
+            pair.to_string(),
+            histories,
+            expire,
+            format!("forwarded by rate-gateway, latest: {}", latest),
+            None,
+        )?;
+
+        if mysql_cli
+            .select_rates_for_forecast_duplicated(tx, &rate, self.expire_minutes)?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        let id = mysql_cli.insert_rates_for_forecast(tx, &rate)?;
+        info!(
+            "forwarded rates for forecast, pair: {}, latest: {}, id: {}",
+            pair, latest, id
+        );
+        Ok(Some(id))
+    }
+
+    // 登録した予想用レート（通貨ペアとIDの組）を予想する（コミット後に呼び出す）
+    // 失敗しても forecast-batch が後から予想するため、ログの出力のみ行う
+    pub fn forecast(&self, mysql_cli: &DefaultClient, forwarded: &[(String, String)]) {
+        let (forecaster, settings) = match &self.forecaster {
+            Some(f) => f,
+            None => return,
+        };
+        let settings = settings.get();
+        for (pair, id) in forwarded {
+            match forecaster.forecast_rate(mysql_cli, &settings, id) {
+                Ok(outcome) => forecast::observe(pair, &outcome),
+                Err(err) => {
+                    warn!(
+                        "failed to forecast on ingest, rate_id: {}, error: {}",
+                        id, err
+                    );
+                }
+            }
+        }
+    }
+}
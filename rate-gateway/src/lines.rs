@@ -0,0 +1,290 @@
+use std::io::Write;
+
+use common_lib::server::error_response::ErrorResponse;
+use flate2::write::GzDecoder;
+use hyper::{body::HttpBody, header::CONTENT_ENCODING, http::request::Parts, Body, StatusCode};
+
+// 展開しながら読み込む場合に、1回に展開する圧縮データのサイズ
+// 圧縮率の高いデータでも、1回の展開で大量のメモリを使わないようにする
+const DECODE_SLICE_BYTES: usize = 1024;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+// リクエストボディの読み込み時の問題
+#[derive(Debug, PartialEq)]
+pub enum LineError {
+    // Content-Encoding が gzip 以外
+    UnsupportedEncoding(String),
+    Read(String),
+    Decode(String),
+    // 改行の無いまま 1行の上限を超えた
+    LineTooLong { max_bytes: usize },
+    // 展開後の合計が上限を超えた
+    BodyTooLarge { max_bytes: u64 },
+}
+
+impl From<LineError> for ErrorResponse {
+    fn from(err: LineError) -> Self {
+        match err {
+            LineError::UnsupportedEncoding(v) => ErrorResponse::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("unsupported content-encoding: {}", v),
+            ),
+            LineError::Read(err) => ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                format!("failed to read body, {}", err),
+            ),
+            LineError::Decode(err) => ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                format!("failed to decode body, {}", err),
+            ),
+            LineError::LineTooLong { max_bytes } => ErrorResponse::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("line is too long, max: {} bytes", max_bytes),
+            ),
+            LineError::BodyTooLarge { max_bytes } => ErrorResponse::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "request body is too large, limit: {} bytes, split the request",
+                    max_bytes
+                ),
+            ),
+        }
+    }
+}
+
+// リクエストボディを全体を読み込まずに1行ずつ取り出す
+// Content-Encoding: gzip の場合は展開しながら読み込む
+pub struct LineReader {
+    body: Body,
+    decoder: Option<GzDecoder<Vec<u8>>>,
+    // gzip かどうかを判定するまで保持しておく先頭部分
+    head: Option<Vec<u8>>,
+    // 改行の届いていない行
+    buf: Vec<u8>,
+    max_line_bytes: usize,
+    max_total_bytes: Option<u64>,
+    total: u64,
+    finished: bool,
+}
+
+impl LineReader {
+    pub fn new(parts: &Parts, body: Body, max_line_bytes: usize) -> Result<Self, LineError> {
+        let encoding = parts
+            .headers
+            .get(CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim().to_lowercase());
+        let decoder = match encoding.as_deref() {
+            None | Some("") | Some("identity") => None,
+            Some("gzip") | Some("x-gzip") => Some(GzDecoder::new(vec![])),
+            Some(v) => return Err(LineError::UnsupportedEncoding(v.to_string())),
+        };
+        Ok(LineReader {
+            body,
+            decoder,
+            head: None,
+            buf: vec![],
+            max_line_bytes,
+            max_total_bytes: None,
+            total: 0,
+            finished: false,
+        })
+    }
+
+    // 展開後のボディ全体の上限
+    pub fn with_max_total_bytes(mut self, max_total_bytes: u64) -> Self {
+        self.max_total_bytes = Some(max_total_bytes);
+        self
+    }
+
+    // Content-Encoding が無い場合も、先頭が gzip のマジックナンバーなら展開する
+    pub fn with_gzip_detection(mut self) -> Self {
+        if self.decoder.is_none() {
+            self.head = Some(vec![]);
+        }
+        self
+    }
+
+    // 届いたチャンクまでで揃った行（改行を含む）をまとめて返す
+    // ボディの終わりでは改行の無い最後の行も返し、それ以降は None を返す
+    pub async fn next_lines(&mut self) -> Option<Result<Vec<Vec<u8>>, LineError>> {
+        if self.finished {
+            return None;
+        }
+
+        let mut lines = vec![];
+        match self.body.data().await {
+            Some(Ok(data)) => {
+                if let Err(err) = self.feed(&data, &mut lines) {
+                    return Some(Err(err));
+                }
+            }
+            Some(Err(err)) => return Some(Err(LineError::Read(err.to_string()))),
+            None => {
+                self.finished = true;
+                // 判定できないほど短いボディは gzip ではない
+                if let Some(head) = self.head.take() {
+                    if let Err(err) = self.push_decoded(&head, &mut lines) {
+                        return Some(Err(err));
+                    }
+                }
+                if let Some(decoder) = self.decoder.take() {
+                    match decoder.finish() {
+                        Ok(rest) => {
+                            if let Err(err) = self.push_decoded(&rest, &mut lines) {
+                                return Some(Err(err));
+                            }
+                        }
+                        Err(err) => return Some(Err(LineError::Decode(err.to_string()))),
+                    }
+                }
+                if !self.buf.is_empty() {
+                    lines.push(std::mem::take(&mut self.buf));
+                }
+            }
+        }
+        Some(Ok(lines))
+    }
+
+    fn feed(&mut self, data: &[u8], lines: &mut Vec<Vec<u8>>) -> Result<(), LineError> {
+        if let Some(head) = self.head.as_mut() {
+            head.extend_from_slice(data);
+            if head.len() < GZIP_MAGIC.len() {
+                return Ok(());
+            }
+            let head = self.head.take().expect("head must exist");
+            if head.starts_with(&GZIP_MAGIC) {
+                self.decoder = Some(GzDecoder::new(vec![]));
+            }
+            return self.feed(&head, lines);
+        }
+        if self.decoder.is_none() {
+            return self.push_decoded(data, lines);
+        }
+        for slice in data.chunks(DECODE_SLICE_BYTES) {
+            let decoder = self.decoder.as_mut().expect("decoder must exist");
+            decoder
+                .write_all(slice)
+                .map_err(|err| LineError::Decode(err.to_string()))?;
+            let decoded = std::mem::take(decoder.get_mut());
+            self.push_decoded(&decoded, lines)?;
+        }
+        Ok(())
+    }
+
+    // 新しく届いた部分だけ改行を探し、取り出した行の分はまとめて取り除く
+    fn push_decoded(&mut self, data: &[u8], lines: &mut Vec<Vec<u8>>) -> Result<(), LineError> {
+        self.total += data.len() as u64;
+        if let Some(max_bytes) = self.max_total_bytes {
+            if self.total > max_bytes {
+                return Err(LineError::BodyTooLarge { max_bytes });
+            }
+        }
+
+        let scanned = self.buf.len();
+        self.buf.extend_from_slice(data);
+        let mut start = 0;
+        for (i, b) in self.buf.iter().enumerate().skip(scanned) {
+            if *b == b'\n' {
+                lines.push(self.buf[start..=i].to_vec());
+                start = i + 1;
+            }
+        }
+        self.buf.drain(..start);
+
+        if self.buf.len() > self.max_line_bytes {
+            return Err(LineError::LineTooLong {
+                max_bytes: self.max_line_bytes,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hyper::Request;
+
+    use super::*;
+
+    pub fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    // 指定したサイズごとに分割したチャンクを順に送るボディ
+    fn reader(data: Vec<u8>, chunk_size: usize, encoding: Option<&str>) -> LineReader {
+        let chunks: Vec<Result<Vec<u8>, std::io::Error>> =
+            data.chunks(chunk_size).map(|c| Ok(c.to_vec())).collect();
+        let mut builder = Request::builder();
+        if let Some(encoding) = encoding {
+            builder = builder.header(CONTENT_ENCODING, encoding);
+        }
+        let (parts, body) = builder
+            .body(Body::wrap_stream(futures::stream::iter(chunks)))
+            .unwrap()
+            .into_parts();
+        LineReader::new(&parts, body, 64).unwrap()
+    }
+
+    async fn read_all(reader: &mut LineReader) -> Result<Vec<String>, LineError> {
+        let mut all = vec![];
+        while let Some(lines) = reader.next_lines().await {
+            all.extend(lines?.into_iter().map(|l| String::from_utf8(l).unwrap()));
+        }
+        Ok(all)
+    }
+
+    #[tokio::test]
+    async fn test_for_next_lines() {
+        let data = "a,1\nbb,2\n\nccc,3";
+        let expected = vec!["a,1\n", "bb,2\n", "\n", "ccc,3"];
+
+        // 行の途中で分割されても、圧縮されていても同じ行を返す
+        for chunk_size in [1, 3, 100] {
+            let mut r = reader(data.as_bytes().to_vec(), chunk_size, None);
+            assert_eq!(read_all(&mut r).await.unwrap(), expected);
+
+            let mut r = reader(gzip(data.as_bytes()), chunk_size, Some("gzip"));
+            assert_eq!(read_all(&mut r).await.unwrap(), expected);
+
+            // Content-Encoding が無くても、先頭から gzip と判定して展開する
+            for body in [data.as_bytes().to_vec(), gzip(data.as_bytes())] {
+                let mut r = reader(body, chunk_size, None).with_gzip_detection();
+                assert_eq!(read_all(&mut r).await.unwrap(), expected);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_for_next_lines_limits() {
+        // 改行が届く前に1行の上限を超えた時点で打ち切る
+        let mut r = reader(vec![b'a'; 65], 10, None);
+        assert_eq!(
+            read_all(&mut r).await,
+            Err(LineError::LineTooLong { max_bytes: 64 })
+        );
+
+        // 圧縮後は小さくても、展開後に上限を超える場合は打ち切る
+        let data = "2022-01-01 00:00:00,100.0\n".repeat(100_000);
+        let body = gzip(data.as_bytes());
+        assert!(body.len() < data.len() / 10);
+        let mut r = reader(body, 1024, Some("gzip")).with_max_total_bytes(1024);
+        assert_eq!(
+            read_all(&mut r).await,
+            Err(LineError::BodyTooLarge { max_bytes: 1024 })
+        );
+
+        let (parts, body) = Request::builder()
+            .header(CONTENT_ENCODING, "br")
+            .body(Body::empty())
+            .unwrap()
+            .into_parts();
+        assert!(matches!(
+            LineReader::new(&parts, body, 64),
+            Err(LineError::UnsupportedEncoding(_))
+        ));
+    }
+}
@@ -1,22 +1,55 @@
 extern crate common_lib;
 extern crate rate_gateway_lib;
 
-use common_lib::mysql;
+use common_lib::{config::HttpConfig, error_report, logger, mysql, settings, telemetry};
 use log::{error, info};
 
 mod config;
+mod csv;
+mod error_response;
+mod forecast;
+mod lines;
+mod mqtt;
+mod ndjson;
+mod queue;
 mod server;
+mod validation;
+mod websocket;
+mod writer;
 
 fn init_logger() {
-    env_logger::init();
+    logger::init("rate-gateway");
 }
 
 #[tokio::main]
 async fn main() {
     init_logger();
 
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init("rate-gateway") {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init("rate-gateway") {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
     let config: config::Config;
-    match envy::from_env::<config::Config>() {
+    match common_lib::config::load::<config::Config>() {
         Ok(c) => {
             config = c;
         }
@@ -25,9 +58,28 @@ async fn main() {
             return;
         }
     }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let http_config: HttpConfig;
+    match common_lib::config::load::<HttpConfig>() {
+        Ok(c) => {
+            http_config = c;
+        }
+        Err(err) => {
+            error!("failed to load http config, error: {}", err);
+            return;
+        }
+    }
+    if let Err(err) = http_config.validate() {
+        error!("invalid http config, error: {}", err);
+        return;
+    }
 
     let mysql_cli: mysql::client::DefaultClient;
-    match mysql::util::make_cli() {
+    match mysql::util::make_server_cli() {
         Ok(cli) => {
             mysql_cli = cli;
         }
@@ -37,7 +89,44 @@ async fn main() {
         }
     }
 
-    let addr = config.get_address();
-    info!("start RateGateway {}", addr);
-    server::run(&addr, mysql_cli).await;
+    // 取り扱う通貨ペアは再起動せずに変更できるよう、SIGHUPまたは一定間隔で読み込み直す
+    let runtime_settings = settings::SharedSettings::new();
+    if let Err(err) = runtime_settings.reload(&mysql_cli) {
+        error!("failed to load runtime settings, error: {}", err);
+        return;
+    }
+    match settings::reload_interval_from_env() {
+        Ok(interval) => {
+            if let Err(err) = runtime_settings.watch(mysql_cli.clone(), interval, |_| {}) {
+                error!("failed to watch runtime settings, error: {}", err);
+                return;
+            }
+        }
+        Err(err) => {
+            error!("failed to load settings config, error: {}", err);
+            return;
+        }
+    }
+
+    let forecaster: Option<common_lib::forecast::Forecaster>;
+    match common_lib::forecast::on_ingest_from_env() {
+        Ok(f) => {
+            forecaster = f;
+        }
+        Err(err) => {
+            error!("failed to load forecast config, error: {}", err);
+            return;
+        }
+    }
+    info!("forecast on ingest: {}", forecaster.is_some());
+
+    info!("start RateGateway {}", http_config.get_address());
+    server::run(
+        &http_config,
+        mysql_cli,
+        &config,
+        runtime_settings,
+        forecaster,
+    )
+    .await;
 }
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use log::{info, warn};
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+
+use crate::queue::{self, WriteQueue};
+
+// 接続が切れた場合に再接続を試みるまでの秒数
+const RECONNECT_INTERVAL_SECONDS: u64 = 5;
+
+// MQTTで購読したティックをキューへ追加する
+pub async fn run_subscriber(
+    host: String,
+    port: u16,
+    topics: Vec<(String, String)>,
+    queue: WriteQueue,
+) {
+    let mut options = MqttOptions::new("rate-gateway", host.as_str(), port);
+    options.set_keep_alive(Duration::from_secs(30));
+    let (client, mut eventloop) = AsyncClient::new(options, 100);
+
+    loop {
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                info!("mqtt connected, host: {}:{}", host, port);
+                // 再接続時にも購読し直す
+                for (topic, _) in &topics {
+                    if let Err(err) = client.subscribe(topic, QoS::AtLeastOnce).await {
+                        warn!("failed to subscribe, topic: {}, error: {}", topic, err);
+                    }
+                }
+            }
+            Ok(Event::Incoming(Packet::Publish(publish))) => {
+                let pair = match find_pair(&topics, &publish.topic) {
+                    Some(pair) => pair,
+                    None => {
+                        warn!("unknown topic, topic: {}", publish.topic);
+                        continue;
+                    }
+                };
+                match queue::parse_tick(pair, &publish.payload) {
+                    Ok(rate) => {
+                        if !queue.push(rate) {
+                            warn!(
+                                "write queue is full, tick dropped, topic: {}",
+                                publish.topic
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        warn!(
+                            "invalid tick, topic: {}, payload: {}, error: {}",
+                            publish.topic,
+                            String::from_utf8_lossy(&publish.payload),
+                            err
+                        );
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                warn!(
+                    "mqtt connection error, host: {}:{}, error: {}",
+                    host, port, err
+                );
+                tokio::time::sleep(Duration::from_secs(RECONNECT_INTERVAL_SECONDS)).await;
+            }
+        }
+    }
+}
+
+fn find_pair<'a>(topics: &'a [(String, String)], topic: &str) -> Option<&'a str> {
+    topics
+        .iter()
+        .find(|(pattern, _)| topic_matches(pattern, topic))
+        .map(|(_, pair)| pair.as_str())
+}
+
+// MQTTのワイルドカード（+: 1階層、#: 以降すべて）を考慮してトピックを比較する
+fn topic_matches(pattern: &str, topic: &str) -> bool {
+    let mut levels = topic.split('/');
+    for p in pattern.split('/') {
+        match (p, levels.next()) {
+            ("#", _) => return true,
+            ("+", Some(_)) => {}
+            (p, Some(level)) if p == level => {}
+            _ => return false,
+        }
+    }
+    levels.next().is_none()
+}
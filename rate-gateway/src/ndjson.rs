@@ -0,0 +1,487 @@
+use std::task::{Context, Poll};
+
+use chrono::Utc;
+use common_lib::{
+    domain::model::RateForTraining,
+    error::MyError,
+    server::{error_response::ErrorResponse, layer::Layer},
+    settings::SharedSettings,
+};
+use futures::future::BoxFuture;
+use hyper::{header::CONTENT_TYPE, service::Service, Body, Method, Request, Response, StatusCode};
+use log::{info, warn};
+use rate_gateway_lib::models;
+
+use crate::{
+    config,
+    error_response::{error_response, json_response, IntoResponse},
+    lines::LineReader,
+    queue,
+    server::INSERT_CHUNK_SIZE,
+    validation,
+    writer::{self, SharedRateWriter},
+};
+
+const CONTENT_TYPE_NDJSON: &str = "application/x-ndjson";
+
+// NDJSON形式のレート登録を受け付ける
+#[derive(Clone)]
+pub struct NdjsonLayer {
+    ingest: Ingest,
+}
+
+impl NdjsonLayer {
+    // 1行あたりの上限は通常のリクエストボディの上限と同じにする
+    pub fn new(
+        rate_writer: SharedRateWriter,
+        config: &config::Config,
+        settings: SharedSettings,
+        max_line_bytes: u64,
+    ) -> Self {
+        NdjsonLayer {
+            ingest: Ingest {
+                rate_writer,
+                settings,
+                max_line_bytes: max_line_bytes as usize,
+                retention_days: config.rate_retention_days,
+                future_tolerance_seconds: config.rate_future_tolerance_seconds,
+            },
+        }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for NdjsonLayer {
+    type State = Self;
+    type Service = Ndjson<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        Ndjson {
+            inner,
+            ingest: state.ingest,
+        }
+    }
+}
+
+// Content-Type が application/x-ndjson の POST /rates/{pair} を処理し、それ以外は後続へ渡す
+// ボディ全体を読み込まずに1行ずつ変換し、一定件数ごとに登録する
+// Content-Encoding: gzip の場合は展開しながら読み込む
+#[derive(Clone)]
+pub struct Ndjson<T> {
+    inner: T,
+    ingest: Ingest,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for Ndjson<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        match rates_pair(request.uri().path()) {
+            Some(pair) if is_ndjson_request(&request) => {
+                let ingest = self.ingest.clone();
+                Box::pin(async move { Ok(ingest.handle(pair, request).await) })
+            }
+            _ => Box::pin(self.inner.call((request, context))),
+        }
+    }
+}
+
+fn rates_pair(path: &str) -> Option<String> {
+    let pair = path.strip_prefix("/rates/")?;
+    if pair.is_empty() || pair.contains('/') {
+        return None;
+    }
+    Some(pair.to_string())
+}
+
+fn is_ndjson_request(request: &Request<Body>) -> bool {
+    request.method() == Method::POST
+        && request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim().starts_with(CONTENT_TYPE_NDJSON))
+            .unwrap_or(false)
+}
+
+#[derive(Clone)]
+struct Ingest {
+    rate_writer: SharedRateWriter,
+    settings: SharedSettings,
+    max_line_bytes: usize,
+    retention_days: i64,
+    future_tolerance_seconds: i64,
+}
+
+impl Ingest {
+    async fn handle(&self, pair: String, request: Request<Body>) -> Response<Body> {
+        info!("rates_pair_post_ndjson(\"{}\")", pair);
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            return error_response(
+                StatusCode::NOT_FOUND,
+                format!("unsupported pair, pair: {}", pair),
+            );
+        }
+
+        let (parts, body) = request.into_parts();
+        let mut reader = match LineReader::new(&parts, body, self.max_line_bytes) {
+            Ok(reader) => reader,
+            Err(err) => return line_error_response(&pair, err.into()),
+        };
+
+        let mut rates: Vec<RateForTraining> = vec![];
+        // レートごとの行番号（0始まり）
+        let mut indexes: Vec<i32> = vec![];
+        // 登録済みの最後のレート（日時が前のまとまりより後かの確認に使う）
+        let mut previous: Option<RateForTraining> = None;
+        let mut line_no: i32 = 0;
+        let mut count: i64 = 0;
+        let mut inserted: i64 = 0;
+        loop {
+            // 最後の行は改行が無くても1行として扱う
+            let (lines, finished) = match reader.next_lines().await {
+                Some(Ok(lines)) => (lines, false),
+                Some(Err(err)) => return line_error_response(&pair, err.into()),
+                None => (vec![], true),
+            };
+
+            for line in lines {
+                let index = line_no;
+                line_no += 1;
+                let line = String::from_utf8_lossy(&line);
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                match queue::parse_tick(&pair, line.as_bytes()) {
+                    Ok(rate) => {
+                        rates.push(rate);
+                        indexes.push(index);
+                    }
+                    Err(message) => {
+                        return invalid_response(
+                            inserted,
+                            models::RecordError {
+                                index,
+                                time: None,
+                                message,
+                            },
+                        );
+                    }
+                }
+            }
+
+            if rates.len() >= INSERT_CHUNK_SIZE || (finished && !rates.is_empty()) {
+                let errors = validation::validate_rate_times_after(
+                    previous.as_ref(),
+                    &rates,
+                    Utc::now().naive_utc(),
+                    self.retention_days,
+                    self.future_tolerance_seconds,
+                );
+                if let Some(mut error) = errors.into_iter().next() {
+                    error.index = indexes[error.index as usize];
+                    return invalid_response(inserted, error);
+                }
+
+                let chunk = std::mem::take(&mut rates);
+                indexes.clear();
+                match writer::write_and_forecast(&self.rate_writer, &chunk) {
+                    Ok(n) => {
+                        count += chunk.len() as i64;
+                        inserted += n as i64;
+                        previous = chunk.last().cloned();
+                    }
+                    Err(err) => return db_error_response(&err, inserted),
+                }
+            }
+
+            if finished {
+                break;
+            }
+        }
+
+        info!(
+            "inserted ndjson rates, pair: {}, count: {}, inserted: {}",
+            pair, count, inserted
+        );
+        json_response(
+            StatusCode::CREATED,
+            &models::PostSuccess {
+                count,
+                inserted,
+                skipped: count - inserted,
+                errors: None,
+            },
+        )
+    }
+}
+
+fn line_error_response(pair: &str, error: ErrorResponse) -> Response<Body> {
+    warn!(
+        "failed to read ndjson body, pair: {}, error: {:?}",
+        pair, error
+    );
+    error.into_response()
+}
+
+// 不正な行より前の行は登録済みのため、登録済みの件数をメッセージに含める
+fn invalid_response(inserted: i64, error: models::RecordError) -> Response<Body> {
+    warn!("invalid ndjson rate, error: {:?}", error);
+    json_response(
+        StatusCode::BAD_REQUEST,
+        &models::ValidationError {
+            message: format!(
+                "parameter is invalid, line: {}, inserted before this line: {}",
+                error.index + 1,
+                inserted
+            ),
+            errors: vec![error],
+        },
+    )
+}
+
+fn db_error_response(err: &MyError, inserted: i64) -> Response<Body> {
+    warn!(
+        "failed to insert ndjson rates, inserted: {}, error: {}",
+        inserted, err
+    );
+    let error = ErrorResponse::from_error_with_tags(err, &[("inserted", &inserted.to_string())]);
+    ErrorResponse {
+        message: format!("{}, inserted: {}", error.message, inserted),
+        ..error
+    }
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use common_lib::test_support::{shared_settings, TEST_PAIR};
+    use futures::future;
+    use hyper::header::CONTENT_ENCODING;
+
+    use super::*;
+    use crate::{lines::tests::gzip, writer::mock::MockRateWriter};
+
+    // NDJSON以外のリクエストを受ける後続のサービス
+    #[derive(Clone)]
+    struct Inner;
+
+    impl Service<(Request<Body>, ())> for Inner {
+        type Response = Response<Body>;
+        type Error = hyper::Error;
+        type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: (Request<Body>, ())) -> Self::Future {
+            future::ready(Ok(error_response(
+                StatusCode::IM_A_TEAPOT,
+                "inner".to_string(),
+            )))
+        }
+    }
+
+    fn service(writer: Arc<MockRateWriter>, max_line_bytes: u64) -> Ndjson<Inner> {
+        let layer = NdjsonLayer::new(
+            writer,
+            &config::tests::config(),
+            shared_settings(&[TEST_PAIR]),
+            max_line_bytes,
+        );
+        <NdjsonLayer as Layer<(), Inner>>::layer(layer, Inner)
+    }
+
+    fn line(minutes_ago: i64, value: f64) -> String {
+        line_at_seconds_ago(minutes_ago * 60, value)
+    }
+
+    fn line_at_seconds_ago(seconds_ago: i64, value: f64) -> String {
+        let time = Utc::now().naive_utc() - chrono::Duration::seconds(seconds_ago);
+        format!(
+            "{{\"time\":\"{}\",\"value\":{}}}\n",
+            time.format("%Y-%m-%d %H:%M:%S"),
+            value
+        )
+    }
+
+    // 行の途中で分割したチャンクを順に送るボディ
+    fn request<B: Into<Vec<u8>>>(chunks: Vec<B>) -> Request<Body> {
+        let chunks: Vec<Result<Vec<u8>, std::io::Error>> =
+            chunks.into_iter().map(|c| Ok(c.into())).collect();
+        let stream = futures::stream::iter(chunks);
+        Request::builder()
+            .method(Method::POST)
+            .uri(format!("/rates/{}", TEST_PAIR))
+            .header(CONTENT_TYPE, CONTENT_TYPE_NDJSON)
+            .body(Body::wrap_stream(stream))
+            .unwrap()
+    }
+
+    async fn call(
+        service: &mut Ndjson<Inner>,
+        request: Request<Body>,
+    ) -> (StatusCode, serde_json::Value) {
+        let response = service.call((request, ())).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_valid_stream() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 1024);
+
+        let body = format!("{}{}{}", line(3, 100.0), line(2, 100.1), line(1, 100.2));
+        // 最後の行は改行なしで送る
+        let body = body.trim_end();
+        let chunks = vec![
+            body[..10].to_string(),
+            body[10..50].to_string(),
+            body[50..].to_string(),
+        ];
+        let (status, body) = call(&mut service, request(chunks)).await;
+
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+        assert_eq!(body["count"], 3);
+        assert_eq!(body["inserted"], 3);
+        assert_eq!(body["skipped"], 0);
+        assert_eq!(writer.persisted().len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_gzip_stream() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 1024);
+
+        let body = gzip(format!("{}{}", line(2, 100.0), line(1, 100.1)).as_bytes());
+        let chunks = body.chunks(5).map(|c| c.to_vec()).collect();
+        let mut request = request(chunks);
+        request
+            .headers_mut()
+            .insert(CONTENT_ENCODING, "gzip".parse().unwrap());
+        let (status, body) = call(&mut service, request).await;
+
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+        assert_eq!(body["inserted"], 2);
+        assert_eq!(writer.persisted().len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_out_of_order_across_chunks() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 1024);
+
+        // 登録単位の境界をまたいで日時が戻る場合も不正な行として扱う
+        let mut lines: Vec<String> = (0..INSERT_CHUNK_SIZE as i64)
+            .map(|i| line_at_seconds_ago(3000 - i, 100.0))
+            .collect();
+        lines.push(line_at_seconds_ago(3000, 100.0));
+        let (status, body) = call(&mut service, request(lines)).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+        assert_eq!(
+            body["message"],
+            format!(
+                "parameter is invalid, line: {}, inserted before this line: {}",
+                INSERT_CHUNK_SIZE + 1,
+                INSERT_CHUNK_SIZE
+            )
+        );
+        assert_eq!(body["errors"][0]["index"], INSERT_CHUNK_SIZE);
+        assert_eq!(writer.persisted().len(), INSERT_CHUNK_SIZE);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_forecast_forwarding() {
+        let writer = Arc::new(MockRateWriter::forwarding());
+        let mut service = service(writer.clone(), 1024);
+
+        let (status, body) = call(&mut service, request(vec![line(1, 100.0)])).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+
+        // 予想は応答後にバックグラウンドで行う
+        for _ in 0..100 {
+            if !writer.forecasted().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(
+            writer.forecasted(),
+            vec![(TEST_PAIR.to_string(), format!("forecast-{}", TEST_PAIR))]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_malformed_line() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 1024);
+
+        let chunks = vec![
+            line(3, 100.0),
+            "\n".to_string(),
+            "{\"time\":\"2022-01-01 00:00:00\",\n".to_string(),
+            line(1, 100.2),
+        ];
+        let (status, body) = call(&mut service, request(chunks)).await;
+
+        // 空行も行番号に数え、不正な行の番号（1始まり）を返す
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+        assert_eq!(
+            body["message"],
+            "parameter is invalid, line: 3, inserted before this line: 0"
+        );
+        assert_eq!(body["errors"][0]["index"], 2);
+        assert!(writer.persisted().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_too_long_line() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 64);
+
+        // 改行が届く前に上限を超えた時点で打ち切る
+        let chunks = vec![line(2, 100.0), " ".repeat(65), line(1, 100.1)];
+        let (status, body) = call(&mut service, request(chunks)).await;
+
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE, "{}", body);
+        assert_eq!(body["message"], "line is too long, max: 64 bytes");
+        assert!(writer.persisted().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_not_ndjson() {
+        let writer = Arc::new(MockRateWriter::default());
+        let mut service = service(writer.clone(), 1024);
+
+        let mut request = request(vec![line(1, 100.0)]);
+        request
+            .headers_mut()
+            .insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        let (status, _) = call(&mut service, request).await;
+
+        assert_eq!(status, StatusCode::IM_A_TEAPOT);
+        assert!(writer.persisted().is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use common_lib::{dead_letter, domain::model::RateForTraining, metrics};
+use log::{error, info, warn};
+use rate_gateway_lib::models;
+use tokio::sync::Notify;
+
+use crate::writer::SharedRateWriter;
+
+// 受信したレートを登録するまで保持する上限付きのキュー
+#[derive(Clone)]
+pub struct WriteQueue {
+    rates: Arc<Mutex<Vec<RateForTraining>>>,
+    capacity: usize,
+    batch_size: usize,
+    notify: Arc<Notify>,
+    // 上限を超えて捨てるレートの書き出し先（bin-option-cli requeue-dead-letters で登録し直す）
+    dead_letter_file: Option<PathBuf>,
+}
+
+impl WriteQueue {
+    pub fn new(capacity: usize, batch_size: usize) -> Self {
+        WriteQueue {
+            rates: Arc::new(Mutex::new(vec![])),
+            capacity,
+            batch_size: batch_size.max(1),
+            notify: Arc::new(Notify::new()),
+            dead_letter_file: None,
+        }
+    }
+
+    pub fn with_dead_letter_file(mut self, dead_letter_file: PathBuf) -> Self {
+        self.dead_letter_file = Some(dead_letter_file);
+        self
+    }
+
+    pub fn push(&self, rate: RateForTraining) -> bool {
+        self.push_all(vec![rate])
+    }
+
+    // 空きが足りない場合は1件も追加せずにfalseを返す
+    pub fn push_all(&self, mut rates: Vec<RateForTraining>) -> bool {
+        let mut queue = self.rates.lock().unwrap();
+        if queue.len() + rates.len() > self.capacity {
+            return false;
+        }
+        queue.append(&mut rates);
+        if queue.len() >= self.batch_size {
+            self.notify.notify_one();
+        }
+        true
+    }
+
+    fn take(&self) -> Vec<RateForTraining> {
+        std::mem::take(&mut *self.rates.lock().unwrap())
+    }
+
+    // 登録に失敗した分を後から受信したレートより前に戻す
+    // 上限を超える分は古いレートから捨て、捨てたレートを返す
+    fn restore(&self, mut rates: Vec<RateForTraining>) -> Vec<RateForTraining> {
+        let mut queue = self.rates.lock().unwrap();
+        let dropped = (rates.len() + queue.len())
+            .saturating_sub(self.capacity)
+            .min(rates.len());
+        let dropped = rates.drain(..dropped).collect();
+        rates.append(&mut queue);
+        *queue = rates;
+        dropped
+    }
+}
+
+// 一定間隔、または一定件数たまるごとにキュー内のレートをまとめて登録する
+pub async fn run_writer(queue: WriteQueue, rate_writer: SharedRateWriter, interval_ms: u64) {
+    let mut interval = tokio::time::interval(Duration::from_millis(interval_ms));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = queue.notify.notified() => {}
+        }
+
+        write_queued(&queue, &rate_writer).await;
+    }
+}
+
+// キュー内のレートをまとめて登録し、失敗した場合はキューに戻す
+async fn write_queued(queue: &WriteQueue, rate_writer: &SharedRateWriter) {
+    let rates = Arc::new(queue.take());
+    if rates.is_empty() {
+        return;
+    }
+
+    let task_rates = rates.clone();
+    let rate_writer = rate_writer.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        rate_writer.write(&task_rates).map(|written| {
+            rate_writer.forecast(&written.forwarded);
+            written.inserted
+        })
+    })
+    .await;
+    let rates = Arc::try_unwrap(rates).unwrap_or_else(|rates| (*rates).clone());
+    let err = match result {
+        Ok(Ok(inserted)) => {
+            info!(
+                "wrote queued rates, count: {}, inserted: {}",
+                rates.len(),
+                inserted
+            );
+            metrics::observe_rates(&rates);
+            return;
+        }
+        Ok(Err(err)) => err.to_string(),
+        // 登録処理がパニックした場合もコミットされていないものとして戻す（登録済みのレートは再登録時に無視される）
+        Err(err) => format!("failed to run write task, {}", err),
+    };
+
+    error!(
+        "failed to insert queued rates, count: {}, error: {}",
+        rates.len(),
+        err
+    );
+    restore_failed(queue, rates);
+}
+
+// 登録に失敗したレートをキューに戻し、戻せずに捨てたレートは設定があればファイルに書き出す
+fn restore_failed(queue: &WriteQueue, rates: Vec<RateForTraining>) {
+    let dropped = queue.restore(rates);
+    if dropped.is_empty() {
+        return;
+    }
+    warn!(
+        "write queue is full, oldest queued rates dropped, count: {}",
+        dropped.len()
+    );
+    if let Some(path) = &queue.dead_letter_file {
+        match dead_letter::append(path, &dropped) {
+            Ok(_) => info!(
+                "dropped rates saved to dead letter file, count: {}, path: {}",
+                dropped.len(),
+                path.display()
+            ),
+            Err(err) => error!(
+                "failed to save dropped rates to dead letter file, count: {}, path: {}, error: {}",
+                dropped.len(),
+                path.display(),
+                err
+            ),
+        }
+    }
+}
+
+// 1件のレート（Rateと同じJSON）をティックとして変換する
+pub fn parse_tick(pair: &str, payload: &[u8]) -> Result<RateForTraining, String> {
+    let rate = serde_json::from_slice::<models::Rate>(payload).map_err(|err| err.to_string())?;
+    RateForTraining::new(pair, &rate.time, rate.value).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lib::test_support::TEST_PAIR;
+
+    use super::*;
+    use crate::writer::mock::MockRateWriter;
+
+    // 値ごとに別の日時のレートを作る
+    fn rate(value: f64) -> RateForTraining {
+        let recorded_at = format!("2022-01-01 00:{:02}:00", value as u32);
+        RateForTraining::new(TEST_PAIR, &recorded_at, value).unwrap()
+    }
+
+    fn values(rates: &[RateForTraining]) -> Vec<f64> {
+        rates.iter().map(|r| r.rate).collect()
+    }
+
+    #[test]
+    fn test_for_push_all() {
+        let queue = WriteQueue::new(3, 10);
+
+        assert!(queue.push_all(vec![rate(1.0), rate(2.0)]));
+        // 空きが足りない場合は1件も追加しない
+        assert!(!queue.push_all(vec![rate(3.0), rate(4.0)]));
+        assert!(queue.push(rate(3.0)));
+        assert!(!queue.push(rate(4.0)));
+
+        assert_eq!(values(&queue.take()), vec![1.0, 2.0, 3.0]);
+        assert!(queue.take().is_empty());
+    }
+
+    #[test]
+    fn test_for_restore() {
+        let queue = WriteQueue::new(5, 10);
+        let failed = vec![rate(1.0), rate(2.0)];
+        assert!(queue.push_all(vec![rate(3.0), rate(4.0)]));
+
+        // 失敗した分は後から受信したレートより前に戻す
+        assert!(queue.restore(failed).is_empty());
+        assert_eq!(values(&queue.take()), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_for_restore_over_capacity() {
+        let queue = WriteQueue::new(3, 10);
+        let failed = vec![rate(1.0), rate(2.0)];
+        assert!(queue.push_all(vec![rate(3.0), rate(4.0)]));
+
+        // 上限を超える分は古いレートから捨てる
+        assert_eq!(values(&queue.restore(failed)), vec![1.0]);
+        assert_eq!(values(&queue.take()), vec![2.0, 3.0, 4.0]);
+        assert!(queue.push_all(vec![]));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_write_queued() {
+        struct Param {
+            title: String,
+            writer: MockRateWriter,
+            expected_persisted: usize,
+            expected_queued: Vec<f64>,
+        }
+        let params = vec![
+            Param {
+                title: "登録に成功した場合はキューから取り除く".to_string(),
+                writer: MockRateWriter::default(),
+                expected_persisted: 2,
+                expected_queued: vec![],
+            },
+            Param {
+                title: "コミットに失敗した場合はキューに戻す".to_string(),
+                writer: MockRateWriter::failing_on_commit(),
+                expected_persisted: 0,
+                expected_queued: vec![1.0, 2.0],
+            },
+            Param {
+                title: "登録処理がパニックした場合もキューに戻す".to_string(),
+                writer: MockRateWriter::panicking(),
+                expected_persisted: 0,
+                expected_queued: vec![1.0, 2.0],
+            },
+        ];
+
+        for p in params {
+            let writer = Arc::new(p.writer);
+            let shared: SharedRateWriter = writer.clone();
+            let queue = WriteQueue::new(10, 10);
+            assert!(queue.push_all(vec![rate(1.0), rate(2.0)]));
+
+            write_queued(&queue, &shared).await;
+
+            assert_eq!(
+                writer.persisted().len(),
+                p.expected_persisted,
+                "{}",
+                p.title
+            );
+            assert_eq!(values(&queue.take()), p.expected_queued, "{}", p.title);
+        }
+    }
+
+    #[test]
+    fn test_for_restore_failed_dead_letter() {
+        let path = std::env::temp_dir().join(format!("rates_dead_letter_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let queue = WriteQueue::new(3, 10).with_dead_letter_file(path.clone());
+        let failed = vec![rate(1.0), rate(2.0)];
+        assert!(queue.push_all(vec![rate(3.0), rate(4.0)]));
+
+        // キューに戻せずに捨てたレートはファイルに書き出す
+        restore_failed(&queue, failed);
+        assert_eq!(values(&queue.take()), vec![2.0, 3.0, 4.0]);
+        assert_eq!(values(&dead_letter::read(&path).unwrap()), vec![1.0]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
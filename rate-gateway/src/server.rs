@@ -1,46 +1,266 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
 use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
 use common_lib::{
+    config::HttpConfig,
     domain,
-    error::MyResult,
-    mysql::{self, client::Client},
+    error::{MyError, MyResult},
+    forecast::Forecaster,
+    mysql::{
+        self,
+        client::{Client, Transaction},
+    },
+    server::{
+        admin,
+        body_limit::BodyLimitLayer,
+        error_response::ErrorResponse,
+        health::HealthLayer,
+        layer::MakeLayer,
+        listener,
+        metrics::MetricsLayer,
+        openapi::OpenApiLayer,
+        rate_limit::{RateLimitLayer, RateLimiter},
+        trace_context::TraceContextLayer,
+    },
+    settings::SharedSettings,
 };
-use log::info;
+use hyper::StatusCode;
+use log::{error, info, warn};
 use rate_gateway_lib::{
     models::{self, PostSuccess},
     server::MakeService,
-    Api, RatesPairPostResponse,
+    Api, RatesPairCsvPostResponse, RatesPairDeleteResponse, RatesPairGapsGetResponse,
+    RatesPairGetResponse, RatesPairPostResponse,
 };
 use swagger::{auth::MakeAllowAllAuthenticator, ApiError, EmptyContext, Has, XSpanIdString};
 
-pub async fn run(addr: &str, mysql_cli: mysql::client::DefaultClient) {
-    let addr = addr.parse().expect("Failed to parse bind address");
+use crate::{
+    config,
+    csv::CsvLayer,
+    error_response::IntoResponse,
+    forecast::ForecastForwarder,
+    mqtt,
+    ndjson::NdjsonLayer,
+    queue::{self, WriteQueue},
+    validation,
+    websocket::WebSocketLayer,
+    writer::{self, DbRateWriter, SharedRateWriter},
+};
+
+// GET /openapi.json で公開するAPIの仕様（生成元の仕様をビルド時に埋め込む）
+const OPENAPI_SPEC: &str = include_str!("../../rate-gateway-lib/api/openapi.yaml");
+
+// レート取得件数（デフォルト/最大）
+const RATES_LIMIT_DEFAULT: i32 = 1000;
+const RATES_LIMIT_MAX: i32 = 10000;
+
+// レート欠損期間の取得件数（デフォルト/最大）
+const RATE_GAPS_LIMIT_DEFAULT: i32 = 100;
+const RATE_GAPS_LIMIT_MAX: i32 = 1000;
+
+// レートを1回のINSERTで登録する件数
+pub(crate) const INSERT_CHUNK_SIZE: usize = 1000;
+
+pub async fn run(
+    http_config: &HttpConfig,
+    mysql_cli: mysql::client::DefaultClient,
+    config: &config::Config,
+    settings: SharedSettings,
+    forecaster: Option<Forecaster>,
+) {
+    let forecast_forwarder = ForecastForwarder::new(
+        config.forecast_forward_enabled,
+        config.forecast_input_size,
+        config.forecast_expire_minutes,
+    )
+    .map(|f| f.with_forecaster(forecaster, settings.clone()));
+
+    let mut queue = WriteQueue::new(config.write_queue_capacity, config.write_batch_size);
+    if !config.write_dead_letter_file.is_empty() {
+        queue = queue.with_dead_letter_file(PathBuf::from(&config.write_dead_letter_file));
+    }
+    tokio::spawn(queue::run_writer(
+        queue.clone(),
+        Arc::new(DbRateWriter::new(
+            mysql_cli.clone(),
+            config.write_batch_size,
+            forecast_forwarder.clone(),
+        )),
+        config.write_flush_interval_ms,
+    ));
 
-    let server = Server::new(mysql_cli);
+    if !config.mqtt_host.is_empty() {
+        match config.get_mqtt_topics() {
+            Ok(topics) => {
+                tokio::spawn(mqtt::run_subscriber(
+                    config.mqtt_host.clone(),
+                    config.mqtt_port,
+                    topics,
+                    queue.clone(),
+                ));
+            }
+            Err(err) => {
+                error!("failed to start mqtt subscriber, error: {}", err);
+            }
+        }
+    }
+
+    let write_queue = if config.write_queue_enabled {
+        Some(queue.clone())
+    } else {
+        None
+    };
+    let server = Server::new(
+        mysql_cli.clone(),
+        write_queue,
+        Arc::new(DbRateWriter::new(
+            mysql_cli.clone(),
+            INSERT_CHUNK_SIZE,
+            forecast_forwarder.clone(),
+        )),
+        config,
+        settings.clone(),
+    );
 
     let service = MakeService::new(server);
 
+    let service = MakeLayer::new(service, BodyLimitLayer::new(http_config.max_body_bytes));
+
+    let service = MakeLayer::new(service, WebSocketLayer::new(queue, settings.clone()));
+
+    let service = MakeLayer::new(
+        service,
+        NdjsonLayer::new(
+            Arc::new(DbRateWriter::new(
+                mysql_cli.clone(),
+                INSERT_CHUNK_SIZE,
+                forecast_forwarder.clone(),
+            )),
+            config,
+            settings.clone(),
+            http_config.max_body_bytes,
+        ),
+    );
+
+    let service = MakeLayer::new(
+        service,
+        CsvLayer::new(
+            Arc::new(DbRateWriter::new(
+                mysql_cli.clone(),
+                INSERT_CHUNK_SIZE,
+                forecast_forwarder,
+            )),
+            config,
+            settings,
+        ),
+    );
+
+    let service = MakeLayer::new(service, TraceContextLayer);
+
+    let limiter = RateLimiter::new(config.rate_limit_per_minute, Duration::from_secs(60));
+    match config.get_rate_limit_api_keys() {
+        Ok(key_limits) => limiter.set_key_limits(key_limits),
+        Err(err) => {
+            error!("invalid rate limit api keys, error: {}", err);
+            return;
+        }
+    }
+    let service = MakeLayer::new(service, RateLimitLayer::with_limiter(limiter));
+
+    let service = MakeLayer::new(
+        service,
+        HealthLayer::new(mysql_cli, env!("CARGO_PKG_VERSION")),
+    );
+
+    let service = match OpenApiLayer::new(OPENAPI_SPEC) {
+        Ok(layer) => MakeLayer::new(service, layer),
+        Err(err) => {
+            error!("failed to load openapi spec, error: {}", err);
+            return;
+        }
+    };
+
+    let service = MakeLayer::new(service, MetricsLayer::new());
+
     let service = MakeAllowAllAuthenticator::new(service, "cosmo");
 
     let service =
         rate_gateway_lib::server::context::MakeAddContext::<_, EmptyContext>::new(service);
 
-    hyper::server::Server::bind(&addr)
-        .serve(service)
-        .await
-        .unwrap()
+    if let Err(err) = listener::serve(http_config, service).await {
+        error!("failed to serve, error: {}", err);
+    }
 }
 
 #[derive(Clone)]
 pub struct Server {
     mysql_cli: mysql::client::DefaultClient,
+    // 指定されている場合、レートの登録はキュー経由で非同期に行う
+    write_queue: Option<WriteQueue>,
+    rate_retention_days: i64,
+    rate_future_tolerance_seconds: i64,
+    admin_api_key: Option<String>,
+    max_rates_per_request: usize,
+    rate_writer: SharedRateWriter,
+    settings: SharedSettings,
 }
 
 impl Server {
-    pub fn new(mysql_cli: mysql::client::DefaultClient) -> Self {
+    pub fn new(
+        mysql_cli: mysql::client::DefaultClient,
+        write_queue: Option<WriteQueue>,
+        rate_writer: SharedRateWriter,
+        config: &config::Config,
+        settings: SharedSettings,
+    ) -> Self {
         Server {
             mysql_cli: mysql_cli,
+            write_queue: write_queue,
+            rate_retention_days: config.rate_retention_days,
+            rate_future_tolerance_seconds: config.rate_future_tolerance_seconds,
+            admin_api_key: config.admin_api_key.clone(),
+            max_rates_per_request: config.max_rates_per_request,
+            rate_writer,
+            settings,
         }
     }
+
+    // DB処理はブロッキングするため、tokioのワーカーを占有しないようにして実行する
+    fn with_transaction<F, T>(&self, f: F) -> MyResult<T>
+    where
+        F: FnMut(&mut Transaction) -> MyResult<T>,
+    {
+        tokio::task::block_in_place(|| self.mysql_cli.with_transaction(f))
+    }
+}
+
+// 取得期間（どちらも省略可）を解析する
+fn parse_range(
+    from: &Option<String>,
+    to: &Option<String>,
+) -> Result<(Option<NaiveDateTime>, Option<NaiveDateTime>), String> {
+    let parse = |value: &Option<String>| match value {
+        Some(v) => NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S")
+            .map(Some)
+            .map_err(|err| format!("invalid datetime format, value: {}, {}", v, err)),
+        None => Ok(None),
+    };
+    match (parse(from)?, parse(to)?) {
+        (Some(b), Some(e)) if b > e => {
+            Err(format!("from must not be after to, from: {}, to: {}", b, e))
+        }
+        range => Ok(range),
+    }
+}
+
+// エラーを警告としてログに出力し、各APIのレスポンスに変換する
+fn warn_response<R>(error: ErrorResponse, span_id: &XSpanIdString) -> R
+where
+    ErrorResponse: IntoResponse<R>,
+{
+    warn!("error: {:?}, X-Span-ID: {:?}", error, span_id.0);
+    error.into_response()
 }
 
 #[async_trait]
@@ -48,41 +268,539 @@ impl<C> Api<C> for Server
 where
     C: Has<XSpanIdString> + Send + Sync,
 {
+    /// CSV形式のレートを一括登録します
+    async fn rates_pair_csv_post(
+        &self,
+        pair: String,
+        body: swagger::ByteArray,
+        context: &C,
+    ) -> Result<RatesPairCsvPostResponse, ApiError> {
+        // CsvLayer でボディを1行ずつ読み込んで処理するため、ここには届かない
+        let error = ErrorResponse::internal_server_error("csv must be handled by CsvLayer");
+        warn!(
+            "rates_pair_csv_post(\"{}\", {} bytes), error: {:?}, X-Span-ID: {:?}",
+            pair,
+            body.0.len(),
+            error,
+            context.get().0.clone()
+        );
+        Ok(error.into_response())
+    }
+
+    /// 指定期間のレートを削除します
+    async fn rates_pair_delete(
+        &self,
+        pair: String,
+        from: String,
+        to: String,
+        x_api_key: Option<String>,
+        context: &C,
+    ) -> Result<RatesPairDeleteResponse, ApiError> {
+        info!(
+            "rates_pair_delete(\"{}\", \"{}\", \"{}\") - X-Span-ID: {:?}",
+            pair,
+            from,
+            to,
+            context.get().0.clone()
+        );
+
+        if !admin::is_admin(self.admin_api_key.as_deref(), x_api_key.as_deref()) {
+            return Ok(RatesPairDeleteResponse::Status401(models::Error {
+                message: "unauthorized".to_string(),
+            }));
+        }
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            let error = ErrorResponse::new(
+                StatusCode::NOT_FOUND,
+                format!("unsupported pair, pair: {}", pair),
+            );
+            return Ok(warn_response(error, context.get()));
+        }
+
+        let parse = |value: &str| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .map_err(|err| format!("invalid datetime format, value: {}, {}", value, err))
+        };
+        let range = match (parse(&from), parse(&to)) {
+            (Ok(b), Ok(e)) if b > e => {
+                Err(format!("from must not be after to, from: {}, to: {}", b, e))
+            }
+            (Ok(b), Ok(e)) => Ok((b, e)),
+            (Err(err), _) | (_, Err(err)) => Err(err),
+        };
+        let (begin, end) = match range {
+            Ok(range) => range,
+            Err(message) => {
+                let error = ErrorResponse::new(StatusCode::BAD_REQUEST, message);
+                return Ok(warn_response(error, context.get()));
+            }
+        };
+
+        match self.with_transaction(|tx| {
+            self.mysql_cli
+                .delete_rates_for_training_range(tx, &pair, &begin, &end)
+        }) {
+            Ok(deleted) => {
+                info!(
+                    "deleted rates, pair: {}, from: {}, to: {}, count: {}",
+                    pair, begin, end, deleted
+                );
+                Ok(RatesPairDeleteResponse::Status200(
+                    models::RatesPairDelete200Response {
+                        deleted: deleted as i64,
+                    },
+                ))
+            }
+            Err(err) => Ok(warn_response(
+                ErrorResponse::from_error(&err),
+                context.get(),
+            )),
+        }
+    }
+
+    /// 学習用レートの欠損期間を取得します
+    async fn rates_pair_gaps_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C,
+    ) -> Result<RatesPairGapsGetResponse, ApiError> {
+        info!(
+            "rates_pair_gaps_get(\"{}\", {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
+            pair,
+            from,
+            to,
+            limit,
+            offset,
+            context.get().0.clone()
+        );
+
+        let limit = limit.unwrap_or(RATE_GAPS_LIMIT_DEFAULT);
+        let offset = offset.unwrap_or(0);
+        if !(1..=RATE_GAPS_LIMIT_MAX).contains(&limit) || offset < 0 {
+            let error = ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "limit must be between 1 and {}, and offset must not be negative, limit: {}, offset: {}",
+                    RATE_GAPS_LIMIT_MAX, limit, offset
+                ),
+            );
+            return Ok(warn_response(error, context.get()));
+        }
+
+        let (begin, end) = match parse_range(&from, &to) {
+            Ok(range) => range,
+            Err(message) => {
+                let error = ErrorResponse::new(StatusCode::BAD_REQUEST, message);
+                return Ok(warn_response(error, context.get()));
+            }
+        };
+
+        let mut gaps: Vec<domain::model::RateGap> = vec![];
+        match self.with_transaction(|tx| {
+            gaps = self.mysql_cli.select_rate_gaps(
+                tx,
+                &pair,
+                begin,
+                end,
+                limit as u32,
+                offset as u32,
+            )?;
+            Ok(())
+        }) {
+            Ok(_) => Ok(RatesPairGapsGetResponse::Status200(
+                models::RatesPairGapsGet200Response {
+                    gaps: gaps
+                        .iter()
+                        .map(|g| models::RateGap {
+                            begin: g.begin_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                            end: g.end_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                            missing_minutes: g.missing_minutes,
+                        })
+                        .collect(),
+                },
+            )),
+            Err(err) => Ok(warn_response(
+                ErrorResponse::from_error(&err),
+                context.get(),
+            )),
+        }
+    }
+
+    /// 登録済みのレートを取得します
+    async fn rates_pair_get(
+        &self,
+        pair: String,
+        from: Option<String>,
+        to: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        context: &C,
+    ) -> Result<RatesPairGetResponse, ApiError> {
+        info!(
+            "rates_pair_get(\"{}\", {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
+            pair,
+            from,
+            to,
+            limit,
+            offset,
+            context.get().0.clone()
+        );
+
+        let limit = limit.unwrap_or(RATES_LIMIT_DEFAULT);
+        let offset = offset.unwrap_or(0);
+        if !(1..=RATES_LIMIT_MAX).contains(&limit) || offset < 0 {
+            let error = ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "limit must be between 1 and {}, and offset must not be negative, limit: {}, offset: {}",
+                    RATES_LIMIT_MAX, limit, offset
+                ),
+            );
+            return Ok(warn_response(error, context.get()));
+        }
+
+        let (begin, end) = match parse_range(&from, &to) {
+            Ok(range) => range,
+            Err(message) => {
+                let error = ErrorResponse::new(StatusCode::BAD_REQUEST, message);
+                return Ok(warn_response(error, context.get()));
+            }
+        };
+
+        let mut rates: Vec<domain::model::RateForTraining> = vec![];
+        match self.with_transaction(|tx| {
+            rates = self.mysql_cli.select_rates_for_training_paged(
+                tx,
+                &pair,
+                begin,
+                end,
+                limit as u32,
+                offset as u32,
+            )?;
+            Ok(())
+        }) {
+            Ok(_) => Ok(RatesPairGetResponse::Status200(
+                models::RatesPairGet200Response {
+                    rates: rates
+                        .iter()
+                        .map(|r| models::Rate {
+                            time: r.recorded_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                            value: r.rate,
+                        })
+                        .collect(),
+                },
+            )),
+            Err(err) => Ok(warn_response(
+                ErrorResponse::from_error(&err),
+                context.get(),
+            )),
+        }
+    }
+
     /// レートを新規登録します
     async fn rates_pair_post(
         &self,
         pair: String,
         rates: &Vec<models::Rate>,
+        partial: Option<bool>,
         context: &C,
     ) -> Result<RatesPairPostResponse, ApiError> {
-        let context = context.clone();
         info!(
-            "rates_pair_post(\"{}\", {:?}) - X-Span-ID: {:?}",
+            "rates_pair_post(\"{}\", {:?}, {:?}) - X-Span-ID: {:?}",
             pair,
             rates,
+            partial,
             context.get().0.clone()
         );
+        let partial = partial.unwrap_or(false);
+
+        if !self.settings.get().is_pair_supported(&pair) {
+            let error = ErrorResponse::new(
+                StatusCode::NOT_FOUND,
+                format!("unsupported pair, pair: {}", pair),
+            );
+            return Ok(warn_response(error, context.get()));
+        }
+
+        // 巨大なリクエストでトランザクションを長時間保持しないよう件数を制限する
+        if rates.len() > self.max_rates_per_request {
+            let error = models::Error {
+                message: format!(
+                    "too many rates, count: {}, max: {}, split the request",
+                    rates.len(),
+                    self.max_rates_per_request
+                ),
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(RatesPairPostResponse::Status413 {
+                body: error,
+                x_max_rates: Some(self.max_rates_per_request as i64),
+            });
+        }
 
-        let rates = rates
-            .iter()
-            .map(|rate| domain::model::RateForTraining::new(&pair, &rate.time, rate.value))
-            .collect();
-        if let Err(err) = rates {
-            return Ok(RatesPairPostResponse::Status400(models::Error {
-                message: format!("parameter is invalid, {}", err),
+        let (rates, errors) = validation::validate_rates(
+            &pair,
+            rates,
+            Utc::now().naive_utc(),
+            self.rate_retention_days,
+            self.rate_future_tolerance_seconds,
+        );
+        if !errors.is_empty() && (!partial || rates.is_empty()) {
+            let error = models::ValidationError {
+                message: format!("parameter is invalid, count: {}", errors.len()),
+                errors,
+            };
+            warn!(
+                "error: {:?}, X-Span-ID: {:?}",
+                error,
+                context.get().0.clone()
+            );
+            return Ok(RatesPairPostResponse::Status400(error));
+        }
+        if !errors.is_empty() {
+            warn!(
+                "invalid rates skipped, count: {}, errors: {:?}, X-Span-ID: {:?}",
+                errors.len(),
+                errors,
+                context.get().0.clone()
+            );
+        }
+        let errors = if partial { Some(errors) } else { None };
+
+        if let Some(queue) = &self.write_queue {
+            let count = rates.len() as i64;
+            if !queue.push_all(rates) {
+                let error = ErrorResponse::service_unavailable("write queue is full, retry later");
+                return Ok(warn_response(error, context.get()));
+            }
+            return Ok(RatesPairPostResponse::Status202(models::PostAccepted {
+                count,
+                errors,
             }));
         }
-        let rates = rates.unwrap();
 
-        match self.mysql_cli.with_transaction(|tx| -> MyResult<()> {
-            self.mysql_cli.insert_rates_for_training(tx, &rates)
-        }) {
-            Ok(_) => Ok(RatesPairPostResponse::Status201(PostSuccess {
-                count: rates.len() as i64,
-            })),
-            Err(err) => Ok(RatesPairPostResponse::Status500(models::Error {
-                message: format!("internal server error, {}", err),
-            })),
+        // 再送されたレートは登録済みとして無視する
+        match writer::write_and_forecast(&self.rate_writer, &rates) {
+            Ok(inserted) => {
+                let inserted = inserted as i64;
+                Ok(RatesPairPostResponse::Status201(PostSuccess {
+                    count: rates.len() as i64,
+                    inserted,
+                    skipped: rates.len() as i64 - inserted,
+                    errors,
+                }))
+            }
+            // コミットの失敗もここで返る（登録されていないため成功扱いにしない）
+            Err(err) => {
+                let response = rates_pair_post_db_error(&err);
+                warn!(
+                    "error: {:?}, X-Span-ID: {:?}",
+                    response,
+                    context.get().0.clone()
+                );
+                Ok(response)
+            }
+        }
+    }
+}
+
+// レート登録時のDBエラーをレスポンスに変換する
+fn rates_pair_post_db_error(err: &MyError) -> RatesPairPostResponse {
+    ErrorResponse::from_error(err).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common_lib::{
+        server::error_response::RETRY_AFTER_SECONDS,
+        test_support::{shared_settings, unconnected_db_client, TEST_PAIR},
+    };
+    use swagger::{ContextBuilder, Push};
+
+    use crate::writer::mock::MockRateWriter;
+
+    fn context() -> ContextBuilder<XSpanIdString, EmptyContext> {
+        EmptyContext.push(XSpanIdString::default())
+    }
+
+    fn rate(minutes_ago: i64, value: f64) -> models::Rate {
+        let time = Utc::now().naive_utc() - chrono::Duration::minutes(minutes_ago);
+        models::Rate {
+            time: time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            value,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_rates_pair_post() {
+        let writer = Arc::new(MockRateWriter::default());
+        let server = Server::new(
+            unconnected_db_client(),
+            None,
+            writer.clone(),
+            &config::tests::config(),
+            shared_settings(&[TEST_PAIR]),
+        );
+
+        let rates = vec![rate(2, 100.0), rate(1, 100.1)];
+        match server
+            .rates_pair_post(TEST_PAIR.to_string(), &rates, None, &context())
+            .await
+            .unwrap()
+        {
+            RatesPairPostResponse::Status201(body) => {
+                assert_eq!(body.count, 2);
+                assert_eq!(body.inserted, 2);
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+        assert_eq!(writer.persisted().len(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_rates_pair_post_commit_failure() {
+        // コミットに失敗した場合は成功扱いにせず、何も登録しない
+        let writer = Arc::new(MockRateWriter::failing_on_commit());
+        let server = Server::new(
+            unconnected_db_client(),
+            None,
+            writer.clone(),
+            &config::tests::config(),
+            shared_settings(&[TEST_PAIR]),
+        );
+
+        let rates = vec![rate(2, 100.0), rate(1, 100.1)];
+        match server
+            .rates_pair_post(TEST_PAIR.to_string(), &rates, None, &context())
+            .await
+            .unwrap()
+        {
+            RatesPairPostResponse::Status500(body) => {
+                assert!(body.message.starts_with("internal server error"));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+        assert!(writer.persisted().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_for_rates_pair_delete_without_admin_api_key() {
+        // 管理用APIキーが未設定の場合は、どのキーを指定しても削除できない
+        for x_api_key in [None, Some("".to_string()), Some("admin-key".to_string())] {
+            let server = Server::new(
+                unconnected_db_client(),
+                None,
+                Arc::new(MockRateWriter::default()),
+                &config::tests::config(),
+                shared_settings(&[TEST_PAIR]),
+            );
+            match server
+                .rates_pair_delete(
+                    TEST_PAIR.to_string(),
+                    "2022-01-01 00:00:00".to_string(),
+                    "2022-01-02 00:00:00".to_string(),
+                    x_api_key.clone(),
+                    &context(),
+                )
+                .await
+                .unwrap()
+            {
+                RatesPairDeleteResponse::Status401(_) => {}
+                other => panic!("unexpected response: {:?}, key: {:?}", other, x_api_key),
+            }
+        }
+    }
+
+    #[test]
+    fn test_for_parse_range() {
+        struct Param {
+            title: String,
+            from: Option<String>,
+            to: Option<String>,
+            is_ok: bool,
+        }
+
+        let params = vec![
+            Param {
+                title: "省略".to_string(),
+                from: None,
+                to: None,
+                is_ok: true,
+            },
+            Param {
+                title: "同じ日時".to_string(),
+                from: Some("2022-01-01 00:00:00".to_string()),
+                to: Some("2022-01-01 00:00:00".to_string()),
+                is_ok: true,
+            },
+            Param {
+                title: "開始が終了より後".to_string(),
+                from: Some("2022-01-02 00:00:00".to_string()),
+                to: Some("2022-01-01 00:00:00".to_string()),
+                is_ok: false,
+            },
+            Param {
+                title: "形式が不正".to_string(),
+                from: None,
+                to: Some("2022-01-01".to_string()),
+                is_ok: false,
+            },
+        ];
+
+        for p in params {
+            let result = parse_range(&p.from, &p.to);
+            assert_eq!(result.is_ok(), p.is_ok, "{}, {:?}", p.title, result);
+        }
+    }
+
+    #[test]
+    fn test_for_rates_pair_post_db_error() {
+        // テーブルが存在しないなど、再試行で解消しない失敗は500
+        let err = ::mysql::Error::MySqlError(::mysql::MySqlError {
+            state: "42S02".to_string(),
+            message: "Table 'binopt.rates_for_training' doesn't exist".to_string(),
+            code: 1146,
+        });
+        match rates_pair_post_db_error(&err.into()) {
+            RatesPairPostResponse::Status500(body) => {
+                assert!(body.message.starts_with("internal server error"));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        // コミット時のデッドロックは再試行で解消する可能性があるため503
+        let err = ::mysql::Error::MySqlError(::mysql::MySqlError {
+            state: "40001".to_string(),
+            message: "Deadlock found when trying to get lock".to_string(),
+            code: 1213,
+        });
+        match rates_pair_post_db_error(&err.into()) {
+            RatesPairPostResponse::Status503 { retry_after, .. } => {
+                assert_eq!(retry_after, Some(RETRY_AFTER_SECONDS));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        // 接続の切断は503
+        let err = ::mysql::Error::IoError(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "connection reset",
+        ));
+        match rates_pair_post_db_error(&err.into()) {
+            RatesPairPostResponse::Status503 { retry_after, .. } => {
+                assert_eq!(retry_after, Some(RETRY_AFTER_SECONDS));
+            }
+            other => panic!("unexpected response: {:?}", other),
         }
     }
 }
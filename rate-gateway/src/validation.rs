@@ -0,0 +1,103 @@
+use chrono::{Duration, NaiveDateTime};
+use common_lib::domain::model::RateForTraining;
+use rate_gateway_lib::models;
+
+// 受信したレートを変換・確認し、登録できるレートと問題のあるレートごとのエラーに分ける
+// エラーの位置はリクエスト内の位置で返す
+pub fn validate_rates(
+    pair: &str,
+    rates: &[models::Rate],
+    now: NaiveDateTime,
+    retention_days: i64,
+    future_tolerance_seconds: i64,
+) -> (Vec<RateForTraining>, Vec<models::RecordError>) {
+    let mut indexes = vec![];
+    let mut parsed = vec![];
+    let mut errors = vec![];
+    for (i, rate) in rates.iter().enumerate() {
+        match RateForTraining::new(pair, &rate.time, rate.value) {
+            Ok(r) => {
+                indexes.push(i);
+                parsed.push(r);
+            }
+            Err(err) => errors.push(models::RecordError {
+                index: i as i32,
+                time: Some(rate.time.clone()),
+                message: err.to_string(),
+            }),
+        }
+    }
+
+    let mut invalid = vec![false; parsed.len()];
+    for mut error in validate_rate_times(&parsed, now, retention_days, future_tolerance_seconds) {
+        let pos = error.index as usize;
+        invalid[pos] = true;
+        error.index = indexes[pos] as i32;
+        errors.push(error);
+    }
+    errors.sort_by_key(|e| e.index);
+
+    let valid = parsed
+        .into_iter()
+        .zip(invalid)
+        .filter(|(_, invalid)| !invalid)
+        .map(|(rate, _)| rate)
+        .collect();
+    (valid, errors)
+}
+
+// 登録するレートの日時が妥当かを確認し、問題のあるレートごとのエラーを返す
+// - 未来の日時（許容する時計のずれを超えるもの）
+// - 保持期間より古い日時
+// - 直前のレートより後ではない日時
+pub fn validate_rate_times(
+    rates: &[RateForTraining],
+    now: NaiveDateTime,
+    retention_days: i64,
+    future_tolerance_seconds: i64,
+) -> Vec<models::RecordError> {
+    validate_rate_times_after(None, rates, now, retention_days, future_tolerance_seconds)
+}
+
+// 分割して受信したレートを、前回確認した最後のレート（previous）に続けて確認する
+pub fn validate_rate_times_after(
+    previous: Option<&RateForTraining>,
+    rates: &[RateForTraining],
+    now: NaiveDateTime,
+    retention_days: i64,
+    future_tolerance_seconds: i64,
+) -> Vec<models::RecordError> {
+    let latest = now + Duration::seconds(future_tolerance_seconds);
+    let oldest = now - Duration::days(retention_days);
+
+    let mut errors = vec![];
+    for (i, rate) in rates.iter().enumerate() {
+        let previous = if i > 0 { Some(&rates[i - 1]) } else { previous };
+        let message = if rate.recorded_at > latest {
+            Some(format!("time is in the future, now: {}", now))
+        } else if rate.recorded_at < oldest {
+            Some(format!(
+                "time is older than the retention window, oldest: {}",
+                oldest
+            ))
+        } else {
+            previous
+                .filter(|p| rate.recorded_at <= p.recorded_at)
+                .map(|p| {
+                    format!(
+                        "time must be after the previous rate, previous: {}",
+                        p.recorded_at
+                    )
+                })
+        };
+
+        if let Some(message) = message {
+            errors.push(models::RecordError {
+                index: i as i32,
+                time: Some(rate.recorded_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+                message,
+            });
+        }
+    }
+    errors
+}
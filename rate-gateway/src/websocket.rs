@@ -0,0 +1,197 @@
+use std::task::{Context, Poll};
+
+use common_lib::{server::layer::Layer, settings::SharedSettings};
+use futures::{
+    future::{self, BoxFuture},
+    SinkExt, StreamExt,
+};
+use hyper::{
+    header::{CONNECTION, CONTENT_TYPE, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, UPGRADE},
+    service::Service,
+    upgrade::Upgraded,
+    Body, Method, Request, Response, StatusCode,
+};
+use log::{info, warn};
+use rate_gateway_lib::models;
+use tokio_tungstenite::{
+    tungstenite::{handshake::derive_accept_key, protocol::Role, Message},
+    WebSocketStream,
+};
+
+use crate::queue::{self, WriteQueue};
+
+// WebSocketでのティック受信を受け付ける
+#[derive(Clone)]
+pub struct WebSocketLayer {
+    queue: WriteQueue,
+    settings: SharedSettings,
+}
+
+impl WebSocketLayer {
+    pub fn new(queue: WriteQueue, settings: SharedSettings) -> Self {
+        WebSocketLayer { queue, settings }
+    }
+}
+
+impl<Target, S> Layer<Target, S> for WebSocketLayer {
+    type State = Self;
+    type Service = WebSocket<S>;
+
+    fn state(&self, _: &Target) -> Self::State {
+        self.clone()
+    }
+
+    fn layer(state: Self::State, inner: S) -> Self::Service {
+        WebSocket {
+            inner,
+            queue: state.queue,
+            settings: state.settings,
+        }
+    }
+}
+
+// GET /rates/{pair}/ticks へのアップグレード要求を処理し、それ以外は後続へ渡す
+#[derive(Clone)]
+pub struct WebSocket<T> {
+    inner: T,
+    queue: WriteQueue,
+    settings: SharedSettings,
+}
+
+impl<T, C> Service<(Request<Body>, C)> for WebSocket<T>
+where
+    T: Service<(Request<Body>, C), Response = Response<Body>>,
+    T::Future: Send + 'static,
+    T::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, (request, context): (Request<Body>, C)) -> Self::Future {
+        match tick_pair(request.uri().path()) {
+            Some(pair) if is_upgrade_request(&request) => {
+                if !self.settings.get().is_pair_supported(&pair) {
+                    return Box::pin(future::ready(Ok(unsupported_pair_response(&pair))));
+                }
+                let response = upgrade(request, pair, self.queue.clone());
+                Box::pin(future::ready(Ok(response)))
+            }
+            _ => Box::pin(self.inner.call((request, context))),
+        }
+    }
+}
+
+fn tick_pair(path: &str) -> Option<String> {
+    let pair = path.strip_prefix("/rates/")?.strip_suffix("/ticks")?;
+    if pair.is_empty() || pair.contains('/') {
+        return None;
+    }
+    Some(pair.to_string())
+}
+
+fn is_upgrade_request(request: &Request<Body>) -> bool {
+    request.method() == Method::GET
+        && request
+            .headers()
+            .get(UPGRADE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.eq_ignore_ascii_case("websocket"))
+            .unwrap_or(false)
+}
+
+fn unsupported_pair_response(pair: &str) -> Response<Body> {
+    warn!("unsupported pair, pair: {}", pair);
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(
+            serde_json::to_string(&models::Error {
+                message: format!("unsupported pair, pair: {}", pair),
+            })
+            .expect("impossible to fail to serialize"),
+        ))
+        .expect("Unable to create Not Found response for unsupported pair")
+}
+
+fn upgrade(mut request: Request<Body>, pair: String, queue: WriteQueue) -> Response<Body> {
+    let accept_key = match request.headers().get(SEC_WEBSOCKET_KEY) {
+        Some(key) => derive_accept_key(key.as_bytes()),
+        None => {
+            warn!("websocket key is missing, pair: {}", pair);
+            return Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    "{\"message\":\"sec-websocket-key header is required\"}",
+                ))
+                .expect("Unable to create Bad Request response for missing websocket key");
+        }
+    };
+
+    tokio::spawn(async move {
+        match hyper::upgrade::on(&mut request).await {
+            Ok(upgraded) => {
+                let ws = WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await;
+                receive_ticks(ws, pair, queue).await;
+            }
+            Err(err) => {
+                warn!("failed to upgrade to websocket, error: {}", err);
+            }
+        }
+    });
+
+    Response::builder()
+        .status(StatusCode::SWITCHING_PROTOCOLS)
+        .header(CONNECTION, "Upgrade")
+        .header(UPGRADE, "websocket")
+        .header(SEC_WEBSOCKET_ACCEPT, accept_key)
+        .body(Body::empty())
+        .expect("Unable to create Switching Protocols response")
+}
+
+// 1メッセージにつき1件のレート（Rateと同じJSON）を受信してキューへ追加する
+async fn receive_ticks(mut ws: WebSocketStream<Upgraded>, pair: String, queue: WriteQueue) {
+    info!("websocket connected, pair: {}", pair);
+
+    while let Some(message) = ws.next().await {
+        let text = match message {
+            Ok(Message::Text(text)) => text,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => continue,
+            Err(err) => {
+                warn!("failed to receive tick, pair: {}, error: {}", pair, err);
+                break;
+            }
+        };
+
+        let message = match queue::parse_tick(&pair, text.as_bytes()) {
+            Ok(rate) => {
+                if queue.push(rate) {
+                    continue;
+                }
+                warn!("write queue is full, tick dropped, pair: {}", pair);
+                "write queue is full, retry later".to_string()
+            }
+            Err(err) => {
+                warn!(
+                    "invalid tick, pair: {}, message: {}, error: {}",
+                    pair, text, err
+                );
+                format!("invalid tick, {}", err)
+            }
+        };
+        let body = serde_json::to_string(&models::Error { message })
+            .expect("impossible to fail to serialize");
+        if let Err(err) = ws.send(Message::Text(body)).await {
+            warn!("failed to send error, pair: {}, error: {}", pair, err);
+            break;
+        }
+    }
+
+    info!("websocket disconnected, pair: {}", pair);
+}
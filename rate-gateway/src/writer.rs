@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use common_lib::{
+    clock::SystemClock,
+    domain::model::RateForTraining,
+    error::MyResult,
+    metrics,
+    mysql::client::{Client, DefaultClient},
+};
+
+use crate::forecast::ForecastForwarder;
+
+// 学習用レートの登録先（DBの失敗時の動作をテストできるよう差し替え可能にする）
+pub trait RateWriter: Send + Sync {
+    // 1つのトランザクションでレートを登録する（失敗した場合は1件も登録しない）
+    fn write(&self, rates: &[RateForTraining]) -> MyResult<Written>;
+
+    // 予想用に登録したレートを予想する（コミット後に呼び出す）
+    fn forecast(&self, forwarded: &[(String, String)]);
+}
+
+pub type SharedRateWriter = Arc<dyn RateWriter>;
+
+// 登録結果
+#[derive(Debug, Default)]
+pub struct Written {
+    // 新規に登録した件数（登録済みのレートは含まない）
+    pub inserted: u64,
+    // 予想用に登録したレート（通貨ペアとIDの組）
+    pub forwarded: Vec<(String, String)>,
+}
+
+// レートを登録し、登録件数を返す（JSON・NDJSON・CSVの登録で共通）
+// 予想用に登録したレートの予想は、応答を待たせないようバックグラウンドで行う
+// DB処理はブロッキングするため、tokioのワーカーを占有しないようにして実行する
+pub fn write_and_forecast(
+    rate_writer: &SharedRateWriter,
+    rates: &[RateForTraining],
+) -> MyResult<u64> {
+    let written = tokio::task::block_in_place(|| rate_writer.write(rates))?;
+    metrics::observe_rates(rates);
+    if !written.forwarded.is_empty() {
+        let rate_writer = rate_writer.clone();
+        tokio::task::spawn_blocking(move || rate_writer.forecast(&written.forwarded));
+    }
+    Ok(written.inserted)
+}
+
+pub struct DbRateWriter {
+    mysql_cli: DefaultClient,
+    chunk_size: usize,
+    forwarder: Option<ForecastForwarder>,
+}
+
+impl DbRateWriter {
+    // chunk_size 件ごとにまとめてINSERTする
+    pub fn new(
+        mysql_cli: DefaultClient,
+        chunk_size: usize,
+        forwarder: Option<ForecastForwarder>,
+    ) -> Self {
+        DbRateWriter {
+            mysql_cli,
+            chunk_size: chunk_size.max(1),
+            forwarder,
+        }
+    }
+}
+
+impl RateWriter for DbRateWriter {
+    fn write(&self, rates: &[RateForTraining]) -> MyResult<Written> {
+        let mut forwarded: Vec<(String, String)> = vec![];
+        let inserted = self.mysql_cli.with_transaction(|tx| {
+            forwarded.clear();
+            let mut inserted = 0;
+            for chunk in rates.chunks(self.chunk_size) {
+                inserted += self.mysql_cli.bulk_insert_rates_for_training(tx, chunk)?;
+            }
+            if let (Some(forwarder), true) = (&self.forwarder, inserted > 0) {
+                let mut pairs: Vec<&str> = rates.iter().map(|r| r.pair.as_str()).collect();
+                pairs.sort_unstable();
+                pairs.dedup();
+                for pair in pairs {
+                    if let Some(id) = forwarder.forward(&self.mysql_cli, tx, pair, &SystemClock)? {
+                        forwarded.push((pair.to_string(), id));
+                    }
+                }
+            }
+            Ok(inserted)
+        })?;
+        Ok(Written {
+            inserted,
+            forwarded,
+        })
+    }
+
+    fn forecast(&self, forwarded: &[(String, String)]) {
+        if let Some(forwarder) = &self.forwarder {
+            forwarder.forecast(&self.mysql_cli, forwarded);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::sync::Mutex;
+
+    use common_lib::error::MyError;
+
+    use super::*;
+
+    // テスト用の登録先（登録したレートをメモリに保持する）
+    #[derive(Default)]
+    pub struct MockRateWriter {
+        persisted: Mutex<Vec<RateForTraining>>,
+        forecasted: Mutex<Vec<(String, String)>>,
+        fail_on_commit: bool,
+        panic_on_write: bool,
+        forward: bool,
+    }
+
+    impl MockRateWriter {
+        // コミットに失敗する（ロールバックされるため何も登録しない）
+        pub fn failing_on_commit() -> Self {
+            MockRateWriter {
+                fail_on_commit: true,
+                ..MockRateWriter::default()
+            }
+        }
+
+        // 登録中にパニックする
+        pub fn panicking() -> Self {
+            MockRateWriter {
+                panic_on_write: true,
+                ..MockRateWriter::default()
+            }
+        }
+
+        // 新規に登録したレートの通貨ペアごとに、予想用のレートも登録する
+        pub fn forwarding() -> Self {
+            MockRateWriter {
+                forward: true,
+                ..MockRateWriter::default()
+            }
+        }
+
+        pub fn persisted(&self) -> Vec<RateForTraining> {
+            self.persisted.lock().unwrap().clone()
+        }
+
+        pub fn forecasted(&self) -> Vec<(String, String)> {
+            self.forecasted.lock().unwrap().clone()
+        }
+    }
+
+    impl RateWriter for MockRateWriter {
+        fn write(&self, rates: &[RateForTraining]) -> MyResult<Written> {
+            if self.panic_on_write {
+                panic!("panicked while writing rates");
+            }
+            if self.fail_on_commit {
+                return Err(MyError::Database(::mysql::Error::MySqlError(
+                    ::mysql::MySqlError {
+                        state: "HY000".to_string(),
+                        message: "Got error during COMMIT".to_string(),
+                        code: 1180,
+                    },
+                )));
+            }
+
+            // 登録済みのレートは無視する
+            let mut persisted = self.persisted.lock().unwrap();
+            let mut inserted = 0;
+            let mut forwarded: Vec<(String, String)> = vec![];
+            for rate in rates {
+                if !persisted
+                    .iter()
+                    .any(|r| r.pair == rate.pair && r.recorded_at == rate.recorded_at)
+                {
+                    persisted.push(rate.clone());
+                    inserted += 1;
+                    if self.forward && !forwarded.iter().any(|(pair, _)| *pair == rate.pair) {
+                        forwarded.push((rate.pair.clone(), format!("forecast-{}", rate.pair)));
+                    }
+                }
+            }
+            Ok(Written {
+                inserted,
+                forwarded,
+            })
+        }
+
+        fn forecast(&self, forwarded: &[(String, String)]) {
+            self.forecasted.lock().unwrap().extend_from_slice(forwarded);
+        }
+    }
+}
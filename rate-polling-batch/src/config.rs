@@ -0,0 +1,20 @@
+use common_lib::{config::Validator, error::MyResult};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    pub cron_schedule: String,
+    // レートの取得元（gmo_coin）
+    pub rate_source: String,
+    // 取得元APIのURL（空文字の場合は取得元ごとの既定値）
+    pub rate_source_url: String,
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_not_empty("rate_source", &self.rate_source);
+        v.finish()
+    }
+}
@@ -0,0 +1,178 @@
+extern crate common_lib;
+
+use common_lib::{
+    batch,
+    error::MyResult,
+    error_report, logger, metrics,
+    mysql::{self, client::Client},
+    settings::{self, SharedSettings},
+    telemetry,
+};
+use log::{error, info};
+use source::RateSource;
+
+mod config;
+mod source;
+
+const JOB_NAME: &str = "rate-polling-batch";
+
+fn init_logger() {
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
+}
+
+fn main() {
+    init_logger();
+
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init(JOB_NAME) {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init(JOB_NAME) {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
+    let config: config::Config;
+    match common_lib::config::load::<config::Config>() {
+        Ok(c) => {
+            config = c;
+        }
+        Err(err) => {
+            error!("failed to load config, error: {}", err);
+            return;
+        }
+    }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
+
+    let mysql_cli: mysql::client::DefaultClient;
+    match mysql::util::make_cli() {
+        Ok(cli) => {
+            mysql_cli = cli;
+        }
+        Err(err) => {
+            error!("failed to make mysql client, error: {}", err);
+            return;
+        }
+    }
+
+    let retry_policy: batch::util::RetryPolicy;
+    match batch::util::RetryPolicy::from_env() {
+        Ok(p) => {
+            retry_policy = p;
+        }
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    }
+
+    // 取り扱う通貨ペアは再起動せずに変更できるよう、SIGHUPまたは一定間隔で読み込み直す
+    let runtime_settings = SharedSettings::new();
+    if let Err(err) = runtime_settings.reload(&mysql_cli) {
+        error!("failed to load runtime settings, error: {}", err);
+        return;
+    }
+    match settings::reload_interval_from_env() {
+        Ok(interval) => {
+            if let Err(err) = runtime_settings.watch(mysql_cli.clone(), interval, |_| {}) {
+                error!("failed to watch runtime settings, error: {}", err);
+                return;
+            }
+        }
+        Err(err) => {
+            error!("failed to load settings config, error: {}", err);
+            return;
+        }
+    }
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    let source: Box<dyn RateSource>;
+    match source::make_source(&config.rate_source, &config.rate_source_url) {
+        Ok(s) => {
+            source = s;
+        }
+        Err(err) => {
+            error!("failed to make rate source, error: {}", err);
+            return;
+        }
+    }
+
+    if let Err(err) =
+        batch::util::start_scheduler_with_lock(JOB_NAME, &mysql_cli, &config.cron_schedule, || {
+            match health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    retry_policy.run(JOB_NAME, || {
+                        run(&mysql_cli, &runtime_settings, source.as_ref())
+                    })
+                })
+            }) {
+                Ok(_) => {}
+                Err(err) => {
+                    error!("failed to poll rate, error: {}", err);
+                }
+            }
+        })
+    {
+        error!("failed to start scheduler, error: {}", err);
+    }
+}
+
+fn run(
+    mysql_cli: &mysql::client::DefaultClient,
+    runtime_settings: &SharedSettings,
+    source: &dyn RateSource,
+) -> MyResult<()> {
+    // 取り扱う通貨ペアごとに取得する（1つの通貨ペアで失敗しても他の通貨ペアは登録する）
+    let mut rates = vec![];
+    let mut result: MyResult<()> = Ok(());
+    for pair in runtime_settings.get().allowed_pairs() {
+        match source.fetch(&pair) {
+            Ok(rate) => {
+                info!(
+                    "fetched rate, source: {}, pair: {}, recorded_at: {}, rate: {}",
+                    source.name(),
+                    rate.pair,
+                    rate.recorded_at,
+                    rate.rate
+                );
+                rates.push(rate);
+            }
+            Err(err) => {
+                error!("failed to fetch rate, pair: {}, error: {}", pair, err);
+                result = Err(err);
+            }
+        }
+    }
+
+    // 前回と同じ日時のレートは登録済みのため無視される
+    let inserted = mysql_cli.with_transaction(|tx| -> MyResult<u64> {
+        mysql_cli.bulk_insert_rates_for_training(tx, &rates)
+    })?;
+    info!("inserted rate count: {}", inserted);
+    metrics::observe_rates(&rates);
+
+    result
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use common_lib::{
+    domain::model::RateForTraining,
+    error::{MyError, MyResult},
+};
+use serde::Deserialize;
+
+// 取引所や証券会社のAPIからレートを取得する
+pub trait RateSource {
+    fn name(&self) -> &str;
+    fn fetch(&self, pair: &str) -> MyResult<RateForTraining>;
+}
+
+pub fn make_source(name: &str, url: &str) -> MyResult<Box<dyn RateSource>> {
+    match name {
+        GMO_COIN_SOURCE_NAME => Ok(Box::new(GmoCoinSource::new(url)?)),
+        _ => Err(MyError::UnsupportedRateSource {
+            name: name.to_string(),
+        }),
+    }
+}
+
+const GMO_COIN_SOURCE_NAME: &str = "gmo_coin";
+const GMO_COIN_DEFAULT_URL: &str = "https://forex-api.coin.z.com/public/v1/ticker";
+
+#[derive(Deserialize, Debug)]
+struct GmoCoinResponse {
+    status: i32,
+    #[serde(default)]
+    data: Vec<GmoCoinTicker>,
+}
+
+#[derive(Deserialize, Debug)]
+struct GmoCoinTicker {
+    symbol: String,
+    ask: String,
+    bid: String,
+    timestamp: String,
+}
+
+// GMOコイン（外国為替FX）の最新レート
+pub struct GmoCoinSource {
+    url: String,
+    client: reqwest::blocking::Client,
+}
+
+impl GmoCoinSource {
+    pub fn new(url: &str) -> MyResult<GmoCoinSource> {
+        let url = if url.is_empty() {
+            GMO_COIN_DEFAULT_URL
+        } else {
+            url
+        };
+        Ok(GmoCoinSource {
+            url: url.to_string(),
+            client: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()?,
+        })
+    }
+
+    fn error(&self, memo: String) -> MyError {
+        MyError::FetchRateFailed {
+            name: self.name().to_string(),
+            memo,
+        }
+    }
+}
+
+impl RateSource for GmoCoinSource {
+    fn name(&self) -> &str {
+        GMO_COIN_SOURCE_NAME
+    }
+
+    fn fetch(&self, pair: &str) -> MyResult<RateForTraining> {
+        // USDJPY -> USD_JPY
+        if pair.len() != 6 {
+            return Err(self.error(format!("unsupported pair, pair: {}", pair)));
+        }
+        let symbol = format!("{}_{}", &pair[..3], &pair[3..]);
+
+        let res: GmoCoinResponse = self.client.get(&self.url).send()?.json()?;
+        if res.status != 0 {
+            return Err(self.error(format!("status is not ok, status: {}", res.status)));
+        }
+        let ticker = res
+            .data
+            .iter()
+            .find(|t| t.symbol == symbol)
+            .ok_or_else(|| self.error(format!("ticker not found, symbol: {}", symbol)))?;
+
+        let ask: f64 = ticker.ask.parse()?;
+        let bid: f64 = ticker.bid.parse()?;
+        let time = DateTime::parse_from_rfc3339(&ticker.timestamp)?.with_timezone(&Utc);
+
+        RateForTraining::new(
+            pair,
+            &time.naive_utc().format("%Y-%m-%d %H:%M:%S").to_string(),
+            (ask + bid) / 2.0,
+        )
+    }
+}
@@ -1,3 +1,13 @@
+use common_lib::{
+    config::Validator,
+    domain::{
+        ensemble::{validate_weighting, ENSEMBLE_WEIGHTING_INVERSE_MSE},
+        model::forecast_type_from_offset_minutes,
+        scaler::{validate_scaling, FEATURE_SCALING_NONE},
+        series::{validate_gap_fill, GAP_FILL_FORWARD},
+    },
+    error::MyResult,
+};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -5,18 +15,32 @@ pub struct Config {
     // 共通設定
     pub forecast_input_size: usize,
     pub forecast_offset_minutes: usize,
-    pub currency_pair: String,
 
     // 定期実行スケジュール（定期実行しない場合は空文字）
     pub cron_schedule: String,
+    // 学習要求の確認スケジュール（定期実行時のみ確認する場合は空文字）
+    #[serde(default)]
+    pub request_cron_schedule: String,
     // 予測用モデルに割り当てる番号
     pub forecast_model_no: i32,
+    // チャレンジャーに割り当てる番号（0の場合は学習完了時に予測用モデルへ直接反映する）
+    #[serde(default)]
+    pub challenger_model_no: i32,
     // 学習中モデルに割り当てる番号
     pub training_model_no: i32,
     // 1世代あたりのモデル数
     pub training_model_count: usize,
     // 最大世代数
     pub generation_count: i32,
+    // 同時に学習する遺伝子の最大数（SVRなどはメモリを多く使うため、メモリ量に合わせて指定する）
+    #[serde(default = "default_training_max_concurrency")]
+    pub training_max_concurrency: usize,
+    // 遺伝子ごとにMSEが小さい順に組み合わせてアンサンブルを作るモデル数（0の場合はアンサンブルを作らない）
+    #[serde(default)]
+    pub training_ensemble_size: usize,
+    // アンサンブルの重み付けの方法（mean: 単純平均, inverse_mse: MSEの逆数で重み付け）
+    #[serde(default = "default_training_ensemble_weighting")]
+    pub training_ensemble_weighting: String,
 
     // 学習データの必要数
     pub training_data_required_count: usize,
@@ -25,6 +49,11 @@ pub struct Config {
     // 学習データ取得範囲（終了）の算出用オフセット値（現在日時から何時間前にするかを指定）
     pub training_data_range_end_offset_hour: i64,
 
+    // 性能の評価に使う期間の数（テストデータの期間と、学習データを分割した期間（数 - 1 個）で評価したMSEを平均する）
+    // 1の場合はテストデータの期間のみで評価する
+    #[serde(default = "default_cross_validation_folds")]
+    pub cross_validation_folds: usize,
+
     // テストデータの必要数
     pub test_data_required_count: usize,
     // テストデータ取得範囲（開始）の算出用オフセット値（現在日時から何時間前にするかを指定）
@@ -32,8 +61,178 @@ pub struct Config {
     // テストデータ取得範囲（終了）の算出用オフセット値（現在日時から何時間前にするかを指定）
     pub test_data_range_end_offset_hour: i64,
 
+    // 埋める欠損の最大分数（これより長い欠損をまたぐデータは学習に使わない）
+    #[serde(default = "default_training_fill_gap_minutes")]
+    pub training_fill_gap_minutes: usize,
+    // 欠損の埋め方（forward: 直前のレートで埋める, linear: 前後のレートから線形補間する）
+    #[serde(default = "default_training_gap_fill_method")]
+    pub training_gap_fill_method: String,
+    // data-clean-batch が異常と判定したレートを学習に使わない場合は true
+    #[serde(default = "default_training_exclude_anomalies")]
+    pub training_exclude_anomalies: bool,
+
+    // 特徴量の尺度の揃え方（none: 変換しない, zscore: 平均0・標準偏差1, minmax: 最小値0・最大値1）
+    #[serde(default = "default_training_feature_scaling")]
+    pub training_feature_scaling: String,
+
     // 交叉率
     pub crossover_rate: f32,
     // 突然変異率
     pub mutation_rate: f32,
+    // 乱数のシード（指定した場合は同じデータから同じ学習結果を再現できる）
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+}
+
+fn default_training_max_concurrency() -> usize {
+    1
+}
+
+fn default_training_ensemble_weighting() -> String {
+    ENSEMBLE_WEIGHTING_INVERSE_MSE.to_string()
+}
+
+fn default_cross_validation_folds() -> usize {
+    1
+}
+
+fn default_training_feature_scaling() -> String {
+    FEATURE_SCALING_NONE.to_string()
+}
+
+fn default_training_gap_fill_method() -> String {
+    GAP_FILL_FORWARD.to_string()
+}
+
+fn default_training_fill_gap_minutes() -> usize {
+    5
+}
+
+fn default_training_exclude_anomalies() -> bool {
+    true
+}
+
+impl Config {
+    pub fn validate(&self) -> MyResult<()> {
+        let mut v = Validator::new();
+        v.check_positive("forecast_input_size", self.forecast_input_size);
+        if let Err(err) = forecast_type_from_offset_minutes(self.forecast_offset_minutes) {
+            v.check(
+                false,
+                format!("forecast_offset_minutes is invalid, {}", err),
+            );
+        }
+        v.check_cron("cron_schedule", &self.cron_schedule);
+        v.check_cron("request_cron_schedule", &self.request_cron_schedule);
+
+        v.check_positive("forecast_model_no", self.forecast_model_no);
+        v.check_positive("training_model_no", self.training_model_no);
+        v.check(
+            self.forecast_model_no != self.training_model_no,
+            format!(
+                "forecast_model_no and training_model_no must be different, value: {}",
+                self.forecast_model_no
+            ),
+        );
+        v.check(
+            self.challenger_model_no >= 0,
+            format!(
+                "challenger_model_no must be 0 or more, value: {}",
+                self.challenger_model_no
+            ),
+        );
+        if self.challenger_model_no > 0 {
+            v.check(
+                self.challenger_model_no != self.forecast_model_no
+                    && self.challenger_model_no != self.training_model_no,
+                format!(
+                    "challenger_model_no must be different from forecast_model_no and training_model_no, value: {}",
+                    self.challenger_model_no
+                ),
+            );
+        }
+        // 交叉には2つ以上の個体が必要
+        v.check(
+            self.training_model_count >= 2,
+            format!(
+                "training_model_count must be 2 or more, value: {}",
+                self.training_model_count
+            ),
+        );
+        v.check_positive("generation_count", self.generation_count);
+        v.check_positive("training_max_concurrency", self.training_max_concurrency);
+        v.check(
+            self.training_ensemble_size != 1,
+            "training_ensemble_size must be 0 or 2 or more, value: 1".to_string(),
+        );
+        if let Err(err) = validate_weighting(&self.training_ensemble_weighting) {
+            v.check(
+                false,
+                format!("training_ensemble_weighting is invalid, {}", err),
+            );
+        }
+
+        v.check_positive(
+            "training_data_required_count",
+            self.training_data_required_count,
+        );
+        v.check(
+            self.training_data_range_begin_offset_hour > self.training_data_range_end_offset_hour,
+            format!(
+                "training_data_range_begin_offset_hour must be greater than training_data_range_end_offset_hour, begin: {}, end: {}",
+                self.training_data_range_begin_offset_hour, self.training_data_range_end_offset_hour
+            ),
+        );
+        v.check_positive("cross_validation_folds", self.cross_validation_folds);
+        // 各期間に学習・評価に使えるデータが残るようにする
+        v.check(
+            self.cross_validation_folds <= self.training_data_required_count,
+            format!(
+                "cross_validation_folds must be training_data_required_count or less, value: {}",
+                self.cross_validation_folds
+            ),
+        );
+        v.check_positive("test_data_required_count", self.test_data_required_count);
+        v.check(
+            self.test_data_range_begin_offset_hour > self.test_data_range_end_offset_hour,
+            format!(
+                "test_data_range_begin_offset_hour must be greater than test_data_range_end_offset_hour, begin: {}, end: {}",
+                self.test_data_range_begin_offset_hour, self.test_data_range_end_offset_hour
+            ),
+        );
+
+        if let Err(err) = validate_gap_fill(&self.training_gap_fill_method) {
+            v.check(
+                false,
+                format!("training_gap_fill_method is invalid, {}", err),
+            );
+        }
+
+        if let Err(err) = validate_scaling(&self.training_feature_scaling) {
+            v.check(
+                false,
+                format!("training_feature_scaling is invalid, {}", err),
+            );
+        }
+
+        v.check_range("crossover_rate", self.crossover_rate, 0.0, 1.0);
+        v.check_range("mutation_rate", self.mutation_rate, 0.0, 1.0);
+        v.check(
+            self.crossover_rate + self.mutation_rate <= 1.0,
+            format!(
+                "crossover_rate + mutation_rate must be 1.0 or less, value: {}",
+                self.crossover_rate + self.mutation_rate
+            ),
+        );
+        v.finish()
+    }
+
+    // 学習完了時にモデルをコピーする先の番号
+    pub fn promotion_model_no(&self) -> i32 {
+        if self.challenger_model_no > 0 {
+            self.challenger_model_no
+        } else {
+            self.forecast_model_no
+        }
+    }
 }
@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use common_lib::{
+    domain::model::{FeatureData, ForecastModel},
+    error::{MyError, MyResult},
+};
+
+// 学習データの範囲と評価データの範囲（学習データより後の期間を評価する）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fold {
+    pub train: Range<usize>,
+    pub validation: Range<usize>,
+}
+
+// 時系列のデータを folds 個に分け、評価データの直前までで学習する分割を作る（rolling origin）
+// 最初のブロックは学習にのみ使うため、分割の数は folds - 1 になる
+pub fn rolling_origin_folds(size: usize, folds: usize) -> MyResult<Vec<Fold>> {
+    let block = if folds == 0 { 0 } else { size / folds };
+    if block == 0 {
+        return Err(MyError::InputDataIsTooLittle {
+            count: size,
+            require: folds.max(1),
+        });
+    }
+
+    Ok((1..folds)
+        .map(|i| {
+            // 割り切れない場合は最後の評価データに含める
+            let end = if i == folds - 1 {
+                size
+            } else {
+                (i + 1) * block
+            };
+            Fold {
+                train: 0..(i * block),
+                validation: (i * block)..end,
+            }
+        })
+        .collect())
+}
+
+// 学習データ内の各分割で学習・評価し、テストデータでの評価（test_mse）と合わせたMSEの平均を返す
+// make は学習データと評価データを受け取り、評価データでの性能を設定したモデルを返す
+pub fn cross_validate<F>(
+    train_x: &[FeatureData],
+    train_y: &[f64],
+    test_mse: f64,
+    folds: usize,
+    make: F,
+) -> MyResult<f64>
+where
+    F: Fn(&Vec<FeatureData>, &Vec<f64>, &Vec<FeatureData>, &Vec<f64>) -> MyResult<ForecastModel>,
+{
+    let size = train_x.len().min(train_y.len());
+    let mut mse_list = vec![test_mse];
+    for fold in rolling_origin_folds(size, folds)? {
+        let m = make(
+            &train_x[fold.train.clone()].to_vec(),
+            &train_y[fold.train].to_vec(),
+            &train_x[fold.validation.clone()].to_vec(),
+            &train_y[fold.validation].to_vec(),
+        )?;
+        mse_list.push(m.get_performance_mse());
+    }
+    Ok(mse_list.iter().sum::<f64>() / mse_list.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_rolling_origin_folds() {
+        let folds = rolling_origin_folds(10, 3).unwrap();
+        assert_eq!(
+            folds,
+            vec![
+                Fold {
+                    train: 0..3,
+                    validation: 3..6
+                },
+                Fold {
+                    train: 0..6,
+                    validation: 6..10
+                },
+            ]
+        );
+
+        // 評価データが学習データより前の期間になることはない
+        for fold in rolling_origin_folds(100, 5).unwrap() {
+            assert_eq!(fold.train.start, 0);
+            assert_eq!(fold.train.end, fold.validation.start);
+        }
+
+        assert!(rolling_origin_folds(2, 3).is_err());
+        assert!(rolling_origin_folds(10, 0).is_err());
+    }
+}
@@ -6,8 +6,6 @@ use common_lib::{
 };
 use rand::Rng;
 
-use crate::config;
-
 #[derive(Clone)]
 pub struct Gene {
     values: Vec<usize>,
@@ -25,17 +23,24 @@ impl Gene {
         values.push((p.slow_period - p.fast_period) * 2);
         values.push(p.signal_period);
         values.push(p.bb_period);
+        values.push(p.rsi_period);
+        values.push(p.stoch_period);
+        values.push(p.stoch_d_period);
         Ok(Gene { values })
     }
 
-    pub fn new_random_gene(config: &config::Config) -> MyResult<Gene> {
+    // 各値は最小値から max_value（入力データのサイズ）までの乱数にする
+    pub fn new_random_gene<R: Rng + ?Sized>(max_value: usize, rng: &mut R) -> MyResult<Gene> {
         Ok(Gene {
             values: vec![
-                Self::gen_value_random(config),
-                Self::gen_value_random(config),
-                Self::gen_value_random(config),
-                Self::gen_value_random(config),
-                Self::gen_value_random(config),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
+                Self::gen_value_random(max_value, rng),
             ],
         })
     }
@@ -47,17 +52,20 @@ impl Gene {
             slow_period: Self::round(self.values[1] / 2 + self.values[2] / 2),
             signal_period: Self::round(self.values[3]),
             bb_period: Self::round(self.values[4]),
+            rsi_period: Self::round(self.values[5]),
+            stoch_period: Self::round(self.values[6]),
+            stoch_d_period: Self::round(self.values[7]),
+            scaler: None,
         })
     }
 
-    pub fn mutation(&mut self, config: &config::Config) -> MyResult<()> {
-        let index = self.gen_index_random();
-        self.values[index] = Self::gen_value_random(config);
+    pub fn mutation<R: Rng + ?Sized>(&mut self, max_value: usize, rng: &mut R) -> MyResult<()> {
+        let index = self.gen_index_random(rng);
+        self.values[index] = Self::gen_value_random(max_value, rng);
         Ok(())
     }
 
-    fn gen_index_random(&self) -> usize {
-        let mut rng = rand::thread_rng();
+    fn gen_index_random<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
         rng.gen_range(0..self.values.len())
     }
 
@@ -78,20 +86,23 @@ impl Gene {
         (v % (Self::FEATURE_SIZE_MAX - Self::FEATURE_SIZE_MIN)) + Self::FEATURE_SIZE_MIN
     }
 
-    pub fn gen_value_random(config: &config::Config) -> usize {
-        let mut rng = rand::thread_rng();
-        rng.gen_range(Self::MIN_VALUE..=config.forecast_input_size)
+    pub fn gen_value_random<R: Rng + ?Sized>(max_value: usize, rng: &mut R) -> usize {
+        rng.gen_range(Self::MIN_VALUE..=max_value)
     }
 
-    pub fn select_gene_index_random(genes: &Vec<Gene>) -> MyResult<usize> {
-        let mut rng = rand::thread_rng();
+    pub fn select_gene_index_random<R: Rng + ?Sized>(
+        genes: &Vec<Gene>,
+        rng: &mut R,
+    ) -> MyResult<usize> {
         Ok(rng.gen_range(0..genes.len()))
     }
 
-    pub fn select_index_roulette(weights: &Vec<f64>) -> MyResult<usize> {
+    pub fn select_index_roulette<R: Rng + ?Sized>(
+        weights: &Vec<f64>,
+        rng: &mut R,
+    ) -> MyResult<usize> {
         let total: f64 = weights.iter().map(|v| 1.0 - v).sum();
 
-        let mut rng = rand::thread_rng();
         let border: f64 = rng.gen();
         let mut sum: f64 = 0.0;
         let mut index: usize = 0;
@@ -110,9 +121,9 @@ impl Gene {
         Ok(index)
     }
 
-    pub fn crossover(g1: &mut Self, g2: &mut Self) -> MyResult<()> {
-        let index1 = g1.gen_index_random();
-        let index2 = g2.gen_index_random();
+    pub fn crossover<R: Rng + ?Sized>(g1: &mut Self, g2: &mut Self, rng: &mut R) -> MyResult<()> {
+        let index1 = g1.gen_index_random(rng);
+        let index2 = g2.gen_index_random(rng);
         let tmp = g1.values[index1];
         g1.values[index1] = g2.values[index2];
         g2.values[index2] = tmp;
@@ -121,9 +132,9 @@ impl Gene {
 
     pub fn make_average_gene(genes: &Vec<Gene>) -> MyResult<Gene> {
         if genes.is_empty() {
-            return Err(Box::new(MyError::ArrayIsEmpty {
+            return Err(MyError::ArrayIsEmpty {
                 name: "genes".to_string(),
-            }));
+            });
         }
 
         let size = genes.len();
@@ -145,3 +156,80 @@ impl Gene {
         Ok(total / genes.len() as f64)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn make_genes() -> (Gene, Gene) {
+        (
+            Gene {
+                values: vec![2, 3, 4, 5, 6],
+            },
+            Gene {
+                values: vec![7, 8, 9, 10, 11],
+            },
+        )
+    }
+
+    #[test]
+    fn test_for_crossover() {
+        for seed in 0..100 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (mut g1, mut g2) = make_genes();
+            Gene::crossover(&mut g1, &mut g2, &mut rng).unwrap();
+
+            // 値を1つずつ交換するため、2つの遺伝子の値の組み合わせは変わらない
+            let mut values: Vec<usize> =
+                g1.values.iter().chain(g2.values.iter()).copied().collect();
+            values.sort_unstable();
+            assert_eq!(values, (2..=11).collect::<Vec<usize>>());
+            assert_eq!(g1.values.iter().filter(|v| **v >= 7).count(), 1);
+        }
+
+        // 同じシードであれば同じ結果になる
+        let run = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (mut g1, mut g2) = make_genes();
+            Gene::crossover(&mut g1, &mut g2, &mut rng).unwrap();
+            (g1.values, g2.values)
+        };
+        assert_eq!(run(1), run(1));
+    }
+
+    #[test]
+    fn test_for_mutation() {
+        for seed in 0..100 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (original, _) = make_genes();
+            let mut gene = original.clone();
+            gene.mutation(20, &mut rng).unwrap();
+
+            // 変化する値は1つまでで、範囲内の値になる
+            let changed = gene
+                .values
+                .iter()
+                .zip(original.values.iter())
+                .filter(|(a, b)| a != b)
+                .count();
+            assert!(changed <= 1);
+            assert!(gene
+                .values
+                .iter()
+                .all(|v| (Gene::MIN_VALUE..=20).contains(v)));
+        }
+    }
+
+    #[test]
+    fn test_for_select_index() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (g1, g2) = make_genes();
+        let genes = vec![g1, g2];
+        let weights = vec![0.1, 0.2, 0.3];
+        for _ in 0..100 {
+            assert!(Gene::select_gene_index_random(&genes, &mut rng).unwrap() < genes.len());
+            assert!(Gene::select_index_roulette(&weights, &mut rng).unwrap() < weights.len());
+        }
+    }
+}
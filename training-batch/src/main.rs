@@ -1,13 +1,21 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Instant};
 
 use common_lib::{
     batch,
-    domain::model::ForecastModel,
+    clock::SystemClock,
+    domain::model::{
+        FeatureParams, ForecastModel, TrainingRun, TRAINING_RUN_STATUS_FAILED,
+        TRAINING_RUN_STATUS_REQUESTED, TRAINING_RUN_STATUS_SUCCEEDED,
+    },
     error::MyResult,
+    error_report, logger, metrics,
     mysql::{
         self,
         client::{Client, DefaultClient},
     },
+    notify::{self, NotifyEvent},
+    settings::{self, SharedSettings},
+    telemetry,
 };
 use ga::Gene;
 use log::{error, info};
@@ -17,19 +25,46 @@ use training::InputDataLoader;
 use crate::training::ModelMaker;
 
 mod config;
+mod cross_validation;
 mod ga;
 mod training;
 mod util;
 
+const JOB_NAME: &str = "training-batch";
+
 fn init_logger() {
-    env_logger::init();
+    logger::init(JOB_NAME);
+    logger::set_field("job", JOB_NAME);
 }
 
 fn main() {
     init_logger();
 
+    // 破棄時に未送信のトレースを送信するため、main の終了まで保持する
+    let _telemetry: telemetry::TelemetryGuard;
+    match telemetry::init(JOB_NAME) {
+        Ok(g) => {
+            _telemetry = g;
+        }
+        Err(err) => {
+            error!("failed to init telemetry, error: {}", err);
+            return;
+        }
+    }
+
+    let _error_report: error_report::ErrorReportGuard;
+    match error_report::init(JOB_NAME) {
+        Ok(g) => {
+            _error_report = g;
+        }
+        Err(err) => {
+            error!("failed to init error report, error: {}", err);
+            return;
+        }
+    }
+
     let config: config::Config;
-    match envy::from_env::<config::Config>() {
+    match common_lib::config::load::<config::Config>() {
         Ok(c) => {
             config = c;
         }
@@ -38,6 +73,10 @@ fn main() {
             return;
         }
     }
+    if let Err(err) = config.validate() {
+        error!("invalid config, error: {}", err);
+        return;
+    }
 
     let mysql_cli: mysql::client::DefaultClient;
     match mysql::util::make_cli() {
@@ -50,23 +89,248 @@ fn main() {
         }
     }
 
-    if let Err(err) = batch::util::start_scheduler(&config.cron_schedule, || {
-        info!("start training");
-        match training(&config, &mysql_cli) {
-            Ok(_) => {
-                info!("finished training");
+    let retry_policy: batch::util::RetryPolicy;
+    match batch::util::RetryPolicy::from_env() {
+        Ok(p) => {
+            retry_policy = p;
+        }
+        Err(err) => {
+            error!("failed to load retry policy, error: {}", err);
+            return;
+        }
+    }
+
+    // 取り扱う通貨ペアは再起動せずに変更できるよう、SIGHUPまたは一定間隔で読み込み直す
+    let runtime_settings = SharedSettings::new();
+    if let Err(err) = runtime_settings.reload(&mysql_cli) {
+        error!("failed to load runtime settings, error: {}", err);
+        return;
+    }
+    match settings::reload_interval_from_env() {
+        Ok(interval) => {
+            if let Err(err) = runtime_settings.watch(mysql_cli.clone(), interval, |_| {}) {
+                error!("failed to watch runtime settings, error: {}", err);
+                return;
             }
+        }
+        Err(err) => {
+            error!("failed to load settings config, error: {}", err);
+            return;
+        }
+    }
+
+    let health = batch::health::HealthReporter::new(JOB_NAME);
+    if let Err(err) = health.serve_from_env() {
+        error!("failed to start health endpoint, error: {}", err);
+        return;
+    }
+
+    // ワーカーモードでは、オーケストレーターからの要求を受けて実行する（再試行もオーケストレーターが行う）
+    let worker_mode: bool;
+    match batch::util::worker_mode_from_env() {
+        Ok(m) => {
+            worker_mode = m;
+        }
+        Err(err) => {
+            error!("failed to load worker mode, error: {}", err);
+            return;
+        }
+    }
+    if worker_mode {
+        if let Err(err) = batch::worker::start_worker(JOB_NAME, &mysql_cli, || {
+            health.track(|| {
+                batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                    run_training(&config, &mysql_cli, &runtime_settings, false, None)
+                })
+            })
+        }) {
+            error!("failed to start worker, error: {}", err);
+        }
+        return;
+    }
+
+    let scheduled_job = || {
+        if let Err(err) = health.track(|| {
+            batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                run_training(
+                    &config,
+                    &mysql_cli,
+                    &runtime_settings,
+                    false,
+                    Some(&retry_policy),
+                )
+            })
+        }) {
+            error!("failed to training, error:{}", err);
+        }
+    };
+    let requested_job = || {
+        // 要求の確認は頻繁に行うため、実行履歴には要求があって学習した場合のみ記録する
+        match has_training_requests(&mysql_cli, &runtime_settings) {
+            Ok(true) => {}
+            Ok(false) => return,
             Err(err) => {
-                error!("failed to training, error:{}", err);
+                error!("failed to check training requests, error: {}", err);
+                return;
             }
         }
-    }) {
+        if let Err(err) = health.track(|| {
+            batch::util::record_job_run(&mysql_cli, JOB_NAME, || {
+                run_training(
+                    &config,
+                    &mysql_cli,
+                    &runtime_settings,
+                    true,
+                    Some(&retry_policy),
+                )
+            })
+        }) {
+            error!("failed to training, error:{}", err);
+        }
+    };
+
+    let result = if config.cron_schedule.is_empty() || config.request_cron_schedule.is_empty() {
+        batch::util::start_scheduler_with_lock(
+            JOB_NAME,
+            &mysql_cli,
+            &config.cron_schedule,
+            scheduled_job,
+        )
+    } else {
+        batch::util::start_multi_scheduler_with_lock(
+            JOB_NAME,
+            &mysql_cli,
+            &[
+                (&config.cron_schedule, &scheduled_job),
+                (&config.request_cron_schedule, &requested_job),
+            ],
+        )
+    };
+    if let Err(err) = result {
         error!("failed to start scheduler, error: {}", err);
     }
 }
 
-fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()> {
-    let loader = InputDataLoader { config, mysql_cli };
+fn has_training_requests(
+    mysql_cli: &DefaultClient,
+    runtime_settings: &SharedSettings,
+) -> MyResult<bool> {
+    mysql_cli.with_transaction(|tx| {
+        for pair in runtime_settings.get().allowed_pairs() {
+            if !mysql_cli
+                .select_training_runs_requested(tx, &pair)?
+                .is_empty()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    })
+}
+
+// 取り扱う通貨ペアごとに学習する（1つの通貨ペアで失敗しても他の通貨ペアは学習する）
+// 再試行は失敗した通貨ペアのみ行う（成功した通貨ペアを学習し直さない）
+fn run_training(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    runtime_settings: &SharedSettings,
+    requested_only: bool,
+    retry_policy: Option<&batch::util::RetryPolicy>,
+) -> MyResult<()> {
+    let mut result: MyResult<()> = Ok(());
+    for pair in runtime_settings.get().allowed_pairs() {
+        logger::set_field("pair", &pair);
+        error_report::set_tag("pair", &pair);
+        let run = || run_training_for_pair(config, mysql_cli, &pair, requested_only);
+        let pair_result = match retry_policy {
+            Some(retry_policy) => retry_policy.run(JOB_NAME, run),
+            None => run(),
+        };
+        if let Err(err) = pair_result {
+            error!("failed to training, pair: {}, error: {}", pair, err);
+            result = Err(err);
+        }
+    }
+    result
+}
+
+// 学習を実行し、その経過を学習実行履歴に記録する
+// requested_only が true の場合は要求済みの学習実行がある場合のみ学習する
+fn run_training_for_pair(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    pair: &str,
+    requested_only: bool,
+) -> MyResult<()> {
+    let mut run_ids: Vec<String> = vec![];
+    mysql_cli.with_transaction(|tx| {
+        run_ids = mysql_cli
+            .select_training_runs_requested(tx, pair)?
+            .iter()
+            .map(|run| run.id.clone())
+            .collect();
+        if run_ids.is_empty() && !requested_only {
+            let run = TrainingRun::new(
+                pair.to_string(),
+                TRAINING_RUN_STATUS_REQUESTED,
+                "scheduled by training-batch".to_string(),
+            )?;
+            run_ids.push(mysql_cli.insert_training_run(tx, &run)?);
+        }
+        for id in run_ids.iter() {
+            mysql_cli.update_training_run_started(tx, id)?;
+        }
+        Ok(())
+    })?;
+    if run_ids.is_empty() {
+        return Ok(());
+    }
+
+    info!("start training, pair: {}, run_ids: {:?}", pair, run_ids);
+    let started = Instant::now();
+    let result = training(config, mysql_cli, pair);
+    metrics::TRAINING_DURATION_SECONDS
+        .with_label_values(&[pair, metrics::status_label(&result)])
+        .observe(started.elapsed().as_secs_f64());
+    let (status, detail) = match &result {
+        Ok(best) => {
+            info!("finished training");
+            if let Some((mse, rmse)) = best {
+                notify::notify_from_env(
+                    NotifyEvent::TrainingCompleted,
+                    &format!(
+                        "pair: {}, model_no: {}, mse: {}, rmse: {}",
+                        pair, config.training_model_no, mse, rmse
+                    ),
+                );
+            }
+            (TRAINING_RUN_STATUS_SUCCEEDED, "completed".to_string())
+        }
+        Err(err) => (TRAINING_RUN_STATUS_FAILED, format!("{}", err)),
+    };
+
+    mysql_cli.with_transaction(|tx| {
+        for id in run_ids.iter() {
+            mysql_cli.update_training_run_finished(tx, id, status, &detail)?;
+        }
+        Ok(())
+    })?;
+
+    result.map(|_| ())
+}
+
+// 学習し、最も良いモデルの性能（mse, rmse）を返す
+fn training(
+    config: &config::Config,
+    mysql_cli: &DefaultClient,
+    pair: &str,
+) -> MyResult<Option<(f64, f64)>> {
+    let loader = InputDataLoader {
+        config,
+        mysql_cli,
+        pair,
+        clock: &SystemClock,
+    };
 
     let (train_x, train_y) = loader.load_training_data()?;
     info!("training data count: {}", train_x.len());
@@ -77,12 +341,14 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
     let maker = ModelMaker {
         config,
         mysql_cli,
+        pair,
         train_x: &train_x,
         train_y: &train_y,
         test_x: &test_x,
         test_y: &test_y,
     };
 
+    let mut rng = util::make_rng(config);
     let mut genes: Vec<Gene> = vec![];
     if let Some(m) = maker.load_existing_model(config.forecast_model_no)? {
         let p = m.get_feature_params()?;
@@ -92,17 +358,18 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
     }
 
     while genes.len() < config.training_model_count {
-        genes.push(Gene::new_random_gene(config)?);
+        genes.push(Gene::new_random_gene(config.forecast_input_size, &mut rng)?);
     }
 
     let genes_count = genes.len() as i32;
+    let mut best: Option<(f64, f64)> = None;
     for gen_count in 1..=config.generation_count {
         info!(
             "generation[{:<03}/{:<03}] start",
             gen_count, config.generation_count
         );
 
-        let mut models: Vec<Vec<ForecastModel>> = vec![];
+        let mut params: Vec<FeatureParams> = vec![];
         for (i, gene) in genes.iter().enumerate() {
             let p = gene.to_feature_params()?;
 
@@ -115,8 +382,9 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
                 p
             );
 
-            models.push(maker.make_new_models(config.training_model_no, &p)?);
+            params.push(p);
         }
+        let models = maker.make_new_models_parallel(config.training_model_no, &params)?;
 
         // モデルを評価
         let mut best_model: Option<&ForecastModel> = None;
@@ -157,6 +425,7 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
                 m.get_performance_rmse(),
             );
             save_model(mysql_cli, m)?;
+            best = Some((m.get_performance_mse(), m.get_performance_rmse()));
 
             if let Some(i) = best_index {
                 selected.insert(i);
@@ -165,13 +434,12 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
         }
 
         if should_training_complete(config, gen_count, &genes)? {
-            copy_training_model_to_forecast_model(mysql_cli, config)?;
+            copy_training_model_to_forecast_model(mysql_cli, config, pair)?;
             break;
         }
 
         // 次世代を生成
         while new_genes.len() < genes.len() {
-            let mut rng = rand::thread_rng();
             let v: f32 = rng.gen();
             if v < config.crossover_rate {
                 // 交叉する空きがあるかチェック
@@ -181,28 +449,28 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
 
                 // 交叉
                 let (index1, index2) = loop {
-                    let i = Gene::select_gene_index_random(&genes)?;
-                    let j = Gene::select_gene_index_random(&genes)?;
+                    let i = Gene::select_gene_index_random(&genes, &mut rng)?;
+                    let j = Gene::select_gene_index_random(&genes, &mut rng)?;
                     if i != j {
                         break (i, j);
                     }
                 };
                 let mut g1 = genes[index1].clone();
                 let mut g2 = genes[index2].clone();
-                Gene::crossover(&mut g1, &mut g2)?;
+                Gene::crossover(&mut g1, &mut g2, &mut rng)?;
                 new_genes.push(g1);
                 new_genes.push(g2);
             } else if v < (config.crossover_rate + config.mutation_rate) {
                 // 突然変異
-                let index = Gene::select_gene_index_random(&genes)?;
+                let index = Gene::select_gene_index_random(&genes, &mut rng)?;
                 let mut new_gene = genes[index].clone();
-                new_gene.mutation(config)?;
+                new_gene.mutation(config.forecast_input_size, &mut rng)?;
                 new_genes.push(new_gene);
             } else {
                 // 選択
                 if selected.len() < genes.len() {
                     let index = loop {
-                        let i = Gene::select_index_roulette(&results)?;
+                        let i = Gene::select_index_roulette(&results, &mut rng)?;
                         if !selected.contains(&i) {
                             break i;
                         }
@@ -215,7 +483,7 @@ fn training(config: &config::Config, mysql_cli: &DefaultClient) -> MyResult<()>
         genes = new_genes;
     }
 
-    Ok(())
+    Ok(best)
 }
 
 fn find_best_model_index(models: &Vec<ForecastModel>) -> MyResult<usize> {
@@ -236,22 +504,30 @@ fn save_model(mysql_cli: &DefaultClient, model: &ForecastModel) -> MyResult<()>
         mysql_cli.upsert_forecast_model(tx, model)?;
         Ok(())
     })?;
+    metrics::MODELS_TRAINED_TOTAL
+        .with_label_values(&[&model.get_pair()?])
+        .inc();
     Ok(())
 }
 
 fn copy_training_model_to_forecast_model(
     mysql_cli: &DefaultClient,
     config: &config::Config,
+    pair: &str,
 ) -> MyResult<()> {
+    // チャレンジャーが設定されている場合は、実績誤差の比較で勝った場合のみ evaluation-batch が予測用モデルへ昇格する
+    let to_no = config.promotion_model_no();
     mysql_cli.with_transaction(|tx| {
-        mysql_cli.copy_forecast_model(
-            tx,
-            &config.currency_pair,
-            config.training_model_no,
-            config.forecast_model_no,
-        )?;
+        mysql_cli.copy_forecast_model(tx, pair, config.training_model_no, to_no)?;
         Ok(())
     })?;
+    notify::notify_from_env(
+        NotifyEvent::ModelPromoted,
+        &format!(
+            "pair: {}, from_no: {}, to_no: {}",
+            pair, config.training_model_no, to_no
+        ),
+    );
     Ok(())
 }
 
@@ -1,11 +1,22 @@
-use chrono::{Duration, NaiveDateTime, Utc};
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use chrono::{Duration, NaiveDateTime};
 use common_lib::{
+    clock::Clock,
     domain::{
+        boosting::GradientBoostingRegressor,
+        ensemble::EnsembleModel,
         model::{FeatureData, FeatureParams, ForecastModel, InputData},
+        quantile::QuantileRegression,
+        scaler::FeatureScaler,
         service::convert_to_features,
     },
     error::{MyError, MyResult},
-    mysql::{self, client::Client},
+    mysql::{self, client::Client, model::ForecastModelBlob},
 };
 use log::{debug, warn};
 use smartcore::{
@@ -25,46 +36,57 @@ use smartcore::{
     },
 };
 
-use crate::{config, util};
+use crate::{config, cross_validation, util};
 
 pub struct InputDataLoader<'a> {
     pub config: &'a config::Config,
     pub mysql_cli: &'a mysql::client::DefaultClient,
+    pub pair: &'a str,
+    pub clock: &'a dyn Clock,
 }
 
 impl InputDataLoader<'_> {
     pub fn load_training_data(&self) -> MyResult<(Vec<InputData>, Vec<f64>)> {
-        let end = (Utc::now() - Duration::hours(self.config.training_data_range_end_offset_hour))
-            .naive_utc();
-        let begin = (Utc::now()
-            - Duration::hours(self.config.training_data_range_begin_offset_hour))
-        .naive_utc();
-
+        let (begin, end) = self.range(
+            self.config.training_data_range_begin_offset_hour,
+            self.config.training_data_range_end_offset_hour,
+        );
         self.load_data(begin, end, self.config.training_data_required_count)
     }
 
     pub fn load_test_data(&self) -> MyResult<(Vec<InputData>, Vec<f64>)> {
-        let end =
-            (Utc::now() - Duration::hours(self.config.test_data_range_end_offset_hour)).naive_utc();
-        let begin = (Utc::now() - Duration::hours(self.config.test_data_range_begin_offset_hour))
-            .naive_utc();
-
+        let (begin, end) = self.range(
+            self.config.test_data_range_begin_offset_hour,
+            self.config.test_data_range_end_offset_hour,
+        );
         self.load_data(begin, end, self.config.test_data_required_count)
     }
 
+    // 現在日時から指定時間前の範囲（開始, 終了）
+    fn range(
+        &self,
+        begin_offset_hour: i64,
+        end_offset_hour: i64,
+    ) -> (NaiveDateTime, NaiveDateTime) {
+        let now = self.clock.now();
+        let begin = (now - Duration::hours(begin_offset_hour)).naive_utc();
+        let end = (now - Duration::hours(end_offset_hour)).naive_utc();
+        (begin, end)
+    }
+
     fn load_data(
         &self,
         begin: NaiveDateTime,
         end: NaiveDateTime,
         required_count: usize,
     ) -> MyResult<(Vec<InputData>, Vec<f64>)> {
-        let (x, y) = util::load_input_data(self.config, self.mysql_cli, begin, end)?;
+        let (x, y) = util::load_input_data(self.config, self.mysql_cli, self.pair, begin, end)?;
         let count = x.len();
         if count < required_count {
-            return Err(Box::new(MyError::InputDataIsTooLittle {
+            return Err(MyError::InputDataIsTooLittle {
                 count,
                 require: required_count,
-            }));
+            });
         }
 
         Ok((x, y))
@@ -74,6 +96,7 @@ impl InputDataLoader<'_> {
 pub struct ModelMaker<'a> {
     pub config: &'a config::Config,
     pub mysql_cli: &'a mysql::client::DefaultClient,
+    pub pair: &'a str,
     pub train_x: &'a Vec<InputData>,
     pub train_y: &'a Vec<f64>,
     pub test_x: &'a Vec<InputData>,
@@ -87,7 +110,7 @@ impl ModelMaker<'_> {
     pub fn load_existing_model(&self, model_no: i32) -> MyResult<Option<ForecastModel>> {
         let model = self.mysql_cli.with_transaction(|tx| {
             self.mysql_cli
-                .select_forecast_model(tx, &self.config.currency_pair, model_no)
+                .select_forecast_model(tx, self.pair, model_no)
         })?;
 
         if let Some(mut m) = model {
@@ -108,6 +131,49 @@ impl ModelMaker<'_> {
         }
     }
 
+    // 遺伝子ごとのモデルを最大 training_max_concurrency 個のスレッドで並列に作る（結果は params_list の順）
+    pub fn make_new_models_parallel(
+        &self,
+        model_no: i32,
+        params_list: &[FeatureParams],
+    ) -> MyResult<Vec<Vec<ForecastModel>>> {
+        let workers = self
+            .config
+            .training_max_concurrency
+            .clamp(1, params_list.len().max(1));
+        // 空いたスレッドから次の遺伝子を取り出して学習する
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<(usize, MyResult<Vec<ForecastModel>>)> = thread::scope(|s| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut done = vec![];
+                        loop {
+                            let i = next.fetch_add(1, Ordering::SeqCst);
+                            if i >= params_list.len() {
+                                break;
+                            }
+                            done.push((i, self.make_new_models(model_no, &params_list[i])));
+                        }
+                        done
+                    })
+                })
+                .collect();
+
+            let mut results = vec![];
+            for h in handles {
+                match h.join() {
+                    Ok(done) => results.extend(done),
+                    Err(err) => panic::resume_unwind(err),
+                }
+            }
+            results
+        });
+        results.sort_by_key(|(i, _)| *i);
+
+        results.into_iter().map(|(_, r)| r).collect()
+    }
+
     pub fn make_new_models(
         &self,
         model_no: i32,
@@ -115,17 +181,26 @@ impl ModelMaker<'_> {
     ) -> MyResult<Vec<ForecastModel>> {
         let mut models: Vec<ForecastModel> = vec![];
 
-        let train_x = convert_to_features(self.train_x, params)?;
-        let test_x = convert_to_features(self.test_x, params)?;
+        // 尺度の変換は学習データから求め、特徴量の設定に含めて予想時にも同じ変換をする
+        let mut params = params.clone();
+        params.scaler = None;
+        let mut train_x = convert_to_features(self.train_x, &params)?;
+        if let Some(scaler) = FeatureScaler::fit(&self.config.training_feature_scaling, &train_x)? {
+            train_x = train_x
+                .iter()
+                .map(|f| scaler.transform(f))
+                .collect::<MyResult<Vec<FeatureData>>>()?;
+            params.scaler = Some(scaler);
+        }
+        let test_x = convert_to_features(self.test_x, &params)?;
 
         debug!("training RandomForest ...");
-        match self.make_random_forest(
+        match self.make_and_validate(
+            Self::make_random_forest,
             model_no,
             &params,
             &train_x,
-            &self.train_y,
             &test_x,
-            &self.test_y,
         ) {
             Ok(m) => {
                 models.push(m);
@@ -136,14 +211,7 @@ impl ModelMaker<'_> {
         }
 
         debug!("training KNN ...");
-        match self.make_knn(
-            model_no,
-            &params,
-            &train_x,
-            &self.train_y,
-            &test_x,
-            &self.test_y,
-        ) {
+        match self.make_and_validate(Self::make_knn, model_no, &params, &train_x, &test_x) {
             Ok(m) => {
                 models.push(m);
             }
@@ -153,14 +221,7 @@ impl ModelMaker<'_> {
         }
 
         debug!("training Linear ...");
-        match self.make_linear(
-            model_no,
-            &params,
-            &train_x,
-            &self.train_y,
-            &test_x,
-            &self.test_y,
-        ) {
+        match self.make_and_validate(Self::make_linear, model_no, &params, &train_x, &test_x) {
             Ok(m) => {
                 models.push(m);
             }
@@ -170,14 +231,7 @@ impl ModelMaker<'_> {
         }
 
         debug!("training Ridge ...");
-        match self.make_ridge(
-            model_no,
-            &params,
-            &train_x,
-            &self.train_y,
-            &test_x,
-            &self.test_y,
-        ) {
+        match self.make_and_validate(Self::make_ridge, model_no, &params, &train_x, &test_x) {
             Ok(m) => {
                 models.push(m);
             }
@@ -187,14 +241,7 @@ impl ModelMaker<'_> {
         }
 
         debug!("training LASSO ...");
-        match self.make_lasso(
-            model_no,
-            &params,
-            &train_x,
-            &self.train_y,
-            &test_x,
-            &self.test_y,
-        ) {
+        match self.make_and_validate(Self::make_lasso, model_no, &params, &train_x, &test_x) {
             Ok(m) => {
                 models.push(m);
             }
@@ -204,14 +251,7 @@ impl ModelMaker<'_> {
         }
 
         debug!("training ElasticNet ...");
-        match self.make_elastic_net(
-            model_no,
-            &params,
-            &train_x,
-            &self.train_y,
-            &test_x,
-            &self.test_y,
-        ) {
+        match self.make_and_validate(Self::make_elastic_net, model_no, &params, &train_x, &test_x) {
             Ok(m) => {
                 models.push(m);
             }
@@ -221,19 +261,53 @@ impl ModelMaker<'_> {
         }
 
         debug!("training SVR ...");
-        match self.make_svr(
+        match self.make_and_validate(Self::make_svr, model_no, &params, &train_x, &test_x) {
+            Ok(m) => {
+                models.push(m);
+            }
+            Err(err) => {
+                warn!("training skip SVR, error occured. error:{}", err);
+            }
+        }
+
+        debug!("training Quantile ...");
+        match self.make_and_validate(Self::make_quantile, model_no, &params, &train_x, &test_x) {
+            Ok(m) => {
+                models.push(m);
+            }
+            Err(err) => {
+                warn!("training skip Quantile, error occured. error:{}", err);
+            }
+        }
+
+        debug!("training GradientBoosting ...");
+        match self.make_and_validate(
+            Self::make_gradient_boosting,
             model_no,
             &params,
             &train_x,
-            &self.train_y,
             &test_x,
-            &self.test_y,
         ) {
             Ok(m) => {
                 models.push(m);
             }
             Err(err) => {
-                warn!("training skip SVR, error occured. error:{}", err);
+                warn!(
+                    "training skip GradientBoosting, error occured. error:{}",
+                    err
+                );
+            }
+        }
+
+        if self.config.training_ensemble_size >= 2 && models.len() >= 2 {
+            debug!("training Ensemble ...");
+            match self.make_ensemble(model_no, &params, &models, &test_x, &self.test_y) {
+                Ok(m) => {
+                    models.push(m);
+                }
+                Err(err) => {
+                    warn!("training skip Ensemble, error occured. error:{}", err);
+                }
             }
         }
 
@@ -251,7 +325,7 @@ impl ModelMaker<'_> {
     ) -> MyResult<ForecastModel> {
         let matrix = DenseMatrix::from_2d_vec(&train_x);
         let mut m = ForecastModel::RandomForest {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: RandomForestRegressor::fit(&matrix, &train_y, Default::default())?,
             input_data_size: self.config.forecast_input_size,
@@ -282,7 +356,7 @@ impl ModelMaker<'_> {
             KNNRegressorParameters::default().with_distance(Distances::euclidian()),
         )?;
         let mut m = ForecastModel::KNN {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -309,7 +383,7 @@ impl ModelMaker<'_> {
         let matrix = DenseMatrix::from_2d_vec(&train_x);
         let r = LinearRegression::fit(&matrix, &train_y, Default::default())?;
         let mut m = ForecastModel::Linear {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -340,7 +414,7 @@ impl ModelMaker<'_> {
             RidgeRegressionParameters::default().with_alpha(0.5),
         )?;
         let mut m = ForecastModel::Ridge {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -371,7 +445,7 @@ impl ModelMaker<'_> {
             LassoParameters::default().with_alpha(0.5),
         )?;
         let mut m = ForecastModel::LASSO {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -404,7 +478,7 @@ impl ModelMaker<'_> {
                 .with_l1_ratio(0.5),
         )?;
         let mut m = ForecastModel::ElasticNet {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -438,7 +512,7 @@ impl ModelMaker<'_> {
                 .with_eps(10.0),
         )?;
         let mut m = ForecastModel::SVR {
-            pair: self.config.currency_pair.clone(),
+            pair: self.pair.to_string(),
             no: model_no,
             model: r,
             input_data_size: self.config.forecast_input_size,
@@ -452,4 +526,153 @@ impl ModelMaker<'_> {
 
         Ok(m)
     }
+
+    // 10%, 50%, 90% の分位点を予想するモデル（中央値を予想値とする）
+    fn make_quantile(
+        &self,
+        model_no: i32,
+        params: &FeatureParams,
+        train_x: &Vec<FeatureData>,
+        train_y: &Vec<f64>,
+        test_x: &Vec<FeatureData>,
+        test_y: &Vec<f64>,
+    ) -> MyResult<ForecastModel> {
+        let r = QuantileRegression::fit(&train_x, &train_y, &Default::default())?;
+        let mut m = ForecastModel::Quantile {
+            pair: self.pair.to_string(),
+            no: model_no,
+            model: r,
+            input_data_size: self.config.forecast_input_size,
+            feature_params: params.clone(),
+            performance_mse: Self::PERFORMANCE_MSE_DEFAULT,
+            performance_rmse: Self::PERFORMANCE_RMSE_DEFAULT,
+            memo: "Quantile".to_string(),
+        };
+
+        m.update_performance(test_x, test_y)?;
+
+        Ok(m)
+    }
+
+    // 決定木を残差に順に当てはめるモデル
+    fn make_gradient_boosting(
+        &self,
+        model_no: i32,
+        params: &FeatureParams,
+        train_x: &Vec<FeatureData>,
+        train_y: &Vec<f64>,
+        test_x: &Vec<FeatureData>,
+        test_y: &Vec<f64>,
+    ) -> MyResult<ForecastModel> {
+        let r = GradientBoostingRegressor::fit(&train_x, &train_y, &Default::default())?;
+        let mut m = ForecastModel::GradientBoosting {
+            pair: self.pair.to_string(),
+            no: model_no,
+            model: r,
+            input_data_size: self.config.forecast_input_size,
+            feature_params: params.clone(),
+            performance_mse: Self::PERFORMANCE_MSE_DEFAULT,
+            performance_rmse: Self::PERFORMANCE_RMSE_DEFAULT,
+            memo: "GradientBoosting".to_string(),
+        };
+
+        m.update_performance(test_x, test_y)?;
+
+        Ok(m)
+    }
+    // テストデータで評価したモデルを作る
+    // cross_validation_folds が2以上の場合は学習データ内の期間でも学習・評価し、MSEの平均をモデルの性能とする
+    fn make_and_validate<F>(
+        &self,
+        make: F,
+        model_no: i32,
+        params: &FeatureParams,
+        train_x: &Vec<FeatureData>,
+        test_x: &Vec<FeatureData>,
+    ) -> MyResult<ForecastModel>
+    where
+        F: Fn(
+            &Self,
+            i32,
+            &FeatureParams,
+            &Vec<FeatureData>,
+            &Vec<f64>,
+            &Vec<FeatureData>,
+            &Vec<f64>,
+        ) -> MyResult<ForecastModel>,
+    {
+        let mut m = make(
+            self,
+            model_no,
+            params,
+            train_x,
+            self.train_y,
+            test_x,
+            self.test_y,
+        )?;
+        if self.config.cross_validation_folds >= 2 {
+            let mse = cross_validation::cross_validate(
+                train_x,
+                self.train_y,
+                m.get_performance_mse(),
+                self.config.cross_validation_folds,
+                |fold_train_x, fold_train_y, fold_test_x, fold_test_y| {
+                    make(
+                        self,
+                        model_no,
+                        params,
+                        fold_train_x,
+                        fold_train_y,
+                        fold_test_x,
+                        fold_test_y,
+                    )
+                },
+            )?;
+            m.set_performance_mse(mse)?;
+        }
+        Ok(m)
+    }
+
+    // テストデータでのMSEが小さい順に training_ensemble_size 個のモデルを組み合わせる
+    // 元のモデルも候補に残すため、組み合わせるモデルは書き出し形式を経由して複製する
+    // アンサンブル自体はテストデータでのみ評価する（交差検証の分割ごとに組み合わせ直すと学習の時間が倍以上になるため）
+    fn make_ensemble(
+        &self,
+        model_no: i32,
+        params: &FeatureParams,
+        models: &[ForecastModel],
+        test_x: &Vec<FeatureData>,
+        test_y: &Vec<f64>,
+    ) -> MyResult<ForecastModel> {
+        let mut sorted: Vec<&ForecastModel> = models.iter().collect();
+        sorted.sort_by(|a, b| a.get_performance_mse().total_cmp(&b.get_performance_mse()));
+        let members = sorted
+            .into_iter()
+            .take(self.config.training_ensemble_size)
+            .map(|m| ForecastModelBlob::from_domain(m)?.to_domain())
+            .collect::<MyResult<Vec<ForecastModel>>>()?;
+        let memo = format!(
+            "Ensemble({})",
+            members
+                .iter()
+                .map(|m| m.get_memo())
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+
+        let mut m = ForecastModel::Ensemble {
+            pair: self.pair.to_string(),
+            no: model_no,
+            model: EnsembleModel::new(members, &self.config.training_ensemble_weighting)?,
+            input_data_size: self.config.forecast_input_size,
+            feature_params: params.clone(),
+            performance_mse: Self::PERFORMANCE_MSE_DEFAULT,
+            performance_rmse: Self::PERFORMANCE_RMSE_DEFAULT,
+            memo,
+        };
+
+        m.update_performance(test_x, test_y)?;
+
+        Ok(m)
+    }
 }
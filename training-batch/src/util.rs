@@ -1,97 +1,103 @@
 use chrono::NaiveDateTime;
 use common_lib::{
-    domain::model::InputData,
+    domain::{anomaly, model::InputData, series},
     error::MyResult,
     mysql::client::{Client, DefaultClient},
 };
 use log::debug;
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::config;
 
+// 異常なレートを除いて1分間隔に並べ、短い欠損は直前のレートで埋めて入力データを作る
+// 埋められない長い欠損をまたぐ入力データは作らない
 pub fn load_input_data(
     config: &config::Config,
     mysql_cli: &DefaultClient,
+    pair: &str,
     begin: NaiveDateTime,
     end: NaiveDateTime,
 ) -> MyResult<(Vec<InputData>, Vec<f64>)> {
-    let mut x: Vec<InputData> = vec![];
-    let mut y: Vec<f64> = vec![];
-
-    mysql_cli.with_transaction(|tx| -> MyResult<()> {
+    let (rates, anomalies) = mysql_cli.with_transaction(|tx| {
         debug!("fetch rates. begin:{}, end:{}", begin, end);
+        let rates = mysql_cli.select_rates_for_training(tx, pair, Some(begin), Some(end))?;
+        let anomalies = if config.training_exclude_anomalies {
+            mysql_cli.select_rate_anomalies(tx, pair, Some(begin), Some(end))?
+        } else {
+            vec![]
+        };
+        Ok((rates, anomalies))
+    })?;
+    debug!(
+        "fetched rates count: {}, anomalies count: {}",
+        rates.len(),
+        anomalies.len()
+    );
+    // 異常なレートは欠損として扱う
+    let rates = anomaly::exclude_anomalies(rates, &anomalies);
 
-        let rates = mysql_cli.select_rates_for_training(
-            tx,
-            &config.currency_pair,
-            Some(begin),
-            Some(end),
-        )?;
-        debug!("fetched rates count: {}", rates.len());
+    let mut points = series::resample_minutely(&rates);
+    series::fill_gaps(
+        &mut points,
+        &config.training_gap_fill_method,
+        config.training_fill_gap_minutes,
+    )?;
+    let gaps = series::detect_gaps(pair, &points, 1);
+    if !gaps.is_empty() {
+        debug!("skip large gaps in rates, count: {}", gaps.len());
+    }
 
-        for offset in 0..rates.len() {
-            // 似たようなデータを減らすために期間を空ける
-            if offset % 10 > 0 {
-                continue;
-            }
+    let mut x: Vec<InputData> = vec![];
+    let mut y: Vec<f64> = vec![];
+    for rates in series::continuous_segments(&points) {
+        append_input_data(config, &rates, &mut x, &mut y);
+    }
+    Ok((x, y))
+}
 
-            let truth =
-                rates.get(offset + config.forecast_input_size - 1 + config.forecast_offset_minutes);
-            if truth.is_none() {
-                break;
-            }
+fn append_input_data(
+    config: &config::Config,
+    rates: &[f64],
+    x: &mut Vec<InputData>,
+    y: &mut Vec<f64>,
+) {
+    for offset in 0..rates.len() {
+        // 似たようなデータを減らすために期間を空ける
+        if offset % 10 > 0 {
+            continue;
+        }
 
-            let mut before: f64 = 0.0;
-            let mut same_count = 0;
-            let mut data: Vec<f64> = vec![];
-            for index in offset..offset + config.forecast_input_size {
-                data.push(rates[index].rate.clone());
-                if rates[index].rate == before {
-                    same_count += 1;
-                }
-                before = rates[index].rate.clone();
-            }
+        let truth =
+            rates.get(offset + config.forecast_input_size - 1 + config.forecast_offset_minutes);
+        if truth.is_none() {
+            break;
+        }
 
-            // 長期間変動がないデータは学習データとしては不適切なのでスキップ
-            if same_count > (data.len() / 2) {
-                continue;
+        let mut before: f64 = 0.0;
+        let mut same_count = 0;
+        let mut data: Vec<f64> = vec![];
+        for rate in &rates[offset..offset + config.forecast_input_size] {
+            data.push(*rate);
+            if *rate == before {
+                same_count += 1;
             }
+            before = *rate;
+        }
 
-            x.push(data);
-            y.push(truth.unwrap().rate);
+        // 長期間変動がないデータは学習データとしては不適切なのでスキップ
+        if same_count > (data.len() / 2) {
+            continue;
         }
 
-        Ok(())
-    })?;
-    Ok((x, y))
+        x.push(data);
+        y.push(*truth.unwrap());
+    }
 }
 
-// pub fn train_test_split(
-//     x: &Vec<InputData>,
-//     y: &Vec<f64>,
-//     test_ratio: f32,
-// ) -> MyResult<(Vec<InputData>, Vec<InputData>, Vec<f64>, Vec<f64>)> {
-//     let mut test_indexes = HashSet::new();
-//     let mut rng = rand::thread_rng();
-//
-//     for i in 0..x.len() {
-//         if rng.gen::<f32>() <= test_ratio {
-//             test_indexes.insert(i);
-//         }
-//     }
-//
-//     let mut train_x = vec![];
-//     let mut train_y = vec![];
-//     let mut test_x = vec![];
-//     let mut test_y = vec![];
-//     for i in 0..x.len() {
-//         if test_indexes.contains(&i) {
-//             test_x.push(x[i].clone());
-//             test_y.push(y[i]);
-//         } else {
-//             train_x.push(x[i].clone());
-//             train_y.push(y[i]);
-//         }
-//     }
-//
-//     Ok((train_x, test_x, train_y, test_y))
-// }
+// 学習で使う乱数生成器（シードが指定されていない場合は毎回異なる）
+pub fn make_rng(config: &config::Config) -> StdRng {
+    match config.random_seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}